@@ -0,0 +1,182 @@
+//! Admin endpoints (admin role only).
+//!
+//! - `GET /api/admin/users` - all users with their roles and workout counts
+//! - `GET /api/admin/stats` - system-wide totals from the workout tables
+
+use axum::{
+    extract::State,
+    middleware,
+    routing::get,
+    Json, Router,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::error::{ApiError, ApiResult, QueryParams};
+use crate::response::{Data, ItemList, PageCursor, PageParams, Paged};
+use crate::roles::{self, Role};
+use crate::user_store::{self, parse_roles};
+use crate::workout_store;
+use crate::AppState;
+
+/// Routes for `/api/admin`, guarded by the admin role.
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/api/admin/users", get(list_users))
+        .route("/api/admin/stats", get(get_stats))
+        .route_layer(middleware::from_fn_with_state(Role::Admin, roles::require_role))
+}
+
+/// A user in the admin user list.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AdminUser {
+    pub id: Uuid,
+    pub clerk_id: String,
+    pub email: Option<String>,
+    pub roles: Vec<Role>,
+    pub created_at: DateTime<Utc>,
+    pub workout_count: i64,
+}
+
+impl Paged for AdminUser {
+    fn cursor(&self) -> PageCursor {
+        PageCursor {
+            at: self.created_at,
+            id: self.id,
+        }
+    }
+}
+
+/// System-wide totals.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Stats {
+    pub total_users: i64,
+    pub total_workouts: i64,
+    pub total_sets: i64,
+    pub completed_sets: i64,
+    pub active_today: i64,
+}
+
+/// GET /api/admin/users - one page of users, oldest first.
+async fn list_users(
+    State(state): State<AppState>,
+    QueryParams(page): QueryParams<PageParams>,
+) -> ApiResult<Json<Data<ItemList<AdminUser>>>> {
+    let page = page.resolve().map_err(ApiError::BadRequest)?;
+    let (users, start_index, total) = user_store::list_users(&state.db, &page).await?;
+
+    let users = users
+        .into_iter()
+        .map(|user| {
+            Ok(AdminUser {
+                id: user.id,
+                clerk_id: user.clerk_id,
+                email: user.email,
+                roles: parse_roles(user.roles)?,
+                created_at: user.created_at,
+                workout_count: user.workout_count,
+            })
+        })
+        .collect::<Result<Vec<_>, sqlx::Error>>()?;
+
+    Ok(Json(Data::new(ItemList::page(users, &page, start_index, total))))
+}
+
+/// GET /api/admin/stats
+async fn get_stats(State(state): State<AppState>) -> ApiResult<Json<Data<Stats>>> {
+    let mut conn = state.db.acquire().await?;
+    let stats = workout_store::workout_stats(&mut conn).await?;
+    Ok(Json(Data::new(Stats {
+        total_users: stats.total_users,
+        total_workouts: stats.total_workouts,
+        total_sets: stats.total_sets,
+        completed_sets: stats.completed_sets,
+        active_today: stats.active_today,
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::{grant_role, send, token};
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use shared::{SetActual, Workout};
+    use sqlx::PgPool;
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_admin_routes_require_the_admin_role(pool: PgPool) {
+        let trainer = token("user_trainer");
+        send(&pool, "GET", "/api/me", Some(&trainer), None).await;
+        grant_role(&pool, "user_trainer", "trainer").await;
+
+        for uri in ["/api/admin/users", "/api/admin/stats"] {
+            let (status, body) = send(&pool, "GET", uri, Some(&trainer), None).await;
+            assert_eq!(status, StatusCode::FORBIDDEN);
+            assert_eq!(body["error"]["errors"][0]["reason"], "forbidden");
+            let (status, _) = send(&pool, "GET", uri, None, None).await;
+            assert_eq!(status, StatusCode::UNAUTHORIZED);
+        }
+
+        let (_, me) = send(&pool, "GET", "/api/me", Some(&trainer), None).await;
+        assert_eq!(me["data"]["roles"], json!(["trainer", "user"]));
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_admin_lists_users_and_stats(pool: PgPool) {
+        let admin = token("user_admin");
+        send(&pool, "GET", "/api/me", Some(&admin), None).await;
+        grant_role(&pool, "user_admin", "admin").await;
+
+        let lifter = token("user_lifter");
+        let mut today = Workout::with_name("Legs");
+        today.add_exercise("Squat").add_set().complete(SetActual::with_weight_and_reps(225.0, 5));
+        today.exercises[0].add_set();
+        let mut old = Workout::with_name("Arms");
+        old.start_timestamp = "2024-01-01T08:00:00Z".parse().unwrap();
+        old.add_exercise("Curl").add_set().complete(SetActual::with_weight_and_reps(30.0, 10));
+        for workout in [&today, &old] {
+            let (status, _) = send(&pool, "POST", "/api/workouts", Some(&lifter), Some(json!(workout))).await;
+            assert_eq!(status, StatusCode::CREATED);
+        }
+
+        let (status, body) = send(&pool, "GET", "/api/admin/users", Some(&admin), None).await;
+        assert_eq!(status, StatusCode::OK);
+        let users = &body["data"];
+        assert_eq!(users["totalItems"], 2);
+        assert_eq!(users["items"][0]["clerkId"], "user_admin");
+        assert_eq!(users["items"][0]["roles"], json!(["admin", "user"]));
+        assert_eq!(users["items"][1]["clerkId"], "user_lifter");
+        assert_eq!(users["items"][1]["roles"], json!(["user"]));
+        assert_eq!(users["items"][1]["workoutCount"], 2);
+
+        let (_, page) = send(&pool, "GET", "/api/admin/users?itemsPerPage=1&startIndex=1", Some(&admin), None).await;
+        assert_eq!(page["data"]["currentItemCount"], 1);
+        assert_eq!(page["data"]["items"][0]["clerkId"], "user_lifter");
+
+        let (_, first) = send(&pool, "GET", "/api/admin/users?itemsPerPage=1", Some(&admin), None).await;
+        let token = first["data"]["nextPageToken"].as_str().unwrap();
+        let uri = format!("/api/admin/users?itemsPerPage=1&pageToken={}", token);
+        let (_, next) = send(&pool, "GET", &uri, Some(&admin), None).await;
+        assert_eq!(next["data"]["startIndex"], 1);
+        assert_eq!(next["data"]["items"][0]["clerkId"], "user_lifter");
+        assert!(next["data"].get("nextPageToken").is_none());
+
+        let (status, body) = send(&pool, "GET", "/api/admin/stats", Some(&admin), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            body["data"],
+            json!({
+                "totalUsers": 2,
+                "totalWorkouts": 2,
+                "totalSets": 3,
+                "completedSets": 2,
+                "activeToday": 1,
+            })
+        );
+    }
+}
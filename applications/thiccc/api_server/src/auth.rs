@@ -0,0 +1,585 @@
+//! Clerk JWT authentication.
+//!
+//! Clerk signs session tokens with RS256 and publishes its public keys as a
+//! JWKS. `JwtVerifier` loads that key set from a file or URL, caches it, and
+//! reloads it when it gets old or when a token names a key id it hasn't seen
+//! (Clerk rotated its keys). Reloads triggered by unknown key ids are rate
+//! limited so garbage tokens can't hammer the JWKS endpoint, and failed
+//! reloads back off while the cached keys keep being used.
+//!
+//! `require_auth` guards the `/api` routes: it verifies the bearer token,
+//! provisions the user named by its `sub` claim (see `user_store`) and hands
+//! handlers an `AuthUser`. Requests it rejects use up their IP address's
+//! public rate limit.
+
+use axum::{
+    extract::{FromRequestParts, Request, State},
+    http::{header, request::Parts},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use jsonwebtoken::{decode, decode_header, jwk::JwkSet, Algorithm, DecodingKey, Validation};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, RwLock};
+use uuid::Uuid;
+
+use crate::error::ApiError;
+use crate::roles::Role;
+use crate::user_store;
+use crate::AppState;
+
+/// How long a loaded key set is used before it is reloaded.
+pub const KEYS_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Minimum time between reloads triggered by an unknown key id.
+pub const MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Longest wait between reloads after repeated failures.
+pub const MAX_RELOAD_BACKOFF: Duration = Duration::from_secs(10 * 60);
+
+/// Time allowed for fetching a remote key set.
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+// =============================================================================
+// MARK: - Claims and Errors
+// =============================================================================
+
+/// The claims the API reads from a verified token.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct Claims {
+    /// Clerk user id (e.g. "user_2abc...")
+    pub sub: String,
+    /// Primary email, when the Clerk session token template includes it
+    pub email: Option<String>,
+    /// Origin the token was issued to (Clerk's authorized party); absent
+    /// for tokens from native apps
+    pub azp: Option<String>,
+}
+
+/// Why a request could not be authenticated.
+#[derive(Debug)]
+pub enum AuthError {
+    /// No `Authorization: Bearer` header
+    MissingToken,
+    /// The token is malformed, expired, or not signed by a known key
+    InvalidToken(String),
+    /// The key set could not be loaded
+    KeysUnavailable(String),
+    /// Looking up or creating the user failed
+    Database(sqlx::Error),
+}
+
+/// Sent as 401, 503 or 500 (see `ApiError`).
+impl IntoResponse for AuthError {
+    fn into_response(self) -> Response {
+        ApiError::from(self).into_response()
+    }
+}
+
+// =============================================================================
+// MARK: - Key Set
+// =============================================================================
+
+/// Where the JWKS is loaded from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JwksSource {
+    /// A local JSON file (offline development and tests)
+    File(PathBuf),
+    /// Clerk's JWKS endpoint, e.g. `https://<instance>.clerk.accounts.dev/.well-known/jwks.json`
+    Url(String),
+}
+
+impl JwksSource {
+    async fn load(&self, client: &reqwest::Client) -> Result<JwkSet, String> {
+        match self {
+            JwksSource::File(path) => {
+                let text = tokio::fs::read_to_string(path)
+                    .await
+                    .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+                serde_json::from_str(&text)
+                    .map_err(|e| format!("Invalid JWKS in {}: {}", path.display(), e))
+            }
+            JwksSource::Url(url) => client
+                .get(url)
+                .timeout(FETCH_TIMEOUT)
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status)
+                .map_err(|e| format!("Could not fetch {}: {}", url, e))?
+                .json()
+                .await
+                .map_err(|e| format!("Invalid JWKS from {}: {}", url, e)),
+        }
+    }
+}
+
+/// Loaded keys by key id.
+#[derive(Default)]
+struct Keys {
+    by_id: HashMap<String, DecodingKey>,
+    loaded_at: Option<Instant>,
+    /// Last load attempt, successful or not
+    attempted_at: Option<Instant>,
+    /// Failed load attempts since the last successful one
+    failures: u32,
+}
+
+impl Keys {
+    fn is_fresh(&self) -> bool {
+        self.loaded_at.is_some_and(|at| at.elapsed() < KEYS_TTL)
+    }
+
+    /// Whether another load may be attempted: `min_interval` after the last
+    /// attempt, doubling with each consecutive failure up to
+    /// `MAX_RELOAD_BACKOFF`.
+    fn may_reload(&self, min_interval: Duration) -> bool {
+        let backoff = min_interval
+            .saturating_mul(1 << self.failures.min(16))
+            .min(MAX_RELOAD_BACKOFF);
+        self.attempted_at.is_none_or(|at| at.elapsed() >= backoff)
+    }
+
+    /// Whether cached keys may be used: they're fresh, or they're stale but a
+    /// reload was just attempted (and failed).
+    fn usable(&self, min_interval: Duration) -> bool {
+        self.is_fresh() || !self.may_reload(min_interval)
+    }
+}
+
+/// Verifies Clerk session tokens against a cached JWKS.
+///
+/// Cheap to clone; clones share the cache.
+#[derive(Clone)]
+pub struct JwtVerifier {
+    inner: Arc<Inner>,
+    /// Origins a token's `azp` must be one of (unchecked when None)
+    authorized_parties: Option<Arc<[String]>>,
+}
+
+struct Inner {
+    source: JwksSource,
+    issuer: Option<String>,
+    min_refresh_interval: Duration,
+    client: reqwest::Client,
+    keys: RwLock<Keys>,
+    /// Held while loading so concurrent misses trigger one load
+    loading: Mutex<()>,
+}
+
+impl JwtVerifier {
+    /// Creates a verifier. Keys are loaded on first use.
+    ///
+    /// When `issuer` is set, tokens must carry it as their `iss` claim.
+    pub fn new(source: JwksSource, issuer: Option<String>) -> Self {
+        Self::with_min_refresh_interval(source, issuer, MIN_REFRESH_INTERVAL)
+    }
+
+    /// Creates a verifier with a custom rate limit on unknown-key reloads.
+    pub fn with_min_refresh_interval(
+        source: JwksSource,
+        issuer: Option<String>,
+        min_refresh_interval: Duration,
+    ) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                source,
+                issuer,
+                min_refresh_interval,
+                client: reqwest::Client::new(),
+                keys: RwLock::new(Keys::default()),
+                loading: Mutex::new(()),
+            }),
+            authorized_parties: None,
+        }
+    }
+
+    /// Only accepts tokens issued to one of `origins` (their `azp` claim).
+    ///
+    /// Clerk sets `azp` to the origin of the page that requested the token,
+    /// so this keeps out tokens minted for other sites on the same Clerk
+    /// instance. Tokens without `azp` (native apps) are still accepted.
+    pub fn with_authorized_parties(mut self, origins: Vec<String>) -> Self {
+        self.authorized_parties = Some(origins.into());
+        self
+    }
+
+    /// Verifies a token's signature, expiry, issuer and authorized party and
+    /// returns its claims.
+    pub async fn verify(&self, token: &str) -> Result<Claims, AuthError> {
+        let header = decode_header(token).map_err(|e| AuthError::InvalidToken(e.to_string()))?;
+        if header.alg != Algorithm::RS256 {
+            return Err(AuthError::InvalidToken(format!(
+                "unsupported algorithm {:?}",
+                header.alg
+            )));
+        }
+        let kid = header
+            .kid
+            .ok_or_else(|| AuthError::InvalidToken("missing key id".to_string()))?;
+        let key = self.key(&kid).await?;
+
+        let mut validation = Validation::new(Algorithm::RS256);
+        validation.set_required_spec_claims(&["exp", "sub"]);
+        // Clerk session tokens have no audience; `azp` is the origin instead
+        validation.validate_aud = false;
+        if let Some(issuer) = &self.inner.issuer {
+            validation.set_issuer(&[issuer]);
+        }
+
+        let claims = decode::<Claims>(token, &key, &validation)
+            .map(|data| data.claims)
+            .map_err(|e| AuthError::InvalidToken(e.to_string()))?;
+
+        match (&self.authorized_parties, &claims.azp) {
+            (Some(parties), Some(azp)) if !parties.contains(azp) => {
+                Err(AuthError::InvalidToken(format!("unauthorized party {}", azp)))
+            }
+            _ => Ok(claims),
+        }
+    }
+
+    /// Returns the key for `kid`, reloading the key set if it is stale or
+    /// doesn't have the key.
+    ///
+    /// While a failed reload backs off, requests use the cached keys without
+    /// waiting on the loading lock.
+    async fn key(&self, kid: &str) -> Result<DecodingKey, AuthError> {
+        let min_interval = self.inner.min_refresh_interval;
+        {
+            let keys = self.inner.keys.read().await;
+            if let Some(key) = keys.by_id.get(kid).filter(|_| keys.usable(min_interval)) {
+                return Ok(key.clone());
+            }
+        }
+
+        let _loading = self.inner.loading.lock().await;
+        let keys = self.inner.keys.read().await;
+        // Another request may have reloaded while we waited
+        if (keys.usable(min_interval) && keys.by_id.contains_key(kid))
+            || !keys.may_reload(min_interval)
+        {
+            if keys.by_id.is_empty() {
+                return Err(AuthError::KeysUnavailable(
+                    "signing keys could not be loaded; retrying later".to_string(),
+                ));
+            }
+            return keys
+                .by_id
+                .get(kid)
+                .cloned()
+                .ok_or_else(|| AuthError::InvalidToken(format!("unknown key id {}", kid)));
+        }
+        drop(keys);
+
+        let loaded = self.inner.source.load(&self.inner.client).await;
+        let mut keys = self.inner.keys.write().await;
+        keys.attempted_at = Some(Instant::now());
+        keys.failures = if loaded.is_ok() { 0 } else { keys.failures + 1 };
+        match loaded {
+            Ok(set) => {
+                keys.by_id = set
+                    .keys
+                    .iter()
+                    .filter_map(|jwk| {
+                        let kid = jwk.common.key_id.clone()?;
+                        DecodingKey::from_jwk(jwk).ok().map(|key| (kid, key))
+                    })
+                    .collect();
+                keys.loaded_at = Some(Instant::now());
+                tracing::info!("Loaded {} signing keys", keys.by_id.len());
+            }
+            // Keep using the old keys rather than locking everyone out
+            Err(reason) if !keys.by_id.is_empty() => {
+                tracing::warn!("Keeping cached signing keys: {}", reason);
+            }
+            Err(reason) => return Err(AuthError::KeysUnavailable(reason)),
+        }
+
+        keys.by_id
+            .get(kid)
+            .cloned()
+            .ok_or_else(|| AuthError::InvalidToken(format!("unknown key id {}", kid)))
+    }
+}
+
+// =============================================================================
+// MARK: - Extractor
+// =============================================================================
+
+/// The authenticated user making a request.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuthUser {
+    /// Row id in `users`
+    pub id: Uuid,
+    /// Clerk user id (the token's `sub`)
+    pub clerk_id: String,
+    /// Latest email seen in the user's tokens
+    pub email: Option<String>,
+    /// When the user first made an authenticated request
+    pub created_at: DateTime<Utc>,
+    /// The user's roles, highest first (always includes `Role::User`)
+    pub roles: Vec<Role>,
+}
+
+impl AuthUser {
+    /// Whether one of the user's roles grants `required`.
+    pub fn has_role(&self, required: Role) -> bool {
+        self.roles.iter().any(|role| role.grants(required))
+    }
+}
+
+impl FromRequestParts<AppState> for AuthUser {
+    type Rejection = AuthError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, AuthError> {
+        if let Some(user) = parts.extensions.get::<AuthUser>() {
+            return Ok(user.clone());
+        }
+
+        let token = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .or_else(|| websocket_token(parts))
+            .ok_or(AuthError::MissingToken)?;
+        let claims = state.auth.verify(token.trim()).await?;
+
+        let user = user_store::provision_user(&state.db, &claims.sub, claims.email.as_deref())
+            .await
+            .map_err(AuthError::Database)?;
+        let roles = user_store::fetch_roles(&state.db, user.id)
+            .await
+            .map_err(AuthError::Database)?;
+        let user = AuthUser {
+            id: user.id,
+            clerk_id: user.clerk_id,
+            email: user.email,
+            created_at: user.created_at,
+            roles,
+        };
+        parts.extensions.insert(user.clone());
+        Ok(user)
+    }
+}
+
+/// The `access_token` query parameter of a WebSocket upgrade request.
+///
+/// Browsers can't set headers on WebSocket requests, so the token travels in
+/// the URL there (RFC 6750 section 2.3). Other requests must use the header.
+/// Request logs record only the path so the token isn't written to them.
+fn websocket_token(parts: &Parts) -> Option<&str> {
+    let upgrade = parts.headers.get(header::UPGRADE)?.to_str().ok()?;
+    if !upgrade.eq_ignore_ascii_case("websocket") {
+        return None;
+    }
+    parts
+        .uri
+        .query()?
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("access_token="))
+}
+
+/// Middleware that rejects requests without a valid token.
+///
+/// The `AuthUser` is kept in the request extensions so handlers extracting
+/// it again don't re-verify the token. Rejected requests count against the
+/// client's IP address (see `rate_limit`).
+pub async fn require_auth(
+    State(state): State<AppState>,
+    user: Result<AuthUser, AuthError>,
+    request: Request,
+    next: Next,
+) -> Response {
+    match user {
+        Ok(_) => next.run(request).await,
+        Err(error) => state.limits.charge_rejected(&request, error.into_response()),
+    }
+}
+
+// =============================================================================
+// MARK: - Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{fixture, sign_token, test_verifier, token};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_verifies_tokens_signed_by_a_known_key() {
+        let verifier = test_verifier();
+
+        let claims = verifier.verify(&token("user_123")).await.unwrap();
+        assert_eq!(claims.sub, "user_123");
+        assert_eq!(claims.email.as_deref(), Some("user_123@example.com"));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_expired_foreign_and_malformed_tokens() {
+        let verifier = test_verifier();
+        let now = jsonwebtoken::get_current_timestamp();
+
+        let expired = sign_token(1, json!({ "sub": "user_1", "exp": now - 3600 }));
+        assert!(matches!(
+            verifier.verify(&expired).await,
+            Err(AuthError::InvalidToken(reason)) if reason.contains("Expired")
+        ));
+
+        // Signed by a key that isn't in the key set
+        let foreign = sign_token(2, json!({ "sub": "user_1", "exp": now + 3600 }));
+        assert!(matches!(
+            verifier.verify(&foreign).await,
+            Err(AuthError::InvalidToken(reason)) if reason.contains("unknown key id")
+        ));
+
+        let no_sub = sign_token(1, json!({ "exp": now + 3600 }));
+        assert!(verifier.verify(&no_sub).await.is_err());
+        assert!(verifier.verify("not.a.token").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_checks_issuer_when_configured() {
+        let source = JwksSource::File(fixture("jwks.json"));
+        let verifier = JwtVerifier::new(source, Some("https://clerk.thiccc.app".to_string()));
+        let now = jsonwebtoken::get_current_timestamp();
+
+        let other = sign_token(1, json!({ "sub": "u", "exp": now + 60, "iss": "https://evil.dev" }));
+        assert!(verifier.verify(&other).await.is_err());
+        let ours = sign_token(1, json!({ "sub": "u", "exp": now + 60, "iss": "https://clerk.thiccc.app" }));
+        assert!(verifier.verify(&ours).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_checks_authorized_party_when_configured() {
+        let verifier = test_verifier().with_authorized_parties(vec!["https://thiccc.app".to_string()]);
+        let now = jsonwebtoken::get_current_timestamp();
+
+        let other = sign_token(1, json!({ "sub": "u", "exp": now + 60, "azp": "https://evil.dev" }));
+        assert!(matches!(
+            verifier.verify(&other).await,
+            Err(AuthError::InvalidToken(reason)) if reason.contains("unauthorized party")
+        ));
+        let ours = sign_token(1, json!({ "sub": "u", "exp": now + 60, "azp": "https://thiccc.app" }));
+        assert!(verifier.verify(&ours).await.is_ok());
+        // Native apps get tokens without `azp`
+        assert!(verifier.verify(&token("u")).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_reloads_keys_when_they_rotate() {
+        let dir = std::env::temp_dir().join(format!("thiccc-jwks-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("jwks.json");
+        std::fs::copy(fixture("jwks.json"), &path).unwrap();
+        let now = jsonwebtoken::get_current_timestamp();
+        let rotated = sign_token(2, json!({ "sub": "user_1", "exp": now + 3600 }));
+
+        let verifier = JwtVerifier::with_min_refresh_interval(
+            JwksSource::File(path.clone()),
+            None,
+            Duration::ZERO,
+        );
+        assert!(verifier.verify(&token("user_1")).await.is_ok());
+        assert!(verifier.verify(&rotated).await.is_err());
+
+        std::fs::copy(fixture("jwks-rotated.json"), &path).unwrap();
+        assert!(verifier.verify(&rotated).await.is_ok());
+
+        // A failed reload (triggered by the old key) keeps the cached keys
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(
+            verifier.verify(&token("user_1")).await,
+            Err(AuthError::InvalidToken(_))
+        ));
+        assert!(verifier.verify(&rotated).await.is_ok());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_unknown_key_reloads_are_rate_limited() {
+        let dir = std::env::temp_dir().join(format!("thiccc-jwks-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("jwks.json");
+        std::fs::copy(fixture("jwks.json"), &path).unwrap();
+        let now = jsonwebtoken::get_current_timestamp();
+        let rotated = sign_token(2, json!({ "sub": "user_1", "exp": now + 3600 }));
+
+        let verifier = JwtVerifier::new(JwksSource::File(path.clone()), None);
+        assert!(verifier.verify(&token("user_1")).await.is_ok());
+
+        // Just loaded, so the unknown key doesn't trigger another load yet
+        std::fs::copy(fixture("jwks-rotated.json"), &path).unwrap();
+        assert!(verifier.verify(&rotated).await.is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_failed_reloads_of_stale_keys_back_off() {
+        let dir = std::env::temp_dir().join(format!("thiccc-jwks-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("jwks.json");
+        std::fs::copy(fixture("jwks.json"), &path).unwrap();
+
+        let verifier = JwtVerifier::new(JwksSource::File(path.clone()), None);
+        assert!(verifier.verify(&token("user_1")).await.is_ok());
+
+        // The keys go stale while the key set is unreachable
+        {
+            let mut keys = verifier.inner.keys.write().await;
+            keys.loaded_at = None;
+            keys.attempted_at = None;
+        }
+        std::fs::remove_file(&path).unwrap();
+        assert!(verifier.verify(&token("user_1")).await.is_ok(), "stale keys are served");
+        {
+            let keys = verifier.inner.keys.read().await;
+            assert_eq!(keys.failures, 1);
+            assert!(!keys.may_reload(MIN_REFRESH_INTERVAL));
+        }
+
+        // During the backoff the stale keys are used without another load
+        std::fs::copy(fixture("jwks.json"), &path).unwrap();
+        assert!(verifier.verify(&token("user_1")).await.is_ok());
+        let keys = verifier.inner.keys.read().await;
+        assert!(keys.loaded_at.is_none(), "no reload during the backoff");
+        assert_eq!(keys.failures, 1);
+        drop(keys);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_reload_backoff_doubles_up_to_a_limit() {
+        let keys = |failures: u32, ago: Duration| Keys {
+            attempted_at: Instant::now().checked_sub(ago),
+            failures,
+            ..Keys::default()
+        };
+        let interval = Duration::from_secs(30);
+        assert!(keys(0, Duration::from_secs(31)).may_reload(interval));
+        assert!(!keys(1, Duration::from_secs(31)).may_reload(interval));
+        assert!(keys(1, Duration::from_secs(61)).may_reload(interval));
+        assert!(!keys(2, Duration::from_secs(61)).may_reload(interval));
+        assert!(keys(20, MAX_RELOAD_BACKOFF).may_reload(interval));
+    }
+
+    #[tokio::test]
+    async fn test_missing_key_set_is_unavailable() {
+        let verifier = JwtVerifier::new(JwksSource::File(fixture("missing.json")), None);
+        assert!(matches!(
+            verifier.verify(&token("user_1")).await,
+            Err(AuthError::KeysUnavailable(_))
+        ));
+        // Backing off, so the next request fails fast without a reload
+        assert!(matches!(
+            verifier.verify(&token("user_1")).await,
+            Err(AuthError::KeysUnavailable(reason)) if reason.contains("retrying later")
+        ));
+    }
+}
@@ -0,0 +1,475 @@
+//! Server configuration, checked once at startup.
+//!
+//! Settings come from the environment, optionally layered over a TOML file
+//! named by `THICCC_CONFIG` (see `config.example.toml`); an environment
+//! variable always wins over the file. Everything except the database URL
+//! and the Clerk key set has a default suitable for local development.
+//!
+//! | Variable | TOML key | Default |
+//! |----------|----------|---------|
+//! | `DATABASE_URL` | `database_url` | required |
+//! | `PORT` | `port` | `8000` |
+//! | `CORS_ALLOWED_ORIGINS` (comma-separated) | `allowed_origins` | `http://localhost:3000`, `http://localhost:3001` |
+//! | `DATABASE_MAX_CONNECTIONS` | `database_max_connections` | `5` |
+//! | `DATABASE_ACQUIRE_TIMEOUT_SECS` | `database_acquire_timeout_secs` | `30` |
+//! | `REQUEST_TIMEOUT_SECS` | `request_timeout_secs` | `30` |
+//! | `CLERK_JWKS_URL` / `CLERK_JWKS_PATH` | `clerk_jwks_url` / `clerk_jwks_path` | required (one of) |
+//! | `CLERK_JWT_ISSUER` | `clerk_jwt_issuer` | not checked |
+//! | `RATE_LIMIT_USER_PER_HOUR` | `rate_limit_user_per_hour` | `1000` |
+//! | `RATE_LIMIT_IP_PER_HOUR` | `rate_limit_ip_per_hour` | `100` |
+//! | `TRUST_FORWARDED_FOR` (`true` or `false`) | `trust_forwarded_for` | `false` |
+//! | `RUST_LOG` (directives, e.g. `info,sqlx=warn`) | `log_level` | `info` |
+//! | `LOG_FORMAT` (`full`, `compact` or `pretty`) | `log_format` | `full` |
+
+use axum::http::HeaderValue;
+use serde::Deserialize;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+use tracing_subscriber::EnvFilter;
+
+use crate::auth::JwksSource;
+use crate::rate_limit::Quota;
+
+/// Environment variable naming the optional TOML config file.
+pub const CONFIG_FILE_VAR: &str = "THICCC_CONFIG";
+
+/// Origins allowed when none are configured (the local web frontend).
+pub const DEFAULT_ALLOWED_ORIGINS: [&str; 2] = ["http://localhost:3000", "http://localhost:3001"];
+
+/// Checked server settings.
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub database_url: String,
+    pub port: u16,
+    pub cors: AllowedOrigins,
+    /// Size of the Postgres connection pool
+    pub database_max_connections: u32,
+    /// How long a request waits for a pooled connection
+    pub database_acquire_timeout: Duration,
+    /// How long a request may take before it gets 408
+    pub request_timeout: Duration,
+    pub jwks: JwksSource,
+    /// Expected `iss` claim (unchecked when None)
+    pub jwt_issuer: Option<String>,
+    /// Requests each signed-in user may make to the `/api` routes
+    pub user_quota: Quota,
+    /// Requests each IP address may make without a valid token
+    pub ip_quota: Quota,
+    /// Take client addresses from `X-Forwarded-For` (only behind a proxy
+    /// that sets it)
+    pub trust_forwarded_for: bool,
+    /// Log filter in `RUST_LOG` directive syntax (checked to parse)
+    pub log_filter: String,
+    pub log_format: LogFormat,
+}
+
+/// Which browser origins may call the API.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AllowedOrigins {
+    /// Any origin (`*`), for development only
+    Any,
+    /// Exactly these origins
+    List(Vec<HeaderValue>),
+}
+
+/// How log lines are formatted (see `tracing_subscriber::fmt`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum LogFormat {
+    /// One line per event with all fields
+    #[default]
+    Full,
+    /// One shorter line per event
+    Compact,
+    /// Multi-line, for reading locally
+    Pretty,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "full" => Ok(LogFormat::Full),
+            "compact" => Ok(LogFormat::Compact),
+            "pretty" => Ok(LogFormat::Pretty),
+            other => Err(format!("expected full, compact or pretty, got '{}'", other)),
+        }
+    }
+}
+
+/// Why the configuration couldn't be loaded.
+#[derive(Debug, PartialEq)]
+pub enum ConfigError {
+    /// A required setting is absent
+    Missing(&'static str),
+    /// A setting has a value that can't be used
+    Invalid { setting: &'static str, reason: String },
+    /// The TOML file couldn't be read or parsed
+    File { path: PathBuf, reason: String },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Missing(setting) => write!(f, "{} must be set", setting),
+            ConfigError::Invalid { setting, reason } => write!(f, "Invalid {}: {}", setting, reason),
+            ConfigError::File { path, reason } => {
+                write!(f, "Can't load config file {}: {}", path.display(), reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// The TOML config file. Every key is optional.
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct FileConfig {
+    pub database_url: Option<String>,
+    pub port: Option<u16>,
+    pub allowed_origins: Option<Vec<String>>,
+    pub database_max_connections: Option<u32>,
+    pub database_acquire_timeout_secs: Option<u64>,
+    pub request_timeout_secs: Option<u64>,
+    pub clerk_jwks_url: Option<String>,
+    pub clerk_jwks_path: Option<PathBuf>,
+    pub clerk_jwt_issuer: Option<String>,
+    pub rate_limit_user_per_hour: Option<u32>,
+    pub rate_limit_ip_per_hour: Option<u32>,
+    pub trust_forwarded_for: Option<bool>,
+    pub log_level: Option<String>,
+    pub log_format: Option<String>,
+}
+
+impl FileConfig {
+    /// Reads and parses a config file.
+    pub fn load(path: PathBuf) -> Result<Self, ConfigError> {
+        let text = std::fs::read_to_string(&path).map_err(|e| ConfigError::File {
+            path: path.clone(),
+            reason: e.to_string(),
+        })?;
+        toml::from_str(&text).map_err(|e| ConfigError::File {
+            path,
+            reason: e.to_string(),
+        })
+    }
+}
+
+impl Config {
+    /// Loads the configuration from the process environment and the file
+    /// named by `THICCC_CONFIG`, if any.
+    pub fn load() -> Result<Self, ConfigError> {
+        let file = match std::env::var_os(CONFIG_FILE_VAR) {
+            Some(path) => FileConfig::load(path.into())?,
+            None => FileConfig::default(),
+        };
+        Self::from_sources(|name| std::env::var(name).ok(), file)
+    }
+
+    /// Combines environment variables (looked up with `env`) with a config
+    /// file and checks the result.
+    pub fn from_sources(
+        env: impl Fn(&str) -> Option<String>,
+        file: FileConfig,
+    ) -> Result<Self, ConfigError> {
+        // Empty variables count as unset, like an empty line in an env file
+        let env = |name: &str| env(name).filter(|value| !value.trim().is_empty());
+
+        let database_url = env("DATABASE_URL")
+            .or(file.database_url)
+            .ok_or(ConfigError::Missing("DATABASE_URL"))?;
+        if !database_url.starts_with("postgres://") && !database_url.starts_with("postgresql://") {
+            return Err(invalid("DATABASE_URL", "expected a postgres:// URL"));
+        }
+
+        let port = parse_env(&env, "PORT")?.or(file.port).unwrap_or(8000);
+
+        let origins = match env("CORS_ALLOWED_ORIGINS") {
+            Some(list) => list.split(',').map(|origin| origin.trim().to_string()).collect(),
+            None => file.allowed_origins.unwrap_or_else(|| {
+                DEFAULT_ALLOWED_ORIGINS.iter().map(|o| o.to_string()).collect()
+            }),
+        };
+        let cors = parse_origins(origins)?;
+
+        let database_max_connections = parse_env(&env, "DATABASE_MAX_CONNECTIONS")?
+            .or(file.database_max_connections)
+            .unwrap_or(5);
+        if database_max_connections == 0 {
+            return Err(invalid("DATABASE_MAX_CONNECTIONS", "must be at least 1"));
+        }
+        let database_acquire_timeout = seconds(
+            "DATABASE_ACQUIRE_TIMEOUT_SECS",
+            parse_env(&env, "DATABASE_ACQUIRE_TIMEOUT_SECS")?.or(file.database_acquire_timeout_secs),
+        )?;
+        let request_timeout = seconds(
+            "REQUEST_TIMEOUT_SECS",
+            parse_env(&env, "REQUEST_TIMEOUT_SECS")?.or(file.request_timeout_secs),
+        )?;
+
+        // The environment wins over the file; within one source a URL wins
+        // over a path
+        let (jwks_url, jwks_path) = match (env("CLERK_JWKS_URL"), env("CLERK_JWKS_PATH")) {
+            (None, None) => (file.clerk_jwks_url, file.clerk_jwks_path),
+            (url, path) => (url, path.map(PathBuf::from)),
+        };
+        let jwks = match (jwks_url, jwks_path) {
+            (Some(url), _) if url.starts_with("https://") || url.starts_with("http://") => {
+                JwksSource::Url(url)
+            }
+            (Some(_), _) => return Err(invalid("CLERK_JWKS_URL", "expected an http(s) URL")),
+            (None, Some(path)) => JwksSource::File(path),
+            (None, None) => return Err(ConfigError::Missing("CLERK_JWKS_URL or CLERK_JWKS_PATH")),
+        };
+        let jwt_issuer = env("CLERK_JWT_ISSUER").or(file.clerk_jwt_issuer);
+
+        let user_quota = hourly_quota(
+            "RATE_LIMIT_USER_PER_HOUR",
+            parse_env(&env, "RATE_LIMIT_USER_PER_HOUR")?.or(file.rate_limit_user_per_hour),
+            1000,
+        )?;
+        let ip_quota = hourly_quota(
+            "RATE_LIMIT_IP_PER_HOUR",
+            parse_env(&env, "RATE_LIMIT_IP_PER_HOUR")?.or(file.rate_limit_ip_per_hour),
+            100,
+        )?;
+        let trust_forwarded_for = match env("TRUST_FORWARDED_FOR") {
+            Some(value) => value.trim().parse().map_err(|_| {
+                invalid("TRUST_FORWARDED_FOR", format!("expected true or false, got '{}'", value))
+            })?,
+            None => file.trust_forwarded_for.unwrap_or(false),
+        };
+
+        let log_filter = env("RUST_LOG")
+            .or(file.log_level)
+            .unwrap_or_else(|| "info".to_string());
+        if let Err(e) = EnvFilter::try_new(&log_filter) {
+            return Err(invalid("RUST_LOG", format!("'{}': {}", log_filter, e)));
+        }
+        let log_format = match env("LOG_FORMAT").or(file.log_format) {
+            Some(format) => format.parse().map_err(|e| invalid("LOG_FORMAT", e))?,
+            None => LogFormat::default(),
+        };
+
+        Ok(Config {
+            database_url,
+            port,
+            cors,
+            database_max_connections,
+            database_acquire_timeout,
+            request_timeout,
+            jwks,
+            jwt_issuer,
+            user_quota,
+            ip_quota,
+            trust_forwarded_for,
+            log_filter,
+            log_format,
+        })
+    }
+}
+
+fn invalid(setting: &'static str, reason: impl Into<String>) -> ConfigError {
+    ConfigError::Invalid {
+        setting,
+        reason: reason.into(),
+    }
+}
+
+fn parse_env<T: FromStr>(
+    env: &impl Fn(&str) -> Option<String>,
+    name: &'static str,
+) -> Result<Option<T>, ConfigError> {
+    env(name)
+        .map(|value| {
+            value
+                .trim()
+                .parse()
+                .map_err(|_| invalid(name, format!("'{}' is not a valid number", value)))
+        })
+        .transpose()
+}
+
+/// A timeout of `secs` seconds (30 by default), which must not be zero.
+fn seconds(setting: &'static str, secs: Option<u64>) -> Result<Duration, ConfigError> {
+    match secs.unwrap_or(30) {
+        0 => Err(invalid(setting, "must be at least 1 second")),
+        secs => Ok(Duration::from_secs(secs)),
+    }
+}
+
+/// A quota of `limit` requests an hour (`default` when unset), which must
+/// not be zero.
+fn hourly_quota(setting: &'static str, limit: Option<u32>, default: u32) -> Result<Quota, ConfigError> {
+    match limit.unwrap_or(default) {
+        0 => Err(invalid(setting, "must be at least 1")),
+        limit => Ok(Quota::per_hour(limit)),
+    }
+}
+
+/// Checks that each origin is `scheme://host[:port]`, or that the list is
+/// just `*`.
+fn parse_origins(origins: Vec<String>) -> Result<AllowedOrigins, ConfigError> {
+    if origins == ["*"] {
+        return Ok(AllowedOrigins::Any);
+    }
+    let mut allowed = Vec::with_capacity(origins.len());
+    for origin in origins {
+        let host = origin
+            .strip_prefix("https://")
+            .or_else(|| origin.strip_prefix("http://"));
+        let value = match host {
+            Some(host) if !host.is_empty() && !host.contains('/') => HeaderValue::from_str(&origin).ok(),
+            _ => None,
+        };
+        allowed.push(value.ok_or_else(|| {
+            invalid(
+                "CORS_ALLOWED_ORIGINS",
+                format!("'{}' is not an origin like https://thiccc.app (use * alone to allow any)", origin),
+            )
+        })?);
+    }
+    if allowed.is_empty() {
+        return Err(invalid("CORS_ALLOWED_ORIGINS", "no origins given"));
+    }
+    Ok(AllowedOrigins::List(allowed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn load(vars: &[(&str, &str)], file: FileConfig) -> Result<Config, ConfigError> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        Config::from_sources(|name| vars.get(name).cloned(), file)
+    }
+
+    const REQUIRED: [(&str, &str); 2] = [
+        ("DATABASE_URL", "postgres://localhost/thiccc"),
+        ("CLERK_JWKS_PATH", "fixtures/auth/jwks.json"),
+    ];
+
+    #[test]
+    fn test_defaults() {
+        let config = load(&REQUIRED, FileConfig::default()).unwrap();
+        assert_eq!(config.port, 8000);
+        assert_eq!(config.database_max_connections, 5);
+        assert_eq!(config.request_timeout, Duration::from_secs(30));
+        assert_eq!(config.jwks, JwksSource::File("fixtures/auth/jwks.json".into()));
+        assert_eq!(config.log_filter, "info");
+        assert_eq!(config.log_format, LogFormat::Full);
+        assert_eq!(config.user_quota, Quota::per_hour(1000));
+        assert_eq!(config.ip_quota, Quota::per_hour(100));
+        assert!(!config.trust_forwarded_for);
+        assert_eq!(
+            config.cors,
+            AllowedOrigins::List(DEFAULT_ALLOWED_ORIGINS.map(HeaderValue::from_static).to_vec())
+        );
+    }
+
+    #[test]
+    fn test_environment_overrides_the_file() {
+        let file: FileConfig = toml::from_str(
+            r#"
+            database_url = "postgres://file/thiccc"
+            port = 9000
+            allowed_origins = ["https://thiccc.app", "https://www.thiccc.app"]
+            database_max_connections = 20
+            clerk_jwks_url = "https://example.clerk.accounts.dev/.well-known/jwks.json"
+            log_format = "compact"
+            rate_limit_user_per_hour = 5000
+            trust_forwarded_for = true
+            "#,
+        )
+        .unwrap();
+        let config = load(&[("PORT", "8080"), ("CLERK_JWKS_PATH", "keys.json")], file).unwrap();
+        assert_eq!(config.database_url, "postgres://file/thiccc");
+        assert_eq!(config.port, 8080);
+        assert_eq!(config.database_max_connections, 20);
+        assert_eq!(
+            config.jwks,
+            JwksSource::File("keys.json".into()),
+            "an environment path wins over a file URL"
+        );
+        assert_eq!(config.log_format, LogFormat::Compact);
+        assert_eq!(config.user_quota, Quota::per_hour(5000));
+        assert!(config.trust_forwarded_for);
+        let AllowedOrigins::List(origins) = config.cors else {
+            panic!("Expected an origin list");
+        };
+        assert_eq!(origins.len(), 2);
+
+        let both = load(
+            &[
+                REQUIRED[0],
+                REQUIRED[1],
+                ("CLERK_JWKS_URL", "https://example.clerk.accounts.dev/.well-known/jwks.json"),
+            ],
+            FileConfig::default(),
+        );
+        assert!(matches!(both.unwrap().jwks, JwksSource::Url(_)), "a URL wins over a path");
+
+        for filter in ["debug", "info,sqlx=warn", "thiccc_api=debug"] {
+            let config = load(&[REQUIRED[0], REQUIRED[1], ("RUST_LOG", filter)], FileConfig::default());
+            assert_eq!(config.unwrap().log_filter, filter, "RUST_LOG directives are accepted");
+        }
+
+        let any = load(&[REQUIRED[0], REQUIRED[1], ("CORS_ALLOWED_ORIGINS", "*")], FileConfig::default());
+        assert_eq!(any.unwrap().cors, AllowedOrigins::Any);
+    }
+
+    #[test]
+    fn test_invalid_settings_are_reported() {
+        let error = |vars: &[(&str, &str)]| {
+            let mut all = REQUIRED.to_vec();
+            all.extend_from_slice(vars);
+            load(&all, FileConfig::default()).unwrap_err()
+        };
+        assert_eq!(
+            load(&REQUIRED[1..], FileConfig::default()).unwrap_err(),
+            ConfigError::Missing("DATABASE_URL")
+        );
+        assert_eq!(
+            load(&REQUIRED[..1], FileConfig::default()).unwrap_err(),
+            ConfigError::Missing("CLERK_JWKS_URL or CLERK_JWKS_PATH")
+        );
+        assert!(error(&[("PORT", "eighty")]).to_string().starts_with("Invalid PORT"));
+        assert!(matches!(
+            error(&[("DATABASE_MAX_CONNECTIONS", "0")]),
+            ConfigError::Invalid { setting: "DATABASE_MAX_CONNECTIONS", .. }
+        ));
+        assert!(matches!(
+            error(&[("REQUEST_TIMEOUT_SECS", "0")]),
+            ConfigError::Invalid { setting: "REQUEST_TIMEOUT_SECS", .. }
+        ));
+        for origins in ["thiccc.app", "https://thiccc.app/", "https://a.app,*"] {
+            assert!(matches!(
+                error(&[("CORS_ALLOWED_ORIGINS", origins)]),
+                ConfigError::Invalid { setting: "CORS_ALLOWED_ORIGINS", .. }
+            ));
+        }
+        assert!(matches!(error(&[("LOG_FORMAT", "json")]), ConfigError::Invalid { setting: "LOG_FORMAT", .. }));
+        assert!(matches!(error(&[("RUST_LOG", "=loud")]), ConfigError::Invalid { setting: "RUST_LOG", .. }));
+        assert!(matches!(
+            error(&[("RATE_LIMIT_IP_PER_HOUR", "0")]),
+            ConfigError::Invalid { setting: "RATE_LIMIT_IP_PER_HOUR", .. }
+        ));
+        assert!(matches!(
+            error(&[("TRUST_FORWARDED_FOR", "yes")]),
+            ConfigError::Invalid { setting: "TRUST_FORWARDED_FOR", .. }
+        ));
+
+        let example: FileConfig = toml::from_str(include_str!("../config.example.toml")).unwrap();
+        assert!(load(&REQUIRED[..1], example).is_ok(), "the example config is valid");
+
+        let unknown = toml::from_str::<FileConfig>("prot = 8000");
+        assert!(unknown.is_err(), "typos in the file are caught");
+    }
+}
@@ -0,0 +1,68 @@
+//! Database setup run at startup: migrations and seed data.
+//!
+//! Migrations are embedded from `db/migrations` at compile time, so a deployed
+//! binary brings its schema up to date before serving requests. The sqlx-cli
+//! `db-migrate` step records runs in the same `_sqlx_migrations` table, so
+//! either can apply them first.
+
+use shared::catalog::exercise_catalog;
+use sqlx::migrate::{MigrateError, Migrator};
+use sqlx::PgPool;
+
+/// Migrations in `db/migrations`.
+pub static MIGRATOR: Migrator = sqlx::migrate!("../db/migrations");
+
+/// Applies any migrations that haven't run yet.
+pub async fn migrate(pool: &PgPool) -> Result<(), MigrateError> {
+    MIGRATOR.run(pool).await
+}
+
+/// Upserts the built-in exercise catalog into `exercise_catalog` by name.
+///
+/// Existing rows keep their id; returns the number of rows inserted or changed.
+pub async fn seed_exercise_catalog(pool: &PgPool) -> Result<u64, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    let mut changed = 0;
+    for exercise in exercise_catalog() {
+        changed += sqlx::query(
+            "INSERT INTO exercise_catalog (name, exercise_type, additional_fk, muscle_group, image_name) \
+             VALUES ($1, $2, $3, $4, $5) \
+             ON CONFLICT (name) DO UPDATE SET exercise_type = EXCLUDED.exercise_type, \
+             additional_fk = EXCLUDED.additional_fk, muscle_group = EXCLUDED.muscle_group, \
+             image_name = EXCLUDED.image_name, updated_at = NOW() \
+             WHERE (exercise_catalog.exercise_type, exercise_catalog.additional_fk, \
+             exercise_catalog.muscle_group, exercise_catalog.image_name) \
+             IS DISTINCT FROM (EXCLUDED.exercise_type, EXCLUDED.additional_fk, \
+             EXCLUDED.muscle_group, EXCLUDED.image_name)",
+        )
+        .bind(&exercise.name)
+        .bind(&exercise.exercise_type)
+        .bind(&exercise.additional_fk)
+        .bind(&exercise.muscle_group)
+        .bind(&exercise.image_name)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+    }
+    tx.commit().await?;
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_seeding_the_catalog_is_idempotent(pool: PgPool) {
+        let count = exercise_catalog().len() as u64;
+        assert_eq!(seed_exercise_catalog(&pool).await.unwrap(), count);
+        assert_eq!(seed_exercise_catalog(&pool).await.unwrap(), 0);
+
+        sqlx::query("UPDATE exercise_catalog SET muscle_group = 'Other' WHERE name = 'Deadlift'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(seed_exercise_catalog(&pool).await.unwrap(), 1);
+    }
+}
@@ -0,0 +1,442 @@
+//! The error responses shared by all endpoints.
+//!
+//! Follows the Google JSON Style Guide used by the API spec: a failed request
+//! gets `{"error": {"code", "message", "errors": [...]}}`, where `code` is the
+//! HTTP status and each entry of `errors` names a `reason` clients can match
+//! on. Handlers return `ApiResult` and use `?` on database and auth errors;
+//! the extractors here make malformed bodies, paths and queries fail the same
+//! way, and the router sends unknown routes, unsupported methods and timeouts
+//! through it too.
+
+use axum::{
+    extract::{
+        rejection::{JsonRejection, PathRejection, QueryRejection},
+        FromRequest, FromRequestParts, Request,
+    },
+    http::{header, request::Parts, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use shared::validation::{describe_errors, ValidationError};
+use std::time::Duration;
+
+use crate::auth::AuthError;
+use crate::rate_limit::whole_seconds;
+
+pub type ApiResult<T> = Result<T, ApiError>;
+
+/// Why a request failed.
+#[derive(Debug)]
+pub enum ApiError {
+    /// 400 - the request is malformed (e.g. an invalid query parameter)
+    BadRequest(String),
+    /// 401 - no token, or one that isn't valid
+    Unauthorized(String),
+    /// 403 - the user lacks a required role
+    Forbidden(String),
+    /// 404 - the resource doesn't exist (or belongs to another user)
+    NotFound(String),
+    /// 405 - the route doesn't support the request's method
+    MethodNotAllowed,
+    /// 408 - the request took longer than the configured timeout
+    Timeout,
+    /// 422 - a workout failed `shared::validation`
+    Invalid(Vec<ValidationError>),
+    /// The body, path or query couldn't be extracted (400, 415 or 422)
+    Rejected(StatusCode, String),
+    /// 429 - the client used up its quota; it may retry after the duration
+    RateLimited(Duration),
+    /// 503 - a dependency (database, key set) is unreachable
+    Unavailable(String),
+    /// 409 on a duplicate key, otherwise a logged 500
+    Database(sqlx::Error),
+}
+
+/// The `error` object of a response.
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorObject {
+    /// HTTP status code
+    pub code: u16,
+    /// Summary of everything that went wrong
+    pub message: String,
+    /// One entry per problem
+    pub errors: Vec<ErrorItem>,
+}
+
+/// One problem with a request.
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorItem {
+    /// Area the problem belongs to (`global`, `auth` or `workout`)
+    pub domain: &'static str,
+    /// Stable identifier for the kind of problem (e.g. `notFound`)
+    pub reason: &'static str,
+    pub message: String,
+    /// Path of the offending field in the request body
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_type: Option<&'static str>,
+}
+
+impl ErrorItem {
+    fn new(domain: &'static str, reason: &'static str, message: String) -> Self {
+        Self {
+            domain,
+            reason,
+            message,
+            location: None,
+            location_type: None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: ErrorObject,
+}
+
+impl ApiError {
+    /// The status and `error` object this error is sent as.
+    pub fn to_error_object(&self) -> (StatusCode, ErrorObject) {
+        let (status, errors) = match self {
+            ApiError::BadRequest(message) => (
+                StatusCode::BAD_REQUEST,
+                vec![ErrorItem::new("global", "badRequest", message.clone())],
+            ),
+            ApiError::Unauthorized(message) => (
+                StatusCode::UNAUTHORIZED,
+                vec![ErrorItem::new("auth", "authError", message.clone())],
+            ),
+            ApiError::Forbidden(message) => (
+                StatusCode::FORBIDDEN,
+                vec![ErrorItem::new("auth", "forbidden", message.clone())],
+            ),
+            ApiError::NotFound(message) => (
+                StatusCode::NOT_FOUND,
+                vec![ErrorItem::new("global", "notFound", message.clone())],
+            ),
+            ApiError::MethodNotAllowed => (
+                StatusCode::METHOD_NOT_ALLOWED,
+                vec![ErrorItem::new(
+                    "global",
+                    "methodNotAllowed",
+                    "This endpoint doesn't support the request's method".to_string(),
+                )],
+            ),
+            ApiError::Timeout => (
+                StatusCode::REQUEST_TIMEOUT,
+                vec![ErrorItem::new(
+                    "global",
+                    "timeout",
+                    "The request took too long to process".to_string(),
+                )],
+            ),
+            ApiError::Invalid(errors) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                errors.iter().map(validation_item).collect(),
+            ),
+            ApiError::Rejected(status, message) => (
+                *status,
+                vec![ErrorItem::new("global", "parseError", message.clone())],
+            ),
+            ApiError::RateLimited(retry_after) => (
+                StatusCode::TOO_MANY_REQUESTS,
+                vec![ErrorItem::new(
+                    "global",
+                    "rateLimitExceeded",
+                    format!(
+                        "Too many requests; try again in {} seconds",
+                        whole_seconds(*retry_after)
+                    ),
+                )],
+            ),
+            ApiError::Unavailable(message) => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                vec![ErrorItem::new("global", "unavailable", message.clone())],
+            ),
+            ApiError::Database(error)
+                if error
+                    .as_database_error()
+                    .is_some_and(|e| e.is_unique_violation()) =>
+            {
+                let db_error = error.as_database_error();
+                let message = conflict_message(
+                    db_error.and_then(|e| e.table()),
+                    db_error.and_then(|e| e.constraint()),
+                );
+                (
+                    StatusCode::CONFLICT,
+                    vec![ErrorItem::new("global", "conflict", message.to_string())],
+                )
+            }
+            ApiError::Database(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                vec![ErrorItem::new("global", "backendError", "Database error".to_string())],
+            ),
+        };
+        let message = match self {
+            ApiError::Invalid(errors) => describe_errors(errors),
+            _ => errors[0].message.clone(),
+        };
+        let error = ErrorObject {
+            code: status.as_u16(),
+            message,
+            errors,
+        };
+        (status, error)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        match &self {
+            ApiError::Database(error) => tracing::error!("Database error: {}", error),
+            ApiError::Unavailable(reason) => tracing::error!("Service unavailable: {}", reason),
+            _ => {}
+        }
+        let (status, error) = self.to_error_object();
+        let mut response = (status, Json(ErrorBody { error })).into_response();
+        if let ApiError::RateLimited(retry_after) = self {
+            response.headers_mut().insert(
+                header::RETRY_AFTER,
+                HeaderValue::from(whole_seconds(retry_after)),
+            );
+        }
+        response
+    }
+}
+
+impl From<sqlx::Error> for ApiError {
+    fn from(error: sqlx::Error) -> Self {
+        ApiError::Database(error)
+    }
+}
+
+impl From<Vec<ValidationError>> for ApiError {
+    fn from(errors: Vec<ValidationError>) -> Self {
+        ApiError::Invalid(errors)
+    }
+}
+
+impl From<AuthError> for ApiError {
+    fn from(error: AuthError) -> Self {
+        match error {
+            AuthError::MissingToken => ApiError::Unauthorized("Missing bearer token".to_string()),
+            AuthError::InvalidToken(reason) => {
+                ApiError::Unauthorized(format!("Invalid token: {}", reason))
+            }
+            AuthError::KeysUnavailable(reason) => {
+                tracing::error!("JWKS unavailable: {}", reason);
+                ApiError::Unavailable("Authentication is temporarily unavailable".to_string())
+            }
+            AuthError::Database(error) => ApiError::Database(error),
+        }
+    }
+}
+
+/// Describes a unique violation by the table and constraint it hit.
+fn conflict_message(table: Option<&str>, constraint: Option<&str>) -> &'static str {
+    match (table, constraint) {
+        (_, Some("exercise_sets_set_index_unique")) => "Two sets of an exercise share a set index",
+        (Some("workouts"), _) => "A workout with this id already exists",
+        (Some("exercises"), _) => "An exercise with this id already exists",
+        (Some("exercise_sets"), _) => "A set with this id already exists",
+        (Some("users"), _) => "This user already exists",
+        (Some("user_roles"), _) => "The user already has this role",
+        _ => "This record already exists",
+    }
+}
+
+/// Names the reason and the offending field of a validation error. Paths use
+/// the request body's (snake_case) field names.
+fn validation_item(error: &ValidationError) -> ErrorItem {
+    let set = |e: &usize, s: &usize| format!("exercises[{}].sets[{}]", e, s);
+    let (reason, location) = match error {
+        ValidationError::InvalidId { .. } => ("invalidId", None),
+        ValidationError::ExerciseWorkoutMismatch { exercise_index } => (
+            "invalidReference",
+            Some(format!("exercises[{}].workout_id", exercise_index)),
+        ),
+        ValidationError::SetExerciseMismatch {
+            exercise_index,
+            set_index,
+        } => (
+            "invalidReference",
+            Some(format!("{}.exercise_id", set(exercise_index, set_index))),
+        ),
+        ValidationError::SetWorkoutMismatch {
+            exercise_index,
+            set_index,
+        } => (
+            "invalidReference",
+            Some(format!("{}.workout_id", set(exercise_index, set_index))),
+        ),
+        ValidationError::NonContiguousSetIndex {
+            exercise_index,
+            set_index,
+            ..
+        } => (
+            "invalidSetIndex",
+            Some(format!("{}.set_index", set(exercise_index, set_index))),
+        ),
+        ValidationError::RpeOutOfRange {
+            exercise_index,
+            set_index,
+            ..
+        }
+        | ValidationError::NegativeWeight {
+            exercise_index,
+            set_index,
+            ..
+        }
+        | ValidationError::NegativeReps {
+            exercise_index,
+            set_index,
+            ..
+        } => ("invalidValue", Some(set(exercise_index, set_index))),
+        ValidationError::EndBeforeStart => ("invalidValue", Some("end_timestamp".to_string())),
+    };
+    ErrorItem {
+        location_type: location.as_ref().map(|_| "field"),
+        location,
+        ..ErrorItem::new("workout", reason, error.to_string())
+    }
+}
+
+// =============================================================================
+// MARK: - Extractors
+// =============================================================================
+
+/// A JSON request body (`axum::Json`) that fails with an `ApiError`.
+pub struct JsonBody<T>(pub T);
+
+impl<S, T> FromRequest<S> for JsonBody<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<T>::from_request(request, state)
+            .await
+            .map_err(|e: JsonRejection| ApiError::Rejected(e.status(), e.body_text()))?;
+        Ok(JsonBody(value))
+    }
+}
+
+/// Path parameters (`axum::extract::Path`) that fail with an `ApiError`.
+pub struct PathParams<T>(pub T);
+
+impl<S, T> FromRequestParts<S> for PathParams<T>
+where
+    T: DeserializeOwned + Send,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let axum::extract::Path(value) = axum::extract::Path::<T>::from_request_parts(parts, state)
+            .await
+            .map_err(|e: PathRejection| ApiError::Rejected(e.status(), e.body_text()))?;
+        Ok(PathParams(value))
+    }
+}
+
+/// Query parameters (`axum::extract::Query`) that fail with an `ApiError`.
+pub struct QueryParams<T>(pub T);
+
+impl<S, T> FromRequestParts<S> for QueryParams<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let axum::extract::Query(value) =
+            axum::extract::Query::<T>::from_request_parts(parts, state)
+                .await
+                .map_err(|e: QueryRejection| ApiError::Rejected(e.status(), e.body_text()))?;
+        Ok(QueryParams(value))
+    }
+}
+
+// =============================================================================
+// MARK: - Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::Workout;
+    use shared::validation::validate_workout;
+
+    #[test]
+    fn test_validation_errors_point_at_fields() {
+        let mut workout = Workout::with_name("Arms");
+        workout.add_exercise("Curl").add_set().actual.rpe = Some(15.0);
+        let errors = validate_workout(&workout).unwrap_err();
+
+        let (status, error) = ApiError::from(errors.clone()).to_error_object();
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(error.code, 422);
+        assert_eq!(error.message, describe_errors(&errors));
+        assert_eq!(error.errors.len(), 1);
+        let item = &error.errors[0];
+        assert_eq!((item.domain, item.reason), ("workout", "invalidValue"));
+        assert_eq!(item.location.as_deref(), Some("exercises[0].sets[0]"));
+        assert_eq!(item.location_type, Some("field"));
+    }
+
+    #[test]
+    fn test_errors_serialize_to_the_envelope() {
+        let response = ApiError::NotFound("Workout 1 not found".to_string());
+        let (status, error) = response.to_error_object();
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(
+            serde_json::to_value(ErrorBody { error }).unwrap(),
+            serde_json::json!({
+                "error": {
+                    "code": 404,
+                    "message": "Workout 1 not found",
+                    "errors": [{
+                        "domain": "global",
+                        "reason": "notFound",
+                        "message": "Workout 1 not found"
+                    }]
+                }
+            })
+        );
+
+        let (status, error) = ApiError::Timeout.to_error_object();
+        assert_eq!(status, StatusCode::REQUEST_TIMEOUT);
+        assert_eq!((error.code, error.errors[0].reason), (408, "timeout"));
+
+        let (status, error) = ApiError::from(sqlx::Error::RowNotFound).to_error_object();
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(error.errors[0].reason, "backendError");
+        assert_eq!(error.message, "Database error");
+    }
+
+    #[test]
+    fn test_conflicts_name_what_already_exists() {
+        assert_eq!(
+            conflict_message(Some("workouts"), Some("workouts_pkey")),
+            "A workout with this id already exists"
+        );
+        assert_eq!(
+            conflict_message(Some("exercise_sets"), Some("exercise_sets_set_index_unique")),
+            "Two sets of an exercise share a set index"
+        );
+        assert_eq!(
+            conflict_message(Some("users"), Some("users_clerk_id_key")),
+            "This user already exists"
+        );
+        assert_eq!(conflict_message(Some("exercise_catalog"), None), "This record already exists");
+        assert_eq!(conflict_message(None, None), "This record already exists");
+    }
+}
@@ -0,0 +1,520 @@
+//! Live sessions: the shared core hosted server-side for the web client.
+//!
+//! Each WebSocket connection gets its own `Core<Thiccc>`, playing the part
+//! the iOS shell plays on a phone. The browser sends `Event`s as JSON text
+//! frames and gets a `ViewModel` back whenever the core asks for a render.
+//! Database effects run against the user's workouts (logged for sync like
+//! REST writes), the in-progress workout lives in `current_workouts`, and
+//! the timer ticks on the server.
+//!
+//! - `GET /api/live` - upgrade to a live session. Browsers can't set headers
+//!   on WebSocket requests, so the token may be sent as `?access_token=`.
+
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        State,
+    },
+    response::Response,
+    routing::get,
+    Router,
+};
+use serde::Serialize;
+use shared::bulk_import::{plan_bulk_import, ImportEntry};
+use shared::validation::{describe_errors, validate_workout};
+use shared::{
+    Core, DatabaseOperation, DatabaseResult, Effect, Event, HistoryFilter, HttpResult,
+    ShareOperation, ShareResult, StorageOperation, StorageResult, Thiccc,
+    TimerOperation, TimerOutput, ViewModel, Workout,
+};
+use sqlx::{PgConnection, PgPool};
+use std::collections::VecDeque;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::{sync_store, workout_store, AppState};
+
+/// Routes for `/api/live`.
+pub fn router() -> Router<AppState> {
+    Router::new().route("/api/live", get(connect))
+}
+
+/// GET /api/live
+async fn connect(
+    State(state): State<AppState>,
+    user: AuthUser,
+    upgrade: WebSocketUpgrade,
+) -> Response {
+    upgrade.on_upgrade(move |socket| run(socket, state.db, user.id))
+}
+
+/// A message sent to the browser.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum LiveMessage {
+    /// The view after the core rendered
+    View(Box<ViewModel>),
+    /// Content for the browser to download, share or copy
+    Share(ShareOperation),
+    /// A message the session couldn't handle
+    Error { message: String },
+}
+
+/// Drives a session until the browser disconnects.
+async fn run(mut socket: WebSocket, db: PgPool, user_id: Uuid) {
+    let (mut session, mut ticks) = LiveSession::new(db, user_id);
+    let messages = session.handle(Event::Initialize).await;
+    if send_all(&mut socket, messages).await.is_err() {
+        return;
+    }
+
+    loop {
+        let messages = tokio::select! {
+            message = socket.recv() => match message {
+                Some(Ok(Message::Text(text))) => match parse_event(&text) {
+                    Ok(event) => session.handle(event).await,
+                    Err(message) => vec![LiveMessage::Error { message }],
+                },
+                Some(Ok(Message::Binary(_))) => vec![LiveMessage::Error {
+                    message: "Send events as JSON text frames".to_string(),
+                }],
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => continue,
+            },
+            Some(()) = ticks.recv() => session.handle(Event::TimerTick).await,
+        };
+        if send_all(&mut socket, messages).await.is_err() {
+            break;
+        }
+    }
+}
+
+async fn send_all(socket: &mut WebSocket, messages: Vec<LiveMessage>) -> Result<(), axum::Error> {
+    for message in messages {
+        let json = serde_json::to_string(&message).expect("live messages serialize");
+        socket.send(Message::Text(json.into())).await?;
+    }
+    Ok(())
+}
+
+/// Parses an event from the browser. Events that carry a shell's answer to
+/// an effect are the server's to send, so they are refused.
+fn parse_event(text: &str) -> Result<Event, String> {
+    let event: Event =
+        serde_json::from_str(text).map_err(|e| format!("Invalid event: {}", e))?;
+    match event {
+        Event::Initialize
+        | Event::TimerTick
+        | Event::ApiSessionChanged { .. }
+        | Event::SyncNow
+        | Event::DatabaseResponse { .. }
+        | Event::ExportHistoryLoaded { .. }
+        | Event::SyncHistoryLoaded { .. }
+        | Event::SyncResponse { .. }
+        | Event::ShareResponse { .. }
+        | Event::StorageResponse { .. }
+        | Event::TimerResponse { .. }
+        | Event::Error { .. } => Err("This event is sent by the server".to_string()),
+        event => Ok(event),
+    }
+}
+
+/// One user's core and the effects it's waiting on.
+pub struct LiveSession {
+    core: Core<Thiccc>,
+    db: PgPool,
+    user_id: Uuid,
+    ticks: mpsc::UnboundedSender<()>,
+    timer: Option<JoinHandle<()>>,
+}
+
+impl LiveSession {
+    /// Creates a session and the channel its timer ticks arrive on.
+    pub fn new(db: PgPool, user_id: Uuid) -> (Self, mpsc::UnboundedReceiver<()>) {
+        let (ticks, receiver) = mpsc::unbounded_channel();
+        let session = Self {
+            core: Core::new(),
+            db,
+            user_id,
+            ticks,
+            timer: None,
+        };
+        (session, receiver)
+    }
+
+    /// Processes an event and resolves every effect it leads to, returning
+    /// the messages for the browser (at most one view, sent last).
+    pub async fn handle(&mut self, event: Event) -> Vec<LiveMessage> {
+        let mut messages = Vec::new();
+        let mut rendered = false;
+        let mut effects: VecDeque<Effect> = self.core.process_event(event).into();
+
+        while let Some(effect) = effects.pop_front() {
+            let resolved = match effect {
+                Effect::Render(_) => {
+                    rendered = true;
+                    continue;
+                }
+                Effect::Database(mut request) => {
+                    let result = self.database(request.operation.clone()).await;
+                    self.core.resolve(&mut request, result)
+                }
+                Effect::Storage(mut request) => {
+                    let result = self.storage(request.operation.clone()).await;
+                    self.core.resolve(&mut request, result)
+                }
+                Effect::Timer(mut request) => {
+                    let output = self.timer(&request.operation);
+                    self.core.resolve(&mut request, output)
+                }
+                Effect::Share(mut request) => {
+                    messages.push(LiveMessage::Share(request.operation.clone()));
+                    self.core.resolve(&mut request, ShareResult::Shared)
+                }
+                Effect::Http(mut request) => {
+                    let result = HttpResult::Failed {
+                        message: "Live sessions don't make HTTP requests".to_string(),
+                    };
+                    self.core.resolve(&mut request, result)
+                }
+            };
+            match resolved {
+                Ok(more) => effects.extend(more),
+                Err(e) => tracing::error!("Failed to resolve a live session effect: {}", e),
+            }
+        }
+
+        if rendered {
+            messages.push(LiveMessage::View(Box::new(self.core.view())));
+        }
+        messages
+    }
+
+    async fn database(&self, operation: DatabaseOperation) -> DatabaseResult {
+        self.run_database(operation)
+            .await
+            .unwrap_or_else(|message| DatabaseResult::Error { message })
+    }
+
+    async fn run_database(&self, operation: DatabaseOperation) -> Result<DatabaseResult, String> {
+        let mut tx = self.db.begin().await.map_err(database_error)?;
+        let conn = &mut *tx;
+        let result = match operation {
+            DatabaseOperation::SaveWorkout(workout) => {
+                self.upsert(conn, &workout).await?;
+                DatabaseResult::WorkoutSaved
+            }
+            DatabaseOperation::LoadAllWorkouts => {
+                let workouts = workout_store::history(
+                    conn,
+                    self.user_id,
+                    &HistoryFilter::default(),
+                    None,
+                    None,
+                )
+                .await
+                .map_err(database_error)?;
+                DatabaseResult::HistoryLoaded {
+                    workouts,
+                    failures: vec![],
+                }
+            }
+            DatabaseOperation::LoadWorkoutsPage(query) => {
+                let limit = query.limit as usize;
+                let mut workouts = workout_store::history(
+                    conn,
+                    self.user_id,
+                    &query.filter,
+                    query.cursor.as_ref(),
+                    Some(limit as i64 + 1),
+                )
+                .await
+                .map_err(database_error)?;
+                let has_more = workouts.len() > limit;
+                workouts.truncate(limit);
+                DatabaseResult::HistoryPageLoaded {
+                    query,
+                    workouts,
+                    failures: vec![],
+                    has_more,
+                }
+            }
+            DatabaseOperation::LoadExerciseHistory(exercise_names) => {
+                let workouts = workout_store::exercise_history(conn, self.user_id, &exercise_names)
+                    .await
+                    .map_err(database_error)?;
+                DatabaseResult::ExerciseHistoryLoaded {
+                    exercise_names,
+                    workouts,
+                    failures: vec![],
+                }
+            }
+            DatabaseOperation::LoadExerciseNames => {
+                let names = workout_store::exercise_names(conn, self.user_id)
+                    .await
+                    .map_err(database_error)?;
+                DatabaseResult::ExerciseNamesLoaded { names }
+            }
+            DatabaseOperation::LoadWorkoutById(id) => {
+                let workout = match Uuid::parse_str(&id) {
+                    Ok(id) => workout_store::fetch_workout(conn, self.user_id, id)
+                        .await
+                        .map_err(database_error)?,
+                    Err(_) => None,
+                };
+                DatabaseResult::WorkoutLoaded { workout }
+            }
+            DatabaseOperation::DeleteWorkout(id) => {
+                let id = Uuid::parse_str(&id).map_err(|_| format!("Invalid workout id '{}'", id))?;
+                sync_store::lock_log(conn, self.user_id)
+                    .await
+                    .map_err(database_error)?;
+                if workout_store::delete_workout(conn, self.user_id, id)
+                    .await
+                    .map_err(database_error)?
+                {
+                    sync_store::record_deleted(conn, self.user_id, id)
+                        .await
+                        .map_err(database_error)?;
+                }
+                DatabaseResult::WorkoutDeleted
+            }
+            DatabaseOperation::UpdateWorkout(workout) => {
+                self.upsert(conn, &workout).await?;
+                DatabaseResult::WorkoutUpdated
+            }
+            DatabaseOperation::ImportWorkouts(workouts) => {
+                sync_store::lock_log(conn, self.user_id)
+                    .await
+                    .map_err(database_error)?;
+                let existing = workout_store::history(
+                    conn,
+                    self.user_id,
+                    &HistoryFilter::default(),
+                    None,
+                    None,
+                )
+                .await
+                .map_err(database_error)?;
+                let mut plan = plan_bulk_import(&existing, workouts);
+                for workout in &plan.to_insert {
+                    check(workout)?;
+                    workout_store::insert_workout(conn, self.user_id, workout)
+                        .await
+                        .map_err(database_error)?;
+                    sync_store::record(conn, self.user_id, None, Some(workout))
+                        .await
+                        .map_err(database_error)?;
+                }
+                plan.summary.inserted = plan.to_insert.iter().map(ImportEntry::of).collect();
+                DatabaseResult::WorkoutsImported {
+                    summary: plan.summary,
+                }
+            }
+        };
+        tx.commit().await.map_err(database_error)?;
+        Ok(result)
+    }
+
+    /// Writes a finished or edited workout to history, whether or not it's
+    /// stored yet, and logs the change for sync.
+    async fn upsert(&self, conn: &mut PgConnection, workout: &Workout) -> Result<(), String> {
+        check(workout)?;
+        let id = workout_store::uuid(&workout.id).map_err(database_error)?;
+        sync_store::lock_log(conn, self.user_id)
+            .await
+            .map_err(database_error)?;
+        let before = workout_store::fetch_workout(conn, self.user_id, id)
+            .await
+            .map_err(database_error)?;
+        if before.is_some() {
+            workout_store::replace_workout(conn, self.user_id, workout)
+                .await
+                .map_err(database_error)?;
+        } else {
+            workout_store::insert_workout(conn, self.user_id, workout)
+                .await
+                .map_err(database_error)?;
+        }
+        sync_store::record(conn, self.user_id, before.as_ref(), Some(workout))
+            .await
+            .map_err(database_error)
+    }
+
+    async fn storage(&self, operation: StorageOperation) -> StorageResult {
+        let mut conn = match self.db.acquire().await {
+            Ok(conn) => conn,
+            Err(e) => return StorageResult::Error { message: database_error(e) },
+        };
+        let result = match operation {
+            StorageOperation::SaveCurrentWorkout(workout) => {
+                workout_store::save_current_workout(&mut conn, self.user_id, &workout)
+                    .await
+                    .map(|_| StorageResult::CurrentWorkoutSaved)
+            }
+            StorageOperation::LoadCurrentWorkout => {
+                workout_store::load_current_workout(&mut conn, self.user_id)
+                    .await
+                    .map(|workout| StorageResult::CurrentWorkoutLoaded { workout })
+            }
+            StorageOperation::DeleteCurrentWorkout => {
+                workout_store::delete_current_workout(&mut conn, self.user_id)
+                    .await
+                    .map(|_| StorageResult::CurrentWorkoutDeleted)
+            }
+            // History writes go straight to Postgres and are logged there, so
+            // a live session has no sync state of its own.
+            StorageOperation::SaveSyncState(_) => Ok(StorageResult::SyncStateSaved),
+            StorageOperation::LoadSyncState => Ok(StorageResult::SyncStateLoaded { state: None }),
+        };
+        result.unwrap_or_else(|e| StorageResult::Error {
+            message: database_error(e),
+        })
+    }
+
+    fn timer(&mut self, operation: &TimerOperation) -> TimerOutput {
+        match operation {
+            TimerOperation::Start => {
+                if self.timer.is_none() {
+                    let ticks = self.ticks.clone();
+                    self.timer = Some(tokio::spawn(async move {
+                        let mut interval = tokio::time::interval(Duration::from_secs(1));
+                        interval.tick().await;
+                        loop {
+                            interval.tick().await;
+                            if ticks.send(()).is_err() {
+                                break;
+                            }
+                        }
+                    }));
+                }
+                TimerOutput::Started
+            }
+            TimerOperation::Stop => {
+                if let Some(timer) = self.timer.take() {
+                    timer.abort();
+                }
+                TimerOutput::Stopped
+            }
+        }
+    }
+}
+
+impl Drop for LiveSession {
+    fn drop(&mut self) {
+        if let Some(timer) = self.timer.take() {
+            timer.abort();
+        }
+    }
+}
+
+fn check(workout: &Workout) -> Result<(), String> {
+    validate_workout(workout).map_err(|errors| describe_errors(&errors))
+}
+
+/// Logs a database error and returns the message the core shows.
+fn database_error(error: sqlx::Error) -> String {
+    tracing::error!("Live session database error: {}", error);
+    "Database error".to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{send, test_config, test_state, token};
+    use futures_util::{SinkExt, StreamExt};
+    use serde_json::{json, Value};
+    use tokio_tungstenite::tungstenite;
+
+    async fn session_for(pool: &PgPool, user: &str) -> LiveSession {
+        let (_, me) = send(pool, "GET", "/api/me", Some(user), None).await;
+        let user_id = me["data"]["id"].as_str().unwrap().parse().unwrap();
+        LiveSession::new(pool.clone(), user_id).0
+    }
+
+    fn view(messages: &[LiveMessage]) -> &ViewModel {
+        match messages.last() {
+            Some(LiveMessage::View(view)) => view,
+            other => panic!("Expected a view, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_event_refuses_shell_events() {
+        assert_eq!(parse_event(r#""StartWorkout""#), Ok(Event::StartWorkout));
+        assert!(parse_event(r#"{"DatabaseResponse":{"result":"WorkoutSaved"}}"#).is_err());
+        assert!(parse_event(r#""TimerTick""#).is_err());
+        assert!(parse_event("not json").is_err());
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_live_session_resolves_effects_against_postgres(pool: PgPool) {
+        let user = token("user_live");
+        let mut session = session_for(&pool, &user).await;
+        let messages = session.handle(Event::Initialize).await;
+        assert!(!view(&messages).workout_view.has_active_workout);
+
+        session.handle(Event::StartWorkout).await;
+        session
+            .handle(Event::UpdateWorkoutName { name: "Web Push".into() })
+            .await;
+        assert!(session.timer.is_some(), "the timer runs on the server");
+
+        // A new session for the same user picks up the workout in progress
+        let mut resumed = session_for(&pool, &user).await;
+        let messages = resumed.handle(Event::Initialize).await;
+        assert!(view(&messages).workout_view.has_active_workout);
+
+        let messages = session.handle(Event::FinishWorkout).await;
+        assert!(view(&messages).workout_summary.is_some());
+        assert!(session.timer.is_none());
+        let (_, list) = send(&pool, "GET", "/api/workouts", Some(&user), None).await;
+        assert_eq!(list["data"]["items"][0]["name"], "Web Push");
+
+        let mut fresh = session_for(&pool, &user).await;
+        let messages = fresh.handle(Event::Initialize).await;
+        let view = view(&messages);
+        assert!(!view.workout_view.has_active_workout);
+        assert_eq!(view.history_view.workouts.len(), 1);
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_live_socket_streams_views(pool: PgPool) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = crate::app(test_state(&pool), &test_config());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let unauthenticated = format!("ws://{}/api/live", addr);
+        assert!(tokio_tungstenite::connect_async(unauthenticated).await.is_err());
+
+        let url = format!("ws://{}/api/live?access_token={}", addr, token("user_socket"));
+        let (socket, _) = tokio_tungstenite::connect_async(url).await.unwrap();
+        let (mut sink, mut stream) = socket.split();
+        let mut next = async || -> Value {
+            let message = stream.next().await.unwrap().unwrap();
+            serde_json::from_str(message.to_text().unwrap()).unwrap()
+        };
+        let initial = next().await;
+        assert_eq!(initial["view"]["workout_view"]["has_active_workout"], false);
+
+        sink
+            .send(tungstenite::Message::text(json!("StartWorkout").to_string()))
+            .await
+            .unwrap();
+        let started = next().await;
+        assert_eq!(started["view"]["workout_view"]["has_active_workout"], true);
+
+        sink
+            .send(tungstenite::Message::text(r#""TimerTick""#))
+            .await
+            .unwrap();
+        let refused = next().await;
+        assert_eq!(refused["error"]["message"], "This event is sent by the server");
+    }
+}
@@ -1,67 +1,203 @@
+mod admin;
+mod auth;
+mod config;
+mod db;
+mod error;
+mod live;
+mod rate_limit;
+mod response;
+mod roles;
+mod sync;
+mod sync_store;
+#[cfg(test)]
+mod test_support;
+mod user_store;
+mod users;
+mod workout_store;
+mod workouts;
+
 use axum::{
+    body::Body,
+    error_handling::HandleErrorLayer,
     extract::State,
-    http::StatusCode,
+    http::{header, Method, Request, Uri},
+    middleware,
     routing::get,
-    Json, Router,
+    BoxError, Json, Router,
 };
 use serde::Serialize;
 use sqlx::postgres::PgPoolOptions;
 use sqlx::PgPool;
 use std::net::SocketAddr;
-use tower_http::cors::{Any, CorsLayer};
+use std::process::ExitCode;
+use tower::timeout::TimeoutLayer;
+use tower::ServiceBuilder;
+use tower_http::cors::{AllowOrigin, CorsLayer};
 use tower_http::trace::TraceLayer;
+use tracing_subscriber::EnvFilter;
+
+use crate::auth::JwtVerifier;
+use crate::config::{AllowedOrigins, Config, LogFormat};
+use crate::error::{ApiError, ApiResult};
+use crate::rate_limit::RateLimiter;
+use crate::response::Data;
 
 /// Application state shared across handlers
 #[derive(Clone)]
 struct AppState {
     db: PgPool,
+    auth: JwtVerifier,
+    limits: RateLimiter,
 }
 
 #[tokio::main]
-async fn main() {
-    // Initialize tracing
-    tracing_subscriber::fmt::init();
+async fn main() -> ExitCode {
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Invalid configuration: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    init_tracing(&config);
+
+    match run(config).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            tracing::error!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
 
-    // Get database URL from environment
-    let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+fn init_tracing(config: &Config) {
+    let builder = tracing_subscriber::fmt().with_env_filter(EnvFilter::new(&config.log_filter));
+    match config.log_format {
+        LogFormat::Full => builder.init(),
+        LogFormat::Compact => builder.compact().init(),
+        LogFormat::Pretty => builder.pretty().init(),
+    }
+}
 
-    // Create database connection pool
+/// Prepares the database and serves requests until the process is stopped.
+async fn run(config: Config) -> Result<(), String> {
     tracing::info!("Connecting to database...");
     let pool = PgPoolOptions::new()
-        .max_connections(5)
-        .connect(&database_url)
+        .max_connections(config.database_max_connections)
+        .acquire_timeout(config.database_acquire_timeout)
+        .connect(&config.database_url)
         .await
-        .expect("Failed to connect to database");
+        .map_err(|e| format!("Failed to connect to database: {}", e))?;
 
     tracing::info!("Connected to database!");
 
-    let state = AppState { db: pool };
+    db::migrate(&pool)
+        .await
+        .map_err(|e| format!("Failed to run database migrations: {}", e))?;
+    let seeded = db::seed_exercise_catalog(&pool)
+        .await
+        .map_err(|e| format!("Failed to seed the exercise catalog: {}", e))?;
+    tracing::info!("Database migrated ({} catalog exercises updated)", seeded);
+
+    let mut auth = JwtVerifier::new(config.jwks.clone(), config.jwt_issuer.clone());
+    if let AllowedOrigins::List(origins) = &config.cors {
+        // Tokens must come from the same sites that may call the API
+        let origins = origins
+            .iter()
+            .filter_map(|origin| origin.to_str().ok().map(str::to_string))
+            .collect();
+        auth = auth.with_authorized_parties(origins);
+    }
+    let limits = RateLimiter::new(config.user_quota, config.ip_quota, config.trust_forwarded_for);
+    let state = AppState {
+        db: pool,
+        auth,
+        limits,
+    };
+    let app = app(state, &config);
 
-    // CORS layer - allow all for development
+    // Start server
+    let addr = SocketAddr::from(([0, 0, 0, 0], config.port));
+    let listener = tokio::net::TcpListener::bind(&addr)
+        .await
+        .map_err(|e| format!("Failed to listen on {}: {}", addr, e))?;
+    tracing::info!("Server listening on {}", addr);
+    // Peer addresses key the per-IP rate limit
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
+        .await
+        .map_err(|e| format!("Server error: {}", e))
+}
+
+/// Builds the router: public health checks, and `/api` routes that require
+/// a valid Clerk token. Both are rate limited, except the liveness check.
+fn app(state: AppState, config: &Config) -> Router {
+    // Browsers may only call the API from the configured origins
+    let origins = match &config.cors {
+        AllowedOrigins::Any => AllowOrigin::any(),
+        AllowedOrigins::List(origins) => AllowOrigin::list(origins.clone()),
+    };
     let cors = CorsLayer::new()
-        .allow_origin(Any)
-        .allow_methods(Any)
-        .allow_headers(Any);
+        .allow_origin(origins)
+        .allow_methods([Method::GET, Method::POST, Method::PATCH, Method::DELETE])
+        .allow_headers([header::AUTHORIZATION, header::CONTENT_TYPE]);
 
-    // Build router
-    let app = Router::new()
-        .route("/health", get(health_check))
+    let api = Router::new()
+        .merge(admin::router())
+        .merge(live::router())
+        .merge(sync::router())
+        .merge(users::router())
+        .merge(workouts::router())
+        .route_layer(middleware::from_fn_with_state(state.clone(), rate_limit::limit_users))
+        .route_layer(middleware::from_fn_with_state(state.clone(), auth::require_auth));
+    let public = Router::new()
         .route("/api/health", get(api_health))
+        .route_layer(middleware::from_fn_with_state(state.clone(), rate_limit::limit_ips));
+
+    Router::new()
+        .route("/health", get(health_check))
+        .merge(public)
+        .merge(api)
+        // Unknown routes, unsupported methods and timeouts get the same
+        // error envelope as handler errors
+        .fallback(unknown_route)
+        .method_not_allowed_fallback(method_not_allowed)
+        .layer(
+            ServiceBuilder::new()
+                .layer(HandleErrorLayer::new(request_timed_out))
+                .layer(TimeoutLayer::new(config.request_timeout)),
+        )
         .layer(cors)
-        .layer(TraceLayer::new_for_http())
-        .with_state(state);
+        .layer(TraceLayer::new_for_http().make_span_with(request_span))
+        .with_state(state)
+}
 
-    // Start server
-    let port: u16 = std::env::var("PORT")
-        .ok()
-        .and_then(|p| p.parse().ok())
-        .unwrap_or(8000);
+/// Span for a request's log lines.
+///
+/// Records the path but not the query, which can carry an access token (see
+/// `auth::websocket_token`).
+fn request_span(request: &Request<Body>) -> tracing::Span {
+    tracing::debug_span!(
+        "request",
+        method = %request.method(),
+        path = %request.uri().path(),
+        version = ?request.version(),
+    )
+}
 
-    let addr = SocketAddr::from(([0, 0, 0, 0], port));
-    tracing::info!("Server listening on {}", addr);
+/// 404 for a path no route matches.
+async fn unknown_route(uri: Uri) -> ApiError {
+    ApiError::NotFound(format!("No endpoint at {}", uri.path()))
+}
 
-    let listener = tokio::net::TcpListener::bind(&addr).await.unwrap();
-    axum::serve(listener, app).await.unwrap();
+/// 405 for a route that exists without the request's method.
+async fn method_not_allowed() -> ApiError {
+    ApiError::MethodNotAllowed
+}
+
+/// 408 for a request that ran past `Config::request_timeout` (the only error
+/// the timeout layer produces).
+async fn request_timed_out(_: BoxError) -> ApiError {
+    ApiError::Timeout
 }
 
 /// Simple health check - just returns OK
@@ -69,30 +205,90 @@ async fn health_check() -> &'static str {
     "OK"
 }
 
+/// Status of the API and its database.
 #[derive(Serialize)]
-struct HealthResponse {
-    status: String,
-    database: String,
+struct Health {
+    status: &'static str,
+    database: &'static str,
 }
 
-/// API health check - verifies database connection
-async fn api_health(State(state): State<AppState>) -> Result<Json<HealthResponse>, StatusCode> {
-    // Try a simple query to verify DB connection
-    let result: Result<(i32,), _> = sqlx::query_as("SELECT 1")
-        .fetch_one(&state.db)
-        .await;
-
-    match result {
-        Ok(_) => Ok(Json(HealthResponse {
-            status: "healthy".to_string(),
-            database: "connected".to_string(),
-        })),
-        Err(e) => {
-            tracing::error!("Database health check failed: {}", e);
-            Ok(Json(HealthResponse {
-                status: "unhealthy".to_string(),
-                database: format!("error: {}", e),
-            }))
-        }
+/// API health check - verifies the database connection (503 when it's down)
+async fn api_health(State(state): State<AppState>) -> ApiResult<Json<Data<Health>>> {
+    sqlx::query("SELECT 1").execute(&state.db).await.map_err(|e| {
+        tracing::error!("Database health check failed: {}", e);
+        ApiError::Unavailable("Database is unavailable".to_string())
+    })?;
+    Ok(Json(Data::new(Health {
+        status: "healthy",
+        database: "connected",
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{test_config, test_state};
+    use tower::ServiceExt;
+
+    async fn preflight(origin: &str) -> Option<String> {
+        let state = test_state(&PgPool::connect_lazy("postgres://localhost/unused").unwrap());
+        let request = Request::builder()
+            .method("OPTIONS")
+            .uri("/api/workouts")
+            .header(header::ORIGIN, origin)
+            .header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+            .body(Body::empty())
+            .unwrap();
+        let response = app(state, &test_config()).oneshot(request).await.unwrap();
+        response
+            .headers()
+            .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+            .map(|value| value.to_str().unwrap().to_string())
+    }
+
+    /// Sends a request without a token and returns its status and body.
+    async fn send(config: &Config, method: &str, uri: &str) -> (u16, serde_json::Value) {
+        let state = test_state(&PgPool::connect_lazy("postgres://localhost/unused").unwrap());
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .body(Body::empty())
+            .unwrap();
+        let response = app(state, config).oneshot(request).await.unwrap();
+        let status = response.status().as_u16();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_router_errors_use_the_envelope() {
+        let config = test_config();
+
+        let (status, body) = send(&config, "GET", "/api/nothing-here").await;
+        assert_eq!(status, 404);
+        assert_eq!(body["error"]["code"], 404);
+        assert_eq!(body["error"]["errors"][0]["reason"], "notFound");
+
+        let (status, body) = send(&config, "DELETE", "/health").await;
+        assert_eq!(status, 405);
+        assert_eq!(body["error"]["errors"][0]["reason"], "methodNotAllowed");
+
+        // The health check can't reach the database before the deadline
+        let config = Config {
+            request_timeout: std::time::Duration::ZERO,
+            ..test_config()
+        };
+        let (status, body) = send(&config, "GET", "/api/health").await;
+        assert_eq!(status, 408);
+        assert_eq!(body["error"]["errors"][0]["reason"], "timeout");
+    }
+
+    #[tokio::test]
+    async fn test_cors_allows_only_configured_origins() {
+        assert_eq!(
+            preflight("http://localhost:3000").await.as_deref(),
+            Some("http://localhost:3000")
+        );
+        assert_eq!(preflight("https://evil.example").await, None);
     }
 }
@@ -0,0 +1,374 @@
+//! Per-client request rate limiting.
+//!
+//! Each route group has a token-bucket `Quota`: a client may burst up to
+//! `limit` requests, and its allowance refills evenly so that `limit` more
+//! are available each `period`. Clients are the signed-in user, falling back
+//! to the IP address for requests without one (public routes and rejected
+//! tokens). Buckets live in process memory, so this works without Redis but
+//! each server instance limits on its own.
+//!
+//! Limited responses carry `X-RateLimit-Limit`, `X-RateLimit-Remaining` and
+//! `X-RateLimit-Reset` (seconds until the allowance is full again). A request
+//! over quota gets 429 in the error envelope, with `Retry-After`.
+//!
+//! Route groups opt in with a route layer (after `require_auth` for users):
+//!
+//! ```ignore
+//! router.route_layer(middleware::from_fn_with_state(state, rate_limit::limit_users))
+//! ```
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{HeaderMap, HeaderName, HeaderValue},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::error::ApiError;
+use crate::AppState;
+
+/// Requests a client may make in the period.
+pub const LIMIT_HEADER: HeaderName = HeaderName::from_static("x-ratelimit-limit");
+
+/// Requests the client may still make right now.
+pub const REMAINING_HEADER: HeaderName = HeaderName::from_static("x-ratelimit-remaining");
+
+/// Seconds until the client's allowance is full again.
+pub const RESET_HEADER: HeaderName = HeaderName::from_static("x-ratelimit-reset");
+
+/// Buckets kept before idle (full) ones are dropped.
+const PRUNE_THRESHOLD: usize = 10_000;
+
+/// How many requests a client of one route group may make.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quota {
+    /// Largest burst, and the number of requests refilled per `period`
+    pub limit: u32,
+    pub period: Duration,
+}
+
+impl Quota {
+    /// `limit` requests an hour.
+    pub const fn per_hour(limit: u32) -> Self {
+        Self {
+            limit,
+            period: Duration::from_secs(60 * 60),
+        }
+    }
+
+    fn per_second(&self) -> f64 {
+        f64::from(self.limit) / self.period.as_secs_f64()
+    }
+}
+
+/// Routes that share a quota.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RouteGroup {
+    /// The authenticated `/api` routes
+    Api,
+    /// Public routes, and requests whose token was rejected
+    Public,
+}
+
+/// Whose allowance a request uses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Client {
+    User(Uuid),
+    Ip(IpAddr),
+    /// No peer address (e.g. requests built in tests)
+    Unknown,
+}
+
+/// A client's allowance after a request.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Allowance {
+    pub limit: u32,
+    pub remaining: u32,
+    /// Time until the allowance is back to `limit`
+    pub reset: Duration,
+    /// Set when the request was refused: time until one is allowed again
+    pub retry_after: Option<Duration>,
+}
+
+impl Allowance {
+    /// Adds the `X-RateLimit-*` headers.
+    fn apply(&self, headers: &mut HeaderMap) {
+        headers.insert(LIMIT_HEADER, HeaderValue::from(self.limit));
+        headers.insert(REMAINING_HEADER, HeaderValue::from(self.remaining));
+        headers.insert(RESET_HEADER, HeaderValue::from(whole_seconds(self.reset)));
+    }
+}
+
+/// Token buckets for every client, by route group.
+///
+/// Cheap to clone; clones share the buckets.
+#[derive(Clone)]
+pub struct RateLimiter {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    api: Quota,
+    public: Quota,
+    /// Take the client IP from `X-Forwarded-For` (behind a trusted proxy)
+    trust_forwarded_for: bool,
+    buckets: Mutex<HashMap<(RouteGroup, Client), Bucket>>,
+}
+
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a limiter with the quotas for signed-in users of the `/api`
+    /// routes and for public clients.
+    pub fn new(api: Quota, public: Quota, trust_forwarded_for: bool) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                api,
+                public,
+                trust_forwarded_for,
+                buckets: Mutex::new(HashMap::new()),
+            }),
+        }
+    }
+
+    fn quota(&self, group: RouteGroup) -> Quota {
+        match group {
+            RouteGroup::Api => self.inner.api,
+            RouteGroup::Public => self.inner.public,
+        }
+    }
+
+    /// Takes one request from the client's allowance at `now`, if it has one.
+    fn take(&self, group: RouteGroup, client: Client, now: Instant) -> Allowance {
+        let quota = self.quota(group);
+        let capacity = f64::from(quota.limit);
+        let rate = quota.per_second();
+        let refilled = |bucket: &Bucket| {
+            let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+            (bucket.tokens + elapsed * rate).min(capacity)
+        };
+
+        let mut buckets = self.inner.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+        if buckets.len() >= PRUNE_THRESHOLD {
+            // A full bucket is the same as no bucket
+            buckets.retain(|(group, _), bucket| {
+                let quota = self.quota(*group);
+                let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+                bucket.tokens + elapsed * quota.per_second() < f64::from(quota.limit)
+            });
+        }
+        let bucket = buckets.entry((group, client)).or_insert(Bucket {
+            tokens: capacity,
+            updated: now,
+        });
+        bucket.tokens = refilled(bucket);
+        bucket.updated = now;
+
+        let retry_after = if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            None
+        } else {
+            Some(Duration::from_secs_f64((1.0 - bucket.tokens) / rate))
+        };
+        Allowance {
+            limit: quota.limit,
+            remaining: bucket.tokens as u32,
+            reset: Duration::from_secs_f64((capacity - bucket.tokens) / rate),
+            retry_after,
+        }
+    }
+
+    /// The signed-in user, or else the IP address the request came from.
+    fn client(&self, request: &Request) -> Client {
+        if let Some(user) = request.extensions().get::<AuthUser>() {
+            return Client::User(user.id);
+        }
+        let forwarded = self
+            .inner
+            .trust_forwarded_for
+            .then(|| forwarded_for(request.headers()))
+            .flatten();
+        forwarded
+            .or_else(|| {
+                request
+                    .extensions()
+                    .get::<ConnectInfo<SocketAddr>>()
+                    .map(|ConnectInfo(addr)| addr.ip())
+            })
+            .map_or(Client::Unknown, Client::Ip)
+    }
+
+    /// Runs the request if the client has allowance left, or refuses it with
+    /// 429, and adds the `X-RateLimit-*` headers either way.
+    async fn limit(&self, group: RouteGroup, request: Request, next: Next) -> Response {
+        let allowance = self.take(group, self.client(&request), Instant::now());
+        let mut response = match allowance.retry_after {
+            Some(retry_after) => ApiError::RateLimited(retry_after).into_response(),
+            None => next.run(request).await,
+        };
+        allowance.apply(response.headers_mut());
+        response
+    }
+
+    /// Counts a request that failed authentication against its IP address,
+    /// replacing `rejection` with 429 once the public quota is used up.
+    pub fn charge_rejected(&self, request: &Request, rejection: Response) -> Response {
+        let allowance = self.take(RouteGroup::Public, self.client(request), Instant::now());
+        let mut response = match allowance.retry_after {
+            Some(retry_after) => ApiError::RateLimited(retry_after).into_response(),
+            None => rejection,
+        };
+        allowance.apply(response.headers_mut());
+        response
+    }
+}
+
+/// Middleware limiting the `/api` routes per signed-in user.
+///
+/// Runs after `require_auth`, which puts the `AuthUser` in the extensions.
+pub async fn limit_users(State(state): State<AppState>, request: Request, next: Next) -> Response {
+    state.limits.limit(RouteGroup::Api, request, next).await
+}
+
+/// Middleware limiting public routes per IP address.
+pub async fn limit_ips(State(state): State<AppState>, request: Request, next: Next) -> Response {
+    state.limits.limit(RouteGroup::Public, request, next).await
+}
+
+/// The client address a trusted proxy appended to `X-Forwarded-For` (the
+/// last entry; earlier ones come from the client and can be forged).
+fn forwarded_for(headers: &HeaderMap) -> Option<IpAddr> {
+    headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .next_back()?
+        .to_str()
+        .ok()?
+        .rsplit(',')
+        .next()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// A duration in whole seconds, rounded up.
+pub fn whole_seconds(duration: Duration) -> u64 {
+    duration.as_secs() + u64::from(duration.subsec_nanos() > 0)
+}
+
+// =============================================================================
+// MARK: - Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{send_from, test_state_with_limits, token};
+    use axum::http::StatusCode;
+    use sqlx::PgPool;
+
+    fn limiter(limit: u32) -> RateLimiter {
+        RateLimiter::new(Quota::per_hour(limit), Quota::per_hour(limit), false)
+    }
+
+    #[test]
+    fn test_bucket_allows_a_burst_then_refills_evenly() {
+        let limits = limiter(3);
+        let client = Client::Ip([10, 0, 0, 1].into());
+        let start = Instant::now();
+
+        for remaining in [2, 1, 0] {
+            let allowance = limits.take(RouteGroup::Public, client, start);
+            assert_eq!(allowance.retry_after, None);
+            assert_eq!(allowance.remaining, remaining);
+        }
+        let refused = limits.take(RouteGroup::Public, client, start);
+        assert_eq!(refused.limit, 3);
+        assert_eq!(refused.remaining, 0);
+        assert_eq!(refused.retry_after.map(whole_seconds), Some(20 * 60));
+        assert_eq!(whole_seconds(refused.reset), 60 * 60);
+
+        // One request comes back every 20 minutes
+        let later = start + Duration::from_secs(20 * 60);
+        assert_eq!(limits.take(RouteGroup::Public, client, later).retry_after, None);
+        assert!(limits.take(RouteGroup::Public, client, later).retry_after.is_some());
+    }
+
+    #[test]
+    fn test_clients_and_groups_have_separate_buckets() {
+        let limits = RateLimiter::new(Quota::per_hour(1), Quota::per_hour(2), false);
+        let now = Instant::now();
+        let user = Client::User(Uuid::new_v4());
+
+        assert_eq!(limits.take(RouteGroup::Api, user, now).retry_after, None);
+        assert!(limits.take(RouteGroup::Api, user, now).retry_after.is_some());
+        let other = Client::User(Uuid::new_v4());
+        assert_eq!(limits.take(RouteGroup::Api, other, now).retry_after, None);
+        let public = limits.take(RouteGroup::Public, user, now);
+        assert_eq!((public.limit, public.remaining), (2, 1));
+    }
+
+    #[test]
+    fn test_forwarded_for_uses_the_entry_the_proxy_added() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(forwarded_for(&headers), None);
+        headers.insert("x-forwarded-for", HeaderValue::from_static("1.2.3.4, 10.0.0.7"));
+        assert_eq!(forwarded_for(&headers), Some([10, 0, 0, 7].into()));
+        headers.insert("x-forwarded-for", HeaderValue::from_static("not an ip"));
+        assert_eq!(forwarded_for(&headers), None);
+    }
+
+    #[tokio::test]
+    async fn test_rejected_tokens_are_limited_per_ip() {
+        let pool = PgPool::connect_lazy("postgres://localhost/unused").unwrap();
+        let state = test_state_with_limits(&pool, limiter(1));
+        let ip: IpAddr = [10, 0, 0, 1].into();
+
+        let (status, headers, _) = send_from(&state, ip, "GET", "/api/workouts", None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(headers[&REMAINING_HEADER], "0");
+
+        let (status, headers, body) = send_from(&state, ip, "GET", "/api/workouts", None).await;
+        assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(headers["retry-after"], "3600");
+        assert_eq!(headers[&LIMIT_HEADER], "1");
+        assert_eq!(body["error"]["code"], 429);
+        assert_eq!(body["error"]["errors"][0]["reason"], "rateLimitExceeded");
+
+        // Another address has its own allowance
+        let other: IpAddr = [10, 0, 0, 2].into();
+        let (status, _, _) = send_from(&state, other, "GET", "/api/workouts", None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_signed_in_users_are_limited_per_user(pool: PgPool) {
+        let state = test_state_with_limits(&pool, limiter(2));
+        let ip: IpAddr = [10, 0, 0, 1].into();
+        let alice = token("user_alice");
+
+        let (status, headers, _) = send_from(&state, ip, "GET", "/api/workouts", Some(&alice)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(headers[&LIMIT_HEADER], "2");
+        assert_eq!(headers[&REMAINING_HEADER], "1");
+        send_from(&state, ip, "GET", "/api/workouts", Some(&alice)).await;
+        let (status, headers, _) = send_from(&state, ip, "GET", "/api/workouts", Some(&alice)).await;
+        assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+        assert!(headers.contains_key("retry-after"));
+
+        // Same address, different user
+        let bob = token("user_bob");
+        let (status, _, _) = send_from(&state, ip, "GET", "/api/workouts", Some(&bob)).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+}
@@ -0,0 +1,241 @@
+//! Response bodies shared by all endpoints.
+//!
+//! Follows the Google JSON Style Guide used by the API spec: every successful
+//! response wraps its payload in `data`, and lists carry paging metadata with
+//! `items` last.
+//!
+//! Lists are sorted by a timestamp and then the id, so every item has a
+//! stable position. A page starts either at a `startIndex` or after the item
+//! named by a `pageToken` (the `nextPageToken` of the previous page), which
+//! keeps paging stable while items are added or removed.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::Workout;
+use uuid::Uuid;
+
+/// Page size when the request doesn't give one.
+pub const DEFAULT_PAGE_SIZE: i64 = 50;
+
+/// Largest page size a request may ask for.
+pub const MAX_PAGE_SIZE: i64 = 100;
+
+/// A successful response: `{"data": ...}`.
+#[derive(Serialize, Debug)]
+pub struct Data<T> {
+    pub data: T,
+}
+
+impl<T> Data<T> {
+    pub fn new(data: T) -> Self {
+        Self { data }
+    }
+}
+
+/// One page of a list.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemList<T> {
+    /// Number of items in this page
+    pub current_item_count: usize,
+    /// Requested page size
+    pub items_per_page: i64,
+    /// Index of the first item in this page
+    pub start_index: i64,
+    /// Total number of items across all pages
+    pub total_items: i64,
+    /// `pageToken` for the following page (absent on the last page)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_page_token: Option<String>,
+    /// The items (kept last, per the style guide)
+    pub items: Vec<T>,
+}
+
+/// Paging query parameters of a list endpoint.
+///
+/// Accepts both the style guide names and the `limit`/`offset` aliases.
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PageParams {
+    #[serde(alias = "limit")]
+    pub items_per_page: Option<i64>,
+    #[serde(alias = "offset")]
+    pub start_index: Option<i64>,
+    pub page_token: Option<String>,
+}
+
+impl PageParams {
+    /// Checks the parameters, clamping the page size to `MAX_PAGE_SIZE`.
+    pub fn resolve(&self) -> Result<Page, String> {
+        let limit = self.items_per_page.unwrap_or(DEFAULT_PAGE_SIZE);
+        if limit < 1 {
+            return Err("itemsPerPage must be at least 1".to_string());
+        }
+        let start = match (self.start_index, &self.page_token) {
+            (Some(_), Some(_)) => {
+                return Err("Use either startIndex or pageToken, not both".to_string());
+            }
+            (None, Some(token)) => PageStart::After(PageCursor::decode(token)?),
+            (Some(index), None) if index < 0 => {
+                return Err("startIndex must not be negative".to_string());
+            }
+            (index, None) => PageStart::Index(index.unwrap_or(0)),
+        };
+        Ok(Page {
+            limit: limit.min(MAX_PAGE_SIZE),
+            start,
+        })
+    }
+}
+
+/// A checked page request.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Page {
+    /// Maximum number of items
+    pub limit: i64,
+    pub start: PageStart,
+}
+
+/// Where a page starts.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PageStart {
+    /// At a zero-based position in the list
+    Index(i64),
+    /// Just past an item, wherever it is now
+    After(PageCursor),
+}
+
+impl Page {
+    /// Rows to skip before the page (after the cursor, if any).
+    pub fn offset(&self) -> i64 {
+        match self.start {
+            PageStart::Index(index) => index,
+            PageStart::After(_) => 0,
+        }
+    }
+
+    /// The item the page starts after.
+    pub fn after(&self) -> Option<&PageCursor> {
+        match &self.start {
+            PageStart::Index(_) => None,
+            PageStart::After(cursor) => Some(cursor),
+        }
+    }
+}
+
+/// The sort key of a listed item: its timestamp, then its id.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PageCursor {
+    pub at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl PageCursor {
+    /// Page tokens are opaque to clients: `<microseconds>.<id>` in hex.
+    pub fn encode(&self) -> String {
+        format!("{}.{}", self.at.timestamp_micros(), self.id.simple())
+            .bytes()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    pub fn decode(token: &str) -> Result<Self, String> {
+        let invalid = || format!("Invalid pageToken '{}'", token);
+        let bytes = (0..token.len())
+            .step_by(2)
+            .map(|i| token.get(i..i + 2).and_then(|hex| u8::from_str_radix(hex, 16).ok()))
+            .collect::<Option<Vec<u8>>>()
+            .ok_or_else(invalid)?;
+        let text = String::from_utf8(bytes).map_err(|_| invalid())?;
+        let (micros, id) = text.split_once('.').ok_or_else(invalid)?;
+        Ok(Self {
+            at: micros
+                .parse()
+                .ok()
+                .and_then(DateTime::from_timestamp_micros)
+                .ok_or_else(invalid)?,
+            id: Uuid::parse_str(id).map_err(|_| invalid())?,
+        })
+    }
+}
+
+/// An item of a paged list.
+pub trait Paged {
+    /// Where the item sits in its list.
+    fn cursor(&self) -> PageCursor;
+}
+
+impl Paged for Workout {
+    fn cursor(&self) -> PageCursor {
+        PageCursor {
+            at: self.start_timestamp.to_datetime(),
+            // Stored workouts always have UUID ids
+            id: Uuid::parse_str(self.id.as_str()).unwrap_or_default(),
+        }
+    }
+}
+
+impl<T: Paged> ItemList<T> {
+    /// A page of `items` starting at `start_index` in a list of
+    /// `total_items`.
+    pub fn page(items: Vec<T>, page: &Page, start_index: i64, total_items: i64) -> Self {
+        let next_index = start_index + items.len() as i64;
+        let next_page_token = items
+            .last()
+            .filter(|_| next_index < total_items)
+            .map(|last| last.cursor().encode());
+        Self {
+            current_item_count: items.len(),
+            items_per_page: page.limit,
+            start_index,
+            total_items,
+            next_page_token,
+            items,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(start_index: Option<i64>, page_token: Option<&str>) -> PageParams {
+        PageParams {
+            items_per_page: None,
+            start_index,
+            page_token: page_token.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_page_tokens_round_trip() {
+        let cursor = PageCursor {
+            at: "2025-03-01T08:00:00.123456Z".parse().unwrap(),
+            id: Uuid::new_v4(),
+        };
+        let token = cursor.encode();
+        assert!(token.bytes().all(|b| b.is_ascii_hexdigit()));
+        assert_eq!(PageCursor::decode(&token), Ok(cursor.clone()));
+
+        let page = params(None, Some(&token)).resolve().unwrap();
+        assert_eq!(page.after(), Some(&cursor));
+        assert_eq!(page.offset(), 0);
+        assert!(PageCursor::decode("abc").is_err());
+        assert!(PageCursor::decode("2e").is_err());
+        assert!(params(Some(3), Some(&token)).resolve().is_err());
+    }
+
+    #[test]
+    fn test_next_page_token_only_when_more_items_remain() {
+        let mut workout = Workout::with_name("Legs");
+        workout.id = shared::Id::new();
+        let page = params(Some(0), None).resolve().unwrap();
+
+        let list = ItemList::page(vec![workout.clone()], &page, 0, 2);
+        let token = list.next_page_token.unwrap();
+        assert_eq!(PageCursor::decode(&token), Ok(workout.cursor()));
+
+        let list = ItemList::page(vec![workout], &page, 1, 2);
+        assert_eq!(list.next_page_token, None);
+    }
+}
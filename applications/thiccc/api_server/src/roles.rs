@@ -0,0 +1,120 @@
+//! Roles and per-route authorization.
+//!
+//! Every authenticated user has the `user` role; `admin` and `trainer` are
+//! granted in `user_roles`. Roles are ranked: an admin can do anything a
+//! trainer can, and a trainer anything a user can (matching the web
+//! frontend's feature flags).
+//!
+//! Routes opt in with a route layer after `require_auth`:
+//!
+//! ```ignore
+//! router.route_layer(middleware::from_fn_with_state(Role::Admin, roles::require_role))
+//! ```
+
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Extension,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::auth::AuthUser;
+use crate::error::ApiError;
+
+/// A user role, stored as the `user_role` Postgres enum.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    /// Manages users and sees system-wide stats
+    Admin,
+    /// Coaches other users
+    Trainer,
+    /// Every authenticated user
+    User,
+}
+
+impl Role {
+    /// The label used in JSON and in the database.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Admin => "admin",
+            Role::Trainer => "trainer",
+            Role::User => "user",
+        }
+    }
+
+    /// Whether having this role satisfies a route requiring `required`.
+    pub fn grants(&self, required: Role) -> bool {
+        self.rank() >= required.rank()
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            Role::Admin => 2,
+            Role::Trainer => 1,
+            Role::User => 0,
+        }
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Role {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "admin" => Ok(Role::Admin),
+            "trainer" => Ok(Role::Trainer),
+            "user" => Ok(Role::User),
+            other => Err(format!("Unknown role '{}'", other)),
+        }
+    }
+}
+
+/// Middleware that rejects users without a role granting `required`.
+///
+/// Runs after `require_auth`, which puts the `AuthUser` in the extensions.
+pub async fn require_role(
+    State(required): State<Role>,
+    Extension(user): Extension<AuthUser>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if user.has_role(required) {
+        next.run(request).await
+    } else {
+        ApiError::Forbidden(format!("Requires the {} role", required)).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_higher_roles_grant_lower_ones() {
+        assert!(Role::Admin.grants(Role::Trainer));
+        assert!(Role::Admin.grants(Role::User));
+        assert!(Role::Trainer.grants(Role::User));
+        assert!(Role::Trainer.grants(Role::Trainer));
+        assert!(!Role::Trainer.grants(Role::Admin));
+        assert!(!Role::User.grants(Role::Trainer));
+    }
+
+    #[test]
+    fn test_roles_parse_from_their_labels() {
+        for role in [Role::Admin, Role::Trainer, Role::User] {
+            assert_eq!(role.as_str().parse::<Role>(), Ok(role));
+            assert_eq!(serde_json::to_value(role).unwrap(), role.as_str());
+        }
+        assert!("owner".parse::<Role>().is_err());
+    }
+}
@@ -0,0 +1,320 @@
+//! Sync endpoint for the offline-first iOS core (see `shared::sync`).
+//!
+//! Clients push the field-level changes they made since their last sync and
+//! get back the current value of every field of each workout that another
+//! replica changed since their cursor. The server is one more replica: pushed
+//! changes go into the user's change log, the workout tables are rebuilt from
+//! the winning changes, and REST writes are logged too so they reach every
+//! device. Changes that would leave a workout invalid are refused for that
+//! workout only; the client gets the server's copy back instead.
+//!
+//! - `POST /api/sync` - exchange changes since a cursor
+
+use axum::{extract::State, routing::post, Json, Router};
+use shared::sync::{self, Change, EntityKind, SyncRejection, SyncRequest, SyncResponse};
+use shared::validation::{describe_errors, validate_workout};
+use sqlx::{Acquire, PgConnection};
+use std::collections::{BTreeMap, BTreeSet};
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::error::{ApiError, ApiResult, JsonBody};
+use crate::response::Data;
+use crate::{sync_store, workout_store, AppState};
+
+/// Routes for `/api/sync`.
+pub fn router() -> Router<AppState> {
+    Router::new().route("/api/sync", post(sync))
+}
+
+/// POST /api/sync
+async fn sync(
+    State(state): State<AppState>,
+    user: AuthUser,
+    JsonBody(request): JsonBody<SyncRequest>,
+) -> ApiResult<Json<Data<SyncResponse>>> {
+    let pushed = check_changes(&request.changes).map_err(ApiError::BadRequest)?;
+
+    let mut tx = state.db.begin().await?;
+    sync_store::lock_log(&mut tx, user.id).await?;
+
+    // Read what other replicas changed before logging this request, so the
+    // client's own changes aren't echoed back to it
+    let mut changed: BTreeSet<Uuid> = sync_store::changed_since(&mut tx, user.id, request.cursor)
+        .await?
+        .into_iter()
+        .collect();
+
+    let mut rejected = Vec::new();
+    for (workout_id, changes) in pushed {
+        let mut savepoint = tx.begin().await?;
+        sync_store::append(&mut savepoint, user.id, &changes).await?;
+        match rebuild(&mut savepoint, user.id, workout_id).await? {
+            Ok(()) => savepoint.commit().await?,
+            Err(error) => {
+                savepoint.rollback().await?;
+                changed.insert(workout_id);
+                rejected.push(SyncRejection {
+                    workout_id: changes[0].workout_id.clone(),
+                    error,
+                });
+            }
+        }
+    }
+
+    let changed: Vec<Uuid> = changed.into_iter().collect();
+    let changes = sync_store::current_values(&mut tx, user.id, &changed).await?;
+    let cursor = sync_store::latest_seq(&mut tx, user.id).await?;
+    tx.commit().await?;
+
+    Ok(Json(Data::new(SyncResponse {
+        cursor,
+        changes,
+        rejected,
+    })))
+}
+
+/// Rebuilds one workout's tables from its winning changes.
+///
+/// Returns `Ok(Err(reason))` when the merged workout can't be stored, so the
+/// caller can refuse just that workout's changes.
+async fn rebuild(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    workout_id: Uuid,
+) -> ApiResult<Result<(), String>> {
+    let current = workout_store::fetch_workout(conn, user_id, workout_id).await?;
+    let values = sync_store::current_values(conn, user_id, &[workout_id]).await?;
+    let merged = match sync::apply_changes(current.clone(), &values) {
+        Ok(merged) => merged,
+        Err(error) => return Ok(Err(error)),
+    };
+
+    match (current, merged) {
+        (current, Some(workout)) if current.as_ref() != Some(&workout) => {
+            if let Err(errors) = validate_workout(&workout) {
+                return Ok(Err(describe_errors(&errors)));
+            }
+            if current.is_some() {
+                workout_store::replace_workout(conn, user_id, &workout).await?;
+            } else {
+                workout_store::insert_workout(conn, user_id, &workout).await?;
+            }
+        }
+        (Some(_), None) => {
+            workout_store::delete_workout(conn, user_id, workout_id).await?;
+        }
+        _ => {}
+    }
+    Ok(Ok(()))
+}
+
+/// Checks the shape of pushed changes and groups them by the workout they
+/// touch.
+fn check_changes(changes: &[Change]) -> Result<BTreeMap<Uuid, Vec<Change>>, String> {
+    let mut pushed: BTreeMap<Uuid, Vec<Change>> = BTreeMap::new();
+    for change in changes {
+        let parse = |id: &str| {
+            Uuid::parse_str(id).map_err(|_| format!("Invalid id '{}' in sync change", id))
+        };
+        parse(&change.entity_id)?;
+        let workout_id = parse(&change.workout_id)?;
+        match (&change.kind, &change.exercise_id) {
+            (EntityKind::Set, Some(exercise_id)) => {
+                parse(exercise_id)?;
+            }
+            (EntityKind::Set, None) => {
+                return Err(format!("Set {} has no exercise id", change.entity_id));
+            }
+            (_, Some(_)) => {
+                return Err(format!(
+                    "Only sets have an exercise id (change to {})",
+                    change.entity_id
+                ));
+            }
+            (_, None) => {}
+        }
+        if serde_json::from_str::<serde_json::Value>(&change.value).is_err() {
+            return Err(format!(
+                "Value of '{}' on {} is not JSON",
+                change.field, change.entity_id
+            ));
+        }
+        if i64::try_from(change.clock.counter).is_err() {
+            return Err(format!("Clock counter {} is too large", change.clock.counter));
+        }
+        pushed.entry(workout_id).or_default().push(change.clone());
+    }
+    Ok(pushed)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::{send, token};
+    use axum::http::StatusCode;
+    use serde_json::{json, Value};
+    use shared::sync::{diff, Clock, SyncRequest};
+    use shared::{SetActual, Workout};
+    use sqlx::PgPool;
+
+    fn clock(counter: u64, replica: &str) -> Clock {
+        Clock {
+            counter,
+            replica: replica.to_string(),
+        }
+    }
+
+    async fn push(pool: &PgPool, user: &str, request: SyncRequest) -> (StatusCode, Value) {
+        send(pool, "POST", "/api/sync", Some(user), Some(json!(request))).await
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_sync_merges_concurrent_edits_from_two_devices(pool: PgPool) {
+        let user = token("user_sync");
+        let mut workout = Workout::with_name("Push");
+        workout.add_exercise("Bench").add_set();
+        let id = workout.id.as_str().to_string();
+
+        // Phone creates the workout
+        let created = SyncRequest {
+            cursor: 0,
+            changes: diff(None, Some(&workout), &clock(1, "phone")),
+        };
+        let (status, body) = push(&pool, &user, created).await;
+        assert_eq!(status, StatusCode::OK);
+        let phone_cursor = body["data"]["cursor"].as_i64().unwrap();
+        assert!(phone_cursor > 0);
+        assert_eq!(body["data"]["changes"], json!([]), "own changes aren't echoed back");
+
+        // Tablet pulls it, then renames it while the phone completes the set
+        let (_, body) = push(&pool, &user, SyncRequest::default()).await;
+        let pulled = body["data"]["changes"].as_array().unwrap().len();
+        assert_eq!(pulled, diff(None, Some(&workout), &clock(1, "phone")).len());
+
+        let mut renamed = workout.clone();
+        renamed.name = "Push Day".into();
+        let mut completed = workout.clone();
+        completed.exercises[0].sets[0].complete(SetActual::with_weight_and_reps(185.0, 5));
+        let (status, _) = push(
+            &pool,
+            &user,
+            SyncRequest {
+                cursor: phone_cursor,
+                changes: diff(Some(&workout), Some(&renamed), &clock(2, "tablet")),
+            },
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, body) = push(
+            &pool,
+            &user,
+            SyncRequest {
+                cursor: phone_cursor,
+                changes: diff(Some(&workout), Some(&completed), &clock(2, "phone")),
+            },
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        // The phone hears about the rename; both edits are stored
+        let changes = body["data"]["changes"].as_array().unwrap();
+        assert!(changes
+            .iter()
+            .any(|c| c["field"] == "name" && c["value"] == r#""Push Day""#));
+        let (_, stored) = send(&pool, "GET", &format!("/api/workouts/{}", id), Some(&user), None).await;
+        assert_eq!(stored["data"]["name"], "Push Day");
+        assert_eq!(stored["data"]["exercises"][0]["sets"][0]["is_completed"], true);
+
+        // Nothing new since the latest cursor
+        let cursor = body["data"]["cursor"].as_i64().unwrap();
+        let (_, body) = push(&pool, &user, SyncRequest { cursor, changes: vec![] }).await;
+        assert_eq!(body["data"]["changes"], json!([]));
+        assert_eq!(body["data"]["cursor"], cursor);
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_sync_sees_rest_writes_and_propagates_deletes(pool: PgPool) {
+        let user = token("user_sync");
+        let workout = Workout::with_name("Legs");
+        let id = workout.id.as_str().to_string();
+        let uri = format!("/api/workouts/{}", id);
+
+        send(&pool, "POST", "/api/workouts", Some(&user), Some(json!(workout))).await;
+        let (_, body) = push(&pool, &user, SyncRequest::default()).await;
+        let cursor = body["data"]["cursor"].as_i64().unwrap();
+        assert!(body["data"]["changes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|c| c["field"] == "name" && c["clock"]["replica"] == "server"));
+
+        let (status, _) = send(&pool, "PATCH", &uri, Some(&user), Some(json!({ "name": "Leg Day" }))).await;
+        assert_eq!(status, StatusCode::OK);
+        let (_, body) = push(&pool, &user, SyncRequest { cursor, changes: vec![] }).await;
+        assert!(body["data"]["changes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|c| c["field"] == "name" && c["value"] == r#""Leg Day""#));
+
+        // A device deletes it
+        let cursor = body["data"]["cursor"].as_i64().unwrap();
+        let mut latest = workout.clone();
+        latest.name = "Leg Day".into();
+        let deleted = diff(Some(&latest), None, &clock(100, "phone"));
+        let (status, _) = push(&pool, &user, SyncRequest { cursor, changes: deleted }).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = send(&pool, "GET", &uri, Some(&user), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_sync_rejects_malformed_changes(pool: PgPool) {
+        let user = token("user_sync");
+        let workout = Workout::with_name("Arms");
+        let mut changes = diff(None, Some(&workout), &clock(1, "phone"));
+        changes[0].entity_id = "not-a-uuid".into();
+
+        let (status, _) = push(&pool, &user, SyncRequest { cursor: 0, changes }).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (_, body) = push(&pool, &user, SyncRequest::default()).await;
+        assert_eq!(body["data"]["cursor"], 0, "a malformed sync stores nothing");
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_sync_refuses_only_the_invalid_workout(pool: PgPool) {
+        let user = token("user_sync");
+        let valid = Workout::with_name("Arms");
+        let mut invalid = Workout::with_name("Back");
+        invalid.add_exercise("Row").add_set().actual.rpe = Some(15.0);
+        let mut changes = diff(None, Some(&valid), &clock(1, "phone"));
+        changes.extend(diff(None, Some(&invalid), &clock(1, "phone")));
+
+        let (status, body) = push(&pool, &user, SyncRequest { cursor: 0, changes }).await;
+        assert_eq!(status, StatusCode::OK);
+        let rejected = body["data"]["rejected"].as_array().unwrap();
+        assert_eq!(rejected.len(), 1);
+        assert_eq!(rejected[0]["workout_id"], invalid.id.as_str());
+        assert!(rejected[0]["error"].as_str().unwrap().contains("RPE"));
+        assert!(body["data"]["cursor"].as_i64().unwrap() > 0);
+
+        let uri = |w: &Workout| format!("/api/workouts/{}", w.id.as_str());
+        let (status, _) = send(&pool, "GET", &uri(&valid), Some(&user), None).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = send(&pool, "GET", &uri(&invalid), Some(&user), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        // The refused changes aren't in the log for other devices either
+        let (_, body) = push(&pool, &user, SyncRequest::default()).await;
+        assert!(body["data"]["changes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|c| c["workout_id"] == valid.id.as_str()));
+    }
+}
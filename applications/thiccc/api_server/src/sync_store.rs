@@ -0,0 +1,173 @@
+//! Postgres storage for the sync change log (`sync_changes`).
+//!
+//! Changes are only ever appended; the current value of a field is the change
+//! with the highest clock. Writers call `lock_log` first and hold it until
+//! commit, so one user's changes get their sequence numbers in commit order
+//! and a client's cursor never skips a change that commits later.
+
+use shared::sync::{self, Change, Clock, SERVER_REPLICA};
+use shared::Workout;
+use sqlx::{FromRow, PgConnection};
+use uuid::Uuid;
+
+use crate::workout_store::{enum_text, id, parse_enum};
+
+#[derive(FromRow)]
+struct ChangeRow {
+    entity_kind: String,
+    entity_id: Uuid,
+    workout_id: Uuid,
+    exercise_id: Option<Uuid>,
+    field: String,
+    value: String,
+    clock_counter: i64,
+    clock_replica: String,
+}
+
+impl TryFrom<ChangeRow> for Change {
+    type Error = sqlx::Error;
+
+    fn try_from(row: ChangeRow) -> Result<Self, Self::Error> {
+        Ok(Change {
+            kind: parse_enum(row.entity_kind)?,
+            entity_id: row.entity_id.to_string(),
+            workout_id: row.workout_id.to_string(),
+            exercise_id: row.exercise_id.map(|id| id.to_string()),
+            field: row.field,
+            value: row.value,
+            clock: Clock {
+                counter: u64::try_from(row.clock_counter)
+                    .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+                replica: row.clock_replica,
+            },
+        })
+    }
+}
+
+/// Takes the user's sync lock until the end of the transaction.
+pub async fn lock_log(conn: &mut PgConnection, user_id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query("SELECT id FROM users WHERE id = $1 FOR UPDATE")
+        .bind(user_id)
+        .execute(conn)
+        .await?;
+    Ok(())
+}
+
+/// Appends changes to the user's log. Ids that are not UUIDs and counters
+/// past `i64::MAX` fail with an encode error.
+pub async fn append(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    changes: &[Change],
+) -> Result<(), sqlx::Error> {
+    for change in changes {
+        let exercise_id = match &change.exercise_id {
+            Some(id) => Some(parse_uuid(id)?),
+            None => None,
+        };
+        let counter =
+            i64::try_from(change.clock.counter).map_err(|e| sqlx::Error::Encode(Box::new(e)))?;
+        sqlx::query(
+            "INSERT INTO sync_changes
+                (user_id, entity_kind, entity_id, workout_id, exercise_id, field, value,
+                 clock_counter, clock_replica)
+             VALUES ($1, $2::sync_entity_kind, $3, $4, $5, $6, $7, $8, $9)",
+        )
+        .bind(user_id)
+        .bind(enum_text(&change.kind))
+        .bind(parse_uuid(&change.entity_id)?)
+        .bind(parse_uuid(&change.workout_id)?)
+        .bind(exercise_id)
+        .bind(&change.field)
+        .bind(&change.value)
+        .bind(counter)
+        .bind(&change.clock.replica)
+        .execute(&mut *conn)
+        .await?;
+    }
+    Ok(())
+}
+
+/// Logs the changes a REST write made, stamped with the server's clock
+/// (one past the highest counter in the user's log).
+pub async fn record(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    before: Option<&Workout>,
+    after: Option<&Workout>,
+) -> Result<(), sqlx::Error> {
+    let counter: i64 = sqlx::query_scalar(
+        "SELECT COALESCE(MAX(clock_counter), 0) + 1 FROM sync_changes WHERE user_id = $1",
+    )
+    .bind(user_id)
+    .fetch_one(&mut *conn)
+    .await?;
+    let clock = Clock {
+        counter: counter as u64,
+        replica: SERVER_REPLICA.to_string(),
+    };
+    append(conn, user_id, &sync::diff(before, after, &clock)).await
+}
+
+/// Logs the deletion of a workout by a REST write.
+pub async fn record_deleted(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    workout_id: Uuid,
+) -> Result<(), sqlx::Error> {
+    let workout = Workout {
+        id: id(workout_id),
+        ..Workout::new()
+    };
+    record(conn, user_id, Some(&workout), None).await
+}
+
+/// The highest sequence number in the user's log (0 when it's empty).
+pub async fn latest_seq(conn: &mut PgConnection, user_id: Uuid) -> Result<i64, sqlx::Error> {
+    sqlx::query_scalar("SELECT COALESCE(MAX(seq), 0) FROM sync_changes WHERE user_id = $1")
+        .bind(user_id)
+        .fetch_one(conn)
+        .await
+}
+
+/// Workouts with a change logged after `cursor`.
+pub async fn changed_since(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    cursor: i64,
+) -> Result<Vec<Uuid>, sqlx::Error> {
+    sqlx::query_scalar(
+        "SELECT DISTINCT workout_id FROM sync_changes WHERE user_id = $1 AND seq > $2",
+    )
+    .bind(user_id)
+    .bind(cursor)
+    .fetch_all(conn)
+    .await
+}
+
+/// The winning change for every field of the given workouts.
+pub async fn current_values(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    workout_ids: &[Uuid],
+) -> Result<Vec<Change>, sqlx::Error> {
+    let rows: Vec<ChangeRow> = sqlx::query_as(
+        "SELECT DISTINCT ON (workout_id, entity_kind, entity_id, field)
+                entity_kind::text AS entity_kind, entity_id, workout_id, exercise_id, field,
+                value, clock_counter, clock_replica
+         FROM sync_changes
+         WHERE user_id = $1 AND workout_id = ANY($2)
+         ORDER BY workout_id, entity_kind, entity_id, field,
+                  clock_counter DESC, clock_replica DESC",
+    )
+    .bind(user_id)
+    .bind(workout_ids)
+    .fetch_all(conn)
+    .await?;
+    rows.into_iter().map(Change::try_from).collect()
+}
+
+
+fn parse_uuid(id: &str) -> Result<Uuid, sqlx::Error> {
+    Uuid::parse_str(id).map_err(|e| sqlx::Error::Encode(Box::new(e)))
+}
@@ -0,0 +1,134 @@
+//! Helpers shared by the API server's tests.
+
+use axum::body::{to_bytes, Body};
+use axum::extract::ConnectInfo;
+use axum::http::{HeaderMap, Request, StatusCode};
+use jsonwebtoken::{encode, EncodingKey, Header};
+use serde_json::{json, Value};
+use sqlx::PgPool;
+use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
+use tower::ServiceExt;
+
+use crate::auth::{JwksSource, JwtVerifier};
+use crate::config::{Config, FileConfig};
+use crate::rate_limit::RateLimiter;
+use crate::AppState;
+
+/// Path of a file in `fixtures/auth`.
+pub fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("fixtures/auth")
+        .join(name)
+}
+
+/// A verifier for the fixture key set (`test-key-1`).
+pub fn test_verifier() -> JwtVerifier {
+    JwtVerifier::new(JwksSource::File(fixture("jwks.json")), None)
+}
+
+/// Signs `claims` with fixture key `test-key-{key}`.
+pub fn sign_token(key: u8, claims: Value) -> String {
+    let pem = std::fs::read(fixture(&format!("test-key-{}.pem", key))).unwrap();
+    let mut header = Header::new(jsonwebtoken::Algorithm::RS256);
+    header.kid = Some(format!("test-key-{}", key));
+    encode(&header, &claims, &EncodingKey::from_rsa_pem(&pem).unwrap()).unwrap()
+}
+
+/// A valid token for Clerk user `sub`, with an email, expiring in an hour.
+pub fn token(sub: &str) -> String {
+    let exp = jsonwebtoken::get_current_timestamp() + 3600;
+    sign_token(1, json!({ "sub": sub, "email": format!("{}@example.com", sub), "exp": exp }))
+}
+
+/// App state backed by `pool` and the fixture key set, with the default
+/// rate limits.
+pub fn test_state(pool: &PgPool) -> AppState {
+    let config = test_config();
+    let limits = RateLimiter::new(config.user_quota, config.ip_quota, false);
+    test_state_with_limits(pool, limits)
+}
+
+/// App state backed by `pool` and the fixture key set, with `limits`.
+pub fn test_state_with_limits(pool: &PgPool, limits: RateLimiter) -> AppState {
+    AppState {
+        db: pool.clone(),
+        auth: test_verifier(),
+        limits,
+    }
+}
+
+/// Default settings with the fixture key set.
+pub fn test_config() -> Config {
+    Config::from_sources(
+        |name| match name {
+            "DATABASE_URL" => Some("postgres://localhost/thiccc_test".to_string()),
+            "CLERK_JWKS_PATH" => Some(fixture("jwks.json").display().to_string()),
+            _ => None,
+        },
+        FileConfig::default(),
+    )
+    .unwrap()
+}
+
+/// Sends a request through the full app and returns the status and JSON body
+/// (`Null` when the body isn't JSON).
+pub async fn send(
+    pool: &PgPool,
+    method: &str,
+    uri: &str,
+    token: Option<&str>,
+    body: Option<Value>,
+) -> (StatusCode, Value) {
+    let mut request = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("content-type", "application/json");
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Bearer {}", token));
+    }
+    let request = request
+        .body(body.map_or_else(Body::empty, |b| Body::from(b.to_string())))
+        .unwrap();
+
+    let response = crate::app(test_state(pool), &test_config()).oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+}
+
+/// Sends a bodiless request from `ip` through the full app built on `state`
+/// (so rate limits carry over between calls) and returns the status, headers
+/// and JSON body.
+pub async fn send_from(
+    state: &AppState,
+    ip: IpAddr,
+    method: &str,
+    uri: &str,
+    token: Option<&str>,
+) -> (StatusCode, HeaderMap, Value) {
+    let mut request = Request::builder().method(method).uri(uri);
+    if let Some(token) = token {
+        request = request.header("authorization", format!("Bearer {}", token));
+    }
+    let mut request = request.body(Body::empty()).unwrap();
+    request
+        .extensions_mut()
+        .insert(ConnectInfo(SocketAddr::new(ip, 0)));
+
+    let response = crate::app(state.clone(), &test_config()).oneshot(request).await.unwrap();
+    let status = response.status();
+    let headers = response.headers().clone();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, headers, serde_json::from_slice(&bytes).unwrap_or(Value::Null))
+}
+
+/// Grants `role` to the (already provisioned) user with `clerk_id`.
+pub async fn grant_role(pool: &PgPool, clerk_id: &str, role: &str) {
+    sqlx::query("INSERT INTO user_roles (user_id, role) SELECT id, $2::user_role FROM users WHERE clerk_id = $1")
+        .bind(clerk_id)
+        .bind(role)
+        .execute(pool)
+        .await
+        .unwrap();
+}
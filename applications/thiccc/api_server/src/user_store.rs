@@ -0,0 +1,203 @@
+//! Postgres storage for users.
+//!
+//! Users are keyed by their Clerk id and provisioned from their token: created
+//! the first time they make an authenticated request, and their email updated
+//! (with the change recorded in `user_email_changes`) when the token's email
+//! differs from the stored one. Roles beyond the base `user` role live in
+//! `user_roles`.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::response::Page;
+use crate::roles::Role;
+
+/// A row in `users`.
+#[derive(FromRow, Clone, Debug, PartialEq)]
+pub struct User {
+    pub id: Uuid,
+    pub clerk_id: String,
+    pub email: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+const USER_COLUMNS: &str = "id, clerk_id, email, created_at, updated_at";
+
+/// Returns the user with `clerk_id`, creating the user or updating their
+/// email as needed.
+///
+/// `email` is the email from the token; `None` (the token doesn't carry one)
+/// leaves the stored email alone.
+pub async fn provision_user(
+    pool: &PgPool,
+    clerk_id: &str,
+    email: Option<&str>,
+) -> Result<User, sqlx::Error> {
+    // Fast path for the common case: known user, unchanged email
+    let existing: Option<User> =
+        sqlx::query_as(&format!("SELECT {} FROM users WHERE clerk_id = $1", USER_COLUMNS))
+            .bind(clerk_id)
+            .fetch_optional(pool)
+            .await?;
+    if let Some(user) = existing.filter(|user| email.is_none() || user.email.as_deref() == email) {
+        return Ok(user);
+    }
+
+    let mut tx = pool.begin().await?;
+    sqlx::query("INSERT INTO users (clerk_id, email) VALUES ($1, $2) ON CONFLICT (clerk_id) DO NOTHING")
+        .bind(clerk_id)
+        .bind(email)
+        .execute(&mut *tx)
+        .await?;
+    let mut user: User = sqlx::query_as(&format!(
+        "SELECT {} FROM users WHERE clerk_id = $1 FOR UPDATE",
+        USER_COLUMNS
+    ))
+    .bind(clerk_id)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if let Some(email) = email.filter(|email| user.email.as_deref() != Some(*email)) {
+        sqlx::query("INSERT INTO user_email_changes (user_id, old_email, new_email) VALUES ($1, $2, $3)")
+            .bind(user.id)
+            .bind(&user.email)
+            .bind(email)
+            .execute(&mut *tx)
+            .await?;
+        user = sqlx::query_as(&format!(
+            "UPDATE users SET email = $2, updated_at = NOW() WHERE id = $1 RETURNING {}",
+            USER_COLUMNS
+        ))
+        .bind(user.id)
+        .bind(email)
+        .fetch_one(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(user)
+}
+
+/// Returns a user's roles: the granted ones plus `Role::User`, highest first.
+pub async fn fetch_roles(pool: &PgPool, user_id: Uuid) -> Result<Vec<Role>, sqlx::Error> {
+    let granted: Vec<(String,)> = sqlx::query_as("SELECT role::text FROM user_roles WHERE user_id = $1")
+        .bind(user_id)
+        .fetch_all(pool)
+        .await?;
+    parse_roles(granted.into_iter().map(|(role,)| role))
+}
+
+/// A user with their roles and number of workouts, for the admin user list.
+#[derive(FromRow, Clone, Debug, PartialEq)]
+pub struct UserSummary {
+    pub id: Uuid,
+    pub clerk_id: String,
+    pub email: Option<String>,
+    pub created_at: DateTime<Utc>,
+    /// Granted roles (without the base `user` role)
+    pub roles: Vec<String>,
+    pub workout_count: i64,
+}
+
+/// Loads one page of users, oldest first (then by id), along with the
+/// page's start index and the total number of users.
+pub async fn list_users(
+    pool: &PgPool,
+    page: &Page,
+) -> Result<(Vec<UserSummary>, i64, i64), sqlx::Error> {
+    let (total,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM users")
+        .fetch_one(pool)
+        .await?;
+    let cursor = page.after();
+    let start_index = match cursor {
+        Some(cursor) => {
+            sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE (created_at, id) <= ($1, $2)")
+                .bind(cursor.at)
+                .bind(cursor.id)
+                .fetch_one(pool)
+                .await?
+        }
+        None => page.offset(),
+    };
+    let users = sqlx::query_as(
+        "SELECT u.id, u.clerk_id, u.email, u.created_at, \
+         COALESCE((SELECT array_agg(r.role::text) FROM user_roles r WHERE r.user_id = u.id), '{}') AS roles, \
+         (SELECT COUNT(*) FROM workouts w WHERE w.user_id = u.id) AS workout_count \
+         FROM users u WHERE ($1::timestamptz IS NULL OR (u.created_at, u.id) > ($1, $2)) \
+         ORDER BY u.created_at, u.id LIMIT $3 OFFSET $4",
+    )
+    .bind(cursor.map(|cursor| cursor.at))
+    .bind(cursor.map(|cursor| cursor.id))
+    .bind(page.limit)
+    .bind(page.offset())
+    .fetch_all(pool)
+    .await?;
+    Ok((users, start_index, total))
+}
+
+/// Parses stored role labels and adds the base `user` role.
+pub fn parse_roles(labels: impl IntoIterator<Item = String>) -> Result<Vec<Role>, sqlx::Error> {
+    let mut roles = vec![Role::User];
+    for label in labels {
+        roles.push(label.parse().map_err(|e: String| sqlx::Error::Decode(e.into()))?);
+    }
+    roles.sort();
+    roles.dedup();
+    Ok(roles)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn email_changes(pool: &PgPool) -> Vec<(Option<String>, String)> {
+        sqlx::query_as("SELECT old_email, new_email FROM user_email_changes ORDER BY id")
+            .fetch_all(pool)
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse_roles_adds_the_base_role() {
+        let roles = parse_roles(["trainer".to_string(), "admin".to_string()]).unwrap();
+        assert_eq!(roles, [Role::Admin, Role::Trainer, Role::User]);
+        assert_eq!(parse_roles([]).unwrap(), [Role::User]);
+        assert!(parse_roles(["owner".to_string()]).is_err());
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_users_are_created_once_per_clerk_id(pool: PgPool) {
+        let first = provision_user(&pool, "user_1", Some("a@example.com")).await.unwrap();
+        let again = provision_user(&pool, "user_1", None).await.unwrap();
+        let other = provision_user(&pool, "user_2", None).await.unwrap();
+
+        assert_eq!(first, again);
+        assert_eq!(first.email.as_deref(), Some("a@example.com"));
+        assert_ne!(first.id, other.id);
+        assert_eq!(other.email, None);
+        assert!(email_changes(&pool).await.is_empty());
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_email_changes_are_recorded(pool: PgPool) {
+        let created = provision_user(&pool, "user_1", None).await.unwrap();
+        provision_user(&pool, "user_1", Some("a@example.com")).await.unwrap();
+        provision_user(&pool, "user_1", Some("a@example.com")).await.unwrap();
+        let changed = provision_user(&pool, "user_1", Some("b@example.com")).await.unwrap();
+
+        assert_eq!(changed.id, created.id);
+        assert_eq!(changed.email.as_deref(), Some("b@example.com"));
+        assert!(changed.updated_at >= created.updated_at);
+        assert_eq!(
+            email_changes(&pool).await,
+            [
+                (None, "a@example.com".to_string()),
+                (Some("a@example.com".to_string()), "b@example.com".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,95 @@
+//! User endpoints.
+//!
+//! - `GET /api/me` - the authenticated user's profile and roles
+//!
+//! The user is provisioned by `require_auth` before the handler runs, so the
+//! first request to any `/api` route creates the account.
+
+use axum::{routing::get, Json, Router};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::response::Data;
+use crate::roles::Role;
+use crate::AppState;
+
+/// Routes for `/api/me`.
+pub fn router() -> Router<AppState> {
+    Router::new().route("/api/me", get(get_me))
+}
+
+/// The authenticated user's profile.
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    pub id: Uuid,
+    pub clerk_id: String,
+    pub email: Option<String>,
+    pub roles: Vec<Role>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// GET /api/me
+async fn get_me(user: AuthUser) -> Json<Data<Profile>> {
+    Json(Data::new(Profile {
+        id: user.id,
+        clerk_id: user.clerk_id,
+        email: user.email,
+        roles: user.roles,
+        created_at: user.created_at,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::{send, sign_token, token};
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use sqlx::PgPool;
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_me_provisions_the_user_on_first_request(pool: PgPool) {
+        let (status, body) = send(&pool, "GET", "/api/me", Some(&token("user_alice")), None).await;
+        assert_eq!(status, StatusCode::OK);
+        let me = &body["data"];
+        assert_eq!(me["clerkId"], "user_alice");
+        assert_eq!(me["email"], "user_alice@example.com");
+        assert_eq!(me["roles"], json!(["user"]));
+
+        let (_, again) = send(&pool, "GET", "/api/me", Some(&token("user_alice")), None).await;
+        assert_eq!(again["data"]["id"], me["id"]);
+        assert_eq!(again["data"]["createdAt"], me["createdAt"]);
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_me_follows_email_changes(pool: PgPool) {
+        send(&pool, "GET", "/api/me", Some(&token("user_alice")), None).await;
+
+        let exp = jsonwebtoken::get_current_timestamp() + 3600;
+        let renamed = sign_token(1, json!({ "sub": "user_alice", "email": "alice@new.dev", "exp": exp }));
+        let (_, body) = send(&pool, "GET", "/api/me", Some(&renamed), None).await;
+        assert_eq!(body["data"]["email"], "alice@new.dev");
+
+        // A token without an email keeps the last one seen
+        let bare = sign_token(1, json!({ "sub": "user_alice", "exp": exp }));
+        let (_, body) = send(&pool, "GET", "/api/me", Some(&bare), None).await;
+        assert_eq!(body["data"]["email"], "alice@new.dev");
+
+        let (changes,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM user_email_changes")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(changes, 1);
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_me_requires_a_token(pool: PgPool) {
+        let (status, _) = send(&pool, "GET", "/api/me", None, None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+}
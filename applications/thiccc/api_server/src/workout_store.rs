@@ -0,0 +1,852 @@
+//! Postgres storage for workouts, exercises and sets.
+//!
+//! Workouts are stored across three tables with one column per model field
+//! (see `db/migrations`) and read back into the shared crate's `Workout`
+//! model. Enums are Postgres enums labelled with their serde names, bound and
+//! read as text. Writes take a connection so callers can run several of them
+//! in one transaction.
+//!
+//! Every workout belongs to a user; reads and writes are scoped to the
+//! owner, so another user's workout looks the same as a missing one.
+//!
+//! Callers validate workouts with `shared::validation::validate_workout`
+//! before writing; ids that are not UUIDs fail with an encode error.
+//!
+//! A live session's in-progress workout is kept apart from history, as JSON
+//! in `current_workouts`.
+
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use shared::operations::{HistoryCursor, HistoryFilter};
+use shared::{
+    exercise_name_key, schema, BodyPart, Exercise, ExerciseSet, Id, SetActual, SetSuggest,
+    Timestamp, Workout,
+};
+use sqlx::{FromRow, PgConnection};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::response::Page;
+
+/// Sort order for listing workouts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum SortOrder {
+    /// Oldest first
+    DateAsc,
+    /// Newest first
+    #[default]
+    DateDesc,
+}
+
+// =============================================================================
+// MARK: - Rows
+// =============================================================================
+
+#[derive(FromRow)]
+struct WorkoutRow {
+    id: Uuid,
+    schema_version: i32,
+    name: String,
+    note: Option<String>,
+    duration: Option<i32>,
+    start_timestamp: DateTime<Utc>,
+    end_timestamp: Option<DateTime<Utc>>,
+}
+
+#[derive(FromRow)]
+struct ExerciseRow {
+    id: Uuid,
+    workout_id: Uuid,
+    superset_id: Option<i32>,
+    name: String,
+    pinned_notes: Vec<String>,
+    notes: Vec<String>,
+    duration: Option<i32>,
+    exercise_type: String,
+    weight_unit: Option<String>,
+    default_warm_up_time: Option<i32>,
+    default_rest_time: Option<i32>,
+    body_part_main: Option<String>,
+    body_part_detailed: Option<Vec<String>>,
+    body_part_scientific: Option<Vec<String>>,
+}
+
+#[derive(FromRow)]
+struct SetRow {
+    id: Uuid,
+    exercise_id: Uuid,
+    workout_id: Uuid,
+    set_index: i32,
+    set_type: String,
+    weight_unit: Option<String>,
+    is_completed: bool,
+    suggest_weight: Option<f64>,
+    suggest_reps: Option<i32>,
+    suggest_rep_range: Option<i32>,
+    suggest_duration: Option<i32>,
+    suggest_rpe: Option<f64>,
+    suggest_rest_time: Option<i32>,
+    actual_weight: Option<f64>,
+    actual_reps: Option<i32>,
+    actual_duration: Option<i32>,
+    actual_rpe: Option<f64>,
+    actual_rest_time: Option<i32>,
+}
+
+const WORKOUT_COLUMNS: &str =
+    "id, schema_version, name, note, duration, start_timestamp, end_timestamp";
+
+// =============================================================================
+// MARK: - Reads
+// =============================================================================
+
+/// Loads one of a user's workouts with its exercises and sets.
+pub async fn fetch_workout(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    id: Uuid,
+) -> Result<Option<Workout>, sqlx::Error> {
+    let row: Option<WorkoutRow> = sqlx::query_as(&format!(
+        "SELECT {} FROM workouts WHERE id = $1 AND user_id = $2",
+        WORKOUT_COLUMNS
+    ))
+    .bind(id)
+    .bind(user_id)
+    .fetch_optional(&mut *conn)
+    .await?;
+
+    match row {
+        Some(row) => Ok(assemble(conn, vec![row]).await?.pop()),
+        None => Ok(None),
+    }
+}
+
+/// Loads one page of a user's workouts ordered by (start time, id), along
+/// with the page's start index and the user's total number of workouts.
+pub async fn list_workouts(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    sort: SortOrder,
+    page: &Page,
+) -> Result<(Vec<Workout>, i64, i64), sqlx::Error> {
+    let (total,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM workouts WHERE user_id = $1")
+        .bind(user_id)
+        .fetch_one(&mut *conn)
+        .await?;
+
+    // Workouts that sort after the cursor, and those up to and including it
+    let (direction, after, through) = match sort {
+        SortOrder::DateAsc => ("ASC", ">", "<="),
+        SortOrder::DateDesc => ("DESC", "<", ">="),
+    };
+    let cursor = page.after();
+    let start_index = match cursor {
+        Some(cursor) => {
+            sqlx::query_scalar(&format!(
+                "SELECT COUNT(*) FROM workouts WHERE user_id = $1 \
+                 AND (start_timestamp, id) {} ($2, $3)",
+                through
+            ))
+            .bind(user_id)
+            .bind(cursor.at)
+            .bind(cursor.id)
+            .fetch_one(&mut *conn)
+            .await?
+        }
+        None => page.offset(),
+    };
+    let rows: Vec<WorkoutRow> = sqlx::query_as(&format!(
+        "SELECT {} FROM workouts WHERE user_id = $1 \
+         AND ($2::timestamptz IS NULL OR (start_timestamp, id) {after} ($2, $3)) \
+         ORDER BY start_timestamp {dir}, id {dir} LIMIT $4 OFFSET $5",
+        WORKOUT_COLUMNS,
+        after = after,
+        dir = direction
+    ))
+    .bind(user_id)
+    .bind(cursor.map(|cursor| cursor.at))
+    .bind(cursor.map(|cursor| cursor.id))
+    .bind(page.limit)
+    .bind(page.offset())
+    .fetch_all(&mut *conn)
+    .await?;
+
+    Ok((assemble(conn, rows).await?, start_index, total))
+}
+
+/// Loads a user's workouts matching `filter`, newest first by (start time,
+/// id), starting after `cursor` and returning at most `limit` (all when None).
+///
+/// Matches `HistoryFilter::matches`, like the iOS database query.
+pub async fn history(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    filter: &HistoryFilter,
+    cursor: Option<&HistoryCursor>,
+    limit: Option<i64>,
+) -> Result<Vec<Workout>, sqlx::Error> {
+    let cursor_id = cursor
+        .map(|cursor| {
+            Uuid::parse_str(&cursor.workout_id).map_err(|e| sqlx::Error::Encode(Box::new(e)))
+        })
+        .transpose()?;
+    // Names are folded in Rust, like the core does, then the stored spellings
+    // are matched exactly; Postgres `lower()` depends on the database locale
+    let exercise_names = match &filter.exercise_name {
+        Some(name) => {
+            let key = exercise_name_key(name);
+            let stored: Vec<String> = stored_exercise_names(&mut *conn, user_id)
+                .await?
+                .into_iter()
+                .filter(|name| exercise_name_key(name) == key)
+                .collect();
+            if stored.is_empty() {
+                return Ok(Vec::new());
+            }
+            Some(stored)
+        }
+        None => None,
+    };
+    let workout_names = match &filter.name_search {
+        Some(search) => {
+            let search = search.to_lowercase();
+            let stored: Vec<String> = stored_workout_names(&mut *conn, user_id)
+                .await?
+                .into_iter()
+                .filter(|name| name.to_lowercase().contains(&search))
+                .collect();
+            if stored.is_empty() {
+                return Ok(Vec::new());
+            }
+            Some(stored)
+        }
+        None => None,
+    };
+
+    let rows: Vec<WorkoutRow> = sqlx::query_as(&format!(
+        "SELECT {} FROM workouts w WHERE user_id = $1 \
+         AND ($2::timestamptz IS NULL OR (start_timestamp, id) < ($2, $3)) \
+         AND ($4::timestamptz IS NULL OR start_timestamp >= $4) \
+         AND ($5::timestamptz IS NULL OR start_timestamp < $5) \
+         AND ($6::text[] IS NULL OR EXISTS (SELECT 1 FROM exercises e \
+              WHERE e.workout_id = w.id AND e.name = ANY($6))) \
+         AND ($7::text[] IS NULL OR name = ANY($7)) \
+         ORDER BY start_timestamp DESC, id DESC LIMIT $8",
+        WORKOUT_COLUMNS
+    ))
+    .bind(user_id)
+    .bind(cursor.map(|cursor| cursor.start_timestamp.to_datetime()))
+    .bind(cursor_id)
+    .bind(filter.from_timestamp.map(|t| t.to_datetime()))
+    .bind(filter.to_timestamp.map(|t| t.to_datetime()))
+    .bind(exercise_names)
+    .bind(workout_names)
+    .bind(limit)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    assemble(conn, rows).await
+}
+
+/// Loads a user's sessions of the named exercises (matched by
+/// `exercise_name_key`), newest first: every workout containing one of them,
+/// with only those exercises.
+pub async fn exercise_history(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    names: &[String],
+) -> Result<Vec<Workout>, sqlx::Error> {
+    let keys: Vec<String> = names.iter().map(|name| exercise_name_key(name)).collect();
+    // Case is folded in Rust, like the core does, then the stored spellings
+    // are matched exactly; Postgres `lower()` depends on the database locale
+    let stored: Vec<String> = stored_exercise_names(&mut *conn, user_id)
+        .await?
+        .into_iter()
+        .filter(|name| keys.contains(&exercise_name_key(name)))
+        .collect();
+    if stored.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let rows: Vec<WorkoutRow> = sqlx::query_as(&format!(
+        "SELECT {} FROM workouts w WHERE user_id = $1 \
+         AND EXISTS (SELECT 1 FROM exercises e \
+              WHERE e.workout_id = w.id AND e.name = ANY($2)) \
+         ORDER BY start_timestamp DESC, id DESC",
+        WORKOUT_COLUMNS
+    ))
+    .bind(user_id)
+    .bind(&stored)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let mut workouts = assemble(conn, rows).await?;
+    for workout in &mut workouts {
+        workout.exercises.retain(|exercise| stored.contains(&exercise.name));
+    }
+    Ok(workouts)
+}
+
+/// Lists the names of a user's exercises, once per `exercise_name_key`.
+pub async fn exercise_names(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+) -> Result<Vec<String>, sqlx::Error> {
+    let mut names: Vec<String> = Vec::new();
+    for name in stored_exercise_names(conn, user_id).await? {
+        if !names.iter().any(|seen| exercise_name_key(seen) == exercise_name_key(&name)) {
+            names.push(name);
+        }
+    }
+    Ok(names)
+}
+
+/// Every distinct spelling of a user's exercise names, sorted.
+async fn stored_exercise_names(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+) -> Result<Vec<String>, sqlx::Error> {
+    sqlx::query_scalar(
+        "SELECT DISTINCT e.name FROM exercises e \
+         JOIN workouts w ON w.id = e.workout_id WHERE w.user_id = $1 \
+         ORDER BY e.name",
+    )
+    .bind(user_id)
+    .fetch_all(conn)
+    .await
+}
+
+/// Every distinct workout name of a user.
+async fn stored_workout_names(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+) -> Result<Vec<String>, sqlx::Error> {
+    sqlx::query_scalar("SELECT DISTINCT name FROM workouts WHERE user_id = $1")
+        .bind(user_id)
+        .fetch_all(conn)
+        .await
+}
+
+/// Returns whether a user's workout exists, locking its row until the
+/// transaction ends.
+pub async fn lock_workout(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    id: Uuid,
+) -> Result<bool, sqlx::Error> {
+    let row: Option<(Uuid,)> = sqlx::query_as(
+        "SELECT id FROM workouts WHERE id = $1 AND user_id = $2 FOR UPDATE",
+    )
+    .bind(id)
+    .bind(user_id)
+    .fetch_optional(conn)
+    .await?;
+    Ok(row.is_some())
+}
+
+/// System-wide totals for the admin dashboard.
+#[derive(FromRow, Clone, Debug, PartialEq)]
+pub struct WorkoutStats {
+    pub total_users: i64,
+    pub total_workouts: i64,
+    pub total_sets: i64,
+    pub completed_sets: i64,
+    /// Users with a workout started since midnight UTC
+    pub active_today: i64,
+}
+
+/// Computes totals across all users.
+pub async fn workout_stats(conn: &mut PgConnection) -> Result<WorkoutStats, sqlx::Error> {
+    sqlx::query_as(
+        "SELECT \
+         (SELECT COUNT(*) FROM users) AS total_users, \
+         (SELECT COUNT(*) FROM workouts) AS total_workouts, \
+         (SELECT COUNT(*) FROM exercise_sets) AS total_sets, \
+         (SELECT COUNT(*) FROM exercise_sets WHERE is_completed) AS completed_sets, \
+         (SELECT COUNT(DISTINCT user_id) FROM workouts \
+          WHERE start_timestamp >= date_trunc('day', NOW() AT TIME ZONE 'UTC') AT TIME ZONE 'UTC') \
+         AS active_today",
+    )
+    .fetch_one(conn)
+    .await
+}
+
+/// Loads the exercises and sets of `rows`, keeping the rows' order.
+async fn assemble(
+    conn: &mut PgConnection,
+    rows: Vec<WorkoutRow>,
+) -> Result<Vec<Workout>, sqlx::Error> {
+    let ids: Vec<Uuid> = rows.iter().map(|row| row.id).collect();
+
+    let exercise_rows: Vec<ExerciseRow> = sqlx::query_as(
+        "SELECT id, workout_id, superset_id, name, pinned_notes, notes, duration, \
+         exercise_type::text AS exercise_type, weight_unit::text AS weight_unit, \
+         default_warm_up_time, default_rest_time, body_part_main::text AS body_part_main, \
+         body_part_detailed, body_part_scientific \
+         FROM exercises WHERE workout_id = ANY($1) ORDER BY workout_id, position",
+    )
+    .bind(&ids)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let set_rows: Vec<SetRow> = sqlx::query_as(
+        "SELECT id, exercise_id, workout_id, set_index, set_type::text AS set_type, \
+         weight_unit::text AS weight_unit, is_completed, suggest_weight, suggest_reps, \
+         suggest_rep_range, suggest_duration, suggest_rpe, suggest_rest_time, actual_weight, \
+         actual_reps, actual_duration, actual_rpe, actual_rest_time \
+         FROM exercise_sets WHERE workout_id = ANY($1) ORDER BY exercise_id, set_index",
+    )
+    .bind(&ids)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let mut sets: HashMap<Uuid, Vec<ExerciseSet>> = HashMap::new();
+    for row in set_rows {
+        sets.entry(row.exercise_id).or_default().push(ExerciseSet {
+            id: id(row.id),
+            set_type: parse_enum(row.set_type)?,
+            weight_unit: row.weight_unit.map(parse_enum).transpose()?,
+            suggest: SetSuggest {
+                weight: row.suggest_weight,
+                reps: row.suggest_reps,
+                rep_range: row.suggest_rep_range,
+                duration: row.suggest_duration,
+                rpe: row.suggest_rpe,
+                rest_time: row.suggest_rest_time,
+            },
+            actual: SetActual {
+                weight: row.actual_weight,
+                reps: row.actual_reps,
+                duration: row.actual_duration,
+                rpe: row.actual_rpe,
+                actual_rest_time: row.actual_rest_time,
+            },
+            is_completed: row.is_completed,
+            exercise_id: id(row.exercise_id),
+            workout_id: id(row.workout_id),
+            set_index: row.set_index,
+        });
+    }
+
+    let mut exercises: HashMap<Uuid, Vec<Exercise>> = HashMap::new();
+    for row in exercise_rows {
+        let body_part = match row.body_part_main {
+            Some(main) => Some(BodyPart {
+                main: parse_enum(main)?,
+                detailed: row.body_part_detailed,
+                scientific: row.body_part_scientific,
+            }),
+            None => None,
+        };
+        exercises.entry(row.workout_id).or_default().push(Exercise {
+            id: id(row.id),
+            superset_id: row.superset_id,
+            workout_id: id(row.workout_id),
+            name: row.name,
+            pinned_notes: row.pinned_notes,
+            notes: row.notes,
+            duration: row.duration,
+            exercise_type: parse_enum(row.exercise_type)?,
+            weight_unit: row.weight_unit.map(parse_enum).transpose()?,
+            default_warm_up_time: row.default_warm_up_time,
+            default_rest_time: row.default_rest_time,
+            sets: sets.remove(&row.id).unwrap_or_default(),
+            body_part,
+        });
+    }
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(Workout {
+                schema_version: u32::try_from(row.schema_version)
+                    .map_err(|e| sqlx::Error::Decode(Box::new(e)))?,
+                id: id(row.id),
+                name: row.name,
+                note: row.note,
+                duration: row.duration,
+                start_timestamp: Timestamp::from(row.start_timestamp),
+                end_timestamp: row.end_timestamp.map(Timestamp::from),
+                exercises: exercises.remove(&row.id).unwrap_or_default(),
+            })
+        })
+        .collect()
+}
+
+// =============================================================================
+// MARK: - Writes
+// =============================================================================
+
+/// Inserts a workout owned by `user_id` with its exercises and sets.
+pub async fn insert_workout(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    workout: &Workout,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO workouts (id, user_id, schema_version, name, note, duration, \
+         start_timestamp, end_timestamp) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
+    )
+    .bind(uuid(&workout.id)?)
+    .bind(user_id)
+    .bind(i32::try_from(workout.schema_version).map_err(|e| sqlx::Error::Encode(Box::new(e)))?)
+    .bind(&workout.name)
+    .bind(&workout.note)
+    .bind(workout.duration)
+    .bind(workout.start_timestamp.to_datetime())
+    .bind(workout.end_timestamp.map(|t| t.to_datetime()))
+    .execute(&mut *conn)
+    .await?;
+
+    insert_exercises(conn, workout).await
+}
+
+/// Replaces a user's workout's fields, exercises and sets.
+///
+/// Returns false if the user has no such workout.
+pub async fn replace_workout(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    workout: &Workout,
+) -> Result<bool, sqlx::Error> {
+    let workout_id = uuid(&workout.id)?;
+    let updated = sqlx::query(
+        "UPDATE workouts SET schema_version = $3, name = $4, note = $5, duration = $6, \
+         start_timestamp = $7, end_timestamp = $8, updated_at = NOW() \
+         WHERE id = $1 AND user_id = $2",
+    )
+    .bind(workout_id)
+    .bind(user_id)
+    .bind(i32::try_from(workout.schema_version).map_err(|e| sqlx::Error::Encode(Box::new(e)))?)
+    .bind(&workout.name)
+    .bind(&workout.note)
+    .bind(workout.duration)
+    .bind(workout.start_timestamp.to_datetime())
+    .bind(workout.end_timestamp.map(|t| t.to_datetime()))
+    .execute(&mut *conn)
+    .await?;
+    if updated.rows_affected() == 0 {
+        return Ok(false);
+    }
+
+    // Sets go with their exercises
+    sqlx::query("DELETE FROM exercises WHERE workout_id = $1")
+        .bind(workout_id)
+        .execute(&mut *conn)
+        .await?;
+    insert_exercises(conn, workout).await?;
+    Ok(true)
+}
+
+/// Deletes a user's workout with its exercises and sets.
+///
+/// Returns false if the user has no such workout.
+pub async fn delete_workout(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    id: Uuid,
+) -> Result<bool, sqlx::Error> {
+    let deleted = sqlx::query("DELETE FROM workouts WHERE id = $1 AND user_id = $2")
+        .bind(id)
+        .bind(user_id)
+        .execute(conn)
+        .await?;
+    Ok(deleted.rows_affected() > 0)
+}
+
+// =============================================================================
+// MARK: - Current Workout
+// =============================================================================
+
+/// Saves the user's in-progress workout (one per user).
+pub async fn save_current_workout(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    workout: &Workout,
+) -> Result<(), sqlx::Error> {
+    let json = serde_json::to_string(workout).map_err(|e| sqlx::Error::Encode(Box::new(e)))?;
+    sqlx::query(
+        "INSERT INTO current_workouts (user_id, workout) VALUES ($1, $2::jsonb) \
+         ON CONFLICT (user_id) DO UPDATE SET workout = EXCLUDED.workout, updated_at = NOW()",
+    )
+    .bind(user_id)
+    .bind(json)
+    .execute(conn)
+    .await?;
+    Ok(())
+}
+
+/// Loads the user's in-progress workout, migrated to the current schema.
+pub async fn load_current_workout(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+) -> Result<Option<Workout>, sqlx::Error> {
+    let json: Option<String> =
+        sqlx::query_scalar("SELECT workout::text FROM current_workouts WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_optional(conn)
+            .await?;
+    json.map(|json| {
+        schema::workout_from_json(&json).map_err(|e| sqlx::Error::Decode(e.into()))
+    })
+    .transpose()
+}
+
+/// Deletes the user's in-progress workout, if any.
+pub async fn delete_current_workout(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM current_workouts WHERE user_id = $1")
+        .bind(user_id)
+        .execute(conn)
+        .await?;
+    Ok(())
+}
+
+async fn insert_exercises(conn: &mut PgConnection, workout: &Workout) -> Result<(), sqlx::Error> {
+    for (position, exercise) in workout.exercises.iter().enumerate() {
+        sqlx::query(
+            "INSERT INTO exercises (id, workout_id, position, superset_id, name, pinned_notes, notes, \
+             duration, exercise_type, weight_unit, default_warm_up_time, default_rest_time, \
+             body_part_main, body_part_detailed, body_part_scientific) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9::exercise_type, $10::weight_unit, $11, $12, \
+             $13::body_part_main, $14, $15)",
+        )
+        .bind(uuid(&exercise.id)?)
+        .bind(uuid(&exercise.workout_id)?)
+        .bind(i32::try_from(position).map_err(|e| sqlx::Error::Encode(Box::new(e)))?)
+        .bind(exercise.superset_id)
+        .bind(&exercise.name)
+        .bind(&exercise.pinned_notes)
+        .bind(&exercise.notes)
+        .bind(exercise.duration)
+        .bind(enum_text(&exercise.exercise_type))
+        .bind(exercise.weight_unit.as_ref().map(enum_text))
+        .bind(exercise.default_warm_up_time)
+        .bind(exercise.default_rest_time)
+        .bind(exercise.body_part.as_ref().map(|part| enum_text(&part.main)))
+        .bind(exercise.body_part.as_ref().and_then(|part| part.detailed.as_ref()))
+        .bind(exercise.body_part.as_ref().and_then(|part| part.scientific.as_ref()))
+        .execute(&mut *conn)
+        .await?;
+
+        for set in &exercise.sets {
+            sqlx::query(
+                "INSERT INTO exercise_sets (id, exercise_id, workout_id, set_index, set_type, \
+                 weight_unit, is_completed, suggest_weight, suggest_reps, suggest_rep_range, \
+                 suggest_duration, suggest_rpe, suggest_rest_time, actual_weight, actual_reps, \
+                 actual_duration, actual_rpe, actual_rest_time) \
+                 VALUES ($1, $2, $3, $4, $5::set_type, $6::weight_unit, $7, $8, $9, $10, $11, $12, \
+                 $13, $14, $15, $16, $17, $18)",
+            )
+            .bind(uuid(&set.id)?)
+            .bind(uuid(&set.exercise_id)?)
+            .bind(uuid(&set.workout_id)?)
+            .bind(set.set_index)
+            .bind(enum_text(&set.set_type))
+            .bind(set.weight_unit.as_ref().map(enum_text))
+            .bind(set.is_completed)
+            .bind(set.suggest.weight)
+            .bind(set.suggest.reps)
+            .bind(set.suggest.rep_range)
+            .bind(set.suggest.duration)
+            .bind(set.suggest.rpe)
+            .bind(set.suggest.rest_time)
+            .bind(set.actual.weight)
+            .bind(set.actual.reps)
+            .bind(set.actual.duration)
+            .bind(set.actual.rpe)
+            .bind(set.actual.actual_rest_time)
+            .execute(&mut *conn)
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+// =============================================================================
+// MARK: - Conversions
+// =============================================================================
+
+pub(crate) fn uuid(id: &Id) -> Result<Uuid, sqlx::Error> {
+    Uuid::parse_str(id.as_str()).map_err(|e| sqlx::Error::Encode(Box::new(e)))
+}
+
+pub(crate) fn id(uuid: Uuid) -> Id {
+    Id::from_string(uuid.to_string()).expect("A Uuid is always a valid Id")
+}
+
+/// The serde name of a unit enum (e.g. `SetType::WarmUp` -> "warmUp").
+pub(crate) fn enum_text<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(text)) => text,
+        other => panic!("Expected a unit enum, got {:?}", other),
+    }
+}
+
+/// Parses a unit enum from its serde name.
+pub(crate) fn parse_enum<T: DeserializeOwned>(text: String) -> Result<T, sqlx::Error> {
+    serde_json::from_value(serde_json::Value::String(text))
+        .map_err(|e| sqlx::Error::Decode(Box::new(e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::{BodyPartMain, ExerciseType, SetType, WeightUnit};
+    use sqlx::PgPool;
+
+    /// A workout with every optional field set and every enum off its default.
+    fn populated_workout() -> Workout {
+        let mut workout = Workout::with_name("Full Body");
+        workout.start_timestamp = "2025-03-01T08:00:00.123Z".parse().unwrap();
+        workout.end_timestamp = Some("2025-03-01T09:15:30.456Z".parse().unwrap());
+        workout.duration = Some(4530);
+        workout.note = Some("Felt strong".to_string());
+
+        let squat = workout.add_exercise("Squat");
+        squat.superset_id = Some(7);
+        squat.pinned_notes = vec!["Belt on".to_string()];
+        squat.notes = vec!["Depth good".to_string(), "Knees out".to_string()];
+        squat.duration = Some(900);
+        squat.exercise_type = ExerciseType::Barbell;
+        squat.weight_unit = Some(WeightUnit::Kg);
+        squat.default_warm_up_time = Some(30);
+        squat.default_rest_time = Some(180);
+        squat.body_part = Some(BodyPart::with_details(
+            BodyPartMain::FullBody,
+            vec!["quads".to_string(), "glutes".to_string()],
+            vec!["quadriceps femoris".to_string()],
+        ));
+        let set = squat.add_set();
+        set.set_type = SetType::WarmUp;
+        set.weight_unit = Some(WeightUnit::Lb);
+        set.suggest = SetSuggest {
+            weight: Some(135.5),
+            reps: Some(8),
+            rep_range: Some(2),
+            duration: Some(40),
+            rpe: Some(6.5),
+            rest_time: Some(90),
+        };
+        set.complete(SetActual {
+            weight: Some(140.25),
+            reps: Some(9),
+            duration: Some(45),
+            rpe: Some(7.5),
+            actual_rest_time: Some(95),
+        });
+        squat.add_set().set_type = SetType::DropSet;
+
+        // Only a main body part, and an exercise without sets
+        let plank = workout.add_exercise("Plank");
+        plank.exercise_type = ExerciseType::Bodyweight;
+        plank.body_part = Some(BodyPart::new(BodyPartMain::Core));
+        workout.add_exercise("Leg Press").body_part = None;
+        workout
+    }
+
+    #[test]
+    fn test_enums_round_trip_through_serde_names() {
+        assert_eq!(enum_text(&SetType::WarmUp), "warmUp");
+        assert_eq!(enum_text(&ExerciseType::Barbell), "barbell");
+        assert_eq!(enum_text(&WeightUnit::Lb), "lb");
+
+        assert_eq!(parse_enum::<SetType>("dropSet".to_string()).unwrap(), SetType::DropSet);
+        assert!(parse_enum::<WeightUnit>("stone".to_string()).is_err());
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_populated_workout_round_trips(pool: PgPool) {
+        let workout = populated_workout();
+        assert!(shared::validation::validate_workout(&workout).is_ok());
+        let user_id = crate::user_store::provision_user(&pool, "user_1", None)
+            .await
+            .unwrap()
+            .id;
+        let mut conn = pool.acquire().await.unwrap();
+
+        insert_workout(&mut conn, user_id, &workout).await.unwrap();
+        let id = uuid(&workout.id).unwrap();
+        let stored = fetch_workout(&mut conn, user_id, id).await.unwrap();
+        assert_eq!(stored, Some(workout.clone()));
+
+        let mut edited = workout.clone();
+        edited.exercises.swap(0, 2);
+        edited.end_timestamp = None;
+        assert!(replace_workout(&mut conn, user_id, &edited).await.unwrap());
+        let stored = fetch_workout(&mut conn, user_id, id).await.unwrap();
+        assert_eq!(stored, Some(edited));
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_exercise_names_match_unicode_case_like_the_core(pool: PgPool) {
+        let user_id = crate::user_store::provision_user(&pool, "user_1", None)
+            .await
+            .unwrap()
+            .id;
+        let mut conn = pool.acquire().await.unwrap();
+        let mut first = Workout::with_name("Arms");
+        first.add_exercise("Ücker Press");
+        first.add_exercise("Curl");
+        let mut second = Workout::with_name("Arms");
+        second.add_exercise("ücker press");
+        insert_workout(&mut conn, user_id, &first).await.unwrap();
+        insert_workout(&mut conn, user_id, &second).await.unwrap();
+
+        let names = exercise_names(&mut conn, user_id).await.unwrap();
+        assert_eq!(names.len(), 2, "one name per key: {:?}", names);
+
+        let sessions = exercise_history(&mut conn, user_id, &["ÜCKER PRESS".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(sessions.len(), 2);
+        assert!(sessions
+            .iter()
+            .all(|w| w.exercises.len() == 1 && w.exercises[0].is_named("Ücker Press")));
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_history_filters_match_unicode_case_like_the_core(pool: PgPool) {
+        let user_id = crate::user_store::provision_user(&pool, "user_1", None)
+            .await
+            .unwrap()
+            .id;
+        let mut conn = pool.acquire().await.unwrap();
+        let mut uber = Workout::with_name("Über Push");
+        uber.add_exercise("Überzug");
+        let mut other = Workout::with_name("100% Legs");
+        other.add_exercise("Squat");
+        insert_workout(&mut conn, user_id, &uber).await.unwrap();
+        insert_workout(&mut conn, user_id, &other).await.unwrap();
+
+        let names = |workouts: Vec<Workout>| -> Vec<String> {
+            workouts.into_iter().map(|w| w.name).collect()
+        };
+        for filter in [
+            HistoryFilter {
+                name_search: Some("ÜBER".to_string()),
+                ..Default::default()
+            },
+            HistoryFilter {
+                exercise_name: Some("ÜBERZUG".to_string()),
+                ..Default::default()
+            },
+        ] {
+            let found = history(&mut conn, user_id, &filter, None, None).await.unwrap();
+            assert!(found.iter().all(|w| filter.matches(w)));
+            assert_eq!(names(found), ["Über Push"], "{:?}", filter);
+        }
+
+        let wildcard = HistoryFilter {
+            name_search: Some("1%0".to_string()),
+            ..Default::default()
+        };
+        let found = history(&mut conn, user_id, &wildcard, None, None).await.unwrap();
+        assert!(found.is_empty(), "% is not a wildcard");
+    }
+}
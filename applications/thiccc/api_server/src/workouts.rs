@@ -0,0 +1,469 @@
+//! Workout CRUD endpoints.
+//!
+//! Requests and responses use the shared crate's `Workout` model (the same
+//! JSON the iOS core stores), wrapped in `data` per the API spec. Every write
+//! runs in one transaction and is logged for sync (see `sync`). Requests only
+//! see the authenticated user's workouts.
+//!
+//! - `POST /api/workouts` - create a workout with its exercises and sets
+//! - `GET /api/workouts` - list workouts, newest first, one page at a time
+//! - `GET /api/workouts/{id}` - get one workout
+//! - `PATCH /api/workouts/{id}` - update fields and/or replace the exercises
+//! - `DELETE /api/workouts/{id}` - delete a workout with its exercises and sets
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Deserializer};
+use shared::validation::validate_workout;
+use shared::{Exercise, Timestamp, Workout};
+use uuid::Uuid;
+
+use crate::auth::AuthUser;
+use crate::error::{ApiError, ApiResult, JsonBody, PathParams, QueryParams};
+use crate::response::{Data, ItemList, Page, PageParams};
+use crate::workout_store::{self, SortOrder};
+use crate::{sync_store, AppState};
+
+/// Routes for `/api/workouts`.
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/api/workouts", get(list_workouts).post(create_workout))
+        .route(
+            "/api/workouts/{id}",
+            get(get_workout).patch(update_workout).delete(delete_workout),
+        )
+}
+
+// =============================================================================
+// MARK: - Requests
+// =============================================================================
+
+/// Query parameters for listing workouts (see `PageParams` for paging).
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ListParams {
+    #[serde(alias = "limit")]
+    pub items_per_page: Option<i64>,
+    #[serde(alias = "offset")]
+    pub start_index: Option<i64>,
+    pub page_token: Option<String>,
+    /// `date_asc` or `date_desc`
+    pub sort: Option<String>,
+}
+
+impl ListParams {
+    /// Returns the sort order and the page.
+    fn resolve(&self) -> Result<(SortOrder, Page), String> {
+        let sort = match self.sort.as_deref() {
+            None | Some("date_desc") => SortOrder::DateDesc,
+            Some("date_asc") => SortOrder::DateAsc,
+            Some(other) => {
+                return Err(format!(
+                    "Invalid sort '{}': expected date_asc or date_desc",
+                    other
+                ));
+            }
+        };
+        let page = PageParams {
+            items_per_page: self.items_per_page,
+            start_index: self.start_index,
+            page_token: self.page_token.clone(),
+        };
+        Ok((sort, page.resolve()?))
+    }
+}
+
+/// Partial update of a workout.
+///
+/// Absent fields are left unchanged; `null` clears an optional field. When
+/// `exercises` is present it replaces all of the workout's exercises and sets.
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct WorkoutPatch {
+    pub name: Option<String>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub note: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub duration: Option<Option<i32>>,
+    pub start_timestamp: Option<Timestamp>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub end_timestamp: Option<Option<Timestamp>>,
+    pub exercises: Option<Vec<Exercise>>,
+}
+
+impl WorkoutPatch {
+    /// Applies the present fields to `workout`.
+    pub fn apply(self, workout: &mut Workout) {
+        if let Some(name) = self.name {
+            workout.name = name;
+        }
+        if let Some(note) = self.note {
+            workout.note = note;
+        }
+        if let Some(duration) = self.duration {
+            workout.duration = duration;
+        }
+        if let Some(start) = self.start_timestamp {
+            workout.start_timestamp = start;
+        }
+        if let Some(end) = self.end_timestamp {
+            workout.end_timestamp = end;
+        }
+        if let Some(exercises) = self.exercises {
+            workout.exercises = exercises;
+        }
+    }
+}
+
+/// Tells a present `null` (Some(None)) apart from an absent field (None).
+fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+// =============================================================================
+// MARK: - Handlers
+// =============================================================================
+
+/// POST /api/workouts - returns 201 with the stored workout.
+async fn create_workout(
+    State(state): State<AppState>,
+    user: AuthUser,
+    JsonBody(workout): JsonBody<Workout>,
+) -> ApiResult<impl IntoResponse> {
+    validate(&workout)?;
+
+    let mut tx = state.db.begin().await?;
+    sync_store::lock_log(&mut tx, user.id).await?;
+    workout_store::insert_workout(&mut tx, user.id, &workout).await?;
+    sync_store::record(&mut tx, user.id, None, Some(&workout)).await?;
+    tx.commit().await?;
+
+    Ok((StatusCode::CREATED, Json(Data::new(workout))))
+}
+
+/// GET /api/workouts - one page of workouts.
+async fn list_workouts(
+    State(state): State<AppState>,
+    user: AuthUser,
+    QueryParams(params): QueryParams<ListParams>,
+) -> ApiResult<Json<Data<ItemList<Workout>>>> {
+    let (sort, page) = params.resolve().map_err(ApiError::BadRequest)?;
+
+    let mut conn = state.db.acquire().await?;
+    let (items, start_index, total_items) =
+        workout_store::list_workouts(&mut conn, user.id, sort, &page).await?;
+
+    Ok(Json(Data::new(ItemList::page(items, &page, start_index, total_items))))
+}
+
+/// GET /api/workouts/{id}
+async fn get_workout(
+    State(state): State<AppState>,
+    user: AuthUser,
+    PathParams(id): PathParams<Uuid>,
+) -> ApiResult<Json<Data<Workout>>> {
+    let mut conn = state.db.acquire().await?;
+    let workout = workout_store::fetch_workout(&mut conn, user.id, id)
+        .await?
+        .ok_or_else(|| not_found(id))?;
+    Ok(Json(Data::new(workout)))
+}
+
+/// PATCH /api/workouts/{id} - returns the updated workout.
+async fn update_workout(
+    State(state): State<AppState>,
+    user: AuthUser,
+    PathParams(id): PathParams<Uuid>,
+    JsonBody(patch): JsonBody<WorkoutPatch>,
+) -> ApiResult<Json<Data<Workout>>> {
+    let mut tx = state.db.begin().await?;
+    sync_store::lock_log(&mut tx, user.id).await?;
+    if !workout_store::lock_workout(&mut tx, user.id, id).await? {
+        return Err(not_found(id));
+    }
+    let before = workout_store::fetch_workout(&mut tx, user.id, id)
+        .await?
+        .ok_or_else(|| not_found(id))?;
+
+    let mut workout = before.clone();
+    patch.apply(&mut workout);
+    validate(&workout)?;
+
+    workout_store::replace_workout(&mut tx, user.id, &workout).await?;
+    sync_store::record(&mut tx, user.id, Some(&before), Some(&workout)).await?;
+    tx.commit().await?;
+
+    Ok(Json(Data::new(workout)))
+}
+
+/// DELETE /api/workouts/{id} - returns 204.
+async fn delete_workout(
+    State(state): State<AppState>,
+    user: AuthUser,
+    PathParams(id): PathParams<Uuid>,
+) -> ApiResult<StatusCode> {
+    let mut tx = state.db.begin().await?;
+    sync_store::lock_log(&mut tx, user.id).await?;
+    let deleted = workout_store::delete_workout(&mut tx, user.id, id).await?;
+    if deleted {
+        sync_store::record_deleted(&mut tx, user.id, id).await?;
+    }
+    tx.commit().await?;
+
+    if deleted {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(not_found(id))
+    }
+}
+
+// =============================================================================
+// MARK: - Errors
+// =============================================================================
+
+pub(crate) fn validate(workout: &Workout) -> ApiResult<()> {
+    Ok(validate_workout(workout)?)
+}
+
+fn not_found(id: Uuid) -> ApiError {
+    ApiError::NotFound(format!("Workout {} not found", id))
+}
+
+// =============================================================================
+// MARK: - Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::{PageStart, DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+    use crate::test_support::{send, token};
+    use serde_json::json;
+    use shared::SetActual;
+    use sqlx::PgPool;
+
+    fn sample_workout(name: &str, start: &str) -> Workout {
+        let mut workout = Workout::with_name(name);
+        workout.start_timestamp = start.parse().unwrap();
+        let squat = workout.add_exercise("Squat");
+        squat.add_set().complete(SetActual::with_weight_and_reps(225.0, 5));
+        squat.add_set();
+        workout.add_exercise("Leg Press");
+        workout
+    }
+
+    fn list_params(uri: &str) -> ListParams {
+        axum::extract::Query::try_from_uri(&uri.parse().unwrap()).unwrap().0
+    }
+
+    #[test]
+    fn test_list_params_defaults_aliases_and_limits() {
+        let page = |limit, index| Page {
+            limit,
+            start: PageStart::Index(index),
+        };
+        let params = list_params("/api/workouts?");
+        assert_eq!(params.resolve(), Ok((SortOrder::DateDesc, page(DEFAULT_PAGE_SIZE, 0))));
+
+        let params = list_params("/api/workouts?limit=500&offset=20&sort=date_asc");
+        assert_eq!(params.resolve(), Ok((SortOrder::DateAsc, page(MAX_PAGE_SIZE, 20))));
+
+        let params = list_params("/api/workouts?itemsPerPage=0");
+        assert!(params.resolve().is_err());
+        let params = list_params("/api/workouts?sort=name");
+        assert!(params.resolve().unwrap_err().contains("Invalid sort"));
+    }
+
+    #[test]
+    fn test_patch_leaves_absent_fields_and_clears_nulls() {
+        let mut workout = sample_workout("Legs", "2025-03-01T08:00:00Z");
+        workout.note = Some("Heavy".to_string());
+        workout.duration = Some(3600);
+
+        let patch: WorkoutPatch =
+            serde_json::from_value(json!({ "name": "Leg Day", "note": null })).unwrap();
+        patch.apply(&mut workout);
+
+        assert_eq!(workout.name, "Leg Day");
+        assert_eq!(workout.note, None);
+        assert_eq!(workout.duration, Some(3600));
+        assert_eq!(workout.exercises.len(), 2);
+
+        assert!(serde_json::from_value::<WorkoutPatch>(json!({ "id": "x" })).is_err());
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_create_get_update_delete(pool: PgPool) {
+        let alice = token("user_alice");
+        let workout = sample_workout("Legs", "2025-03-01T08:00:00Z");
+        let uri = format!("/api/workouts/{}", workout.id.as_str());
+
+        let (status, body) = send(&pool, "POST", "/api/workouts", Some(&alice), Some(json!(workout))).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["data"], json!(workout));
+
+        let (status, body) = send(&pool, "GET", &uri, Some(&alice), None).await;
+        assert_eq!(status, StatusCode::OK);
+        let stored: Workout = serde_json::from_value(body["data"].clone()).unwrap();
+        assert_eq!(stored, workout);
+
+        let (status, _) = send(&pool, "POST", "/api/workouts", Some(&alice), Some(json!(workout))).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+
+        let mut exercises = workout.exercises.clone();
+        exercises.remove(0);
+        let patch = json!({ "note": "Deep squats", "exercises": exercises });
+        let (status, body) = send(&pool, "PATCH", &uri, Some(&alice), Some(patch)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["note"], "Deep squats");
+        assert_eq!(body["data"]["exercises"].as_array().unwrap().len(), 1);
+
+        let (status, _) = send(&pool, "DELETE", &uri, Some(&alice), None).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let (status, _) = send(&pool, "GET", &uri, Some(&alice), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (sets,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM exercise_sets")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(sets, 0);
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_invalid_writes_are_rejected_and_rolled_back(pool: PgPool) {
+        let alice = token("user_alice");
+        let mut workout = sample_workout("Legs", "2025-03-01T08:00:00Z");
+        workout.exercises[0].sets[0].actual.rpe = Some(11.0);
+        let (status, body) = send(&pool, "POST", "/api/workouts", Some(&alice), Some(json!(workout))).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        let error = &body["error"];
+        assert_eq!(error["code"], 422);
+        assert_eq!(error["errors"][0]["reason"], "invalidValue");
+        assert_eq!(error["errors"][0]["location"], "exercises[0].sets[0]");
+
+        let (status, body) = send(&pool, "POST", "/api/workouts", Some(&alice), Some(json!({ "name": 1 }))).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["error"]["errors"][0]["reason"], "parseError");
+
+        // The second workout reuses an exercise id, so its insert fails part-way
+        let first = sample_workout("Legs", "2025-03-01T08:00:00Z");
+        let mut second = sample_workout("Legs", "2025-03-02T08:00:00Z");
+        second.exercises[1] = first.exercises[0].clone();
+        second.exercises[1].workout_id = second.id.clone();
+        for set in &mut second.exercises[1].sets {
+            set.workout_id = second.id.clone();
+        }
+        send(&pool, "POST", "/api/workouts", Some(&alice), Some(json!(first))).await;
+        let (status, body) = send(&pool, "POST", "/api/workouts", Some(&alice), Some(json!(second))).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["error"]["errors"][0]["reason"], "conflict");
+
+        let (status, body) = send(&pool, "GET", &format!("/api/workouts/{}", second.id.as_str()), Some(&alice), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["error"]["code"], 404);
+        assert_eq!(body["error"]["message"], format!("Workout {} not found", second.id.as_str()));
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_list_pages_newest_first(pool: PgPool) {
+        let alice = token("user_alice");
+        for day in 1..=3 {
+            let workout = sample_workout("Legs", &format!("2025-03-0{}T08:00:00Z", day));
+            send(&pool, "POST", "/api/workouts", Some(&alice), Some(json!(workout))).await;
+        }
+
+        let (status, body) = send(&pool, "GET", "/api/workouts?itemsPerPage=2&startIndex=1", Some(&alice), None).await;
+        assert_eq!(status, StatusCode::OK);
+        let page = &body["data"];
+        assert_eq!(page["currentItemCount"], 2);
+        assert_eq!(page["itemsPerPage"], 2);
+        assert_eq!(page["startIndex"], 1);
+        assert_eq!(page["totalItems"], 3);
+        assert_eq!(page["items"][0]["start_timestamp"], "2025-03-02T08:00:00.000Z");
+        assert_eq!(page["items"][1]["exercises"][0]["sets"].as_array().unwrap().len(), 2);
+
+        // Tokens continue after the last workout even when earlier ones change
+        let (_, body) = send(&pool, "GET", "/api/workouts?itemsPerPage=2", Some(&alice), None).await;
+        let token = body["data"]["nextPageToken"].as_str().unwrap().to_string();
+        let newest = body["data"]["items"][0]["id"].as_str().unwrap().to_string();
+        send(&pool, "DELETE", &format!("/api/workouts/{}", newest), Some(&alice), None).await;
+        let uri = format!("/api/workouts?itemsPerPage=2&pageToken={}", token);
+        let (status, body) = send(&pool, "GET", &uri, Some(&alice), None).await;
+        assert_eq!(status, StatusCode::OK);
+        let page = &body["data"];
+        assert_eq!(page["startIndex"], 1);
+        assert_eq!(page["totalItems"], 2);
+        assert_eq!(page["items"][0]["start_timestamp"], "2025-03-01T08:00:00.000Z");
+        assert!(page.get("nextPageToken").is_none());
+
+        let (status, _) = send(&pool, "GET", &format!("{}&startIndex=0", uri), Some(&alice), None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (status, _) = send(&pool, "GET", "/api/workouts?pageToken=zz", Some(&alice), None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, body) = send(&pool, "GET", "/api/workouts?sort=name", Some(&alice), None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error"]["errors"][0]["reason"], "badRequest");
+        let (status, body) = send(&pool, "GET", "/api/workouts/not-a-uuid", Some(&alice), None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error"]["code"], 400);
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_workouts_are_private_to_their_owner(pool: PgPool) {
+        let alice = token("user_alice");
+        let bob = token("user_bob");
+        let workout = sample_workout("Legs", "2025-03-01T08:00:00Z");
+        let uri = format!("/api/workouts/{}", workout.id.as_str());
+        send(&pool, "POST", "/api/workouts", Some(&alice), Some(json!(workout))).await;
+
+        let (status, _) = send(&pool, "GET", &uri, Some(&bob), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = send(&pool, "PATCH", &uri, Some(&bob), Some(json!({ "name": "Mine" }))).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = send(&pool, "DELETE", &uri, Some(&bob), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (_, body) = send(&pool, "GET", "/api/workouts", Some(&bob), None).await;
+        assert_eq!(body["data"]["totalItems"], 0);
+
+        let (status, body) = send(&pool, "GET", &uri, Some(&alice), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["name"], "Legs");
+    }
+
+    #[sqlx::test(migrations = "../db/migrations")]
+    #[ignore = "needs a Postgres DATABASE_URL"]
+    async fn test_requests_without_a_valid_token_are_rejected(pool: PgPool) {
+        let (status, body) = send(&pool, "GET", "/api/workouts", None, None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["error"]["message"], "Missing bearer token");
+        let (status, body) = send(&pool, "GET", "/api/workouts", Some("garbage"), None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["error"]["errors"][0]["reason"], "authError");
+
+        // Health checks stay public
+        let (status, body) = send(&pool, "GET", "/api/health", None, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["database"], "connected");
+
+        let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM users")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(count, 0);
+    }
+}
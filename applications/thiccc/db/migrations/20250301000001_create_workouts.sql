@@ -0,0 +1,52 @@
+-- Create workouts, exercises and exercise_sets tables
+-- Stores the shared crate's Workout model; enum values are stored as their
+-- serde names (e.g. 'barbell', 'warmUp', 'lb')
+
+CREATE TABLE IF NOT EXISTS workouts (
+    id UUID PRIMARY KEY,
+    schema_version INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    note TEXT,
+    duration INTEGER,
+    start_timestamp TIMESTAMPTZ NOT NULL,
+    end_timestamp TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Index for listing newest first
+CREATE INDEX IF NOT EXISTS idx_workouts_start_timestamp ON workouts(start_timestamp DESC, id);
+
+CREATE TABLE IF NOT EXISTS exercises (
+    id UUID PRIMARY KEY,
+    workout_id UUID NOT NULL REFERENCES workouts(id) ON DELETE CASCADE,
+    -- Position within Workout::exercises
+    position INTEGER NOT NULL,
+    superset_id INTEGER,
+    name TEXT NOT NULL,
+    pinned_notes TEXT[] NOT NULL DEFAULT '{}',
+    notes TEXT[] NOT NULL DEFAULT '{}',
+    duration INTEGER,
+    exercise_type TEXT NOT NULL,
+    weight_unit TEXT,
+    default_warm_up_time INTEGER,
+    default_rest_time INTEGER,
+    body_part JSONB
+);
+
+CREATE INDEX IF NOT EXISTS idx_exercises_workout_id ON exercises(workout_id, position);
+
+CREATE TABLE IF NOT EXISTS exercise_sets (
+    id UUID PRIMARY KEY,
+    exercise_id UUID NOT NULL REFERENCES exercises(id) ON DELETE CASCADE,
+    workout_id UUID NOT NULL REFERENCES workouts(id) ON DELETE CASCADE,
+    set_index INTEGER NOT NULL,
+    set_type TEXT NOT NULL,
+    weight_unit TEXT,
+    suggest JSONB NOT NULL,
+    actual JSONB NOT NULL,
+    is_completed BOOLEAN NOT NULL DEFAULT FALSE
+);
+
+CREATE INDEX IF NOT EXISTS idx_exercise_sets_exercise_id ON exercise_sets(exercise_id, set_index);
+CREATE INDEX IF NOT EXISTS idx_exercise_sets_workout_id ON exercise_sets(workout_id);
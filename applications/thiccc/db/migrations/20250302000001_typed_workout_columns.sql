@@ -0,0 +1,84 @@
+-- Mirror the shared crate's workout model in typed columns
+-- Replaces the JSONB set values and body part with one column per field, and
+-- the TEXT enum columns with Postgres enums named after the Rust types.
+-- Enum labels are the serde names (e.g. 'warmUp', 'lb', 'fullBody').
+
+CREATE TYPE exercise_type AS ENUM (
+    'dumbbell', 'kettlebell', 'barbell', 'hexbar', 'bodyweight', 'machine', 'unknown'
+);
+CREATE TYPE weight_unit AS ENUM ('kg', 'lb', 'bodyweight');
+CREATE TYPE set_type AS ENUM ('warmUp', 'working', 'dropSet', 'amrap', 'failure');
+CREATE TYPE body_part_main AS ENUM (
+    'chest', 'legs', 'arms', 'back', 'calves', 'shoulders', 'core', 'cardio', 'fullBody', 'other'
+);
+
+-- Exercises: enums and BodyPart
+ALTER TABLE exercises
+    ALTER COLUMN exercise_type TYPE exercise_type USING exercise_type::exercise_type,
+    ALTER COLUMN weight_unit TYPE weight_unit USING weight_unit::weight_unit,
+    ADD COLUMN body_part_main body_part_main,
+    ADD COLUMN body_part_detailed TEXT[],
+    ADD COLUMN body_part_scientific TEXT[];
+
+UPDATE exercises SET
+    body_part_main = (body_part->>'main')::body_part_main,
+    body_part_detailed = CASE WHEN jsonb_typeof(body_part->'detailed') = 'array'
+        THEN ARRAY(SELECT jsonb_array_elements_text(body_part->'detailed')) END,
+    body_part_scientific = CASE WHEN jsonb_typeof(body_part->'scientific') = 'array'
+        THEN ARRAY(SELECT jsonb_array_elements_text(body_part->'scientific')) END
+WHERE body_part IS NOT NULL;
+
+ALTER TABLE exercises
+    DROP COLUMN body_part,
+    -- Detailed and scientific names only exist alongside a main body part
+    ADD CONSTRAINT exercises_body_part_check CHECK (
+        body_part_main IS NOT NULL
+        OR (body_part_detailed IS NULL AND body_part_scientific IS NULL)
+    );
+
+-- Sets: enums, SetSuggest and SetActual
+ALTER TABLE exercise_sets
+    ALTER COLUMN set_type TYPE set_type USING set_type::set_type,
+    ALTER COLUMN weight_unit TYPE weight_unit USING weight_unit::weight_unit,
+    ADD COLUMN suggest_weight DOUBLE PRECISION,
+    ADD COLUMN suggest_reps INTEGER,
+    ADD COLUMN suggest_rep_range INTEGER,
+    ADD COLUMN suggest_duration INTEGER,
+    ADD COLUMN suggest_rpe DOUBLE PRECISION,
+    ADD COLUMN suggest_rest_time INTEGER,
+    ADD COLUMN actual_weight DOUBLE PRECISION,
+    ADD COLUMN actual_reps INTEGER,
+    ADD COLUMN actual_duration INTEGER,
+    ADD COLUMN actual_rpe DOUBLE PRECISION,
+    ADD COLUMN actual_rest_time INTEGER;
+
+UPDATE exercise_sets SET
+    suggest_weight = (suggest->>'weight')::DOUBLE PRECISION,
+    suggest_reps = (suggest->>'reps')::INTEGER,
+    suggest_rep_range = (suggest->>'rep_range')::INTEGER,
+    suggest_duration = (suggest->>'duration')::INTEGER,
+    suggest_rpe = (suggest->>'rpe')::DOUBLE PRECISION,
+    suggest_rest_time = (suggest->>'rest_time')::INTEGER,
+    actual_weight = (actual->>'weight')::DOUBLE PRECISION,
+    actual_reps = (actual->>'reps')::INTEGER,
+    actual_duration = (actual->>'duration')::INTEGER,
+    actual_rpe = (actual->>'rpe')::DOUBLE PRECISION,
+    actual_rest_time = (actual->>'actual_rest_time')::INTEGER;
+
+ALTER TABLE exercise_sets
+    DROP COLUMN suggest,
+    DROP COLUMN actual,
+    ADD CONSTRAINT exercise_sets_set_index_unique UNIQUE (exercise_id, set_index);
+
+-- Exercise catalog (shared::catalog), seeded by the API server at startup
+CREATE TABLE IF NOT EXISTS exercise_catalog (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name TEXT UNIQUE NOT NULL,
+    -- Free-form category ("Compound", "Isolation", ...), as in GlobalExercise
+    exercise_type TEXT NOT NULL,
+    additional_fk TEXT,
+    muscle_group TEXT NOT NULL,
+    image_name TEXT NOT NULL DEFAULT '',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,11 @@
+-- Add an owner to workouts
+-- Workouts created before authentication have no owner and are not visible
+-- through the API.
+
+ALTER TABLE workouts ADD COLUMN user_id UUID REFERENCES users(id) ON DELETE CASCADE;
+
+DROP INDEX IF EXISTS idx_workouts_start_timestamp;
+
+-- Index for listing a user's workouts newest first
+CREATE INDEX IF NOT EXISTS idx_workouts_user_id_start_timestamp
+    ON workouts(user_id, start_timestamp DESC, id);
@@ -0,0 +1,12 @@
+-- Create user_email_changes table
+-- Records each change of a user's email as seen in their Clerk tokens
+
+CREATE TABLE IF NOT EXISTS user_email_changes (
+    id BIGSERIAL PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    old_email VARCHAR(255),
+    new_email VARCHAR(255) NOT NULL,
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_user_email_changes_user_id ON user_email_changes(user_id, changed_at);
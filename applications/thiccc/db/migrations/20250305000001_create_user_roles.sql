@@ -0,0 +1,17 @@
+-- Create user_roles table
+-- Roles granted to users on top of the base 'user' role every user has.
+-- Grant the first admin by hand:
+--   INSERT INTO user_roles (user_id, role)
+--   SELECT id, 'admin' FROM users WHERE clerk_id = 'user_...';
+
+CREATE TYPE user_role AS ENUM ('admin', 'trainer', 'user');
+
+CREATE TABLE IF NOT EXISTS user_roles (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    role user_role NOT NULL,
+    granted_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, role)
+);
+
+-- Index for listing users by role
+CREATE INDEX IF NOT EXISTS idx_user_roles_role ON user_roles(role);
@@ -0,0 +1,33 @@
+-- Create sync_changes table
+-- Field-level change log for offline-first sync (shared::sync). Each row is
+-- one new value for one field of a workout, exercise or set, stamped with the
+-- Lamport clock of the replica (device, or 'server' for REST writes) that made
+-- it. The latest clock per (entity, field) is the current value.
+
+CREATE TYPE sync_entity_kind AS ENUM ('workout', 'exercise', 'set');
+
+CREATE TABLE IF NOT EXISTS sync_changes (
+    -- Sync cursor: clients ask for changes with a higher seq
+    seq BIGSERIAL PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    entity_kind sync_entity_kind NOT NULL,
+    entity_id UUID NOT NULL,
+    workout_id UUID NOT NULL,
+    -- Exercise a set belongs to (sets only)
+    exercise_id UUID,
+    field TEXT NOT NULL,
+    -- JSON-encoded value, as sent by the client
+    value TEXT NOT NULL,
+    clock_counter BIGINT NOT NULL,
+    -- Byte order, so ties between replicas break the same way as in Rust
+    clock_replica TEXT COLLATE "C" NOT NULL,
+    received_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((entity_kind = 'set') = (exercise_id IS NOT NULL))
+);
+
+-- Changes since a cursor
+CREATE INDEX IF NOT EXISTS idx_sync_changes_user_seq ON sync_changes(user_id, seq);
+-- Latest change per field of a workout
+CREATE INDEX IF NOT EXISTS idx_sync_changes_workout ON sync_changes(
+    user_id, workout_id, entity_kind, entity_id, field, clock_counter DESC, clock_replica DESC
+);
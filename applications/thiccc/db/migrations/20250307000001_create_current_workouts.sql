@@ -0,0 +1,10 @@
+-- Create current_workouts table
+-- The in-progress workout of a user's live (WebSocket) session, saved by the
+-- server-hosted core like the iOS app saves it to a file. Stored as the
+-- shared crate's workout JSON and migrated on load.
+
+CREATE TABLE IF NOT EXISTS current_workouts (
+    user_id UUID PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    workout JSONB NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
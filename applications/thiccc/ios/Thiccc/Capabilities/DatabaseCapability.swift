@@ -11,9 +11,9 @@ import GRDB
 /// 3. **Persistent failure:** Backup to file + schedule background retry
 ///
 /// # Architecture
-/// - Receives JSON-encoded workout data from Rust core
-/// - Parses JSON and inserts into SQLite using raw SQL
-/// - Returns JSON strings back to Rust core (maintains clean separation)
+/// - Receives typed `Workout` values (generated SharedTypes) from Rust core
+/// - Maps them to SQLite rows using raw SQL
+/// - Returns typed workouts back to Rust core (no hand-built JSON)
 /// - All business logic remains in Rust (this is just I/O)
 @MainActor
 class DatabaseCapability {
@@ -53,17 +53,32 @@ class DatabaseCapability {
     /// Handle a database operation from the Rust core.
     func handle(_ operation: SharedTypes.DatabaseOperation, requestId: UInt32) async {
         switch operation {
-        case .saveWorkout(let workoutJson):
-            await handleSaveWorkout(workoutJson: workoutJson, requestId: requestId)
+        case .saveWorkout(let workout):
+            await handleSaveWorkout(workout: workout, requestId: requestId)
             
         case .loadAllWorkouts:
             await handleLoadAllWorkouts(requestId: requestId)
-            
+
+        case .loadWorkoutsPage(let query):
+            await handleLoadWorkoutsPage(query: query, requestId: requestId)
+
         case .loadWorkoutById(let id):
             await handleLoadWorkoutById(id: id, requestId: requestId)
+
+        case .loadExerciseHistory(let names):
+            await handleLoadExerciseHistory(names: names, requestId: requestId)
+
+        case .loadExerciseNames:
+            await handleLoadExerciseNames(requestId: requestId)
             
         case .deleteWorkout(let id):
             await handleDeleteWorkout(id: id, requestId: requestId)
+            
+        case .updateWorkout(let workout):
+            await handleUpdateWorkout(workout: workout, requestId: requestId)
+
+        case .importWorkouts(let workouts):
+            await handleImportWorkouts(workouts: workouts, requestId: requestId)
         }
     }
     
@@ -75,8 +90,8 @@ class DatabaseCapability {
     /// 1. Try database save
     /// 2. On failure, retry once (0.5s delay)
     /// 3. On retry failure, save to backup file
-    private func handleSaveWorkout(workoutJson: String, requestId: UInt32) async {
-        ConsoleLogger.shared.log("Saving workout (\(workoutJson.count) bytes)", emoji: "💾")
+    private func handleSaveWorkout(workout: SharedTypes.Workout, requestId: UInt32) async {
+        ConsoleLogger.shared.log("Saving workout '\(workout.name)' (\(workout.exercises.count) exercises)", emoji: "💾")
         
         var attempt = 0
         let maxAttempts = 2  // Original + 1 retry
@@ -86,7 +101,7 @@ class DatabaseCapability {
             
             do {
                 // ATTEMPT: Save to database
-                try await saveWorkoutToDatabase(workoutJson)
+                try await saveWorkoutToDatabase(workout)
                 
                 // ✅ SUCCESS
                 ConsoleLogger.shared.log("Workout saved ✓", emoji: "✅")
@@ -111,7 +126,7 @@ class DatabaseCapability {
                 // ❌ BOTH ATTEMPTS FAILED - Use backup strategy
                 do {
                     // Save to backup file
-                    try await saveWorkoutToBackup(workoutJson)
+                    try await saveWorkoutToBackup(workout)
                     
                     // Tell Rust core: Saved to backup, will retry later
                     let result = SharedTypes.DatabaseResult.error(
@@ -136,168 +151,102 @@ class DatabaseCapability {
     
     /// Save workout to database using GRDB.
     ///
-    /// Parses JSON and inserts into workouts/exercises/exerciseSets tables.
-    private func saveWorkoutToDatabase(_ workoutJson: String) async throws {
-        // Parse JSON to extract basic fields for database
-        guard let jsonData = workoutJson.data(using: .utf8),
-              let workout = try? JSONSerialization.jsonObject(with: jsonData) as? [String: Any] else {
-            throw NSError(domain: "DatabaseCapability", code: 1, userInfo: [
-                NSLocalizedDescriptionKey: "Failed to parse workout JSON"
-            ])
-        }
-        
-        let workoutId = workout["id"] as? String ?? ""
-        let name = workout["name"] as? String ?? "Unnamed Workout"
-        let note = workout["note"] as? String
-        let duration = workout["duration"] as? Int
-        
-        // Parse timestamps
-        let startTimestamp: Date
-        let endTimestamp: Date?
-        
-        if let startStr = workout["start_timestamp"] as? String {
-            let formatter = ISO8601DateFormatter()
-            startTimestamp = formatter.date(from: startStr) ?? Date()
-        } else {
-            startTimestamp = Date()
-        }
-        
-        if let endStr = workout["end_timestamp"] as? String {
-            let formatter = ISO8601DateFormatter()
-            endTimestamp = formatter.date(from: endStr)
-        } else {
-            endTimestamp = nil
-        }
-        
+    /// Inserts into workouts/exercises/exerciseSets tables.
+    /// When `replacingExisting` is true, the stored workout (and via CASCADE
+    /// its exercises and sets) is deleted first in the same transaction, so
+    /// exercises/sets removed during an edit don't linger.
+    private func saveWorkoutToDatabase(_ workout: SharedTypes.Workout, replacingExisting: Bool = false) async throws {
         // Insert into database (transaction ensures atomicity)
         try await database.write { db in
-            // Insert workout
+            if replacingExisting {
+                try db.execute(sql: "DELETE FROM workouts WHERE id = ?", arguments: [workout.id])
+            }
+            try Self.insertWorkout(workout, into: db)
+        }
+    }
+    
+    /// Insert a workout with its exercises and sets inside an open transaction.
+    private nonisolated static func insertWorkout(_ workout: SharedTypes.Workout, into db: Database) throws {
+        // Insert workout
+        try db.execute(
+            sql: """
+            INSERT OR REPLACE INTO workouts 
+            (id, schemaVersion, name, note, duration, startTimestamp, endTimestamp)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            """,
+            arguments: [
+                workout.id,
+                Int(workout.schema_version),
+                workout.name,
+                workout.note,
+                workout.duration.map(Int.init),
+                workout.start_timestamp.timeIntervalSince1970,
+                workout.end_timestamp?.timeIntervalSince1970
+            ]
+        )
+        
+        // Insert exercises and sets
+        for exercise in workout.exercises {
             try db.execute(
                 sql: """
-                INSERT OR REPLACE INTO workouts 
-                (id, name, note, duration, startTimestamp, endTimestamp)
-                VALUES (?, ?, ?, ?, ?, ?)
+                INSERT OR REPLACE INTO exercises
+                (id, workoutId, supersetId, name, pinnedNotes, notes, duration, type, weightUnit, defaultWarmUpTime, defaultRestTime, bodyPart)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                 """,
                 arguments: [
-                    workoutId,
-                    name,
-                    note,
-                    duration,
-                    startTimestamp.timeIntervalSince1970,
-                    endTimestamp?.timeIntervalSince1970
+                    exercise.id,
+                    workout.id,
+                    exercise.superset_id.map(Int.init),
+                    exercise.name,
+                    Self.jsonString(exercise.pinned_notes.isEmpty ? nil : exercise.pinned_notes),
+                    Self.jsonString(exercise.notes.isEmpty ? nil : exercise.notes),
+                    exercise.duration.map(Int.init),
+                    exercise.type.dbValue,
+                    exercise.weight_unit?.dbValue,
+                    exercise.default_warm_up_time.map(Int.init),
+                    exercise.default_rest_time.map(Int.init),
+                    Self.jsonString(exercise.body_part?.jsonObject)
                 ]
             )
             
-            // Insert exercises and sets
-            if let exercises = workout["exercises"] as? [[String: Any]] {
-                for exercise in exercises {
-                    let exerciseId = exercise["id"] as? String ?? ""
-                    let exerciseName = exercise["name"] as? String ?? "Unknown"
-                    let exerciseType = exercise["type"] as? String ?? "unknown"
-                    let supersetId = exercise["superset_id"] as? Int
-                    let duration = exercise["duration"] as? Int
-                    let weightUnit = exercise["weight_unit"] as? String
-                    let defaultWarmUpTime = exercise["default_warm_up_time"] as? Int
-                    let defaultRestTime = exercise["default_rest_time"] as? Int
-                    
-                    // Serialize optional arrays/objects to JSON
-                    let pinnedNotesJson: String? = {
-                        if let notes = exercise["pinned_notes"] as? [String], !notes.isEmpty {
-                            return try? String(data: JSONSerialization.data(withJSONObject: notes), encoding: .utf8)
-                        }
-                        return nil
-                    }()
-                    
-                    let notesJson: String? = {
-                        if let notes = exercise["notes"] as? [String], !notes.isEmpty {
-                            return try? String(data: JSONSerialization.data(withJSONObject: notes), encoding: .utf8)
-                        }
-                        return nil
-                    }()
-                    
-                    let bodyPartJson: String? = {
-                        if let bodyPart = exercise["body_part"] as? [String: Any] {
-                            return try? String(data: JSONSerialization.data(withJSONObject: bodyPart), encoding: .utf8)
-                        }
-                        return nil
-                    }()
-                    
-                    try db.execute(
-                        sql: """
-                        INSERT OR REPLACE INTO exercises
-                        (id, workoutId, supersetId, name, pinnedNotes, notes, duration, type, weightUnit, defaultWarmUpTime, defaultRestTime, bodyPart)
-                        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-                        """,
-                        arguments: [
-                            exerciseId,
-                            workoutId,
-                            supersetId,
-                            exerciseName,
-                            pinnedNotesJson,
-                            notesJson,
-                            duration,
-                            exerciseType,
-                            weightUnit,
-                            defaultWarmUpTime,
-                            defaultRestTime,
-                            bodyPartJson
-                        ]
-                    )
-                    if let sets = exercise["sets"] as? [[String: Any]] {
-                        for (index, set) in sets.enumerated() {
-                            let setId = set["id"] as? String ?? ""
-                            let setType = set["type"] as? String ?? "working"
-                            let isCompleted = set["is_completed"] as? Bool ?? false
-                            let weightUnit = set["weight_unit"] as? String
-                            
-                            // Serialize suggest and actual objects to JSON
-                            let suggestJson: String? = {
-                                if let suggest = set["suggest"] as? [String: Any] {
-                                    return try? String(data: JSONSerialization.data(withJSONObject: suggest), encoding: .utf8)
-                                }
-                                return nil
-                            }()
-                            
-                            let actualJson: String? = {
-                                if let actual = set["actual"] as? [String: Any] {
-                                    return try? String(data: JSONSerialization.data(withJSONObject: actual), encoding: .utf8)
-                                }
-                                return nil
-                            }()
-                            
-                            try db.execute(
-                                sql: """
-                                INSERT OR REPLACE INTO exerciseSets
-                                (id, exerciseId, workoutId, setIndex, type, weightUnit, suggest, actual, isCompleted)
-                                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
-                                """,
-                                arguments: [
-                                    setId,
-                                    exerciseId,
-                                    workoutId,
-                                    index,
-                                    setType,
-                                    weightUnit,
-                                    suggestJson,
-                                    actualJson,
-                                    isCompleted ? 1 : 0
-                                ]
-                            )
-                        }
-                    }
-                }
+            for (index, set) in exercise.sets.enumerated() {
+                try db.execute(
+                    sql: """
+                    INSERT OR REPLACE INTO exerciseSets
+                    (id, exerciseId, workoutId, setIndex, type, weightUnit, suggest, actual, isCompleted)
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                    """,
+                    arguments: [
+                        set.id,
+                        exercise.id,
+                        workout.id,
+                        index,
+                        set.type.dbValue,
+                        set.weight_unit?.dbValue,
+                        Self.jsonString(set.suggest.jsonObject),
+                        Self.jsonString(set.actual.jsonObject),
+                        set.is_completed ? 1 : 0
+                    ]
+                )
             }
         }
     }
     
+    /// Encode an array/dictionary column value as a JSON string (nil stays NULL).
+    private nonisolated static func jsonString(_ object: Any?) -> String? {
+        guard let object else { return nil }
+        guard let data = try? JSONSerialization.data(withJSONObject: object) else { return nil }
+        return String(data: data, encoding: .utf8)
+    }
+    
     // MARK: - Backup Strategy
     
-    /// Save workout to backup file.
-    private func saveWorkoutToBackup(_ workoutJson: String) async throws {
+    /// Save workout to backup file (bincode, the same encoding the core uses).
+    private func saveWorkoutToBackup(_ workout: SharedTypes.Workout) async throws {
         let timestamp = ISO8601DateFormatter().string(from: Date())
-        let filename = "workout-backup-\(timestamp).json"
+        let filename = "workout-backup-\(timestamp).bin"
         let fileURL = backupDirectory.appendingPathComponent(filename)
-        try workoutJson.write(to: fileURL, atomically: true, encoding: .utf8)
+        try Data(workout.bincodeSerialize()).write(to: fileURL, options: .atomic)
     }
     
     /// Schedule background task to retry backup files.
@@ -314,14 +263,15 @@ class DatabaseCapability {
             let fileURLs = try FileManager.default.contentsOfDirectory(
                 at: backupDirectory,
                 includingPropertiesForKeys: nil
-            ).filter { $0.pathExtension == "json" }
+            ).filter { $0.pathExtension == "bin" }
             
             guard !fileURLs.isEmpty else { return }
             
             for fileURL in fileURLs {
                 do {
-                    let workoutJson = try String(contentsOf: fileURL, encoding: .utf8)
-                    try await saveWorkoutToDatabase(workoutJson)
+                    let bytes = try [UInt8](Data(contentsOf: fileURL))
+                    let workout = try SharedTypes.Workout.bincodeDeserialize(input: bytes)
+                    try await saveWorkoutToDatabase(workout)
                     try FileManager.default.removeItem(at: fileURL)
                 } catch {
                     print("❌ [DatabaseCapability] Failed to process backup: \(error)")
@@ -338,7 +288,7 @@ class DatabaseCapability {
             let fileURLs = try FileManager.default.contentsOfDirectory(
                 at: backupDirectory,
                 includingPropertiesForKeys: nil
-            ).filter { $0.pathExtension == "json" }
+            ).filter { $0.pathExtension == "bin" }
             
             for fileURL in fileURLs {
                 try FileManager.default.removeItem(at: fileURL)
@@ -350,89 +300,454 @@ class DatabaseCapability {
     
     // MARK: - Load All Workouts
     
-    /// Load all workouts for the history view.
+    /// Load every stored workout with its exercises and sets.
     ///
-    /// Returns workouts as JSON strings in reverse chronological order.
+    /// Returns workouts in reverse chronological order. The core exports
+    /// these and records them for the first sync, so summaries won't do.
     private func handleLoadAllWorkouts(requestId: UInt32) async {
         do {
-            let workoutJsons = try await database.read { db -> [String] in
+            let (workouts, failures) = try await database.read { db in
                 let rows = try Row.fetchAll(db, sql: """
-                    SELECT id, name, note, duration, startTimestamp, endTimestamp
+                    SELECT id, schemaVersion, name, note, duration, startTimestamp, endTimestamp
                     FROM workouts
                     ORDER BY startTimestamp DESC
                 """)
-                
-                return try rows.map { row -> String in
-                    var dict: [String: Any] = [
-                        "id": row["id"] as String,
-                        "name": row["name"] as String,
-                        "start_timestamp": ISO8601DateFormatter().string(from: Date(timeIntervalSince1970: row["startTimestamp"])),
-                    ]
-                    
-                    if let note: String = row["note"] { dict["note"] = note }
-                    if let duration: Int = row["duration"] { dict["duration"] = duration }
-                    if let endTs: Double = row["endTimestamp"] {
-                        dict["end_timestamp"] = ISO8601DateFormatter().string(from: Date(timeIntervalSince1970: endTs))
-                    }
-                    
-                    dict["exercises"] = []
-                    
-                    let jsonData = try JSONSerialization.data(withJSONObject: dict)
-                    return String(data: jsonData, encoding: .utf8)!
-                }
+                return Self.fullWorkouts(from: rows, in: db)
             }
             
-            let result = SharedTypes.DatabaseResult.historyLoaded(workouts_json: workoutJsons)
-        await core?.sendDatabaseResponse(requestId: requestId, result: result)
-        
+            let result = SharedTypes.DatabaseResult.historyLoaded(workouts: workouts, failures: failures)
+            await core?.sendDatabaseResponse(requestId: requestId, result: result)
+            
         } catch {
             print("❌ [DatabaseCapability] Load failed: \(error)")
             let result = SharedTypes.DatabaseResult.error(message: error.localizedDescription)
             await core?.sendDatabaseResponse(requestId: requestId, result: result)
         }
     }
-    
+
+    // MARK: - Load Workouts Page
+
+    /// Load one page of workout history matching the query's filter.
+    ///
+    /// Uses keyset pagination on (startTimestamp, id) so pages stay stable when
+    /// workouts are added. Fetches one extra row to compute `has_more`, and
+    /// echoes the query back so the core can discard stale pages.
+    ///
+    /// Names are compared through GRDB's `swiftLowercaseString` (Swift's
+    /// `lowercased()`), like `HistoryFilter::matches` in the core; SQLite's
+    /// `NOCASE` only folds ASCII. `%`, `_` and `\` in the search are literal.
+    private func handleLoadWorkoutsPage(query: SharedTypes.HistoryQuery, requestId: UInt32) async {
+        do {
+            let (workouts, failures, hasMore) = try await database.read { db in
+                var conditions: [String] = []
+                var arguments: StatementArguments = []
+
+                if let cursor = query.cursor {
+                    let cursorSeconds = cursor.start_timestamp.timeIntervalSince1970
+                    conditions.append("(startTimestamp < ? OR (startTimestamp = ? AND id < ?))")
+                    arguments += [cursorSeconds, cursorSeconds, cursor.workout_id]
+                }
+                if let from = query.filter.from_timestamp {
+                    conditions.append("startTimestamp >= ?")
+                    arguments += [from.timeIntervalSince1970]
+                }
+                if let to = query.filter.to_timestamp {
+                    conditions.append("startTimestamp < ?")
+                    arguments += [to.timeIntervalSince1970]
+                }
+                if let search = query.filter.name_search {
+                    conditions.append("swiftLowercaseString(name) LIKE ? ESCAPE '\\'")
+                    arguments += ["%\(Self.escapeLikePattern(search.lowercased()))%"]
+                }
+                if let exerciseName = query.filter.exercise_name {
+                    conditions.append("""
+                        EXISTS (SELECT 1 FROM exercises
+                                WHERE exercises.workoutId = workouts.id
+                                AND swiftLowercaseString(exercises.name) = ?)
+                        """)
+                    arguments += [exerciseName.lowercased()]
+                }
+
+                let whereClause = conditions.isEmpty ? "" : "WHERE " + conditions.joined(separator: " AND ")
+                arguments += [Int(query.limit) + 1]
+
+                let rows = try Row.fetchAll(db, sql: """
+                    SELECT id, schemaVersion, name, note, duration, startTimestamp, endTimestamp
+                    FROM workouts
+                    \(whereClause)
+                    ORDER BY startTimestamp DESC, id DESC
+                    LIMIT ?
+                """, arguments: arguments)
+
+                let hasMore = rows.count > Int(query.limit)
+                let (workouts, failures) = Self.workoutSummaries(from: Array(rows.prefix(Int(query.limit))))
+                return (workouts, failures, hasMore)
+            }
+
+            let result = SharedTypes.DatabaseResult.historyPageLoaded(
+                query: query,
+                workouts: workouts,
+                failures: failures,
+                has_more: hasMore
+            )
+            await core?.sendDatabaseResponse(requestId: requestId, result: result)
+
+        } catch {
+            print("❌ [DatabaseCapability] Page load failed: \(error)")
+            let result = SharedTypes.DatabaseResult.error(message: error.localizedDescription)
+            await core?.sendDatabaseResponse(requestId: requestId, result: result)
+        }
+    }
+
+    /// Escapes `LIKE` wildcards so the text matches literally with `ESCAPE '\'`.
+    private nonisolated static func escapeLikePattern(_ text: String) -> String {
+        var escaped = ""
+        for character in text {
+            if character == "\\" || character == "%" || character == "_" {
+                escaped.append("\\")
+            }
+            escaped.append(character)
+        }
+        return escaped
+    }
+
+    // MARK: - Load Exercise History
+
+    /// Load every stored session of the named exercises, newest first.
+    ///
+    /// Names match case-insensitively through `lowercased()`, like the core's
+    /// `exercise_name_key` (SQLite's `lower()` only folds ASCII), then the
+    /// stored spellings are matched exactly. Each workout keeps only the
+    /// matching exercises, with their sets.
+    private func handleLoadExerciseHistory(names: [String], requestId: UInt32) async {
+        do {
+            let (workouts, failures) = try await database.read { db in
+                let keys = Set(names.map { $0.lowercased() })
+                let stored = try Self.storedExerciseNames(in: db).filter { keys.contains($0.lowercased()) }
+                let placeholders = Array(repeating: "?", count: stored.count).joined(separator: ", ")
+                let rows = try stored.isEmpty ? [] : Row.fetchAll(db, sql: """
+                    SELECT id, schemaVersion, name, note, duration, startTimestamp, endTimestamp
+                    FROM workouts
+                    WHERE EXISTS (SELECT 1 FROM exercises
+                                  WHERE exercises.workoutId = workouts.id
+                                  AND exercises.name IN (\(placeholders)))
+                    ORDER BY startTimestamp DESC, id DESC
+                """, arguments: StatementArguments(stored))
+
+                let (loaded, failures) = Self.fullWorkouts(from: rows, in: db)
+                var workouts = loaded
+                for index in workouts.indices {
+                    workouts[index].exercises.removeAll { !stored.contains($0.name) }
+                }
+                return (workouts, failures)
+            }
+
+            let result = SharedTypes.DatabaseResult.exerciseHistoryLoaded(
+                exercise_names: names,
+                workouts: workouts,
+                failures: failures
+            )
+            await core?.sendDatabaseResponse(requestId: requestId, result: result)
+
+        } catch {
+            print("❌ [DatabaseCapability] Exercise history load failed: \(error)")
+            let result = SharedTypes.DatabaseResult.error(message: error.localizedDescription)
+            await core?.sendDatabaseResponse(requestId: requestId, result: result)
+        }
+    }
+
+    /// Load the names of every stored exercise, once each (ignoring case,
+    /// as in `handleLoadExerciseHistory`).
+    private func handleLoadExerciseNames(requestId: UInt32) async {
+        do {
+            let names = try await database.read { db in
+                var seen = Set<String>()
+                return try Self.storedExerciseNames(in: db).filter { seen.insert($0.lowercased()).inserted }
+            }
+
+            let result = SharedTypes.DatabaseResult.exerciseNamesLoaded(names: names)
+            await core?.sendDatabaseResponse(requestId: requestId, result: result)
+
+        } catch {
+            print("❌ [DatabaseCapability] Exercise names load failed: \(error)")
+            let result = SharedTypes.DatabaseResult.error(message: error.localizedDescription)
+            await core?.sendDatabaseResponse(requestId: requestId, result: result)
+        }
+    }
+
+    /// Every distinct spelling of the stored exercise names, sorted.
+    private nonisolated static func storedExerciseNames(in db: Database) throws -> [String] {
+        try String.fetchAll(db, sql: "SELECT DISTINCT name FROM exercises ORDER BY name")
+    }
+
     // MARK: - Load Workout By ID
     
-    /// Load a specific workout by its ID.
+    /// Load a specific workout by its ID, with its exercises and sets.
+    ///
+    /// A stored workout that can't be read is sent as a load failure, so the
+    /// core quarantines it instead of reporting it missing.
     private func handleLoadWorkoutById(id: String, requestId: UInt32) async {
         do {
-            let workoutJson = try await database.read { db -> String? in
-                guard let workoutRow = try Row.fetchOne(db, sql: """
-                    SELECT id, name, note, duration, startTimestamp, endTimestamp
+            let (workouts, failures) = try await database.read { db in
+                let rows = try Row.fetchAll(db, sql: """
+                    SELECT id, schemaVersion, name, note, duration, startTimestamp, endTimestamp
                     FROM workouts WHERE id = ?
-                """, arguments: [id]) else {
-                    return nil
-                }
-                
-                var dict: [String: Any] = [
-                    "id": workoutRow["id"] as String,
-                    "name": workoutRow["name"] as String,
-                    "start_timestamp": ISO8601DateFormatter().string(from: Date(timeIntervalSince1970: workoutRow["startTimestamp"])),
-                ]
+                """, arguments: [id])
+                return Self.fullWorkouts(from: rows, in: db)
+            }
+
+            let result: SharedTypes.DatabaseResult
+            if let failure = failures.first {
+                result = .workoutLoadFailed(failure: failure)
+            } else {
+                result = .workoutLoaded(workout: workouts.first)
+            }
+            await core?.sendDatabaseResponse(requestId: requestId, result: result)
+            
+        } catch {
+            print("❌ [DatabaseCapability] Load failed: \(error)")
+            let result = SharedTypes.DatabaseResult.error(message: error.localizedDescription)
+            await core?.sendDatabaseResponse(requestId: requestId, result: result)
+        }
+    }
+    
+    /// Build workouts from `workouts` rows, reporting rows that can't be read.
+    ///
+    /// Unreadable rows are sent to the core as load failures instead of
+    /// crashing the app or silently disappearing from history.
+    private nonisolated static func workoutSummaries(
+        from rows: [Row]
+    ) -> ([SharedTypes.Workout], [SharedTypes.WorkoutLoadFailure]) {
+        collectWorkouts(from: rows, using: workoutSummary(from:))
+    }
+
+    /// Build workouts from `workouts` rows with their exercises and sets,
+    /// reporting rows (or children) that can't be read.
+    private nonisolated static func fullWorkouts(
+        from rows: [Row],
+        in db: Database
+    ) -> ([SharedTypes.Workout], [SharedTypes.WorkoutLoadFailure]) {
+        collectWorkouts(from: rows) { row in try fullWorkout(from: row, in: db) }
+    }
+
+    private nonisolated static func collectWorkouts(
+        from rows: [Row],
+        using build: (Row) throws -> SharedTypes.Workout
+    ) -> ([SharedTypes.Workout], [SharedTypes.WorkoutLoadFailure]) {
+        var workouts: [SharedTypes.Workout] = []
+        var failures: [SharedTypes.WorkoutLoadFailure] = []
+        for row in rows {
+            do {
+                workouts.append(try build(row))
+            } catch {
+                let id: String? = row["id"]
+                failures.append(SharedTypes.WorkoutLoadFailure(
+                    workout_id: id ?? "",
+                    error: error.localizedDescription,
+                    raw_payload: row.description
+                ))
+            }
+        }
+        return (workouts, failures)
+    }
+
+    /// Build a workout from a `workouts` row.
+    ///
+    /// Exercises are not loaded yet, so the list is empty.
+    /// Throws if a required column is NULL or has the wrong type.
+    private nonisolated static func workoutSummary(from row: Row) throws -> SharedTypes.Workout {
+        let duration: Int? = row["duration"]
+        let endTimestamp: Double? = row["endTimestamp"]
+        return SharedTypes.Workout(
+            schema_version: UInt32(try row.decode(Int.self, forKey: "schemaVersion")),
+            id: try row.decode(String.self, forKey: "id"),
+            name: try row.decode(String.self, forKey: "name"),
+            note: row["note"],
+            duration: duration.map(Int32.init),
+            start_timestamp: SharedTypes.Timestamp(
+                timeIntervalSince1970: try row.decode(Double.self, forKey: "startTimestamp")
+            ),
+            end_timestamp: endTimestamp.map(SharedTypes.Timestamp.init(timeIntervalSince1970:)),
+            exercises: []
+        )
+    }
+
+    /// Build a workout from a `workouts` row, loading its exercises and sets.
+    ///
+    /// Exercises come back in insertion order, sets in `setIndex` order.
+    private nonisolated static func fullWorkout(from row: Row, in db: Database) throws -> SharedTypes.Workout {
+        var workout = try workoutSummary(from: row)
+
+        let setRows = try Row.fetchAll(db, sql: """
+            SELECT * FROM exerciseSets WHERE workoutId = ? ORDER BY setIndex
+        """, arguments: [workout.id])
+        var setsByExercise: [String: [SharedTypes.ExerciseSet]] = [:]
+        for setRow in setRows {
+            let set = try exerciseSet(from: setRow)
+            setsByExercise[set.exercise_id, default: []].append(set)
+        }
+
+        let exerciseRows = try Row.fetchAll(db, sql: """
+            SELECT * FROM exercises WHERE workoutId = ? ORDER BY rowid
+        """, arguments: [workout.id])
+        workout.exercises = try exerciseRows.map { exerciseRow in
+            var loaded = try Self.exercise(from: exerciseRow)
+            loaded.sets = setsByExercise[loaded.id] ?? []
+            return loaded
+        }
+        return workout
+    }
+
+    /// Build an exercise (without sets) from an `exercises` row.
+    private nonisolated static func exercise(from row: Row) throws -> SharedTypes.Exercise {
+        let supersetId: Int? = row["supersetId"]
+        let duration: Int? = row["duration"]
+        let defaultWarmUpTime: Int? = row["defaultWarmUpTime"]
+        let defaultRestTime: Int? = row["defaultRestTime"]
+        let weightUnit: String? = row["weightUnit"]
+        let bodyPart = try jsonValue(row["bodyPart"]) as? [String: Any]
+        return SharedTypes.Exercise(
+            id: try row.decode(String.self, forKey: "id"),
+            superset_id: supersetId.map(Int32.init),
+            workout_id: try row.decode(String.self, forKey: "workoutId"),
+            name: try row.decode(String.self, forKey: "name"),
+            pinned_notes: try jsonValue(row["pinnedNotes"]) as? [String] ?? [],
+            notes: try jsonValue(row["notes"]) as? [String] ?? [],
+            duration: duration.map(Int32.init),
+            type: try dbEnum(row, "type", SharedTypes.ExerciseType.init(dbValue:)),
+            weight_unit: try weightUnit.map { try dbEnum($0, SharedTypes.WeightUnit.init(dbValue:)) },
+            default_warm_up_time: defaultWarmUpTime.map(Int32.init),
+            default_rest_time: defaultRestTime.map(Int32.init),
+            sets: [],
+            body_part: try bodyPart.map(SharedTypes.BodyPart.init(jsonObject:))
+        )
+    }
+
+    /// Build a set from an `exerciseSets` row.
+    private nonisolated static func exerciseSet(from row: Row) throws -> SharedTypes.ExerciseSet {
+        let weightUnit: String? = row["weightUnit"]
+        let suggest = try jsonValue(row["suggest"]) as? [String: Any] ?? [:]
+        let actual = try jsonValue(row["actual"]) as? [String: Any] ?? [:]
+        return SharedTypes.ExerciseSet(
+            id: try row.decode(String.self, forKey: "id"),
+            type: try dbEnum(row, "type", SharedTypes.SetType.init(dbValue:)),
+            weight_unit: try weightUnit.map { try dbEnum($0, SharedTypes.WeightUnit.init(dbValue:)) },
+            suggest: SharedTypes.SetSuggest(jsonObject: suggest),
+            actual: SharedTypes.SetActual(jsonObject: actual),
+            is_completed: try row.decode(Int.self, forKey: "isCompleted") != 0,
+            exercise_id: try row.decode(String.self, forKey: "exerciseId"),
+            workout_id: try row.decode(String.self, forKey: "workoutId"),
+            set_index: Int32(try row.decode(Int.self, forKey: "setIndex"))
+        )
+    }
+
+    /// Decode a JSON column value (NULL stays nil).
+    private nonisolated static func jsonValue(_ text: String?) throws -> Any? {
+        guard let data = text?.data(using: .utf8) else { return nil }
+        return try JSONSerialization.jsonObject(with: data)
+    }
+
+    /// Decode an enum text column, throwing on values the app doesn't know.
+    private nonisolated static func dbEnum<T>(_ row: Row, _ column: String, _ parse: (String) -> T?) throws -> T {
+        try dbEnum(try row.decode(String.self, forKey: column), parse)
+    }
+
+    private nonisolated static func dbEnum<T>(_ value: String, _ parse: (String) -> T?) throws -> T {
+        guard let parsed = parse(value) else { throw DatabaseValueError(value: value) }
+        return parsed
+    }
+    
+    // MARK: - Update Workout
+    
+    /// Replace a saved workout with an edited copy from history.
+    private func handleUpdateWorkout(workout: SharedTypes.Workout, requestId: UInt32) async {
+        do {
+            try await saveWorkoutToDatabase(workout, replacingExisting: true)
+            
+            let result = SharedTypes.DatabaseResult.workoutUpdated
+            await core?.sendDatabaseResponse(requestId: requestId, result: result)
+            
+        } catch {
+            print("❌ [DatabaseCapability] Update failed: \(error)")
+            let result = SharedTypes.DatabaseResult.error(message: error.localizedDescription)
+            await core?.sendDatabaseResponse(requestId: requestId, result: result)
+        }
+    }
+    
+    // MARK: - Import Workouts
+    
+    /// Insert a batch of imported workouts in one transaction.
+    ///
+    /// Applies the core's `bulk_import` rules against every stored workout:
+    /// a stored workout with the same id and fingerprint (start time and
+    /// case-insensitive name), or any stored workout with the same
+    /// fingerprint, means it's skipped; a stored workout with the same id
+    /// but a different fingerprint is a conflict. Nothing is overwritten.
+    private func handleImportWorkouts(workouts: [SharedTypes.Workout], requestId: UInt32) async {
+        ConsoleLogger.shared.log("Importing \(workouts.count) workouts", emoji: "📥")
+        
+        do {
+            let summary = try await database.write { db -> SharedTypes.ImportSummary in
+                var summary = SharedTypes.ImportSummary(inserted: [], skipped: [], conflicting: [])
                 
-                if let note: String = workoutRow["note"] { dict["note"] = note }
-                if let duration: Int = workoutRow["duration"] { dict["duration"] = duration }
-                if let endTs: Double = workoutRow["endTimestamp"] {
-                    dict["end_timestamp"] = ISO8601DateFormatter().string(from: Date(timeIntervalSince1970: endTs))
+                for workout in workouts {
+                    let entry = SharedTypes.ImportEntry(workout_id: workout.id, name: workout.name)
+                    let fingerprint = Self.fingerprint(start: workout.start_timestamp, name: workout.name)
+                    
+                    if let stored = try Row.fetchOne(
+                        db,
+                        sql: "SELECT startTimestamp, name FROM workouts WHERE id = ?",
+                        arguments: [workout.id]
+                    ) {
+                        let storedFingerprint = Self.fingerprint(
+                            start: SharedTypes.Timestamp(timeIntervalSince1970: stored["startTimestamp"]),
+                            name: stored["name"]
+                        )
+                        if storedFingerprint == fingerprint {
+                            summary.skipped.append(entry)
+                        } else {
+                            summary.conflicting.append(entry)
+                        }
+                        continue
+                    }
+                    
+                    // Other apps' exports get fresh ids on every import, so match on fingerprint too
+                    let namesAtSameStart = try String.fetchAll(
+                        db,
+                        sql: "SELECT name FROM workouts WHERE ABS(startTimestamp - ?) < 0.0005",
+                        arguments: [workout.start_timestamp.timeIntervalSince1970]
+                    )
+                    if namesAtSameStart.contains(where: { Self.fingerprint(start: workout.start_timestamp, name: $0) == fingerprint }) {
+                        summary.skipped.append(entry)
+                        continue
+                    }
+                    
+                    try Self.insertWorkout(workout, into: db)
+                    summary.inserted.append(entry)
                 }
                 
-                dict["exercises"] = []
-                
-                let jsonData = try JSONSerialization.data(withJSONObject: dict)
-                return String(data: jsonData, encoding: .utf8)
+                return summary
             }
             
-            let result = SharedTypes.DatabaseResult.workoutLoaded(workout_json: workoutJson)
-        await core?.sendDatabaseResponse(requestId: requestId, result: result)
-        
+            ConsoleLogger.shared.log(
+                "Imported \(summary.inserted.count), skipped \(summary.skipped.count), conflicts \(summary.conflicting.count)",
+                emoji: "✅"
+            )
+            let result = SharedTypes.DatabaseResult.workoutsImported(summary: summary)
+            await core?.sendDatabaseResponse(requestId: requestId, result: result)
+            
         } catch {
-            print("❌ [DatabaseCapability] Load failed: \(error)")
-            let result = SharedTypes.DatabaseResult.error(message: error.localizedDescription)
+            print("❌ [DatabaseCapability] Import failed: \(error)")
+            let result = SharedTypes.DatabaseResult.error(message: "Import failed: \(error.localizedDescription)")
             await core?.sendDatabaseResponse(requestId: requestId, result: result)
         }
     }
     
+    /// Fingerprint matching `WorkoutFingerprint` in the core: start time and trimmed, lowercased name.
+    private nonisolated static func fingerprint(start: SharedTypes.Timestamp, name: String) -> String {
+        "\(start.value)|\(name.trimmingCharacters(in: .whitespacesAndNewlines).lowercased())"
+    }
+    
     // MARK: - Delete Workout
     
     /// Delete a workout from the database.
@@ -454,3 +769,203 @@ class DatabaseCapability {
         }
     }
 }
+
+// MARK: - Database Column Encoding
+
+extension SharedTypes.Timestamp {
+    /// Create a timestamp from seconds since 1970 (the `REAL` column format).
+    init(timeIntervalSince1970 seconds: Double) {
+        self.init(value: Int64((seconds * 1000).rounded()))
+    }
+    
+    /// Seconds since 1970, as stored in `REAL` timestamp columns.
+    var timeIntervalSince1970: Double {
+        Double(value) / 1000
+    }
+}
+
+/// A stored text value the app doesn't recognise (e.g. an unknown enum case).
+struct DatabaseValueError: LocalizedError {
+    let value: String
+
+    var errorDescription: String? { "Unrecognised stored value '\(value)'" }
+}
+
+// Text column values match the Rust serde names, so rows written before
+// typed payloads existed read back the same way.
+
+extension SharedTypes.ExerciseType {
+    var dbValue: String {
+        switch self {
+        case .dumbbell: return "dumbbell"
+        case .kettlebell: return "kettlebell"
+        case .barbell: return "barbell"
+        case .hexbar: return "hexbar"
+        case .bodyweight: return "bodyweight"
+        case .machine: return "machine"
+        case .unknown: return "unknown"
+        }
+    }
+
+    init?(dbValue: String) {
+        switch dbValue {
+        case "dumbbell": self = .dumbbell
+        case "kettlebell": self = .kettlebell
+        case "barbell": self = .barbell
+        case "hexbar": self = .hexbar
+        case "bodyweight": self = .bodyweight
+        case "machine": self = .machine
+        case "unknown": self = .unknown
+        default: return nil
+        }
+    }
+
+}
+
+extension SharedTypes.WeightUnit {
+    var dbValue: String {
+        switch self {
+        case .kg: return "kg"
+        case .lb: return "lb"
+        case .bodyweight: return "bodyweight"
+        }
+    }
+
+    init?(dbValue: String) {
+        switch dbValue {
+        case "kg": self = .kg
+        case "lb": self = .lb
+        case "bodyweight": self = .bodyweight
+        default: return nil
+        }
+    }
+
+}
+
+extension SharedTypes.SetType {
+    var dbValue: String {
+        switch self {
+        case .warmUp: return "warmUp"
+        case .working: return "working"
+        case .dropSet: return "dropSet"
+        case .amrap: return "amrap"
+        case .failure: return "failure"
+        }
+    }
+
+    init?(dbValue: String) {
+        switch dbValue {
+        case "warmUp": self = .warmUp
+        case "working": self = .working
+        case "dropSet": self = .dropSet
+        case "amrap": self = .amrap
+        case "failure": self = .failure
+        default: return nil
+        }
+    }
+
+}
+
+extension SharedTypes.BodyPartMain {
+    var dbValue: String {
+        switch self {
+        case .chest: return "chest"
+        case .legs: return "legs"
+        case .arms: return "arms"
+        case .back: return "back"
+        case .calves: return "calves"
+        case .shoulders: return "shoulders"
+        case .core: return "core"
+        case .cardio: return "cardio"
+        case .fullBody: return "fullBody"
+        case .other: return "other"
+        }
+    }
+
+    init?(dbValue: String) {
+        switch dbValue {
+        case "chest": self = .chest
+        case "legs": self = .legs
+        case "arms": self = .arms
+        case "back": self = .back
+        case "calves": self = .calves
+        case "shoulders": self = .shoulders
+        case "core": self = .core
+        case "cardio": self = .cardio
+        case "fullBody": self = .fullBody
+        case "other": self = .other
+        default: return nil
+        }
+    }
+
+}
+
+extension SharedTypes.BodyPart {
+    /// Dictionary stored in the `bodyPart` JSON column.
+    var jsonObject: [String: Any] {
+        var dict: [String: Any] = ["main": main.dbValue]
+        if let detailed { dict["detailed"] = detailed }
+        if let scientific { dict["scientific"] = scientific }
+        return dict
+    }
+
+    init(jsonObject dict: [String: Any]) throws {
+        let main = dict["main"] as? String ?? ""
+        guard let parsed = SharedTypes.BodyPartMain(dbValue: main) else {
+            throw DatabaseValueError(value: main)
+        }
+        self.init(
+            main: parsed,
+            detailed: dict["detailed"] as? [String],
+            scientific: dict["scientific"] as? [String]
+        )
+    }
+}
+
+extension SharedTypes.SetSuggest {
+    /// Dictionary stored in the `suggest` JSON column.
+    var jsonObject: [String: Any] {
+        var dict: [String: Any] = [:]
+        if let weight { dict["weight"] = weight }
+        if let reps { dict["reps"] = reps }
+        if let rep_range { dict["rep_range"] = rep_range }
+        if let duration { dict["duration"] = duration }
+        if let rpe { dict["rpe"] = rpe }
+        if let rest_time { dict["rest_time"] = rest_time }
+        return dict
+    }
+
+    init(jsonObject dict: [String: Any]) {
+        self.init(
+            weight: (dict["weight"] as? NSNumber)?.doubleValue,
+            reps: (dict["reps"] as? NSNumber)?.int32Value,
+            rep_range: (dict["rep_range"] as? NSNumber)?.int32Value,
+            duration: (dict["duration"] as? NSNumber)?.int32Value,
+            rpe: (dict["rpe"] as? NSNumber)?.doubleValue,
+            rest_time: (dict["rest_time"] as? NSNumber)?.int32Value
+        )
+    }
+}
+
+extension SharedTypes.SetActual {
+    /// Dictionary stored in the `actual` JSON column.
+    var jsonObject: [String: Any] {
+        var dict: [String: Any] = [:]
+        if let weight { dict["weight"] = weight }
+        if let reps { dict["reps"] = reps }
+        if let duration { dict["duration"] = duration }
+        if let rpe { dict["rpe"] = rpe }
+        if let actual_rest_time { dict["actual_rest_time"] = actual_rest_time }
+        return dict
+    }
+
+    init(jsonObject dict: [String: Any]) {
+        self.init(
+            weight: (dict["weight"] as? NSNumber)?.doubleValue,
+            reps: (dict["reps"] as? NSNumber)?.int32Value,
+            duration: (dict["duration"] as? NSNumber)?.int32Value,
+            rpe: (dict["rpe"] as? NSNumber)?.doubleValue,
+            actual_rest_time: (dict["actual_rest_time"] as? NSNumber)?.int32Value
+        )
+    }
+}
@@ -0,0 +1,77 @@
+import Foundation
+import SharedTypes
+
+/// Sends HTTP requests built by the Rust core.
+///
+/// The core adds the session token, decides on retries and parses responses
+/// (see `http` in the shared crate); this capability only waits `delay_ms`,
+/// sends the request as given and reports what happened.
+@MainActor
+class HttpCapability {
+    private weak var core: Core?
+    private let session: URLSession
+
+    init(core: Core) {
+        self.core = core
+        self.session = URLSession(configuration: .default)
+        print("🌐 [HttpCapability] Initialized")
+    }
+
+    /// Handle an HTTP operation from the Rust core.
+    func handle(_ operation: SharedTypes.HttpOperation, requestId: UInt32) async {
+        switch operation {
+        case .send(let request):
+            let result = await send(request)
+            await core?.sendHttpResponse(requestId: requestId, result: result)
+        }
+    }
+
+    private func send(_ request: SharedTypes.HttpRequest) async -> SharedTypes.HttpResult {
+        guard let url = URL(string: request.url) else {
+            return .failed(message: "Invalid URL: \(request.url)")
+        }
+
+        if request.delay_ms > 0 {
+            try? await Task.sleep(for: .milliseconds(Int(request.delay_ms)))
+        }
+
+        var urlRequest = URLRequest(url: url)
+        urlRequest.httpMethod = Self.methodName(request.method)
+        urlRequest.timeoutInterval = TimeInterval(request.timeout_ms) / 1000
+        for header in request.headers {
+            urlRequest.addValue(header.value, forHTTPHeaderField: header.name)
+        }
+        urlRequest.httpBody = request.body.map { Data($0.utf8) }
+
+        do {
+            let (data, response) = try await session.data(for: urlRequest)
+            let http = response as? HTTPURLResponse
+            let status = http?.statusCode ?? 0
+            let headers = (http?.allHeaderFields ?? [:]).map { name, value in
+                SharedTypes.HttpHeader(name: "\(name)", value: "\(value)")
+            }
+            print("🌐 [HttpCapability] \(urlRequest.httpMethod ?? "") \(url.path()) -> \(status) (\(data.count) bytes)")
+            return .response(SharedTypes.HttpResponse(
+                status: UInt16(clamping: status),
+                headers: headers,
+                body: String(decoding: data, as: UTF8.self)
+            ))
+        } catch let error as URLError where error.code == .timedOut {
+            print("⏱️ [HttpCapability] \(url.path()) timed out")
+            return .timedOut
+        } catch {
+            print("❌ [HttpCapability] \(url.path()) failed: \(error)")
+            return .failed(message: error.localizedDescription)
+        }
+    }
+
+    private static func methodName(_ method: SharedTypes.HttpMethod) -> String {
+        switch method {
+        case .get: return "GET"
+        case .post: return "POST"
+        case .put: return "PUT"
+        case .patch: return "PATCH"
+        case .delete: return "DELETE"
+        }
+    }
+}
@@ -0,0 +1,91 @@
+import Foundation
+import SharedTypes
+import UIKit
+
+/// Presents files and text generated by the Rust core in the system share sheet.
+///
+/// The core builds the complete content (e.g. a history export or a workout
+/// summary); this capability writes files to a temporary location so they can
+/// be shared, saved to Files or AirDropped, copies text to the clipboard, and
+/// reports whether the user completed or dismissed the sheet.
+///
+/// File location: `tmp/<file name>` (replaced on each share)
+@MainActor
+class ShareCapability {
+    private weak var core: Core?
+
+    init(core: Core) {
+        self.core = core
+        print("📤 [ShareCapability] Initialized")
+    }
+
+    /// Handle a share operation from the Rust core.
+    func handle(_ operation: SharedTypes.ShareOperation, requestId: UInt32) async {
+        switch operation {
+        case .shareFile(let fileName, let mimeType, let contents):
+            await handleShareFile(fileName: fileName, mimeType: mimeType, contents: contents, requestId: requestId)
+
+        case .shareText(let text):
+            print("📤 [ShareCapability] Sharing text (\(text.count) characters)")
+            let result = await present(items: [text])
+            await core?.sendShareResponse(requestId: requestId, result: result)
+
+        case .copyText(let text):
+            UIPasteboard.general.string = text
+            print("📋 [ShareCapability] Copied text (\(text.count) characters)")
+            await core?.sendShareResponse(requestId: requestId, result: .shared)
+        }
+    }
+
+    /// Write the file and present the share sheet.
+    private func handleShareFile(fileName: String, mimeType: String, contents: String, requestId: UInt32) async {
+        print("📤 [ShareCapability] Sharing \(fileName) (\(mimeType))")
+
+        let fileURL = URL.temporaryDirectory.appending(component: fileName)
+        do {
+            try Data(contents.utf8).write(to: fileURL, options: .atomic)
+        } catch {
+            print("❌ [ShareCapability] Write failed: \(error)")
+            let result = SharedTypes.ShareResult.failed(message: "Could not write \(fileName): \(error.localizedDescription)")
+            await core?.sendShareResponse(requestId: requestId, result: result)
+            return
+        }
+
+        let result = await present(items: [fileURL])
+        await core?.sendShareResponse(requestId: requestId, result: result)
+    }
+
+    /// Present the share sheet and wait for the user to finish with it.
+    private func present(items: [Any]) async -> SharedTypes.ShareResult {
+        guard let presenter = Self.topViewController() else {
+            print("❌ [ShareCapability] No view controller to present from")
+            return .failed(message: "Could not open the share sheet")
+        }
+
+        let completed = await withCheckedContinuation { continuation in
+            let controller = UIActivityViewController(activityItems: items, applicationActivities: nil)
+            controller.completionWithItemsHandler = { _, completed, _, _ in
+                continuation.resume(returning: completed)
+            }
+            // iPad presents the sheet as a popover and needs an anchor
+            controller.popoverPresentationController?.sourceView = presenter.view
+            presenter.present(controller, animated: true)
+        }
+
+        print(completed ? "✅ [ShareCapability] Shared" : "📤 [ShareCapability] Share cancelled")
+        return completed ? .shared : .cancelled
+    }
+
+    /// The front-most view controller of the key window.
+    private static func topViewController() -> UIViewController? {
+        let window = UIApplication.shared.connectedScenes
+            .compactMap { $0 as? UIWindowScene }
+            .flatMap(\.windows)
+            .first(where: \.isKeyWindow)
+        var controller = window?.rootViewController
+        while let presented = controller?.presentedViewController {
+            controller = presented
+        }
+        return controller
+    }
+}
@@ -3,19 +3,27 @@ import SharedTypes
 
 /// Handles file-based storage of the current in-progress workout.
 ///
-/// The current workout is persisted to a JSON file so it can be restored
+/// The current workout is persisted to a file so it can be restored
 /// if the app is terminated. This is separate from the database which
 /// stores completed workouts.
 ///
-/// File location: `Documents/current-workout.json`
+/// The file holds the workout's bincode encoding (the same format used to
+/// talk to the Rust core), so it round-trips exactly.
+///
+/// The sync bookkeeping (replica id, cursor and unsent changes) is stored
+/// the same way in its own file.
+///
+/// File locations: `Documents/current-workout.bin`, `Documents/sync-state.bin`
 @MainActor
 class StorageCapability {
     private weak var core: Core?
     private let fileURL: URL
+    private let syncStateURL: URL
     
     init(core: Core) {
         self.core = core
-        self.fileURL = URL.documentsDirectory.appending(component: "current-workout.json")
+        self.fileURL = URL.documentsDirectory.appending(component: "current-workout.bin")
+        self.syncStateURL = URL.documentsDirectory.appending(component: "sync-state.bin")
         
         print("📂 [StorageCapability] Initialized")
         print("📂 [StorageCapability] File path: \(fileURL.path)")
@@ -24,29 +32,67 @@ class StorageCapability {
     /// Handle a storage operation from the Rust core.
     func handle(_ operation: SharedTypes.StorageOperation, requestId: UInt32) async {
         switch operation {
-        case .saveCurrentWorkout(let workoutJson):
-            await handleSave(workoutJson: workoutJson, requestId: requestId)
+        case .saveCurrentWorkout(let workout):
+            await handleSave(workout: workout, requestId: requestId)
             
         case .loadCurrentWorkout:
             await handleLoad(requestId: requestId)
             
         case .deleteCurrentWorkout:
             await handleDelete(requestId: requestId)
+            
+        case .saveSyncState(let state):
+            await handleSaveSyncState(state, requestId: requestId)
+            
+        case .loadSyncState:
+            await handleLoadSyncState(requestId: requestId)
         }
     }
     
-    /// Save the current workout to file storage.
+    /// Save the sync state, replacing the previous file.
+    private func handleSaveSyncState(_ state: SharedTypes.SyncState, requestId: UInt32) async {
+        do {
+            let bytes = try state.bincodeSerialize()
+            try Data(bytes).write(to: syncStateURL, options: .atomic)
+            await core?.sendStorageResponse(requestId: requestId, result: .syncStateSaved)
+        } catch {
+            print("❌ [StorageCapability] Sync state save failed: \(error)")
+            let result = SharedTypes.StorageResult.error(message: error.localizedDescription)
+            await core?.sendStorageResponse(requestId: requestId, result: result)
+        }
+    }
+    
+    /// Load the sync state (nil if it was never saved).
     ///
-    /// The workout is already JSON-encoded by the Rust core.
-    private func handleSave(workoutJson: String, requestId: UInt32) async {
+    /// An unreadable file is reported as an error rather than nil, so the
+    /// core doesn't start a new replica and lose the unsent changes.
+    private func handleLoadSyncState(requestId: UInt32) async {
+        guard FileManager.default.fileExists(atPath: syncStateURL.path) else {
+            await core?.sendStorageResponse(requestId: requestId, result: .syncStateLoaded(state: nil))
+            return
+        }
+        
+        do {
+            let bytes = try [UInt8](Data(contentsOf: syncStateURL))
+            let state = try SharedTypes.SyncState.bincodeDeserialize(input: bytes)
+            await core?.sendStorageResponse(requestId: requestId, result: .syncStateLoaded(state: state))
+        } catch {
+            print("❌ [StorageCapability] Sync state load failed: \(error)")
+            let result = SharedTypes.StorageResult.error(message: "Could not read sync state: \(error.localizedDescription)")
+            await core?.sendStorageResponse(requestId: requestId, result: result)
+        }
+    }
+    
+    /// Save the current workout to file storage.
+    private func handleSave(workout: SharedTypes.Workout, requestId: UInt32) async {
         print("💾 [StorageCapability] Saving current workout...")
         
         do {
-            // Write the JSON string directly to the file
-            try workoutJson.write(to: fileURL, atomically: true, encoding: .utf8)
+            let bytes = try workout.bincodeSerialize()
+            try Data(bytes).write(to: fileURL, options: .atomic)
             
             print("✅ [StorageCapability] Workout saved successfully")
-            print("📄 [StorageCapability] File size: \(workoutJson.count) bytes")
+            print("📄 [StorageCapability] File size: \(bytes.count) bytes")
             
             // Send success response
             let result = SharedTypes.StorageResult.currentWorkoutSaved
@@ -63,7 +109,7 @@ class StorageCapability {
     
     /// Load the current workout from file storage.
     ///
-    /// Returns the JSON string directly - the Rust core handles deserialization.
+    /// An unreadable file is treated as "no saved workout".
     private func handleLoad(requestId: UInt32) async {
         print("📖 [StorageCapability] Loading current workout...")
         
@@ -71,27 +117,25 @@ class StorageCapability {
         guard FileManager.default.fileExists(atPath: fileURL.path) else {
             print("ℹ️ [StorageCapability] No saved workout found")
             
-            let result = SharedTypes.StorageResult.currentWorkoutLoaded(workout_json: nil)
+            let result = SharedTypes.StorageResult.currentWorkoutLoaded(workout: nil)
             await core?.sendStorageResponse(requestId: requestId, result: result)
             return
         }
         
         do {
-            // Read the JSON string from file
-            let jsonString = try String(contentsOf: fileURL, encoding: .utf8)
+            let bytes = try [UInt8](Data(contentsOf: fileURL))
+            let workout = try SharedTypes.Workout.bincodeDeserialize(input: bytes)
             
-            print("📄 [StorageCapability] Loaded JSON (\(jsonString.count) bytes)")
-            print("✅ [StorageCapability] Returning JSON to Rust core for deserialization")
+            print("✅ [StorageCapability] Loaded workout (\(bytes.count) bytes)")
             
-            // Return JSON string - Rust core will deserialize it
-            let result = SharedTypes.StorageResult.currentWorkoutLoaded(workout_json: jsonString)
+            let result = SharedTypes.StorageResult.currentWorkoutLoaded(workout: workout)
             await core?.sendStorageResponse(requestId: requestId, result: result)
             
         } catch {
             print("❌ [StorageCapability] Load failed: \(error)")
             
             // Return nil on error
-            let result = SharedTypes.StorageResult.currentWorkoutLoaded(workout_json: nil)
+            let result = SharedTypes.StorageResult.currentWorkoutLoaded(workout: nil)
             await core?.sendStorageResponse(requestId: requestId, result: result)
         }
     }
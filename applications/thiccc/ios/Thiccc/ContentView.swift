@@ -52,6 +52,40 @@ struct ContentView: View {
             .tag(Tab.debug)
             #endif
         }
+        // Fatal errors block until acknowledged; recoverable ones are shown
+        // inline by the view that raised them.
+        .alert(
+            core.view.errors.fatal?.title ?? "Error",
+            isPresented: Binding(
+                get: { core.view.errors.fatal != nil },
+                set: { _ in }
+            ),
+            presenting: core.view.errors.fatal
+        ) { error in
+            Button("OK") {
+                Task { await core.update(.dismissError(error_id: error.id)) }
+            }
+        } message: { error in
+            Text(error.message)
+        }
+        .alert(
+            "Import Complete",
+            isPresented: Binding(
+                get: { core.view.import_summary != nil && core.view.errors.fatal == nil },
+                set: { _ in }
+            ),
+            presenting: core.view.import_summary
+        ) { _ in
+            Button("OK") {
+                Task { await core.update(.dismissImportSummary) }
+            }
+        } message: { summary in
+            if summary.conflicts.isEmpty {
+                Text(summary.message)
+            } else {
+                Text("\(summary.message)\n\nAlready used by another workout: \(summary.conflicts.joined(separator: ", "))")
+            }
+        }
     }
 }
 
@@ -85,6 +119,54 @@ struct HistoryPlaceholderView: View {
         }
         .padding()
         .navigationTitle("History")
+        .toolbar {
+            ToolbarItem(placement: .topBarTrailing) {
+                Menu {
+                    Button("JSON Archive") { export(.jsonArchive) }
+                    Button("CSV (one row per set)") { export(.csv) }
+                    Button("Markdown Summary") { export(.markdown) }
+                } label: {
+                    Label("Export", systemImage: "square.and.arrow.up")
+                }
+                .disabled(core.view.is_loading)
+            }
+            ToolbarItem(placement: .topBarLeading) {
+                Menu {
+                    Picker("Totals Unit", selection: Binding(
+                        get: { core.view.preferred_weight_unit },
+                        set: { unit in Task { await core.update(.setPreferredWeightUnit(unit: unit)) } }
+                    )) {
+                        Text("Pounds (lb)").tag(WeightUnit.lb)
+                        Text("Kilograms (kg)").tag(WeightUnit.kg)
+                    }
+                } label: {
+                    Label("Units", systemImage: "scalemass")
+                }
+            }
+        }
+        .alert(
+            exportError?.title ?? "Export Failed",
+            isPresented: Binding(
+                get: { exportError != nil },
+                set: { _ in }
+            ),
+            presenting: exportError
+        ) { error in
+            Button("OK") {
+                Task { await core.update(.dismissError(error_id: error.id)) }
+            }
+        } message: { error in
+            Text(error.message)
+        }
+    }
+
+    /// The newest export failure, if any (raised while loading, building or sharing the file)
+    private var exportError: ErrorViewModel? {
+        core.view.errors.recoverable.last { $0.title == "Export Failed" }
+    }
+
+    private func export(_ format: ExportFormat) {
+        Task { await core.update(.exportHistory(format: format)) }
     }
 }
 
@@ -162,6 +162,12 @@ final class DatabaseManager {
             """)
         }
         
+        migrator.registerMigration("v2_add_workout_schema_version") { db in
+            try db.execute(sql: """
+                ALTER TABLE workouts ADD COLUMN schemaVersion INTEGER NOT NULL DEFAULT 1
+            """)
+        }
+        
         try migrator.migrate(testDB)
         
         print("✅ [DatabaseManager] Test database ready")
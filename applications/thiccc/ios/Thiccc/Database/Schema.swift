@@ -141,6 +141,15 @@ func createAppDatabase() throws -> DatabaseWriter {
         print("✅ [Database] Tables created successfully")
     }
     
+    // MIGRATION v2: Track the workout schema version
+    // Rows written before versioning are schema version 1; the core
+    // migrates them when they're loaded.
+    migrator.registerMigration("v2_add_workout_schema_version") { db in
+        try db.execute(sql: """
+            ALTER TABLE workouts ADD COLUMN schemaVersion INTEGER NOT NULL DEFAULT 1
+        """)
+    }
+    
     // STEP 5: Run migrations
     try migrator.migrate(database)
     
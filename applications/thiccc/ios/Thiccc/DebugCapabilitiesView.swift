@@ -98,19 +98,20 @@ struct DebugCapabilitiesView: View {
                     }
                     
                     Button("Show File Path") {
-                        let fileURL = URL.documentsDirectory.appending(component: "current-workout.json")
+                        let fileURL = URL.documentsDirectory.appending(component: "current-workout.bin")
                         print("📂 File location: \(fileURL.path)")
                         print("📂 File exists: \(FileManager.default.fileExists(atPath: fileURL.path))")
                         testResult = "Check console for file path"
                     }
                     
                     Button("Read Storage File") {
-                        let fileURL = URL.documentsDirectory.appending(component: "current-workout.json")
+                        let fileURL = URL.documentsDirectory.appending(component: "current-workout.bin")
                         if FileManager.default.fileExists(atPath: fileURL.path) {
                             do {
-                                let content = try String(contentsOf: fileURL, encoding: .utf8)
-                                print("📄 File content:\n\(content)")
-                                testResult = "File content logged to console (\(content.count) bytes)"
+                                let bytes = try [UInt8](Data(contentsOf: fileURL))
+                                let workout = try SharedTypes.Workout.bincodeDeserialize(input: bytes)
+                                print("📄 File content:\n\(workout)")
+                                testResult = "File content logged to console (\(bytes.count) bytes)"
                             } catch {
                                 testResult = "Failed to read file: \(error)"
                             }
@@ -1,56 +1,35 @@
 import SwiftUI
 import SharedTypes
+import UniformTypeIdentifiers
 
 struct ImportWorkoutView: View {
     @Bindable var core: Core
     @Environment(\.dismiss) private var dismiss
     @State private var jsonText = ""
-    @State private var showError = false
+    @State private var importError: SharedTypes.ErrorViewModel?
+    @State private var csvWeightUnit: WeightUnit = .lb
+    @State private var choosingWatchFile = false
+
+    /// Workout JSON starts with "{" or "["; anything else is treated as a Strong or Hevy CSV export
+    private var isCsv: Bool {
+        let text = jsonText.trimmingCharacters(in: .whitespacesAndNewlines)
+        return !text.hasPrefix("{") && !text.hasPrefix("[")
+    }
+
+    /// Garmin FIT activity files and Apple Health JSON exports
+    private static let watchFileTypes: [UTType] = [UTType(filenameExtension: "fit") ?? .data, .json]
     
     var body: some View {
         NavigationStack {
             VStack(spacing: 16) {
-                Text("Paste workout JSON below")
-                    .font(.subheadline)
-                    .foregroundColor(.secondary)
-                    .frame(maxWidth: .infinity, alignment: .leading)
-                    .padding(.horizontal)
-                
-                TextEditor(text: $jsonText)
-                    .font(.system(.body, design: .monospaced))
-                    .autocapitalization(.none)
-                    .disableAutocorrection(true)
-                    .padding(8)
-                    .background(Color(.systemGray6))
-                    .cornerRadius(8)
-                    .padding(.horizontal)
-                
-                Button {
-                    Task {
-                        await core.update(.importWorkout(json_data: jsonText))
-                        // Check if there's an error after import
-                        if core.view.error_message == nil {
-                            // Success - view will be dismissed by the core state change
-                        } else {
-                            showError = true
-                        }
-                    }
-                } label: {
-                    Text("Import Workout")
-                        .fontWeight(.semibold)
-                        .frame(maxWidth: .infinity)
-                        .padding()
-                        .background(jsonText.isEmpty ? Color.gray : Color.blue)
-                        .foregroundColor(.white)
-                        .cornerRadius(10)
+                if let preview = core.view.csv_import_preview {
+                    csvPreview(preview)
+                } else {
+                    pasteForm
                 }
-                .disabled(jsonText.isEmpty)
-                .padding(.horizontal)
-                
-                Spacer()
             }
             .padding(.vertical)
-            .navigationTitle("Import Workout")
+            .navigationTitle("Import Workouts")
             .navigationBarTitleDisplayMode(.inline)
             .toolbar {
                 ToolbarItem(placement: .cancellationAction) {
@@ -59,13 +38,160 @@ struct ImportWorkoutView: View {
                     }
                 }
             }
-            .alert("Import Failed", isPresented: $showError) {
+            .fileImporter(isPresented: $choosingWatchFile, allowedContentTypes: Self.watchFileTypes) { result in
+                Task { await importWatchFile(result) }
+            }
+            .alert(
+                importError?.title ?? "Import Failed",
+                isPresented: Binding(
+                    get: { importError != nil },
+                    set: { if !$0 { importError = nil } }
+                ),
+                presenting: importError
+            ) { error in
                 Button("OK") {
-                    showError = false
+                    Task { await core.update(.dismissError(error_id: error.id)) }
+                }
+            } message: { error in
+                Text(error.message)
+            }
+        }
+    }
+
+    /// Read the chosen watch file and hand its bytes to the core.
+    private func importWatchFile(_ result: Result<URL, Error>) async {
+        guard case .success(let url) = result else { return }
+        let accessing = url.startAccessingSecurityScopedResource()
+        defer { if accessing { url.stopAccessingSecurityScopedResource() } }
+
+        guard let data = try? Data(contentsOf: url) else {
+            print("❌ [ImportWorkoutView] Could not read \(url.lastPathComponent)")
+            return
+        }
+        await core.update(.importWatchFile(data: [UInt8](data)))
+        // Success dismisses the import view; a failure is queued as the newest recoverable error
+        importError = core.view.errors.recoverable.last { $0.source_event == "ImportWatchFile" }
+    }
+
+    private var pasteForm: some View {
+        VStack(spacing: 16) {
+            Button {
+                choosingWatchFile = true
+            } label: {
+                Label("Import Garmin FIT or Apple Health File", systemImage: "applewatch")
+                    .frame(maxWidth: .infinity)
+            }
+            .buttonStyle(.bordered)
+            .padding(.horizontal)
+
+            Text("Paste workout JSON, or a Strong or Hevy CSV export")
+                .font(.subheadline)
+                .foregroundColor(.secondary)
+                .frame(maxWidth: .infinity, alignment: .leading)
+                .padding(.horizontal)
+
+            TextEditor(text: $jsonText)
+                .font(.system(.body, design: .monospaced))
+                .autocapitalization(.none)
+                .disableAutocorrection(true)
+                .padding(8)
+                .background(Color(.systemGray6))
+                .cornerRadius(8)
+                .padding(.horizontal)
+
+            if isCsv && !jsonText.isEmpty {
+                Picker("Strong weights are in", selection: $csvWeightUnit) {
+                    Text("lb").tag(WeightUnit.lb)
+                    Text("kg").tag(WeightUnit.kg)
+                }
+                .pickerStyle(.segmented)
+                .padding(.horizontal)
+            }
+
+            Button {
+                Task {
+                    let source = isCsv ? "PreviewCsvImport" : "ImportWorkout"
+                    if isCsv {
+                        let options = CsvImportOptions(source_weight_unit: csvWeightUnit, target_weight_unit: .lb)
+                        await core.update(.previewCsvImport(csv_data: jsonText, options: options))
+                    } else {
+                        await core.update(.importWorkout(json_data: jsonText))
+                    }
+                    // Success changes the core state (dismiss or preview);
+                    // a failure is queued as the newest recoverable error
+                    importError = core.view.errors.recoverable.last { $0.source_event == source }
+                }
+            } label: {
+                Text(isCsv ? "Preview Import" : "Import Workout")
+                    .fontWeight(.semibold)
+                    .frame(maxWidth: .infinity)
+                    .padding()
+                    .background(jsonText.isEmpty ? Color.gray : Color.blue)
+                    .foregroundColor(.white)
+                    .cornerRadius(10)
+            }
+            .disabled(jsonText.isEmpty)
+            .padding(.horizontal)
+
+            Spacer()
+        }
+    }
+
+    private func csvPreview(_ preview: CsvImportPreviewViewModel) -> some View {
+        VStack(spacing: 16) {
+            List {
+                Section("From \(preview.source)") {
+                    LabeledContent("Workouts", value: "\(preview.workout_count)")
+                    LabeledContent("Exercises", value: "\(preview.exercise_count)")
+                    LabeledContent("Sets", value: "\(preview.set_count)")
+                    LabeledContent("Dates", value: preview.date_range)
+                }
+
+                if !preview.unmatched_exercises.isEmpty {
+                    Section("Not in exercise library (imported as-is)") {
+                        ForEach(preview.unmatched_exercises, id: \.self) { Text($0) }
+                    }
+                }
+
+                if !preview.skipped_rows.isEmpty {
+                    Section("Skipped rows") {
+                        ForEach(preview.skipped_rows, id: \.self) { row in
+                            Text(row).font(.footnote).foregroundColor(.secondary)
+                        }
+                    }
+                }
+
+                Section("Workouts") {
+                    ForEach(preview.workouts, id: \.id) { workout in
+                        VStack(alignment: .leading) {
+                            Text(workout.name)
+                            Text("\(workout.date) · \(workout.set_count) sets")
+                                .font(.caption)
+                                .foregroundColor(.secondary)
+                        }
+                    }
+                }
+            }
+
+            HStack {
+                Button("Back") {
+                    Task { await core.update(.cancelCsvImport) }
+                }
+                .frame(maxWidth: .infinity)
+
+                Button {
+                    Task { await core.update(.confirmCsvImport) }
+                } label: {
+                    Text("Import \(preview.workout_count) Workouts")
+                        .fontWeight(.semibold)
+                        .frame(maxWidth: .infinity)
+                        .padding()
+                        .background(Color.blue)
+                        .foregroundColor(.white)
+                        .cornerRadius(10)
                 }
-            } message: {
-                Text(core.view.error_message ?? "An error occurred while importing the workout")
             }
+            .padding(.horizontal)
         }
     }
 }
@@ -10,8 +10,9 @@ import SharedTypes
 /// 2. Load all workouts (history)
 /// 3. Load specific workout by ID
 /// 4. Delete workout
-/// 5. Persistence (survives "app restart")
-/// 6. Error handling (retry + backup)
+/// 5. Bulk import with deduplication
+/// 6. Persistence (survives "app restart")
+/// 7. Error handling (retry + backup)
 ///
 /// All tests use in-memory databases for isolation.
 @Suite("Database Capability Tests")
@@ -46,12 +47,12 @@ struct DatabaseCapabilityTests {
     /// - Returns success response
     @Test("Save workout to database")
     func saveWorkout() async throws {
-        // GIVEN: A workout JSON
-        let workoutJson = createTestWorkoutJson()
+        // GIVEN: A workout
+        let workout = createTestWorkout()
         
         // WHEN: Save workout
         await capability.handle(
-            .saveWorkout(workoutJson),
+            .saveWorkout(workout),
             requestId: 1
         )
         
@@ -87,12 +88,12 @@ struct DatabaseCapabilityTests {
     @Test("Save replaces existing workout with same ID")
     func saveWorkoutUpdateExisting() async throws {
         // GIVEN: A workout already in database
-        let workoutJson1 = createTestWorkoutJson(name: "Original Name")
-        await capability.handle(.saveWorkout(workoutJson1), requestId: 1)
+        let workout1 = createTestWorkout(name: "Original Name")
+        await capability.handle(.saveWorkout(workout1), requestId: 1)
         
         // WHEN: Save workout with same ID but different name
-        let workoutJson2 = createTestWorkoutJson(name: "Updated Name")
-        await capability.handle(.saveWorkout(workoutJson2), requestId: 2)
+        let workout2 = createTestWorkout(name: "Updated Name")
+        await capability.handle(.saveWorkout(workout2), requestId: 2)
         
         // THEN: Still only 1 workout (not 2)
         let count = try await database.read { db in
@@ -117,7 +118,7 @@ struct DatabaseCapabilityTests {
         
         // THEN: Returns empty list
         #expect(core.responsesReceived.count == 1)
-        guard case .historyLoaded(let workouts) = core.responsesReceived[0].result else {
+        guard case .historyLoaded(let workouts, _) = core.responsesReceived[0].result else {
             Issue.record("Expected .historyLoaded response")
             return
         }
@@ -134,8 +135,8 @@ struct DatabaseCapabilityTests {
     func loadAllWorkoutsReturnsAll() async throws {
         // GIVEN: 3 workouts in database
         for i in 1...3 {
-            let json = createTestWorkoutJson(id: "workout-\(i)", name: "Workout \(i)")
-            await capability.handle(.saveWorkout(json), requestId: UInt32(i))
+            let workout = createTestWorkout(id: "workout-\(i)", name: "Workout \(i)")
+            await capability.handle(.saveWorkout(workout), requestId: UInt32(i))
         }
         
         // WHEN: Load all workouts
@@ -143,21 +144,42 @@ struct DatabaseCapabilityTests {
         
         // THEN: Returns 3 workouts
         let response = core.responsesReceived.last!
-        guard case .historyLoaded(let workouts) = response.result else {
+        guard case .historyLoaded(let workouts, _) = response.result else {
             Issue.record("Expected .historyLoaded response")
             return
         }
         #expect(workouts.count == 3, "Should return 3 workouts")
         
-        // THEN: Each workout has valid JSON
-        for workoutJson in workouts {
-            #expect(!workoutJson.isEmpty, "Workout JSON should not be empty")
-            // Verify it's valid JSON
-            let data = workoutJson.data(using: .utf8)!
-            let parsed = try JSONSerialization.jsonObject(with: data)
-            #expect(parsed != nil)
+        // THEN: Each workout round-trips its summary fields
+        for workout in workouts {
+            #expect(workout.name.hasPrefix("Workout "))
+            #expect(workout.start_timestamp.value == 1_766_656_800_000)
+            #expect(workout.duration == 3600)
         }
     }
+
+    /// Test: Load all workouts returns whole workouts, not list summaries.
+    ///
+    /// Export and the first sync use this load, so every set must come through.
+    @Test("Load all workouts includes exercises and sets")
+    func loadAllWorkoutsIncludesSets() async throws {
+        // GIVEN: A workout with 2 exercises of 3 sets each
+        await capability.handle(.saveWorkout(createTestWorkout()), requestId: 1)
+
+        // WHEN: Load all workouts
+        await capability.handle(.loadAllWorkouts, requestId: 2)
+
+        // THEN: The exercises and sets are included
+        guard case .historyLoaded(let workouts, let failures) = core.responsesReceived.last!.result else {
+            Issue.record("Expected .historyLoaded response")
+            return
+        }
+        #expect(failures.isEmpty)
+        #expect(workouts.count == 1)
+        #expect(workouts.first?.exercises.map(\.name) == ["Bench Press", "Pull Up"])
+        #expect(workouts.first?.exercises.map(\.sets.count) == [3, 3])
+        #expect(workouts.first?.exercises.first?.sets.map(\.actual.reps) == [10, 6, 3])
+    }
     
     // MARK: - Load Workout By ID Tests
     
@@ -186,27 +208,127 @@ struct DatabaseCapabilityTests {
     @Test("Load workout by ID returns full workout details")
     func loadWorkoutByIdReturnsFullDetails() async throws {
         // GIVEN: A workout in database
-        let workoutJson = createTestWorkoutJson()
-        await capability.handle(.saveWorkout(workoutJson), requestId: 1)
+        await capability.handle(.saveWorkout(createTestWorkout()), requestId: 1)
         
         // WHEN: Load by ID
         await capability.handle(.loadWorkoutById("test-workout-1"), requestId: 2)
         
         // THEN: Returns full workout
         let response = core.responsesReceived.last!
-        guard case .workoutLoaded(let loadedJson) = response.result else {
-            Issue.record("Expected .workoutLoaded response")
+        guard case .workoutLoaded(let loaded) = response.result, let loaded else {
+            Issue.record("Expected .workoutLoaded response with a workout")
             return
         }
-        #expect(loadedJson != nil, "Should return workout")
         
         // THEN: Workout has exercises
-        let data = loadedJson!.data(using: .utf8)!
-        let parsed = try JSONSerialization.jsonObject(with: data) as! [String: Any]
-        let exercises = parsed["exercises"] as! [[String: Any]]
-        #expect(exercises.count == 2, "Should have 2 exercises")
+        #expect(loaded.exercises.count == 2, "Should have 2 exercises")
+
+        // THEN: Exercises have their sets
+        #expect(loaded.exercises[0].sets.map(\.actual.weight) == [135, 185, 205])
     }
-    
+
+    /// Test: A stored workout that can't be read is reported, not "not found".
+    @Test("Load workout by ID reports an unreadable workout")
+    func loadWorkoutByIdReportsUnreadableWorkout() async throws {
+        // GIVEN: A workout whose exercise has an unknown type
+        await capability.handle(.saveWorkout(createTestWorkout()), requestId: 1)
+        try await database.write { db in
+            try db.execute(sql: "UPDATE exercises SET type = 'trebuchet' WHERE id = 'exercise-1'")
+        }
+
+        // WHEN: Load by ID
+        await capability.handle(.loadWorkoutById("test-workout-1"), requestId: 2)
+
+        // THEN: The core gets a load failure naming the workout
+        guard case .workoutLoadFailed(let failure) = core.responsesReceived.last!.result else {
+            Issue.record("Expected .workoutLoadFailed response")
+            return
+        }
+        #expect(failure.workout_id == "test-workout-1")
+        #expect(failure.error.contains("trebuchet"))
+    }
+
+    // MARK: - Exercise History Tests
+
+    /// Test: Exercise history is read from the database with full sets.
+    ///
+    /// Verifies:
+    /// - Names match case-insensitively
+    /// - Only the matching exercise is kept, with its sets
+    /// - Exercise names are listed once each
+    @Test("Load exercise history returns matching exercises with sets")
+    func loadExerciseHistory() async throws {
+        // GIVEN: A workout with Bench Press and Pull Up
+        await capability.handle(.saveWorkout(createTestWorkout()), requestId: 1)
+
+        // WHEN: Load the history of one exercise
+        await capability.handle(.loadExerciseHistory(["bench press"]), requestId: 3)
+
+        // THEN: The session comes back with its sets
+        guard case .exerciseHistoryLoaded(let names, let workouts, let failures) = core.responsesReceived.last!.result else {
+            Issue.record("Expected .exerciseHistoryLoaded response")
+            return
+        }
+        #expect(names == ["bench press"])
+        #expect(failures.isEmpty)
+        #expect(workouts.count == 1)
+        for workout in workouts {
+            #expect(workout.exercises.map(\.name) == ["Bench Press"])
+            #expect(workout.exercises[0].sets.map(\.actual.weight) == [135, 185, 205])
+            #expect(workout.exercises[0].sets.map(\.set_index) == [0, 1, 2])
+        }
+
+        // WHEN: Load the exercise names
+        await capability.handle(.loadExerciseNames, requestId: 4)
+
+        // THEN: Each name appears once
+        guard case .exerciseNamesLoaded(let exerciseNames) = core.responsesReceived.last!.result else {
+            Issue.record("Expected .exerciseNamesLoaded response")
+            return
+        }
+        #expect(exerciseNames == ["Bench Press", "Pull Up"])
+    }
+
+    /// Test: History page filters fold names like the core and treat `LIKE`
+    /// wildcards literally.
+    ///
+    /// Verifies:
+    /// - Name search folds non-ASCII case (SQLite's NOCASE doesn't)
+    /// - `%` and `_` in the search only match themselves
+    /// - The exercise filter folds non-ASCII case too
+    @Test("Load workouts page matches names like HistoryFilter::matches")
+    func loadWorkoutsPageMatchesNamesLikeTheCore() async throws {
+        // GIVEN: Workouts whose names contain non-ASCII letters and wildcards
+        var uber = createTestWorkout(id: "workout-uber", name: "Über Push")
+        uber.exercises[0].name = "Überzug"
+        await capability.handle(.saveWorkout(uber), requestId: 1)
+        for (index, name) in ["100% Effort", "Leg_Day", "Legs Day"].enumerated() {
+            var workout = createTestWorkout(id: "workout-\(index)", name: name)
+            workout.exercises = []
+            await capability.handle(.saveWorkout(workout), requestId: UInt32(index + 2))
+        }
+
+        func page(search: String? = nil, exercise: String? = nil) async -> [String] {
+            let filter = SharedTypes.HistoryFilter(
+                from_timestamp: nil, to_timestamp: nil, exercise_name: exercise, name_search: search
+            )
+            let query = SharedTypes.HistoryQuery(cursor: nil, limit: 20, filter: filter)
+            await capability.handle(.loadWorkoutsPage(query), requestId: 10)
+            guard case .historyPageLoaded(_, let workouts, _, _) = core.responsesReceived.last!.result else {
+                Issue.record("Expected .historyPageLoaded response")
+                return []
+            }
+            return workouts.map(\.name).sorted()
+        }
+
+        // THEN: Case folds beyond ASCII, and wildcards are literal
+        #expect(await page(search: "über") == ["Über Push"])
+        #expect(await page(search: "ÜBER") == ["Über Push"])
+        #expect(await page(search: "%") == ["100% Effort"])
+        #expect(await page(search: "g_d") == ["Leg_Day"])
+        #expect(await page(exercise: "überzug") == ["Über Push"])
+    }
+
     // MARK: - Delete Workout Tests
     
     /// Test: Delete removes workout from database.
@@ -218,8 +340,7 @@ struct DatabaseCapabilityTests {
     @Test("Delete removes workout and cascades to exercises and sets")
     func deleteWorkout() async throws {
         // GIVEN: A workout in database
-        let workoutJson = createTestWorkoutJson()
-        await capability.handle(.saveWorkout(workoutJson), requestId: 1)
+        await capability.handle(.saveWorkout(createTestWorkout()), requestId: 1)
         
         // Verify it exists
         let countBefore = try await database.read { db in
@@ -249,6 +370,51 @@ struct DatabaseCapabilityTests {
         #expect(setCount == 0, "Sets should be deleted (CASCADE)")
     }
     
+    // MARK: - Import Workouts Tests
+    
+    /// Test: Bulk import skips stored workouts and reports id conflicts.
+    ///
+    /// Verifies:
+    /// - Same id and fingerprint is skipped
+    /// - Same fingerprint with a new id (re-imported CSV) is skipped
+    /// - Same id with a different workout is a conflict and isn't overwritten
+    /// - New workouts are inserted
+    @Test("Import workouts deduplicates by id and fingerprint")
+    func importWorkoutsDeduplicates() async throws {
+        // GIVEN: A stored workout
+        let stored = createTestWorkout()
+        await capability.handle(.saveWorkout(stored), requestId: 1)
+        
+        var reimported = stored
+        reimported.id = "test-workout-csv"
+        reimported.name = "  test WORKOUT "
+        reimported.exercises = []
+        var conflicting = stored
+        conflicting.name = "Different Workout"
+        conflicting.exercises = []
+        var new = stored
+        new.id = "test-workout-2"
+        new.name = "Leg Day"
+        new.exercises = []
+        
+        // WHEN: Import a batch
+        await capability.handle(.importWorkouts([stored, reimported, conflicting, new]), requestId: 2)
+        
+        // THEN: Only the new workout was inserted
+        guard case .workoutsImported(let summary) = core.responsesReceived[1].result else {
+            Issue.record("Expected .workoutsImported response")
+            return
+        }
+        #expect(summary.inserted.map(\.workout_id) == ["test-workout-2"])
+        #expect(summary.skipped.map(\.workout_id) == ["test-workout-1", "test-workout-csv"])
+        #expect(summary.conflicting.map(\.workout_id) == ["test-workout-1"])
+        
+        let names = try await database.read { db in
+            try String.fetchAll(db, sql: "SELECT name FROM workouts ORDER BY id")
+        }
+        #expect(names == ["Test Workout", "Leg Day"])
+    }
+    
     // MARK: - Persistence Tests
     
     /// Test: Workout survives "app restart" (database persists).
@@ -280,6 +446,7 @@ struct DatabaseCapabilityTests {
                 try db.execute(sql: """
                     CREATE TABLE workouts (
                         id TEXT PRIMARY KEY,
+                        schemaVersion INTEGER NOT NULL DEFAULT 1,
                         name TEXT NOT NULL,
                         note TEXT,
                         duration INTEGER,
@@ -313,8 +480,7 @@ struct DatabaseCapabilityTests {
             let core1 = MockCore()
             let capability1 = DatabaseCapability(core: core1, database: db1)
             
-            let workoutJson = createTestWorkoutJson()
-            await capability1.handle(.saveWorkout(workoutJson), requestId: 1)
+            await capability1.handle(.saveWorkout(createTestWorkout()), requestId: 1)
             
             // PHASE 2: "App restart" - close database
             // (db1 gets deallocated)
@@ -343,81 +509,60 @@ struct DatabaseCapabilityTests {
     
     // MARK: - Test Helpers
     
-    /// Create a test workout JSON string.
+    /// Create a test workout.
     ///
     /// Creates a workout with 2 exercises, each with 3 sets.
-    private func createTestWorkoutJson(
+    private func createTestWorkout(
         id: String = "test-workout-1",
         name: String = "Test Workout"
-    ) -> String {
-        return """
-        {
-          "id": "\(id)",
-          "name": "\(name)",
-          "note": "Test note",
-          "duration": 3600,
-          "start_timestamp": "2025-12-25T10:00:00Z",
-          "end_timestamp": "2025-12-25T11:00:00Z",
-          "exercises": [
-            {
-              "id": "exercise-1",
-              "name": "Bench Press",
-              "type": "barbell",
-              "weight_unit": "lb",
-              "default_rest_time": 90,
-              "sets": [
-                {
-                  "id": "set-1-1",
-                  "set_type": "working",
-                  "weight_unit": "lb",
-                  "actual": "{\\"weight\\":135,\\"reps\\":10,\\"rpe\\":7.5}",
-                  "is_completed": true
-                },
-                {
-                  "id": "set-1-2",
-                  "set_type": "working",
-                  "weight_unit": "lb",
-                  "actual": "{\\"weight\\":185,\\"reps\\":6,\\"rpe\\":8.5}",
-                  "is_completed": true
-                },
-                {
-                  "id": "set-1-3",
-                  "set_type": "working",
-                  "weight_unit": "lb",
-                  "actual": "{\\"weight\\":205,\\"reps\\":3,\\"rpe\\":9.5}",
-                  "is_completed": true
-                }
-              ]
-            },
-            {
-              "id": "exercise-2",
-              "name": "Pull Up",
-              "type": "bodyweight",
-              "default_rest_time": 60,
-              "sets": [
-                {
-                  "id": "set-2-1",
-                  "set_type": "working",
-                  "actual": "{\\"reps\\":12,\\"rpe\\":7.0}",
-                  "is_completed": true
-                },
-                {
-                  "id": "set-2-2",
-                  "set_type": "working",
-                  "actual": "{\\"reps\\":10,\\"rpe\\":8.0}",
-                  "is_completed": true
-                },
-                {
-                  "id": "set-2-3",
-                  "set_type": "working",
-                  "actual": "{\\"reps\\":8,\\"rpe\\":9.0}",
-                  "is_completed": true
-                }
-              ]
-            }
-          ]
+    ) -> SharedTypes.Workout {
+        func set(_ setId: String, exerciseId: String, index: Int32, weight: Double?, reps: Int32, rpe: Double) -> SharedTypes.ExerciseSet {
+            SharedTypes.ExerciseSet(
+                id: setId,
+                type: .working,
+                weight_unit: weight == nil ? nil : .lb,
+                suggest: SharedTypes.SetSuggest(weight: nil, reps: nil, rep_range: nil, duration: nil, rpe: nil, rest_time: nil),
+                actual: SharedTypes.SetActual(weight: weight, reps: reps, duration: nil, rpe: rpe, actual_rest_time: nil),
+                is_completed: true,
+                exercise_id: exerciseId,
+                workout_id: id,
+                set_index: index
+            )
         }
-        """
+        
+        let benchPress = SharedTypes.Exercise(
+            id: "exercise-1", superset_id: nil, workout_id: id, name: "Bench Press",
+            pinned_notes: [], notes: [], duration: nil, type: .barbell, weight_unit: .lb,
+            default_warm_up_time: nil, default_rest_time: 90,
+            sets: [
+                set("set-1-1", exerciseId: "exercise-1", index: 0, weight: 135, reps: 10, rpe: 7.5),
+                set("set-1-2", exerciseId: "exercise-1", index: 1, weight: 185, reps: 6, rpe: 8.5),
+                set("set-1-3", exerciseId: "exercise-1", index: 2, weight: 205, reps: 3, rpe: 9.5),
+            ],
+            body_part: nil
+        )
+        let pullUp = SharedTypes.Exercise(
+            id: "exercise-2", superset_id: nil, workout_id: id, name: "Pull Up",
+            pinned_notes: [], notes: [], duration: nil, type: .bodyweight, weight_unit: nil,
+            default_warm_up_time: nil, default_rest_time: 60,
+            sets: [
+                set("set-2-1", exerciseId: "exercise-2", index: 0, weight: nil, reps: 12, rpe: 7.0),
+                set("set-2-2", exerciseId: "exercise-2", index: 1, weight: nil, reps: 10, rpe: 8.0),
+                set("set-2-3", exerciseId: "exercise-2", index: 2, weight: nil, reps: 8, rpe: 9.0),
+            ],
+            body_part: nil
+        )
+        
+        return SharedTypes.Workout(
+            schema_version: 2,
+            id: id,
+            name: name,
+            note: "Test note",
+            duration: 3600,
+            start_timestamp: SharedTypes.Timestamp(value: 1_766_656_800_000),  // 2025-12-25T10:00:00Z
+            end_timestamp: SharedTypes.Timestamp(value: 1_766_660_400_000),    // 2025-12-25T11:00:00Z
+            exercises: [benchPress, pullUp]
+        )
     }
 }
 
@@ -24,6 +24,14 @@ struct WorkoutView: View {
         )) {
             ImportWorkoutView(core: core)
         }
+        .sheet(isPresented: .init(
+            get: { core.view.workout_summary != nil },
+            set: { if !$0 { Task { await core.update(.dismissWorkoutSummary) } } }
+        )) {
+            if let summary = core.view.workout_summary {
+                WorkoutSummarySheet(core: core, summary: summary)
+            }
+        }
     }
     
     private var activeWorkoutView: some View {
@@ -210,3 +218,59 @@ struct StatView: View {
     }
 }
 
+/// Summary shown after finishing a workout, with share and copy actions.
+///
+/// The text is built by the core so sharing and copying send exactly what
+/// is shown here.
+struct WorkoutSummarySheet: View {
+    @Bindable var core: Core
+    let summary: WorkoutSummaryViewModel
+
+    var body: some View {
+        NavigationStack {
+            ScrollView {
+                VStack(alignment: .leading, spacing: 16) {
+                    if !summary.personal_records.isEmpty {
+                        VStack(alignment: .leading, spacing: 6) {
+                            Label("New personal records", systemImage: "trophy.fill")
+                                .font(.headline)
+                                .foregroundColor(.yellow)
+                            ForEach(summary.personal_records, id: \.self) { record in
+                                Text(record)
+                                    .font(.subheadline)
+                            }
+                        }
+                    }
+
+                    Text(summary.text)
+                        .font(.system(.body, design: .monospaced))
+                        .textSelection(.enabled)
+                }
+                .frame(maxWidth: .infinity, alignment: .leading)
+                .padding()
+            }
+            .navigationTitle("Workout Complete")
+            .navigationBarTitleDisplayMode(.inline)
+            .toolbar {
+                ToolbarItem(placement: .cancellationAction) {
+                    Button("Done") {
+                        Task { await core.update(.dismissWorkoutSummary) }
+                    }
+                }
+                ToolbarItemGroup(placement: .bottomBar) {
+                    Button {
+                        Task { await core.update(.copyWorkoutSummary) }
+                    } label: {
+                        Label("Copy", systemImage: "doc.on.doc")
+                    }
+                    Spacer()
+                    Button {
+                        Task { await core.update(.shareWorkoutSummary) }
+                    } label: {
+                        Label("Share", systemImage: "square.and.arrow.up")
+                    }
+                }
+            }
+        }
+    }
+}
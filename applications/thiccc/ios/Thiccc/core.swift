@@ -22,6 +22,8 @@ final class Core {
     private var databaseCapability: DatabaseCapability?
     private var storageCapability: StorageCapability?
     private var timerCapability: TimerCapability?
+    private var shareCapability: ShareCapability?
+    private var httpCapability: HttpCapability?
     
     init() {
         // Get initial view from Rust core via FFI
@@ -42,12 +44,30 @@ final class Core {
         
         self.storageCapability = StorageCapability(core: self)
         self.timerCapability = TimerCapability(core: self)
+        self.shareCapability = ShareCapability(core: self)
+        self.httpCapability = HttpCapability(core: self)
         
-        // Send Initialize event to load any saved workout
+        // Send Initialize event to load any saved workout, then start syncing
         Task {
             await update(.initialize)
+            await updateApiSession(token: Core.sessionToken)
         }
     }
+
+    /// Token of the signed-in user, restored on launch (nil when signed out).
+    static var sessionToken: String?
+
+    /// Tell the core where the API is and who is signed in.
+    ///
+    /// Call this after signing in or out and whenever the session token is
+    /// refreshed; the core syncs when a user signs in. The API URL is
+    /// `ThicccAPIBaseURL` in Info.plist.
+    func updateApiSession(token: String?) async {
+        Core.sessionToken = token
+        let baseURL = (Bundle.main.object(forInfoDictionaryKey: "ThicccAPIBaseURL") as? String)
+            .flatMap { $0.isEmpty ? nil : $0 }
+        await update(.apiSessionChanged(base_url: baseURL, token: token))
+    }
     
     /// Send an event to the Rust core and process any resulting effects.
     ///
@@ -122,6 +142,16 @@ final class Core {
             if let timerCapability = timerCapability {
                 await timerCapability.handle(operation, requestId: requestId)
             }
+            
+        case .share(let operation):
+            if let shareCapability = shareCapability {
+                await shareCapability.handle(operation, requestId: requestId)
+            }
+            
+        case .http(let operation):
+            if let httpCapability = httpCapability {
+                await httpCapability.handle(operation, requestId: requestId)
+            }
         }
     }
     
@@ -161,6 +191,30 @@ final class Core {
         refreshView()
     }
     
+    /// Send a share response back to the Rust core.
+    func sendShareResponse(requestId: UInt32, result: SharedTypes.ShareResult) async {
+        let bytes = try! result.bincodeSerialize()
+        let data = Data(bytes)
+        
+        let effectsData = Thiccc.handleResponse(requestId, data)
+        let effectsBytes = Array(effectsData)
+        
+        await processEffects(effectsBytes)
+        refreshView()
+    }
+    
+    /// Send an HTTP response back to the Rust core.
+    func sendHttpResponse(requestId: UInt32, result: SharedTypes.HttpResult) async {
+        let bytes = try! result.bincodeSerialize()
+        let data = Data(bytes)
+        
+        let effectsData = Thiccc.handleResponse(requestId, data)
+        let effectsBytes = Array(effectsData)
+        
+        await processEffects(effectsBytes)
+        refreshView()
+    }
+    
     /// Refresh the view from the Rust core.
     private func refreshView() {
         let viewData = Thiccc.view()
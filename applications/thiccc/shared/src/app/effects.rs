@@ -5,7 +5,9 @@
 
 use crux_core::{macros::effect, render::RenderOperation};
 
-use crate::operations::{DatabaseOperation, StorageOperation, TimerOperation};
+use crate::operations::{
+    DatabaseOperation, HttpOperation, ShareOperation, StorageOperation, TimerOperation,
+};
 
 // =============================================================================
 // MARK: - Effects
@@ -30,5 +32,9 @@ pub enum Effect {
     Storage(StorageOperation),
     /// Timer operations (workout duration tracking)
     Timer(TimerOperation),
+    /// Share sheet (exported files)
+    Share(ShareOperation),
+    /// HTTP requests to the API server
+    Http(HttpOperation),
 }
 
@@ -0,0 +1,167 @@
+//! Error types for the Thiccc application.
+//!
+//! Errors raised while handling events are queued in the model as
+//! `ErrorRecord`s rather than overwriting a single message, so an error from
+//! a capability response can't hide one the user hasn't seen yet. Each record
+//! carries a severity (so the UI can show recoverable and fatal errors
+//! differently) and the name of the event that raised it.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// An error raised while handling an event, categorized by where it came from.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: There is no meaningful "default error". Every error must state
+/// its category and message explicitly.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum AppError {
+    /// User input or workout data failed validation
+    Validation { message: String },
+    /// File storage (in-progress workout) failed
+    Storage { message: String },
+    /// The workout database failed or returned unusable data
+    Database { message: String },
+    /// Importing a workout or template failed
+    Import { message: String },
+    /// Exporting or sharing workout history failed
+    Export { message: String },
+    /// The workout timer failed
+    Timer { message: String },
+    /// Syncing with the server failed (changes stay queued for the next sync)
+    Sync { message: String },
+}
+
+impl AppError {
+    /// Creates a validation error.
+    pub fn validation(message: impl Into<String>) -> Self {
+        AppError::Validation {
+            message: message.into(),
+        }
+    }
+
+    /// Creates a storage error.
+    pub fn storage(message: impl Into<String>) -> Self {
+        AppError::Storage {
+            message: message.into(),
+        }
+    }
+
+    /// Creates a database error.
+    pub fn database(message: impl Into<String>) -> Self {
+        AppError::Database {
+            message: message.into(),
+        }
+    }
+
+    /// Creates an import error.
+    pub fn import(message: impl Into<String>) -> Self {
+        AppError::Import {
+            message: message.into(),
+        }
+    }
+
+    /// Creates an export error.
+    pub fn export(message: impl Into<String>) -> Self {
+        AppError::Export {
+            message: message.into(),
+        }
+    }
+
+    /// Creates a timer error.
+    pub fn timer(message: impl Into<String>) -> Self {
+        AppError::Timer {
+            message: message.into(),
+        }
+    }
+
+    /// Creates a sync error.
+    pub fn sync(message: impl Into<String>) -> Self {
+        AppError::Sync {
+            message: message.into(),
+        }
+    }
+
+    /// Returns the user-facing message.
+    pub fn message(&self) -> &str {
+        match self {
+            AppError::Validation { message }
+            | AppError::Storage { message }
+            | AppError::Database { message }
+            | AppError::Import { message }
+            | AppError::Export { message }
+            | AppError::Timer { message }
+            | AppError::Sync { message } => message,
+        }
+    }
+
+    /// Returns a short title for the error's category (e.g. "Database Error").
+    pub fn title(&self) -> &'static str {
+        match self {
+            AppError::Validation { .. } => "Invalid Input",
+            AppError::Storage { .. } => "Storage Error",
+            AppError::Database { .. } => "Database Error",
+            AppError::Import { .. } => "Import Failed",
+            AppError::Export { .. } => "Export Failed",
+            AppError::Timer { .. } => "Timer Error",
+            AppError::Sync { .. } => "Sync Failed",
+        }
+    }
+
+    /// Returns how serious this error is.
+    ///
+    /// Storage and database failures mean workout data may not have been
+    /// saved or loaded, so they're fatal and stay until dismissed. Everything
+    /// else only affects the action the user just tried.
+    pub fn severity(&self) -> ErrorSeverity {
+        match self {
+            AppError::Storage { .. } | AppError::Database { .. } => ErrorSeverity::Fatal,
+            AppError::Validation { .. }
+            | AppError::Import { .. }
+            | AppError::Export { .. }
+            | AppError::Timer { .. }
+            | AppError::Sync { .. } => ErrorSeverity::Recoverable,
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+/// How serious an error is, which decides how the UI presents it.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: Severity is derived from the error category and should never
+/// silently fall back to a default.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorSeverity {
+    /// Affects only the last action; shown inline and cleared by the next
+    /// successful action
+    Recoverable,
+    /// Data may be lost or unavailable; shown as a blocking alert until the
+    /// user dismisses it
+    Fatal,
+}
+
+/// A queued error with its id, severity and source event.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: Records are only created by `Model::report_error`, which
+/// assigns a unique id.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorRecord {
+    /// Unique id, used by `Event::DismissError`
+    pub id: u32,
+    /// The error itself
+    pub error: AppError,
+    /// Severity at the time the error was raised
+    pub severity: ErrorSeverity,
+    /// Name of the event whose handling raised the error (e.g. "ImportWorkout")
+    pub source_event: &'static str,
+}
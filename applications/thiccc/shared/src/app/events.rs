@@ -5,8 +5,13 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::errors::AppError;
+use crate::bulk_import::ImportSummary;
+use crate::csv_import::CsvImportOptions;
+use crate::export::ExportFormat;
 use crate::models::*;
-use crate::operations::TimerOutput;
+use crate::operations::{HistoryFilter, HistoryQuery, HttpResult, ShareResult, TimerOutput};
+use crate::sync::SyncState;
 
 // =============================================================================
 // MARK: - Events
@@ -34,6 +39,18 @@ pub enum Event {
     /// Update workout notes
     UpdateWorkoutNotes { notes: String },
 
+    /// Share the summary of the just-finished workout
+    ShareWorkoutSummary,
+
+    /// Copy the summary of the just-finished workout to the clipboard
+    CopyWorkoutSummary,
+
+    /// Dismiss the summary shown after finishing a workout
+    DismissWorkoutSummary,
+
+    /// Set the unit used for totals such as workout volume
+    SetPreferredWeightUnit { unit: WeightUnit },
+
     // ===== Exercise Management =====
     /// Add an exercise from the library to the current workout
     ///
@@ -100,22 +117,83 @@ pub enum Event {
     DismissRestTimer,
 
     // ===== History & Navigation =====
-    /// Load workout history from database
+    /// Load the first page of workout history from database
     LoadHistory,
 
+    /// Load the next page of workout history (no-op if everything is loaded)
+    LoadMoreHistory,
+
+    /// Filter the history list and reload it from the first page
+    SetHistoryFilter { filter: HistoryFilter },
+
+    /// Remove all history filters and reload from the first page
+    ClearHistoryFilter,
+
     /// View a specific workout from history
     ViewHistoryItem { workout_id: String },
 
+    /// View every past session of an exercise from the current workout
+    ViewExerciseHistory { exercise_id: String },
+
+    /// Clear the list of history workouts that could not be read
+    DismissCorruptWorkouts,
+
     /// Navigate back
     NavigateBack,
 
     /// Change selected tab
     ChangeTab { tab: Tab },
 
+    // ===== History Editing =====
+    /// Ask to delete a workout from history (shows a confirmation)
+    DeleteHistoryWorkout { workout_id: String },
+
+    /// Confirm the pending history workout deletion
+    ConfirmDeleteHistoryWorkout,
+
+    /// Cancel the pending history workout deletion
+    CancelDeleteHistoryWorkout,
+
+    /// Reopen a workout from history for editing
+    ///
+    /// The workout must be open in the history detail view, which loads it
+    /// in full (see `ViewHistoryItem`). While editing, the exercise and set
+    /// events (AddSet, UpdateSetActual, etc.) apply to the workout being
+    /// edited instead of the active one.
+    EditHistoryWorkout { workout_id: String },
+
+    /// Save edits to the history workout back to the database
+    SaveHistoryWorkoutEdits,
+
+    /// Discard edits to the history workout
+    CancelHistoryWorkoutEdits,
+
     // ===== Import/Export =====
     /// Import workout from JSON string
     ImportWorkout { json_data: String },
 
+    /// Parse a Strong or Hevy CSV export and show a preview (nothing is saved)
+    PreviewCsvImport {
+        csv_data: String,
+        options: CsvImportOptions,
+    },
+
+    /// Save the previewed CSV workouts to history
+    ConfirmCsvImport,
+
+    /// Discard the CSV import preview
+    CancelCsvImport,
+
+    /// Import strength workouts from a watch file (Garmin FIT or Apple
+    /// Health JSON export) into history
+    ImportWatchFile { data: Vec<u8> },
+
+    /// Dismiss the summary shown after a bulk import
+    DismissImportSummary,
+
+    /// Export the full workout history in the given format and share it
+    ExportHistory { format: ExportFormat },
+
     /// Show import view
     ShowImportView,
 
@@ -149,18 +227,134 @@ pub enum Event {
     /// Initialize the app (load current workout from storage)
     Initialize,
 
+    // ===== Sync =====
+    /// The API server or the signed-in user changed
+    ///
+    /// The shell sends this on launch and whenever the session token is
+    /// refreshed or the user signs in or out. The token is added to every
+    /// request to the server.
+    ApiSessionChanged {
+        base_url: Option<String>,
+        token: Option<String>,
+    },
+
+    /// Exchange changes with the server (no-op while a sync is running, or
+    /// when nobody is signed in)
+    ///
+    /// The shell sends this on launch, when the app comes to the foreground
+    /// and when connectivity returns.
+    SyncNow,
+
     // ===== Capability Responses =====
     /// Database operation completed
     DatabaseResponse { result: DatabaseResult },
 
+    /// Full history loaded for an export
+    ExportHistoryLoaded {
+        format: ExportFormat,
+        result: DatabaseResult,
+    },
+
+    /// Full history loaded to record it for the first sync
+    SyncHistoryLoaded { result: DatabaseResult },
+
+    /// Sync exchange response (after any retries)
+    SyncResponse { result: HttpResult },
+
+    /// Share sheet response
+    ShareResponse { result: ShareResult },
+
     /// File storage operation completed
     StorageResponse { result: StorageResult },
 
     /// Timer operation response
     TimerResponse { output: TimerOutput },
 
-    /// Error occurred
-    Error { message: String },
+    /// Error reported by the shell
+    Error { error: AppError },
+
+    // ===== Errors =====
+    /// Dismiss one queued error
+    DismissError { error_id: u32 },
+
+    /// Dismiss every queued error
+    DismissAllErrors,
+}
+
+impl Event {
+    /// Returns the variant name, used to attribute errors to their source.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Event::StartWorkout => "StartWorkout",
+            Event::FinishWorkout => "FinishWorkout",
+            Event::DiscardWorkout => "DiscardWorkout",
+            Event::UpdateWorkoutName { .. } => "UpdateWorkoutName",
+            Event::UpdateWorkoutNotes { .. } => "UpdateWorkoutNotes",
+            Event::ShareWorkoutSummary => "ShareWorkoutSummary",
+            Event::CopyWorkoutSummary => "CopyWorkoutSummary",
+            Event::DismissWorkoutSummary => "DismissWorkoutSummary",
+            Event::SetPreferredWeightUnit { .. } => "SetPreferredWeightUnit",
+            Event::AddExercise { .. } => "AddExercise",
+            Event::DeleteExercise { .. } => "DeleteExercise",
+            Event::MoveExercise { .. } => "MoveExercise",
+            Event::ShowAddExerciseView => "ShowAddExerciseView",
+            Event::DismissAddExerciseView => "DismissAddExerciseView",
+            Event::AddSet { .. } => "AddSet",
+            Event::DeleteSet { .. } => "DeleteSet",
+            Event::UpdateSetActual { .. } => "UpdateSetActual",
+            Event::ToggleSetCompleted { .. } => "ToggleSetCompleted",
+            Event::TimerTick => "TimerTick",
+            Event::StartTimer => "StartTimer",
+            Event::StopTimer => "StopTimer",
+            Event::ToggleTimer => "ToggleTimer",
+            Event::ShowStopwatch => "ShowStopwatch",
+            Event::DismissStopwatch => "DismissStopwatch",
+            Event::ShowRestTimer { .. } => "ShowRestTimer",
+            Event::DismissRestTimer => "DismissRestTimer",
+            Event::LoadHistory => "LoadHistory",
+            Event::LoadMoreHistory => "LoadMoreHistory",
+            Event::SetHistoryFilter { .. } => "SetHistoryFilter",
+            Event::ClearHistoryFilter => "ClearHistoryFilter",
+            Event::ViewHistoryItem { .. } => "ViewHistoryItem",
+            Event::ViewExerciseHistory { .. } => "ViewExerciseHistory",
+            Event::DismissCorruptWorkouts => "DismissCorruptWorkouts",
+            Event::NavigateBack => "NavigateBack",
+            Event::ChangeTab { .. } => "ChangeTab",
+            Event::DeleteHistoryWorkout { .. } => "DeleteHistoryWorkout",
+            Event::ConfirmDeleteHistoryWorkout => "ConfirmDeleteHistoryWorkout",
+            Event::CancelDeleteHistoryWorkout => "CancelDeleteHistoryWorkout",
+            Event::EditHistoryWorkout { .. } => "EditHistoryWorkout",
+            Event::SaveHistoryWorkoutEdits => "SaveHistoryWorkoutEdits",
+            Event::CancelHistoryWorkoutEdits => "CancelHistoryWorkoutEdits",
+            Event::ImportWorkout { .. } => "ImportWorkout",
+            Event::PreviewCsvImport { .. } => "PreviewCsvImport",
+            Event::ConfirmCsvImport => "ConfirmCsvImport",
+            Event::CancelCsvImport => "CancelCsvImport",
+            Event::ImportWatchFile { .. } => "ImportWatchFile",
+            Event::DismissImportSummary => "DismissImportSummary",
+            Event::ExportHistory { .. } => "ExportHistory",
+            Event::ShowImportView => "ShowImportView",
+            Event::DismissImportView => "DismissImportView",
+            Event::LoadWorkoutTemplate => "LoadWorkoutTemplate",
+            Event::CalculatePlates { .. } => "CalculatePlates",
+            Event::ClearPlateCalculation => "ClearPlateCalculation",
+            Event::ShowPlateCalculator => "ShowPlateCalculator",
+            Event::DismissPlateCalculator => "DismissPlateCalculator",
+            Event::Initialize => "Initialize",
+            Event::ApiSessionChanged { .. } => "ApiSessionChanged",
+            Event::SyncNow => "SyncNow",
+            Event::SyncHistoryLoaded { .. } => "SyncHistoryLoaded",
+            Event::DatabaseResponse { .. } => "DatabaseResponse",
+            Event::ExportHistoryLoaded { .. } => "ExportHistoryLoaded",
+            Event::SyncResponse { .. } => "SyncResponse",
+            Event::ShareResponse { .. } => "ShareResponse",
+            Event::StorageResponse { .. } => "StorageResponse",
+            Event::TimerResponse { .. } => "TimerResponse",
+            Event::Error { .. } => "Error",
+            Event::DismissError { .. } => "DismissError",
+            Event::DismissAllErrors => "DismissAllErrors",
+        }
+    }
 }
 
 // =============================================================================
@@ -197,10 +391,6 @@ pub enum Tab {
 /// Reasoning: While database results should normally be constructed explicitly,
 /// Default is needed for TypeGen to successfully trace this type for Swift binding
 /// generation. The default (WorkoutSaved) is never actually used at runtime.
-///
-/// **Note**: Uses JSON strings instead of Workout objects to avoid TypeGen issues
-/// with complex nested types (similar to StorageResult). The Rust core handles
-/// serialization/deserialization of workout data.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
 pub enum DatabaseResult {
     /// Workout was successfully saved to the database
@@ -208,14 +398,60 @@ pub enum DatabaseResult {
     WorkoutSaved,
     /// Workout was successfully deleted from the database
     WorkoutDeleted,
-    /// Workout history was loaded from the database (JSON strings, newest first)
-    HistoryLoaded { workouts_json: Vec<String> },
-    /// A specific workout was loaded from the database (JSON string, None if not found)
-    WorkoutLoaded { workout_json: Option<String> },
+    /// Edited workout was successfully written to the database
+    WorkoutUpdated,
+    /// Workout history was loaded from the database (newest first)
+    ///
+    /// `failures` lists stored workouts the shell could not read.
+    HistoryLoaded {
+        workouts: Vec<Workout>,
+        failures: Vec<WorkoutLoadFailure>,
+    },
+    /// One page of workout history was loaded (newest first)
+    ///
+    /// `query` echoes the request so stale pages can be discarded.
+    HistoryPageLoaded {
+        query: HistoryQuery,
+        workouts: Vec<Workout>,
+        failures: Vec<WorkoutLoadFailure>,
+        has_more: bool,
+    },
+    /// Past sessions of exercises were loaded (newest first)
+    ///
+    /// `exercise_names` echoes the request so stale results can be discarded.
+    ExerciseHistoryLoaded {
+        exercise_names: Vec<String>,
+        workouts: Vec<Workout>,
+        failures: Vec<WorkoutLoadFailure>,
+    },
+    /// Names of every exercise with at least one stored session
+    ExerciseNamesLoaded { names: Vec<String> },
+    /// A specific workout was loaded from the database (None if not found)
+    WorkoutLoaded { workout: Option<Workout> },
+    /// A specific workout is stored but could not be read
+    WorkoutLoadFailed { failure: WorkoutLoadFailure },
+    /// A bulk import finished; `summary` covers the workouts that were sent
+    WorkoutsImported { summary: ImportSummary },
     /// An error occurred during database operation
     Error { message: String },
 }
 
+/// A stored workout that could not be read while loading history.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: A failure always describes a specific stored workout and the
+/// reason it was rejected. No meaningful default exists.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct WorkoutLoadFailure {
+    /// ID of the workout as stored (may itself be malformed)
+    pub workout_id: String,
+    /// Why the workout could not be read
+    pub error: String,
+    /// The stored data as it was received, kept for export and bug reports
+    pub raw_payload: String,
+}
+
 /// Result of a file storage operation.
 ///
 /// **Default Trait: IMPLEMENTED (for TypeGen compatibility)**
@@ -223,18 +459,19 @@ pub enum DatabaseResult {
 /// Reasoning: While storage results should normally be constructed explicitly,
 /// Default is needed for TypeGen to successfully trace this type for Swift binding
 /// generation. The default (CurrentWorkoutSaved) is never actually used at runtime.
-///
-/// **Note**: CurrentWorkoutLoaded uses a JSON string instead of Workout to avoid
-/// TypeGen issues with complex nested types. The Rust core deserializes the JSON.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
 pub enum StorageResult {
     /// Current workout was saved to storage
     #[default]
     CurrentWorkoutSaved,
-    /// Current workout was loaded from storage (JSON string, None if no file)
-    CurrentWorkoutLoaded { workout_json: Option<String> },
+    /// Current workout was loaded from storage (None if no file)
+    CurrentWorkoutLoaded { workout: Option<Workout> },
     /// Current workout was deleted from storage
     CurrentWorkoutDeleted,
+    /// Sync state was saved to storage
+    SyncStateSaved,
+    /// Sync state was loaded from storage (None if never saved)
+    SyncStateLoaded { state: Option<SyncState> },
     /// An error occurred during storage operation
     Error { message: String },
 }
@@ -254,5 +491,9 @@ pub enum NavigationDestination {
     WorkoutDetail { workout_id: String },
     /// Navigate to a history detail view (for viewing past workout)
     HistoryDetail { workout_id: String },
+    /// Navigate to the history of a single exercise across past workouts
+    ///
+    /// Keyed by name because exercise IDs are unique per workout.
+    ExerciseHistory { exercise_name: String },
 }
 
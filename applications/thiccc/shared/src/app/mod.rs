@@ -7,12 +7,14 @@
 //! - Business logic: Event handling (update) and state transformation (view)
 
 // Module declarations
+pub mod errors;
 pub mod events;
 pub mod model;
 pub mod view_models;
 pub mod effects;
 
 // Re-export all public types for convenience
+pub use errors::*;
 pub use events::*;
 pub use model::*;
 pub use view_models::*;
@@ -21,9 +23,22 @@ pub use effects::*;
 use crux_core::{render::render, App, Command};
 use chrono::Utc;
 
+use crate::bulk_import::{self, ImportSummary};
+use crate::csv_import::{self, CsvImportPreview};
+use crate::export;
+use crate::http::{self, ApiSession, HttpError, RetryPolicy};
 use crate::id::Id;
 use crate::models::*;
-use crate::operations::{DatabaseOperation, StorageOperation, TimerOperation, TimerOutput};
+use crate::schema;
+use crate::summary::{self, WorkoutSummary};
+use crate::sync::{self, EntityKind, SyncResponse};
+use crate::timestamp::Timestamp;
+use crate::validation;
+use crate::watch_import;
+use crate::operations::{
+    DatabaseOperation, HistoryFilter, HttpMethod, ShareOperation, ShareResult, StorageOperation,
+    TimerOperation, TimerOutput,
+};
 
 // =============================================================================
 // MARK: - Crux App Implementation
@@ -45,7 +60,7 @@ impl Thiccc {
             let exercise_vms = workout
                 .exercises
                 .iter()
-                .map(|exercise| self.build_exercise_view(exercise))
+                .map(|exercise| self.build_exercise_view(model, exercise))
                 .collect();
 
             (workout.name.clone(), exercise_vms)
@@ -69,7 +84,7 @@ impl Thiccc {
     }
 
     /// Builds an ExerciseViewModel from an Exercise.
-    fn build_exercise_view(&self, exercise: &Exercise) -> ExerciseViewModel {
+    fn build_exercise_view(&self, model: &Model, exercise: &Exercise) -> ExerciseViewModel {
         let sets = exercise
             .sets
             .iter()
@@ -81,6 +96,7 @@ impl Thiccc {
             id: exercise.id.as_str().to_string(), // Convert Id to String for ViewModel
             name: exercise.name.clone(),
             sets,
+            has_history: model.has_exercise_history(&exercise.name),
         }
     }
 
@@ -118,15 +134,193 @@ impl Thiccc {
             .map(|workout| self.build_history_item(workout))
             .collect();
 
+        let delete_confirmation = model.pending_delete_workout_id.as_ref().map(|id| {
+            DeleteConfirmationViewModel {
+                workout_id: id.as_str().to_string(),
+                workout_name: model
+                    .workout_history
+                    .iter()
+                    .find(|w| w.id == *id)
+                    .map(|w| w.name.clone())
+                    .unwrap_or_default(),
+            }
+        });
+
         HistoryViewModel {
             workouts,
             is_loading: model.is_loading,
+            delete_confirmation,
+            load_problems: self.build_load_problems_view(model),
+            has_more: model.history_has_more,
+            active_filter: model.history_filter.clone(),
+            is_filtered: !model.history_filter.is_empty(),
+        }
+    }
+
+    /// Builds the "N workouts could not be read" notice, if any are quarantined.
+    fn build_load_problems_view(&self, model: &Model) -> Option<LoadProblemsViewModel> {
+        let count = model.quarantined_workouts.len();
+        if count == 0 {
+            return None;
+        }
+
+        let message = if count == 1 {
+            "1 workout could not be read".to_string()
+        } else {
+            format!("{} workouts could not be read", count)
+        };
+
+        Some(LoadProblemsViewModel {
+            message,
+            workouts: model
+                .quarantined_workouts
+                .iter()
+                .map(|failure| CorruptWorkoutViewModel {
+                    workout_id: failure.workout_id.clone(),
+                    error: failure.error.clone(),
+                })
+                .collect(),
+            export_payload: serde_json::to_string_pretty(&model.quarantined_workouts)
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Builds the error queue, separating recoverable and fatal errors.
+    fn build_error_queue_view(&self, model: &Model) -> ErrorQueueViewModel {
+        let to_view = |record: &ErrorRecord| ErrorViewModel {
+            id: record.id,
+            title: record.error.title().to_string(),
+            message: record.error.message().to_string(),
+            source_event: record.source_event.to_string(),
+        };
+
+        let (fatal, recoverable): (Vec<_>, Vec<_>) = model
+            .errors
+            .iter()
+            .partition(|record| record.severity == ErrorSeverity::Fatal);
+
+        ErrorQueueViewModel {
+            recoverable: recoverable.into_iter().map(to_view).collect(),
+            fatal: fatal.first().map(|record| to_view(record)),
+            pending_fatal_count: fatal.len().saturating_sub(1),
+        }
+    }
+
+    /// Builds the HistoryEditViewModel for the workout being edited.
+    fn build_history_edit_view(&self, model: &Model) -> Option<HistoryEditViewModel> {
+        let workout = model.editing_history_workout.as_ref()?;
+
+        Some(HistoryEditViewModel {
+            id: workout.id.as_str().to_string(), // Convert Id to String for ViewModel
+            workout_name: workout.name.clone(),
+            notes: workout.note.clone(),
+            exercises: workout
+                .exercises
+                .iter()
+                .map(|exercise| self.build_exercise_view(model, exercise))
+                .collect(),
+            total_volume: workout.total_volume() as i32,
+            total_sets: workout.total_sets(),
+        })
+    }
+
+    /// Builds the CsvImportPreviewViewModel for a pending CSV import.
+    fn build_csv_import_preview_view(
+        &self,
+        preview: &CsvImportPreview,
+    ) -> CsvImportPreviewViewModel {
+        // Workouts are sorted newest first
+        let format_date = |workout: &Workout| {
+            workout.start_timestamp.to_datetime().format("%b %d, %Y").to_string()
+        };
+        let date_range = match (preview.workouts.last(), preview.workouts.first()) {
+            (Some(oldest), Some(newest)) if format_date(oldest) != format_date(newest) => {
+                format!("{} – {}", format_date(oldest), format_date(newest))
+            }
+            (Some(only), _) => format_date(only),
+            _ => String::new(),
+        };
+
+        CsvImportPreviewViewModel {
+            source: preview.source.display_name().to_string(),
+            workout_count: preview.workouts.len(),
+            exercise_count: preview.exercise_count(),
+            set_count: preview.set_count(),
+            date_range,
+            unmatched_exercises: preview.unmatched_exercises.clone(),
+            skipped_rows: preview
+                .skipped_rows
+                .iter()
+                .map(|row| format!("Line {}: {}", row.line, row.message))
+                .collect(),
+            workouts: preview
+                .workouts
+                .iter()
+                .map(|workout| self.build_history_item(workout))
+                .collect(),
+        }
+    }
+
+    /// Builds the WorkoutSummaryViewModel shown after finishing a workout.
+    fn build_workout_summary_view(&self, summary: &WorkoutSummary) -> WorkoutSummaryViewModel {
+        WorkoutSummaryViewModel {
+            workout_name: summary.workout_name.clone(),
+            text: summary.text.clone(),
+            personal_records: summary
+                .personal_records
+                .iter()
+                .map(|record| {
+                    format!(
+                        "{} · e1RM {:.1} {}",
+                        record.exercise_name,
+                        record.estimated_one_rep_max,
+                        summary.unit.as_str()
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Builds the ImportSummaryViewModel shown after a bulk import.
+    fn build_import_summary_view(&self, summary: &ImportSummary) -> ImportSummaryViewModel {
+        let plural = |count: usize, noun: &str| {
+            if count == 1 {
+                format!("1 {}", noun)
+            } else {
+                format!("{} {}s", count, noun)
+            }
+        };
+
+        let mut message = format!("Imported {}", plural(summary.inserted.len(), "workout"));
+        if !summary.skipped.is_empty() {
+            message.push_str(&format!(
+                ", skipped {}",
+                plural(summary.skipped.len(), "duplicate")
+            ));
+        }
+        if !summary.conflicting.is_empty() {
+            message.push_str(&format!(
+                ", {} not imported",
+                plural(summary.conflicting.len(), "conflict")
+            ));
+        }
+
+        ImportSummaryViewModel {
+            message,
+            inserted_count: summary.inserted.len(),
+            skipped_count: summary.skipped.len(),
+            conflicting_count: summary.conflicting.len(),
+            conflicts: summary
+                .conflicting
+                .iter()
+                .map(|entry| entry.name.clone())
+                .collect(),
         }
     }
 
     /// Builds a HistoryItemViewModel from a Workout.
     fn build_history_item(&self, workout: &Workout) -> HistoryItemViewModel {
-        let date = workout.start_timestamp.format("%b %d, %Y").to_string();
+        let date = workout.start_timestamp.to_datetime().format("%b %d, %Y").to_string();
 
         HistoryItemViewModel {
             id: workout.id.as_str().to_string(), // Convert Id to String for ViewModel
@@ -138,6 +332,129 @@ impl Thiccc {
         }
     }
 
+    /// Builds the ExerciseHistoryViewModel when an exercise history is on top
+    /// of the navigation stack.
+    fn build_exercise_history_view(&self, model: &Model) -> Option<ExerciseHistoryViewModel> {
+        let Some(NavigationDestination::ExerciseHistory { exercise_name }) =
+            model.navigation_stack.last()
+        else {
+            return None;
+        };
+
+        let history = model.exercise_sessions(exercise_name);
+
+        let sessions: Vec<ExerciseSessionViewModel> = history
+            .iter()
+            .map(|(workout, exercise)| self.build_exercise_session(workout, exercise))
+            .collect();
+
+        let best_estimated_one_rep_max = sessions
+            .iter()
+            .filter_map(|session| session.estimated_one_rep_max)
+            .fold(None, |max: Option<f64>, e1rm| Some(max.map_or(e1rm, |m| m.max(e1rm))));
+
+        // Chart series run oldest first; history is stored newest first
+        let chart_point = |workout: &Workout, value: f64| ChartPointViewModel {
+            timestamp: workout.start_timestamp.as_seconds(),
+            label: workout.start_timestamp.to_datetime().format("%b %d").to_string(),
+            value,
+        };
+        let estimated_one_rep_max_series = history
+            .iter()
+            .rev()
+            .filter_map(|(workout, exercise)| {
+                let e1rm = exercise.best_set()?.actual.estimated_one_rep_max()?;
+                Some(chart_point(workout, e1rm))
+            })
+            .collect();
+        let volume_series = history
+            .iter()
+            .rev()
+            .map(|(workout, exercise)| chart_point(workout, exercise.total_volume()))
+            .collect();
+        let max_weight_series = history
+            .iter()
+            .rev()
+            .filter_map(|(workout, exercise)| Some(chart_point(workout, exercise.max_weight()?)))
+            .collect();
+
+        Some(ExerciseHistoryViewModel {
+            exercise_name: exercise_name.clone(),
+            sessions,
+            best_estimated_one_rep_max,
+            estimated_one_rep_max_series,
+            volume_series,
+            max_weight_series,
+        })
+    }
+
+    /// Builds an ExerciseSessionViewModel from an Exercise in a past Workout.
+    fn build_exercise_session(
+        &self,
+        workout: &Workout,
+        exercise: &Exercise,
+    ) -> ExerciseSessionViewModel {
+        let best_set = exercise.best_set();
+
+        ExerciseSessionViewModel {
+            workout_id: workout.id.as_str().to_string(), // Convert Id to String for ViewModel
+            workout_name: workout.name.clone(),
+            date: workout.start_timestamp.to_datetime().format("%b %d, %Y").to_string(),
+            best_set: best_set.map(|set| set.format_set()),
+            estimated_one_rep_max: best_set.and_then(|set| set.actual.estimated_one_rep_max()),
+            total_volume: exercise.total_volume() as i32,
+            sets: self.build_set_details(exercise),
+        }
+    }
+
+    /// Builds the HistoryDetailViewModel for the selected past workout.
+    fn build_history_detail_view(&self, model: &Model) -> Option<HistoryDetailViewModel> {
+        let workout = model.selected_history_workout.as_ref()?;
+
+        let formatted_date = workout
+            .start_timestamp
+            .to_datetime()
+            .format("%b %d, %Y at %-I:%M %p")
+            .to_string();
+
+        let duration = workout
+            .duration
+            .map(|secs| format!("{:02}:{:02}", secs / 60, secs % 60));
+
+        let exercises = workout
+            .exercises
+            .iter()
+            .map(|exercise| ExerciseDetailViewModel {
+                name: exercise.name.clone(),
+                sets: self.build_set_details(exercise),
+            })
+            .collect();
+
+        Some(HistoryDetailViewModel {
+            id: workout.id.as_str().to_string(), // Convert Id to String for ViewModel
+            workout_name: workout.name.clone(),
+            formatted_date,
+            duration,
+            exercises,
+            notes: workout.note.clone(),
+            total_volume: workout.total_volume() as i32,
+            total_sets: workout.total_sets(),
+        })
+    }
+
+    /// Builds the read-only SetDetailViewModels for an Exercise.
+    fn build_set_details(&self, exercise: &Exercise) -> Vec<SetDetailViewModel> {
+        exercise
+            .sets
+            .iter()
+            .enumerate()
+            .map(|(idx, set)| SetDetailViewModel {
+                set_number: idx as i32 + 1,
+                display_text: set.format_set(),
+            })
+            .collect()
+    }
+
     /// Performs the plate calculation after all validations have passed.
     ///
     /// # Arguments
@@ -161,7 +478,7 @@ impl Thiccc {
         let weight_per_side = (actual_weight - bar_weight) / 2.0;
 
         if weight_per_side < 0.0 {
-            model.error_message = Some("Target weight is less than bar weight".to_string());
+            model.report_error(AppError::validation("Target weight is less than bar weight"));
             model.plate_calculation = None;
         } else {
             // Get standard plates (use pounds for now)
@@ -190,63 +507,247 @@ impl Thiccc {
         }
     }
 
-    /// Validates all IDs in a workout to ensure they are valid UUIDs.
+    /// Splits workouts loaded from the database into readable ones and failures.
     ///
-    /// The Id type uses #[serde(transparent)] which allows invalid strings
-    /// to bypass validation during deserialization. This function manually
-    /// validates all IDs to prevent data corruption from malformed imports.
+    /// Every workout is upgraded to the current schema version and checked
+    /// with `validate_workout`; rejects join the failures reported by the
+    /// shell.
+    fn check_loaded_workouts(
+        workouts: Vec<Workout>,
+        mut failures: Vec<WorkoutLoadFailure>,
+    ) -> (Vec<Workout>, Vec<WorkoutLoadFailure>) {
+        let mut accepted = Vec::with_capacity(workouts.len());
+        for workout in workouts {
+            match Self::check_loaded_workout(workout) {
+                Ok(upgraded) => accepted.push(upgraded),
+                Err(failure) => failures.push(failure),
+            }
+        }
+        (accepted, failures)
+    }
+
+    /// Upgrades one workout loaded from the database to the current schema
+    /// version and checks it with `validate_workout`.
+    fn check_loaded_workout(workout: Workout) -> Result<Workout, WorkoutLoadFailure> {
+        schema::upgrade_workout(workout.clone())
+            .and_then(|upgraded| match validation::validate_workout(&upgraded) {
+                Ok(()) => Ok(upgraded),
+                Err(errors) => Err(validation::describe_errors(&errors)),
+            })
+            .map_err(|error| WorkoutLoadFailure {
+                workout_id: workout.id.as_str().to_string(),
+                error,
+                raw_payload: serde_json::to_string_pretty(&workout).unwrap_or_default(),
+            })
+    }
+
+    /// Adds an unreadable workout to the quarantine, once.
+    fn quarantine_workout(model: &mut Model, failure: WorkoutLoadFailure) {
+        if !model
+            .quarantined_workouts
+            .iter()
+            .any(|q| q.workout_id == failure.workout_id)
+        {
+            model.quarantined_workouts.push(failure);
+        }
+    }
+
+    /// Leaves the history detail view for a workout that can't be shown,
+    /// reporting it and adding it to the quarantine.
+    fn reject_history_detail(model: &mut Model, failure: WorkoutLoadFailure) {
+        model.navigation_stack.pop();
+        model.selected_history_workout = None;
+        model.report_error_with_severity(
+            AppError::database(format!("Failed to load workout: {}", failure.error)),
+            ErrorSeverity::Recoverable,
+        );
+        Self::quarantine_workout(model, failure);
+    }
+
+    /// Filters workouts loaded from the database, quarantining unreadable ones.
     ///
-    /// # Returns
-    /// - `Ok(())` if all IDs are valid UUIDs
-    /// - `Err(String)` with a descriptive error message if any ID is invalid
-    fn validate_workout_ids(workout: &Workout) -> Result<(), String> {
-        // Validate workout ID
-        Id::from_string(workout.id.as_str().to_string())
-            .map_err(|e| format!("Invalid workout ID: {}", e))?;
-
-        // Validate all exercise IDs and their nested set IDs
-        for (exercise_idx, exercise) in workout.exercises.iter().enumerate() {
-            // Validate exercise ID
-            Id::from_string(exercise.id.as_str().to_string())
-                .map_err(|e| format!("Invalid exercise ID at index {}: {}", exercise_idx, e))?;
-
-            // Validate exercise's workout_id reference
-            Id::from_string(exercise.workout_id.as_str().to_string()).map_err(|e| {
-                format!(
-                    "Invalid workout_id in exercise at index {}: {}",
-                    exercise_idx, e
-                )
+    /// All failures from `check_loaded_workouts` are recorded in a load
+    /// report and added to the quarantine rather than silently dropped.
+    fn accept_loaded_workouts(
+        model: &mut Model,
+        workouts: Vec<Workout>,
+        failures: Vec<WorkoutLoadFailure>,
+    ) -> Vec<Workout> {
+        let (accepted, failures) = Self::check_loaded_workouts(workouts, failures);
+
+        for failure in &failures {
+            Self::quarantine_workout(model, failure.clone());
+        }
+        model.last_history_load = Some(HistoryLoadReport {
+            loaded: accepted.len(),
+            failures,
+        });
+        accepted
+    }
+
+    /// Returns true if import data is a batch of finished workouts (a JSON
+    /// array or a history export archive) rather than a single workout.
+    fn is_workout_batch(json_data: &str) -> bool {
+        json_data.trim_start().starts_with('[')
+            || serde_json::from_str(json_data)
+                .map(|value| export::is_archive(&value))
+                .unwrap_or(false)
+    }
+
+    /// Parses and validates a batch of finished workouts for import.
+    fn workout_batch_from_json(json_data: &str) -> Result<Vec<Workout>, String> {
+        let workouts = if json_data.trim_start().starts_with('[') {
+            schema::workouts_from_json(json_data)?
+        } else {
+            let value = serde_json::from_str(json_data).map_err(|e| e.to_string())?;
+            export::workouts_from_archive(value)?
+        };
+        for (index, workout) in workouts.iter().enumerate() {
+            validation::validate_workout(workout).map_err(|errors| {
+                format!("Workout {}: {}", index, validation::describe_errors(&errors))
             })?;
+        }
+        Ok(workouts)
+    }
 
-            // Validate all set IDs
-            for (set_idx, set) in exercise.sets.iter().enumerate() {
-                // Validate set ID
-                Id::from_string(set.id.as_str().to_string()).map_err(|e| {
-                    format!(
-                        "Invalid set ID at exercise {} set {}: {}",
-                        exercise_idx, set_idx, e
-                    )
-                })?;
+    /// Starts importing finished workouts into history.
+    ///
+    /// Duplicates within the batch and of loaded history are ruled out here;
+    /// the shell checks the rest against the whole database and the summaries
+    /// are merged when `WorkoutsImported` arrives.
+    fn start_bulk_import(model: &mut Model, workouts: Vec<Workout>) -> Command<Effect, Event> {
+        let plan = bulk_import::plan_bulk_import(&model.workout_history, workouts);
+        if plan.to_insert.is_empty() {
+            model.import_summary = Some(plan.summary);
+            return render();
+        }
 
-                // Validate set's exercise_id reference
-                Id::from_string(set.exercise_id.as_str().to_string()).map_err(|e| {
-                    format!(
-                        "Invalid exercise_id in set at exercise {} set {}: {}",
-                        exercise_idx, set_idx, e
-                    )
-                })?;
+        model.import_summary = None;
+        model.pending_import = Some(plan.summary);
+        model.pending_import_workouts = plan.to_insert.clone();
+        model.is_loading = true;
+        Command::all([
+            Command::request_from_shell(DatabaseOperation::ImportWorkouts(plan.to_insert))
+                .then_send(|result| Event::DatabaseResponse { result }),
+            render(),
+        ])
+    }
 
-                // Validate set's workout_id reference
-                Id::from_string(set.workout_id.as_str().to_string()).map_err(|e| {
-                    format!(
-                        "Invalid workout_id in set at exercise {} set {}: {}",
-                        exercise_idx, set_idx, e
-                    )
-                })?;
+    /// Reloads the names of exercises with stored sessions (for `has_history`).
+    fn load_exercise_names() -> Command<Effect, Event> {
+        Command::request_from_shell(DatabaseOperation::LoadExerciseNames)
+            .then_send(|result| Event::DatabaseResponse { result })
+    }
+
+    /// Runs history writes, then reloads the exercise names once after all
+    /// of them finished (a sync can write many workouts at a time).
+    fn write_history(
+        writes: impl IntoIterator<Item = DatabaseOperation>,
+    ) -> Command<Effect, Event> {
+        Command::all(writes.into_iter().map(|write| {
+            Command::request_from_shell(write).then_send(|result| Event::DatabaseResponse { result })
+        }))
+        .then(Self::load_exercise_names())
+    }
+
+    /// Persists the sync state after it changed.
+    fn save_sync_state(model: &Model) -> Command<Effect, Event> {
+        Command::request_from_shell(StorageOperation::SaveSyncState(model.sync.clone()))
+            .then_send(|result| Event::StorageResponse { result })
+    }
+
+    /// Sends the pending changes to the server.
+    ///
+    /// Syncing only repeats changes the server already merged, so failed
+    /// exchanges are retried with the default policy.
+    fn start_sync_exchange(model: &mut Model) -> Command<Effect, Event> {
+        let request = model
+            .api
+            .request(HttpMethod::Post, "api/sync")
+            .and_then(|request| request.json(&model.sync.request()));
+        let request = match request {
+            Ok(request) => request,
+            Err(e) => {
+                model.is_syncing = false;
+                model.report_error(AppError::sync(Self::sync_error_message(e)));
+                return render();
+            }
+        };
+        model.is_syncing = true;
+        model.sync_sent_counter = model.sync.counter;
+        Command::all([
+            http::send(request, RetryPolicy::default())
+                .then_send(|result| Event::SyncResponse { result }),
+            render(),
+        ])
+    }
+
+    /// The message shown when a sync fails.
+    fn sync_error_message(error: HttpError) -> String {
+        match error {
+            HttpError::Unauthorized => "Sign in to sync your workouts".to_string(),
+            error => error.to_string(),
+        }
+    }
+
+    /// Replaces every workout in the response with the server's state plus
+    /// any changes recorded while the request was in flight, then writes the
+    /// results to the database.
+    fn apply_sync_response(model: &mut Model, response: SyncResponse) -> Command<Effect, Event> {
+        model.sync.acknowledge(model.sync_sent_counter, &response);
+        model.last_synced_at = Some(Timestamp::now());
+
+        // Refused changes are dropped; the server's copy (if any) follows
+        for rejection in &response.rejected {
+            model.report_error(AppError::sync(format!(
+                "The server refused changes to workout {}: {}",
+                rejection.workout_id, rejection.error
+            )));
+        }
+
+        let mut writes = Vec::new();
+        for (workout_id, changes) in sync::group_by_workout(response.changes) {
+            let deleted = changes
+                .iter()
+                .any(|change| change.kind == EntityKind::Workout && change.is_delete());
+            let merged = sync::apply_changes(None, &changes).and_then(|workout| {
+                // Writes replace the stored workout, so only a whole copy from
+                // the server (or its delete) may be written
+                let whole = match &workout {
+                    Some(workout) => sync::is_complete(workout, &changes),
+                    None => deleted,
+                };
+                if !whole {
+                    return Err("the server sent only part of it".to_string());
+                }
+                sync::apply_changes(workout, &model.sync.pending_for(&workout_id))
+            });
+            match merged {
+                Ok(Some(workout)) => {
+                    model.upsert_history_workout(workout.clone());
+                    writes.push(DatabaseOperation::UpdateWorkout(workout));
+                }
+                Ok(None) => {
+                    if let Ok(id) = Id::from_string(workout_id.clone()) {
+                        model.remove_history_workout(&id);
+                    }
+                    writes.push(DatabaseOperation::DeleteWorkout(workout_id));
+                }
+                Err(e) => {
+                    model.report_error(AppError::sync(format!(
+                        "Could not apply changes to workout {}: {}",
+                        workout_id, e
+                    )));
+                }
             }
         }
 
-        Ok(())
+        let write_history = if writes.is_empty() {
+            Command::done()
+        } else {
+            Self::write_history(writes)
+        };
+        Command::all([write_history, Self::save_sync_state(model), render()])
     }
 }
 
@@ -267,6 +768,56 @@ impl App for Thiccc {
         model: &mut Self::Model,
         _caps: &(), // will be deprecated, so prefix with underscore for now
     ) -> Command<Effect, Event> {
+        // Errors raised while handling this event are attributed to it
+        let source_event = event.name();
+        let first_new_error = model.next_error_id;
+        let command = self.handle_event(event, model);
+        model.attribute_errors(first_new_error, source_event);
+        command
+    }
+
+    fn view(&self, model: &Self::Model) -> Self::ViewModel {
+        ViewModel {
+            selected_tab: model.selected_tab.clone(),
+            workout_view: self.build_workout_view(model),
+            history_view: self.build_history_view(model),
+            history_detail_view: self.build_history_detail_view(model),
+            history_edit_view: self.build_history_edit_view(model),
+            exercise_history_view: self.build_exercise_history_view(model),
+            csv_import_preview: model
+                .csv_import_preview
+                .as_ref()
+                .map(|preview| self.build_csv_import_preview_view(preview)),
+            import_summary: model
+                .import_summary
+                .as_ref()
+                .map(|summary| self.build_import_summary_view(summary)),
+            workout_summary: model
+                .workout_summary
+                .as_ref()
+                .map(|summary| self.build_workout_summary_view(summary)),
+            preferred_weight_unit: model.preferred_weight_unit.clone(),
+            errors: self.build_error_queue_view(model),
+            sync: SyncViewModel {
+                is_syncing: model.is_syncing,
+                pending_changes: model.sync.pending.len(),
+                last_synced_at: model.last_synced_at,
+            },
+            is_loading: model.is_loading,
+        }
+    }
+}
+
+// =============================================================================
+// MARK: - Event Handling
+// =============================================================================
+
+impl Thiccc {
+    /// Applies an event to the model, returning any effects to run.
+    ///
+    /// Errors are reported with `Model::report_error`; `update` attributes
+    /// them to the event afterwards.
+    fn handle_event(&self, event: Event, model: &mut Model) -> Command<Effect, Event> {
         match event {
             // =================================================================
             // App Lifecycle
@@ -276,8 +827,13 @@ impl App for Thiccc {
                 return Command::all([
                     Command::request_from_shell(StorageOperation::LoadCurrentWorkout)
                         .then_send(|result| Event::StorageResponse { result }),
-                    Command::request_from_shell(DatabaseOperation::LoadAllWorkouts)
-                        .then_send(|result| Event::DatabaseResponse { result }),
+                    Command::request_from_shell(StorageOperation::LoadSyncState)
+                        .then_send(|result| Event::StorageResponse { result }),
+                    Command::request_from_shell(DatabaseOperation::LoadWorkoutsPage(
+                        model.history_page_query(true),
+                    ))
+                    .then_send(|result| Event::DatabaseResponse { result }),
+                    Self::load_exercise_names(),
                 ]);
             }
 
@@ -287,25 +843,19 @@ impl App for Thiccc {
             Event::StartWorkout => {
                 if model.current_workout.is_some() {
                     const WIP_MSG: &str = "A workout is already in progress. Please finish or discard it first.";
-                    model.error_message = Some(WIP_MSG.to_string());
+                    model.report_error(AppError::validation(WIP_MSG));
                 } else {
-                    model.current_workout = Some(Workout::new());
+                    let workout = Workout::new();
+                    model.current_workout = Some(workout.clone());
                     model.workout_timer_seconds = 0;
                     model.timer_running = true;
-                    model.error_message = None; // Clear any stale errors on successful start
+                    model.clear_recoverable_errors(); // Clear any stale errors on successful start
 
                     // Start timer and save current workout to storage
-                    // Serialize workout to JSON for storage operation
-                    let workout_json = model.current_workout.as_ref()
-                        .and_then(|w| serde_json::to_string(w).ok())
-                        .unwrap_or_else(|| {
-                            eprintln!("ERROR: Failed to serialize workout for storage");
-                            "{}".to_string() // Return valid empty JSON as fallback
-                        });
                     return Command::all([
                         Command::request_from_shell(TimerOperation::Start)
                             .then_send(|output| Event::TimerResponse { output }),
-                        Command::request_from_shell(StorageOperation::SaveCurrentWorkout(workout_json))
+                        Command::request_from_shell(StorageOperation::SaveCurrentWorkout(workout))
                             .then_send(|result| Event::StorageResponse { result }),
                         render(),
                     ]);
@@ -313,22 +863,50 @@ impl App for Thiccc {
             }
 
             Event::FinishWorkout => {
-                if let Some(mut workout) = model.current_workout.take() {
+                if let Some(mut workout) = model.current_workout.clone() {
                     workout.finish(model.workout_timer_seconds);
-                    model.workout_history.insert(0, workout.clone());
+                    // Keep an invalid workout open rather than saving it
+                    if let Err(errors) = validation::validate_workout(&workout) {
+                        model.report_error(AppError::validation(format!(
+                            "Invalid workout data: {}",
+                            validation::describe_errors(&errors)
+                        )));
+                        return render();
+                    }
+                    model.current_workout = None;
+                    // Records are judged against every stored session of the
+                    // performed exercises, not just the loaded history page
+                    let record_names = summary::record_exercise_names(&workout);
+                    let load_sessions = if record_names.is_empty() {
+                        model.workout_summary = Some(summary::summarize_workout(
+                            &workout,
+                            &[],
+                            &model.preferred_weight_unit,
+                        ));
+                        model.pending_summary_workout = None;
+                        Command::done()
+                    } else {
+                        model.workout_summary = None;
+                        model.pending_summary_workout = Some(workout.clone());
+                        Command::request_from_shell(DatabaseOperation::LoadExerciseHistory(
+                            record_names,
+                        ))
+                        .then_send(|result| Event::DatabaseResponse { result })
+                    };
+                    // Only list it if it belongs in the filtered history
+                    if model.history_filter.matches(&workout) {
+                        model.workout_history.insert(0, workout.clone());
+                    }
                     model.workout_timer_seconds = 0;
                     model.timer_running = false;
-                    model.error_message = None; // Clear any stale errors on successful finish
+                    model.clear_recoverable_errors(); // Clear any stale errors on successful finish
+                    model.sync.record(None, Some(&workout));
 
                     // Save to database, delete from storage, stop timer
-                    // Serialize workout to JSON for database operation
-                    let workout_json = serde_json::to_string(&workout).unwrap_or_else(|e| {
-                        eprintln!("ERROR: Failed to serialize workout for database: {}", e);
-                        "{}".to_string() // Return valid empty JSON as fallback
-                    });
                     return Command::all([
-                        Command::request_from_shell(DatabaseOperation::SaveWorkout(workout_json))
-                            .then_send(|result| Event::DatabaseResponse { result }),
+                        Self::write_history([DatabaseOperation::SaveWorkout(workout)]),
+                        load_sessions,
+                        Self::save_sync_state(model),
                         Command::request_from_shell(StorageOperation::DeleteCurrentWorkout)
                             .then_send(|result| Event::StorageResponse { result }),
                         Command::request_from_shell(TimerOperation::Stop)
@@ -339,14 +917,14 @@ impl App for Thiccc {
                 model.current_workout = None;
                 model.workout_timer_seconds = 0;
                 model.timer_running = false;
-                model.error_message = None; // Clear any previous error
+                model.clear_recoverable_errors(); // Clear any previous error
             }
 
             Event::DiscardWorkout => {
                 model.current_workout = None;
                 model.workout_timer_seconds = 0;
                 model.timer_running = false;
-                model.error_message = None; // Clear any stale errors on discard
+                model.clear_recoverable_errors(); // Clear any stale errors on discard
 
                 // Delete from storage and stop timer
                 return Command::all([
@@ -359,17 +937,43 @@ impl App for Thiccc {
             }
 
             Event::UpdateWorkoutName { name } => {
-                if let Some(workout) = &mut model.current_workout {
+                if let Some(workout) = model.editable_workout_mut() {
                     workout.name = name;
                 }
             }
 
             Event::UpdateWorkoutNotes { notes } => {
-                if let Some(workout) = &mut model.current_workout {
+                if let Some(workout) = model.editable_workout_mut() {
                     workout.note = if notes.is_empty() { None } else { Some(notes) };
                 }
             }
 
+            Event::ShareWorkoutSummary => {
+                if let Some(summary) = &model.workout_summary {
+                    return Command::request_from_shell(ShareOperation::ShareText {
+                        text: summary.text.clone(),
+                    })
+                    .then_send(|result| Event::ShareResponse { result });
+                }
+            }
+
+            Event::CopyWorkoutSummary => {
+                if let Some(summary) = &model.workout_summary {
+                    return Command::request_from_shell(ShareOperation::CopyText {
+                        text: summary.text.clone(),
+                    })
+                    .then_send(|result| Event::ShareResponse { result });
+                }
+            }
+
+            Event::DismissWorkoutSummary => {
+                model.workout_summary = None;
+            }
+
+            Event::SetPreferredWeightUnit { unit } => {
+                model.preferred_weight_unit = unit;
+            }
+
             // =================================================================
             // Exercise Management
             // =================================================================
@@ -378,25 +982,31 @@ impl App for Thiccc {
                 exercise_type,
                 muscle_group,
             } => {
-                let workout = model.get_or_create_workout();
+                let workout = match model.editing_history_workout.as_mut() {
+                    Some(workout) => workout,
+                    None => model.get_or_create_workout(),
+                };
                 // Create GlobalExercise from the provided fields
                 let global_exercise = GlobalExercise::new(name, exercise_type, muscle_group);
                 let new_exercise = Exercise::from_global(&global_exercise, workout.id.clone());
                 workout.exercises.push(new_exercise);
                 model.showing_add_exercise = false;
-                model.error_message = None; // Clear any stale errors on successful add
+                model.clear_recoverable_errors(); // Clear any stale errors on successful add
             }
 
             Event::DeleteExercise { exercise_id } => {
                 // Validate and convert String to Id type
                 match Id::from_string(exercise_id) {
                     Ok(id) => {
-                        if let Some(workout) = &mut model.current_workout {
+                        if let Some(workout) = model.editable_workout_mut() {
                             workout.exercises.retain(|e| e.id != id);
                         }
                     }
                     Err(e) => {
-                        model.error_message = Some(format!("Invalid exercise ID: {}", e));
+                        model.report_error(AppError::validation(format!(
+                            "Invalid exercise ID: {}",
+                            e
+                        )));
                     }
                 }
             }
@@ -405,17 +1015,18 @@ impl App for Thiccc {
                 from_index,
                 to_index,
             } => {
-                if let Some(workout) = &mut model.current_workout {
+                if let Some(workout) = model.editable_workout_mut() {
                     if from_index < workout.exercises.len() && to_index < workout.exercises.len() {
                         let exercise = workout.exercises.remove(from_index);
                         workout.exercises.insert(to_index, exercise);
                     } else {
-                        model.error_message = Some(format!(
+                        let error = AppError::validation(format!(
                             "Cannot move exercise: invalid position (from: {}, to: {}, total: {})",
                             from_index,
                             to_index,
                             workout.exercises.len()
                         ));
+                        model.report_error(error);
                     }
                 }
             }
@@ -437,11 +1048,14 @@ impl App for Thiccc {
                     Ok(id) => {
                         if let Some(exercise) = model.find_exercise_mut(&id) {
                             exercise.add_set();
-                            model.error_message = None; // Clear any stale errors on successful add
+                            model.clear_recoverable_errors(); // Clear any stale errors on successful add
                         }
                     }
                     Err(e) => {
-                        model.error_message = Some(format!("Invalid exercise ID: {}", e));
+                        model.report_error(AppError::validation(format!(
+                            "Invalid exercise ID: {}",
+                            e
+                        )));
                     }
                 }
             }
@@ -461,16 +1075,20 @@ impl App for Thiccc {
                                     set.set_index = idx as i32;
                                 }
                             } else {
-                                model.error_message = Some(format!(
+                                let error = AppError::validation(format!(
                                     "Cannot delete set: index {} is out of bounds (total sets: {})",
                                     set_index,
                                     exercise.sets.len()
                                 ));
+                                model.report_error(error);
                             }
                         }
                     }
                     Err(e) => {
-                        model.error_message = Some(format!("Invalid exercise ID: {}", e));
+                        model.report_error(AppError::validation(format!(
+                            "Invalid exercise ID: {}",
+                            e
+                        )));
                     }
                 }
             }
@@ -479,12 +1097,42 @@ impl App for Thiccc {
                 // Validate and convert String to Id type at the boundary
                 match Id::from_string(set_id) {
                     Ok(id) => {
-                        if let Some(set) = model.find_set_mut(&id) {
-                            set.actual = actual;
+                        // Out-of-range values are rejected and the set is kept
+                        let rejected = model.editable_workout_mut().and_then(|workout| {
+                            workout.exercises.iter_mut().enumerate().find_map(
+                                |(exercise_index, exercise)| {
+                                    let (set_index, set) = exercise
+                                        .sets
+                                        .iter_mut()
+                                        .enumerate()
+                                        .find(|(_, set)| set.id == id)?;
+                                    let updated = ExerciseSet {
+                                        actual: actual.clone(),
+                                        ..set.clone()
+                                    };
+                                    match validation::validate_set(
+                                        &updated,
+                                        exercise_index,
+                                        set_index,
+                                    ) {
+                                        Ok(()) => {
+                                            *set = updated;
+                                            None
+                                        }
+                                        Err(errors) => Some(errors),
+                                    }
+                                },
+                            )
+                        });
+                        if let Some(errors) = rejected {
+                            model.report_error(AppError::validation(format!(
+                                "Invalid set values: {}",
+                                validation::describe_errors(&errors)
+                            )));
                         }
                     }
                     Err(e) => {
-                        model.error_message = Some(format!("Invalid set ID: {}", e));
+                        model.report_error(AppError::validation(format!("Invalid set ID: {}", e)));
                     }
                 }
             }
@@ -498,7 +1146,7 @@ impl App for Thiccc {
                         }
                     }
                     Err(e) => {
-                        model.error_message = Some(format!("Invalid set ID: {}", e));
+                        model.report_error(AppError::validation(format!("Invalid set ID: {}", e)));
                     }
                 }
             }
@@ -552,63 +1200,436 @@ impl App for Thiccc {
             // =================================================================
             Event::LoadHistory => {
                 model.is_loading = true;
-                return Command::request_from_shell(DatabaseOperation::LoadAllWorkouts)
-                    .then_send(|result| Event::DatabaseResponse { result });
+                return Command::request_from_shell(DatabaseOperation::LoadWorkoutsPage(
+                    model.history_page_query(true),
+                ))
+                .then_send(|result| Event::DatabaseResponse { result });
+            }
+
+            Event::LoadMoreHistory => {
+                if !model.history_has_more || model.is_loading {
+                    return render();
+                }
+                model.is_loading = true;
+                return Command::request_from_shell(DatabaseOperation::LoadWorkoutsPage(
+                    model.history_page_query(false),
+                ))
+                .then_send(|result| Event::DatabaseResponse { result });
+            }
+
+            Event::SetHistoryFilter { filter } => {
+                model.history_filter = filter;
+                model.is_loading = true;
+                return Command::all([
+                    Command::request_from_shell(DatabaseOperation::LoadWorkoutsPage(
+                        model.history_page_query(true),
+                    ))
+                    .then_send(|result| Event::DatabaseResponse { result }),
+                    render(),
+                ]);
+            }
+
+            Event::ClearHistoryFilter => {
+                model.history_filter = HistoryFilter::default();
+                model.is_loading = true;
+                return Command::all([
+                    Command::request_from_shell(DatabaseOperation::LoadWorkoutsPage(
+                        model.history_page_query(true),
+                    ))
+                    .then_send(|result| Event::DatabaseResponse { result }),
+                    render(),
+                ]);
+            }
+
+            Event::DismissCorruptWorkouts => {
+                model.quarantined_workouts.clear();
             }
 
             Event::ViewHistoryItem { workout_id } => {
-                // String IDs are used directly in navigation - no parsing needed
-                // They'll be parsed when actually loading the workout from database
-                model
-                    .navigation_stack
-                    .push(NavigationDestination::HistoryDetail { workout_id });
+                // Validate and convert String to Id type at the boundary
+                match Id::from_string(workout_id) {
+                    Ok(id) => {
+                        // Show the cached copy right away (if any), then load the
+                        // authoritative version from the database
+                        model.selected_history_workout = model
+                            .workout_history
+                            .iter()
+                            .find(|w| w.id == id)
+                            .cloned();
+                        model.history_detail_loaded = false;
+                        model.navigation_stack.push(NavigationDestination::HistoryDetail {
+                            workout_id: id.as_str().to_string(),
+                        });
+                        model.is_loading = true;
+
+                        return Command::all([
+                            Command::request_from_shell(DatabaseOperation::LoadWorkoutById(
+                                id.into_string(),
+                            ))
+                            .then_send(|result| Event::DatabaseResponse { result }),
+                            render(),
+                        ]);
+                    }
+                    Err(e) => {
+                        model.report_error(AppError::validation(format!(
+                            "Invalid workout ID: {}",
+                            e
+                        )));
+                    }
+                }
             }
 
-            Event::NavigateBack => {
-                model.navigation_stack.pop();
+            Event::ViewExerciseHistory { exercise_id } => {
+                // Validate and convert String to Id type at the boundary
+                match Id::from_string(exercise_id) {
+                    Ok(id) => {
+                        if let Some(exercise) = model.find_exercise_mut(&id) {
+                            // Sessions come from the whole database, not the
+                            // (filtered, paged) history list
+                            let exercise_name = exercise.name.clone();
+                            model.exercise_history.clear();
+                            model.navigation_stack.push(NavigationDestination::ExerciseHistory {
+                                exercise_name: exercise_name.clone(),
+                            });
+                            model.is_loading = true;
+
+                            return Command::all([
+                                Command::request_from_shell(
+                                    DatabaseOperation::LoadExerciseHistory(vec![exercise_name]),
+                                )
+                                .then_send(|result| Event::DatabaseResponse { result }),
+                                render(),
+                            ]);
+                        }
+                    }
+                    Err(e) => {
+                        model.report_error(AppError::validation(format!(
+                            "Invalid exercise ID: {}",
+                            e
+                        )));
+                    }
+                }
             }
 
+            Event::NavigateBack => match model.navigation_stack.pop() {
+                Some(NavigationDestination::HistoryDetail { .. }) => {
+                    model.selected_history_workout = None;
+                }
+                Some(NavigationDestination::ExerciseHistory { .. }) => {
+                    model.exercise_history.clear();
+                }
+                _ => {}
+            },
+
             Event::ChangeTab { tab } => {
                 model.selected_tab = tab;
                 // Clear navigation stack when changing tabs
                 model.navigation_stack.clear();
-                model.error_message = None; // Clear stale errors when navigating
+                model.selected_history_workout = None;
+                model.exercise_history.clear();
+                model.clear_recoverable_errors(); // Clear stale errors when navigating
+            }
+
+            // =================================================================
+            // History Editing
+            // =================================================================
+            Event::DeleteHistoryWorkout { workout_id } => {
+                // Validate and convert String to Id type at the boundary
+                match Id::from_string(workout_id) {
+                    Ok(id) => {
+                        model.pending_delete_workout_id = Some(id);
+                    }
+                    Err(e) => {
+                        model.report_error(AppError::validation(format!(
+                            "Invalid workout ID: {}",
+                            e
+                        )));
+                    }
+                }
+            }
+
+            Event::ConfirmDeleteHistoryWorkout => {
+                if let Some(id) = model.pending_delete_workout_id.take() {
+                    // The detail view can hold a workout that isn't on the loaded page
+                    let deleted = model
+                        .workout_history
+                        .iter()
+                        .chain(&model.selected_history_workout)
+                        .chain(&model.editing_history_workout)
+                        .find(|w| w.id == id)
+                        .cloned();
+                    if let Some(workout) = &deleted {
+                        model.sync.record(Some(workout), None);
+                    }
+                    model.remove_history_workout(&id);
+                    model.clear_recoverable_errors();
+
+                    return Command::all([
+                        Self::write_history([DatabaseOperation::DeleteWorkout(id.into_string())]),
+                        Self::save_sync_state(model),
+                        render(),
+                    ]);
+                }
+            }
+
+            Event::CancelDeleteHistoryWorkout => {
+                model.pending_delete_workout_id = None;
+            }
+
+            Event::EditHistoryWorkout { workout_id } => {
+                // Validate and convert String to Id type at the boundary
+                match Id::from_string(workout_id) {
+                    Ok(id) => {
+                        // Only the detail view's copy is loaded in full; the
+                        // history list may hold a summary without exercises,
+                        // and saving that would wipe the stored sets
+                        let workout = model
+                            .selected_history_workout
+                            .as_ref()
+                            .filter(|w| w.id == id && model.history_detail_loaded)
+                            .cloned();
+                        match workout {
+                            Some(workout) => {
+                                model.editing_history_workout = Some(workout);
+                                model.clear_recoverable_errors();
+                            }
+                            None if model
+                                .selected_history_workout
+                                .iter()
+                                .chain(&model.workout_history)
+                                .any(|w| w.id == id) =>
+                            {
+                                model.report_error(AppError::validation(
+                                    "Open the workout and wait for it to load before editing it",
+                                ));
+                            }
+                            None => {
+                                model.report_error(AppError::validation("Workout not found"));
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        model.report_error(AppError::validation(format!(
+                            "Invalid workout ID: {}",
+                            e
+                        )));
+                    }
+                }
+            }
+
+            Event::SaveHistoryWorkoutEdits => {
+                if let Some(workout) = model.editing_history_workout.take() {
+                    // Edits may have added exercises/sets - validate before persisting
+                    if let Err(errors) = validation::validate_workout(&workout) {
+                        model.report_error(AppError::validation(format!(
+                            "Invalid workout data: {}",
+                            validation::describe_errors(&errors)
+                        )));
+                        model.editing_history_workout = Some(workout);
+                    } else {
+                        let stored = model
+                            .selected_history_workout
+                            .as_ref()
+                            .filter(|w| w.id == workout.id)
+                            .cloned();
+                        model.sync.record(stored.as_ref(), Some(&workout));
+                        if let Some(existing) =
+                            model.workout_history.iter_mut().find(|w| w.id == workout.id)
+                        {
+                            *existing = workout.clone();
+                        }
+                        if model
+                            .selected_history_workout
+                            .as_ref()
+                            .is_some_and(|w| w.id == workout.id)
+                        {
+                            model.selected_history_workout = Some(workout.clone());
+                        }
+                        model.clear_recoverable_errors();
+
+                        return Command::all([
+                            Self::write_history([DatabaseOperation::UpdateWorkout(workout)]),
+                            Self::save_sync_state(model),
+                            render(),
+                        ]);
+                    }
+                }
+            }
+
+            Event::CancelHistoryWorkoutEdits => {
+                model.editing_history_workout = None;
             }
 
             // =================================================================
             // Import/Export
             // =================================================================
+            Event::ImportWorkout { json_data } if Self::is_workout_batch(&json_data) => {
+                // An array or export archive is a batch of finished workouts,
+                // imported into history
+                match Self::workout_batch_from_json(&json_data) {
+                    Ok(workouts) => {
+                        model.showing_import = false;
+                        model.clear_recoverable_errors();
+                        return Self::start_bulk_import(model, workouts);
+                    }
+                    Err(e) => {
+                        model.report_error(AppError::import(format!(
+                            "Failed to import workouts: {}",
+                            e
+                        )));
+                    }
+                }
+            }
+
             Event::ImportWorkout { json_data } => {
-                match serde_json::from_str::<Workout>(&json_data) {
+                // Parsing goes through the schema so older exports still import
+                match schema::workout_from_json(&json_data) {
                     Ok(workout) => {
-                        // Validate all IDs in the imported workout to prevent data corruption
-                        // The Id type uses #[serde(transparent)] which bypasses validation
-                        // during deserialization, so we must validate manually.
-                        if let Err(e) = Self::validate_workout_ids(&workout) {
-                            model.error_message = Some(format!("Invalid workout data: {}", e));
+                        // Serde only checks the shape; validate ids, references and
+                        // value ranges before the workout reaches the model.
+                        if let Err(errors) = validation::validate_workout(&workout) {
+                            model.report_error(AppError::import(format!(
+                                "Invalid workout data: {}",
+                                validation::describe_errors(&errors)
+                            )));
                         } else {
                             model.current_workout = Some(workout);
                             model.showing_import = false;
-                            model.error_message = None;
+                            model.clear_recoverable_errors();
                         }
                     }
                     Err(e) => {
-                        model.error_message = Some(format!("Failed to import workout: {}", e));
+                        model.report_error(AppError::import(format!(
+                            "Failed to import workout: {}",
+                            e
+                        )));
+                    }
+                }
+            }
+
+            Event::PreviewCsvImport { csv_data, options } => {
+                match csv_import::parse_csv_export(&csv_data, &options) {
+                    Ok(preview) => {
+                        model.csv_import_preview = Some(preview);
+                        model.clear_recoverable_errors();
+                    }
+                    Err(e) => {
+                        model.csv_import_preview = None;
+                        model.report_error(AppError::import(format!(
+                            "Failed to import CSV: {}",
+                            e
+                        )));
+                    }
+                }
+            }
+
+            Event::ConfirmCsvImport => {
+                if let Some(preview) = model.csv_import_preview.take() {
+                    model.showing_import = false;
+                    model.clear_recoverable_errors();
+                    return Self::start_bulk_import(model, preview.workouts);
+                }
+            }
+
+            Event::CancelCsvImport => {
+                model.csv_import_preview = None;
+            }
+
+            Event::ImportWatchFile { data } => match watch_import::parse_watch_file(&data) {
+                Ok(import) if import.workouts.is_empty() => {
+                    let reason = import
+                        .skipped
+                        .first()
+                        .cloned()
+                        .unwrap_or_else(|| "No workouts in file".to_string());
+                    model.report_error(AppError::import(format!(
+                        "No strength workouts to import from {}: {}",
+                        import.source.display_name(),
+                        reason
+                    )));
+                }
+                Ok(import) => {
+                    model.showing_import = false;
+                    model.clear_recoverable_errors();
+                    return Self::start_bulk_import(model, import.workouts);
+                }
+                Err(e) => {
+                    model.report_error(AppError::import(format!(
+                        "Failed to import watch file: {}",
+                        e
+                    )));
+                }
+            },
+
+            Event::DismissImportSummary => {
+                model.import_summary = None;
+            }
+
+            Event::ExportHistory { format } => {
+                // Export everything stored, not just the pages loaded so far
+                model.is_loading = true;
+                return Command::all([
+                    Command::request_from_shell(DatabaseOperation::LoadAllWorkouts)
+                        .then_send(move |result| Event::ExportHistoryLoaded { format, result }),
+                    render(),
+                ]);
+            }
+
+            Event::ExportHistoryLoaded { format, result } => {
+                model.is_loading = false;
+                let (workouts, failures) = match result {
+                    DatabaseResult::HistoryLoaded { workouts, failures } => {
+                        Self::check_loaded_workouts(workouts, failures)
+                    }
+                    DatabaseResult::Error { message } => {
+                        model.report_error(AppError::export(format!(
+                            "Failed to load history: {}",
+                            message
+                        )));
+                        return render();
+                    }
+                    _ => return render(),
+                };
+
+                match export::export_history(format, &workouts, &failures, Timestamp::now()) {
+                    Ok(file) => {
+                        return Command::all([
+                            Command::request_from_shell(ShareOperation::ShareFile {
+                                file_name: file.file_name,
+                                mime_type: file.mime_type,
+                                contents: file.contents,
+                            })
+                            .then_send(|result| Event::ShareResponse { result }),
+                            render(),
+                        ]);
+                    }
+                    Err(e) => {
+                        model.report_error(AppError::export(format!(
+                            "Failed to export history: {}",
+                            e
+                        )));
                     }
                 }
             }
 
+            Event::ShareResponse { result } => {
+                if let ShareResult::Failed { message } = result {
+                    model.report_error(AppError::export(message));
+                }
+            }
+
             Event::ShowImportView => {
                 model.showing_import = true;
             }
 
             Event::DismissImportView => {
                 model.showing_import = false;
+                model.csv_import_preview = None;
             }
 
             Event::LoadWorkoutTemplate => {
                 // TODO: In Phase 3, implement template loading via capability
-                model.error_message = Some("Template loading not yet implemented".to_string());
+                model.report_error(AppError::import("Template loading not yet implemented"));
             }
 
             // =================================================================
@@ -621,17 +1642,19 @@ impl App for Thiccc {
             } => {
                 // Validate inputs before calculation
                 if target_weight <= 0.0 {
-                    model.error_message = Some("Target weight must be greater than 0".to_string());
+                    model.report_error(AppError::validation(
+                        "Target weight must be greater than 0",
+                    ));
                     model.plate_calculation = None;
                 } else if bar_weight <= 0.0 {
-                    model.error_message = Some("Bar weight must be greater than 0".to_string());
+                    model.report_error(AppError::validation("Bar weight must be greater than 0"));
                     model.plate_calculation = None;
                 } else if let Some(percentage) = use_percentage {
                     if !(0.0..=100.0).contains(&percentage) {
-                        model.error_message = Some(format!(
+                        model.report_error(AppError::validation(format!(
                             "Percentage must be between 0 and 100 (got {})",
                             percentage
-                        ));
+                        )));
                         model.plate_calculation = None;
                     } else {
                         // All validations passed, perform calculation
@@ -661,34 +1684,256 @@ impl App for Thiccc {
                 model.plate_calculation = None;
             }
 
+            // =================================================================
+            // Sync
+            // =================================================================
+            Event::ApiSessionChanged { base_url, token } => {
+                let signed_in = token.is_some() && model.api.token.is_none();
+                model.api = ApiSession { base_url, token };
+                if signed_in {
+                    // Changes queued while signed out can go now
+                    return Command::event(Event::SyncNow);
+                }
+            }
+
+            Event::SyncNow => {
+                if model.is_syncing || model.sync.replica_id.is_empty() || !model.api.is_ready() {
+                    // Already running, the stored state hasn't loaded yet, or
+                    // nobody is signed in (changes stay queued)
+                    return Command::done();
+                }
+                if !model.sync.seeded {
+                    // Record history saved before sync existed, once per device
+                    model.is_syncing = true;
+                    return Command::all([
+                        Command::request_from_shell(DatabaseOperation::LoadAllWorkouts)
+                            .then_send(|result| Event::SyncHistoryLoaded { result }),
+                        render(),
+                    ]);
+                }
+                return Self::start_sync_exchange(model);
+            }
+
+            Event::SyncHistoryLoaded { result } => match result {
+                DatabaseResult::HistoryLoaded { workouts, failures } => {
+                    // LoadAllWorkouts returns whole workouts, so the server
+                    // gets every exercise and set
+                    let (workouts, failures) = Self::check_loaded_workouts(workouts, failures);
+                    for workout in &workouts {
+                        model.sync.record(None, Some(workout));
+                    }
+                    model.sync.seeded = true;
+                    if !failures.is_empty() {
+                        let message = if failures.len() == 1 {
+                            "1 workout could not be read and was not synced".to_string()
+                        } else {
+                            format!(
+                                "{} workouts could not be read and were not synced",
+                                failures.len()
+                            )
+                        };
+                        model.report_error(AppError::sync(message));
+                        for failure in failures {
+                            Self::quarantine_workout(model, failure);
+                        }
+                    }
+                    return Command::all([
+                        Self::save_sync_state(model),
+                        Self::start_sync_exchange(model),
+                    ]);
+                }
+                DatabaseResult::Error { message } => {
+                    model.is_syncing = false;
+                    model.report_error(AppError::sync(format!(
+                        "Failed to load history: {}",
+                        message
+                    )));
+                }
+                _ => model.is_syncing = false,
+            },
+
+            Event::SyncResponse { result } => {
+                model.is_syncing = false;
+                match http::decode::<SyncResponse>(result) {
+                    Ok(response) => return Self::apply_sync_response(model, response),
+                    Err(e) => model.report_error(AppError::sync(Self::sync_error_message(e))),
+                }
+            }
+
             // =================================================================
             // Capability Responses
             // =================================================================
             Event::DatabaseResponse { result } => {
-                model.is_loading = false;
+                // Exercise names reload in the background, next to whatever
+                // the spinner is waiting for
+                if !matches!(result, DatabaseResult::ExerciseNamesLoaded { .. }) {
+                    model.is_loading = false;
+                }
                 match result {
-                    DatabaseResult::WorkoutSaved => {
-                        // Success - no action needed
+                    DatabaseResult::WorkoutSaved
+                    | DatabaseResult::WorkoutDeleted
+                    | DatabaseResult::WorkoutUpdated => {
+                        // Success - exercise names reload after the write (see `write_history`)
                     }
-                    DatabaseResult::WorkoutDeleted => {
-                        // Success - workout removed from database
+                    DatabaseResult::HistoryLoaded { workouts, failures } => {
+                        model.quarantined_workouts.clear();
+                        model.workout_history =
+                            Self::accept_loaded_workouts(model, workouts, failures);
+                        model.history_has_more = false;
                     }
-                    DatabaseResult::HistoryLoaded { workouts_json } => {
-                        // Deserialize JSON strings to Workout objects
-                        let workouts: Vec<Workout> = workouts_json
-                            .iter()
-                            .filter_map(|json| serde_json::from_str(json).ok())
-                            .collect();
-                        model.workout_history = workouts;
+                    DatabaseResult::HistoryPageLoaded {
+                        query,
+                        workouts,
+                        failures,
+                        has_more,
+                    } => {
+                        if query.filter != model.history_filter {
+                            // Page for a filter the user has since changed
+                            return render();
+                        }
+
+                        if query.cursor.is_none() {
+                            model.quarantined_workouts.clear();
+                        }
+                        let workouts = Self::accept_loaded_workouts(model, workouts, failures);
+
+                        if query.cursor.is_none() {
+                            model.workout_history = workouts;
+                        } else {
+                            // Skip anything already listed (e.g. finished since the last page)
+                            for workout in workouts {
+                                if !model.workout_history.iter().any(|w| w.id == workout.id) {
+                                    model.workout_history.push(workout);
+                                }
+                            }
+                        }
+                        model.history_has_more = has_more;
+                    }
+                    DatabaseResult::ExerciseHistoryLoaded {
+                        exercise_names,
+                        workouts,
+                        failures,
+                    } => {
+                        // Unreadable workouts are reported by the history list
+                        let (workouts, _) = Self::check_loaded_workouts(workouts, failures);
+
+                        // Sessions for the summary of the workout just finished
+                        if let Some(workout) = model.pending_summary_workout.take_if(|workout| {
+                            summary::record_exercise_names(workout) == exercise_names
+                        }) {
+                            model.workout_summary = Some(summary::summarize_workout(
+                                &workout,
+                                &workouts,
+                                &model.preferred_weight_unit,
+                            ));
+                        }
+
+                        // Sessions for the exercise history view; dropped if the
+                        // user navigated away or moved on to another exercise
+                        let for_history_view = matches!(
+                            model.navigation_stack.last(),
+                            Some(NavigationDestination::ExerciseHistory { exercise_name })
+                                if exercise_names.as_slice() == std::slice::from_ref(exercise_name)
+                        );
+                        if for_history_view {
+                            model.exercise_history = workouts;
+                        }
+                    }
+                    DatabaseResult::ExerciseNamesLoaded { names } => {
+                        model.exercises_with_history = names;
+                    }
+                    DatabaseResult::WorkoutLoaded { workout } => {
+                        // LoadWorkoutById is only issued by the history detail flow,
+                        // so the result never touches the active workout.
+                        let Some(NavigationDestination::HistoryDetail { workout_id }) =
+                            model.navigation_stack.last()
+                        else {
+                            // User navigated away before the load finished
+                            return render();
+                        };
+
+                        match workout {
+                            Some(workout) if workout.id.as_str() == workout_id => {
+                                // Checked like the history list, so a corrupt
+                                // workout is quarantined rather than shown
+                                match Self::check_loaded_workout(workout) {
+                                    Ok(workout) => {
+                                        model.selected_history_workout = Some(workout);
+                                        model.history_detail_loaded = true;
+                                    }
+                                    Err(failure) => Self::reject_history_detail(model, failure),
+                                }
+                            }
+                            Some(_) => {
+                                // Stale response for a previously viewed workout
+                            }
+                            None => {
+                                model.navigation_stack.pop();
+                                model.selected_history_workout = None;
+                                // Deleted elsewhere; not a database failure
+                                model.report_error_with_severity(
+                                    AppError::database("Workout not found"),
+                                    ErrorSeverity::Recoverable,
+                                );
+                            }
+                        }
+                    }
+                    DatabaseResult::WorkoutLoadFailed { failure } => {
+                        // The shell couldn't read the stored workout at all
+                        let for_detail_view = matches!(
+                            model.navigation_stack.last(),
+                            Some(NavigationDestination::HistoryDetail { workout_id })
+                                if *workout_id == failure.workout_id
+                        );
+                        if for_detail_view {
+                            Self::reject_history_detail(model, failure);
+                        } else {
+                            Self::quarantine_workout(model, failure);
+                        }
                     }
-                    DatabaseResult::WorkoutLoaded { workout_json } => {
-                        // Deserialize JSON string to Workout object
-                        model.current_workout = workout_json
-                            .and_then(|json| serde_json::from_str(&json).ok());
+                    DatabaseResult::WorkoutsImported { summary } => {
+                        let sent = std::mem::take(&mut model.pending_import_workouts);
+                        for entry in &summary.inserted {
+                            if let Some(workout) =
+                                sent.iter().find(|w| w.id.as_str() == entry.workout_id)
+                            {
+                                model.sync.record(None, Some(workout));
+                            }
+                        }
+                        let recorded_any = !summary.inserted.is_empty();
+                        let mut combined = model.pending_import.take().unwrap_or_default();
+                        combined.merge(summary);
+                        let inserted_any = !combined.inserted.is_empty();
+                        model.import_summary = Some(combined);
+
+                        if inserted_any {
+                            // Reload so imported workouts land in order and pages stay consistent
+                            return Command::all([
+                                Command::request_from_shell(DatabaseOperation::LoadWorkoutsPage(
+                                    model.history_page_query(true),
+                                ))
+                                .then_send(|result| Event::DatabaseResponse { result }),
+                                Self::load_exercise_names(),
+                                if recorded_any {
+                                    Self::save_sync_state(model)
+                                } else {
+                                    Command::done()
+                                },
+                                render(),
+                            ]);
+                        }
                     }
                     DatabaseResult::Error { message } => {
                         // Database error occurred
-                        model.error_message = Some(message);
+                        model.report_error(AppError::database(message));
+                        // Show a pending summary without records rather than not at all
+                        if let Some(workout) = model.pending_summary_workout.take() {
+                            model.workout_summary = Some(summary::summarize_workout(
+                                &workout,
+                                &[],
+                                &model.preferred_weight_unit,
+                            ));
+                        }
                     }
                 }
             }
@@ -699,33 +1944,52 @@ impl App for Thiccc {
                     StorageResult::CurrentWorkoutSaved => {
                         // Success - no action needed
                     }
-                    StorageResult::CurrentWorkoutLoaded { workout_json } => {
-                        // Deserialize workout from JSON if present
-                        if let Some(json) = workout_json {
-                            match serde_json::from_str::<Workout>(&json) {
-                                Ok(workout) => {
-                                    // Calculate elapsed time since workout started
-                                    let elapsed = Utc::now().signed_duration_since(workout.start_timestamp);
-                                    model.workout_timer_seconds = elapsed.num_seconds().max(0) as i32;
-                                    
-                                    model.current_workout = Some(workout);
-                                    // If a workout was loaded, also start the timer
-                                    model.timer_running = true;
-                                    return Command::request_from_shell(TimerOperation::Start)
-                                        .then_send(|output| Event::TimerResponse { output });
-                                }
-                                Err(e) => {
-                                    model.error_message =
-                                        Some(format!("Failed to load workout: {}", e));
-                                }
+                    StorageResult::CurrentWorkoutLoaded { workout } => {
+                        let restored = workout.map(|workout| {
+                            let workout = schema::upgrade_workout(workout)?;
+                            validation::validate_workout(&workout)
+                                .map_err(|errors| validation::describe_errors(&errors))?;
+                            Ok::<_, String>(workout)
+                        });
+                        let workout = match restored.transpose() {
+                            Ok(workout) => workout,
+                            Err(e) => {
+                                model.report_error(AppError::storage(format!(
+                                    "Failed to restore workout: {}",
+                                    e
+                                )));
+                                None
                             }
+                        };
+                        if let Some(workout) = workout {
+                            // Calculate elapsed time since workout started
+                            let elapsed = Utc::now()
+                                .signed_duration_since(workout.start_timestamp.to_datetime());
+                            model.workout_timer_seconds = elapsed.num_seconds().max(0) as i32;
+
+                            model.current_workout = Some(workout);
+                            // If a workout was loaded, also start the timer
+                            model.timer_running = true;
+                            return Command::request_from_shell(TimerOperation::Start)
+                                .then_send(|output| Event::TimerResponse { output });
                         }
                     }
                     StorageResult::CurrentWorkoutDeleted => {
                         // Success - no action needed
                     }
+                    StorageResult::SyncStateSaved => {
+                        // Success - no action needed
+                    }
+                    StorageResult::SyncStateLoaded { state } => {
+                        // A new replica's id must be saved before anything is sent
+                        model.sync.restore(state);
+                        return Self::save_sync_state(model);
+                    }
                     StorageResult::Error { message } => {
-                        model.error_message = Some(format!("Storage error: {}", message));
+                        model.report_error(AppError::storage(format!(
+                            "Storage error: {}",
+                            message
+                        )));
                     }
                 }
             }
@@ -744,27 +2008,31 @@ impl App for Thiccc {
                     TimerOutput::Stopped => {
                         // Timer stopped - no action needed, state already set
                     }
+                    TimerOutput::Error { message } => {
+                        model.report_error(AppError::timer(message));
+                    }
                 }
             }
 
-            Event::Error { message } => {
-                model.error_message = Some(message);
+            // =================================================================
+            // Errors
+            // =================================================================
+            Event::DismissError { error_id } => {
+                model.errors.retain(|record| record.id != error_id);
+            }
+
+            Event::DismissAllErrors => {
+                model.errors.clear();
+            }
+
+            Event::Error { error } => {
+                model.report_error(error);
                 model.is_loading = false;
             }
         }
 
         render()
     }
-
-    fn view(&self, model: &Self::Model) -> Self::ViewModel {
-        ViewModel {
-            selected_tab: model.selected_tab.clone(),
-            workout_view: self.build_workout_view(model),
-            history_view: self.build_history_view(model),
-            error_message: model.error_message.clone(),
-            is_loading: model.is_loading,
-        }
-    }
 }
 
 // =============================================================================
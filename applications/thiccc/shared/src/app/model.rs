@@ -3,9 +3,20 @@
 //! This module defines the core application state and helper methods
 //! for working with that state.
 
-use super::events::{NavigationDestination, Tab};
+use super::errors::{AppError, ErrorRecord, ErrorSeverity};
+use super::events::{NavigationDestination, Tab, WorkoutLoadFailure};
+use crate::bulk_import::ImportSummary;
+use crate::csv_import::CsvImportPreview;
+use crate::http::ApiSession;
 use crate::id::Id;
+use crate::operations::{HistoryCursor, HistoryFilter, HistoryQuery};
 use crate::models::*;
+use crate::summary::WorkoutSummary;
+use crate::sync::SyncState;
+use crate::timestamp::Timestamp;
+
+/// Number of workouts requested per page of history.
+pub const HISTORY_PAGE_SIZE: u32 = 20;
 
 // =============================================================================
 // MARK: - Core Application State (Model)
@@ -39,10 +50,52 @@ pub struct Model {
     /// Whether the workout timer is running
     pub timer_running: bool,
 
+    /// Summary of the workout finished last, shown until dismissed
+    pub workout_summary: Option<WorkoutSummary>,
+
+    /// Workout finished last, kept until the past sessions of its exercises
+    /// are loaded and its summary can be built
+    pub pending_summary_workout: Option<Workout>,
+
+    /// Unit used for totals such as workout volume
+    pub preferred_weight_unit: WeightUnit,
+
     // ===== History =====
     /// List of completed workouts loaded from the database
     pub workout_history: Vec<Workout>,
 
+    /// Past workout shown in the history detail view (separate from `current_workout`)
+    pub selected_history_workout: Option<Workout>,
+
+    /// Whether `selected_history_workout` was loaded in full from the database,
+    /// rather than being the history list's copy shown while it loads
+    pub history_detail_loaded: bool,
+
+    /// Copy of a past workout being edited (None if not editing)
+    pub editing_history_workout: Option<Workout>,
+
+    /// History workout awaiting delete confirmation
+    pub pending_delete_workout_id: Option<Id>,
+
+    /// Active filter for the history list
+    pub history_filter: HistoryFilter,
+
+    /// Whether the database has more history beyond `workout_history`
+    pub history_has_more: bool,
+
+    /// Outcome of the most recent history load (None before the first load)
+    pub last_history_load: Option<HistoryLoadReport>,
+
+    /// Stored workouts that could not be read, kept until dismissed
+    pub quarantined_workouts: Vec<WorkoutLoadFailure>,
+
+    /// Past sessions of the exercise in the exercise history view, loaded
+    /// from the whole database (newest first, only that exercise)
+    pub exercise_history: Vec<Workout>,
+
+    /// Names of exercises with at least one stored session
+    pub exercises_with_history: Vec<String>,
+
     // ===== Navigation State =====
     /// Currently selected tab
     pub selected_tab: Tab,
@@ -57,6 +110,19 @@ pub struct Model {
     /// Whether import view is shown
     pub showing_import: bool,
 
+    /// Parsed CSV export awaiting confirmation (None if no import is pending)
+    pub csv_import_preview: Option<CsvImportPreview>,
+
+    /// Workouts the core ruled out of a bulk import still awaiting the shell
+    pub pending_import: Option<ImportSummary>,
+
+    /// Outcome of the last bulk import, shown until dismissed
+    pub import_summary: Option<ImportSummary>,
+
+    /// Workouts sent in the pending bulk import, recorded for sync once the
+    /// shell reports which of them were inserted
+    pub pending_import_workouts: Vec<Workout>,
+
     /// Whether stopwatch modal is shown
     pub showing_stopwatch: bool,
 
@@ -70,12 +136,31 @@ pub struct Model {
     /// Current plate calculation result
     pub plate_calculation: Option<PlateCalculation>,
 
+    // ===== Sync =====
+    /// API server and session token reported by the shell
+    pub api: ApiSession,
+
+    /// Replica id, cursor and unsent changes (loaded from storage on launch)
+    pub sync: SyncState,
+
+    /// Whether a sync is running
+    pub is_syncing: bool,
+
+    /// Clock counter when the running sync's request was built
+    pub sync_sent_counter: u64,
+
+    /// When the last sync finished successfully
+    pub last_synced_at: Option<Timestamp>,
+
     // ===== Loading & Error State =====
     /// Whether a database operation is in progress
     pub is_loading: bool,
 
-    /// Current error message (if any)
-    pub error_message: Option<String>,
+    /// Errors waiting to be shown or dismissed, oldest first
+    pub errors: Vec<ErrorRecord>,
+
+    /// Id assigned to the next reported error
+    pub next_error_id: u32,
 }
 
 impl Default for Model {
@@ -93,9 +178,22 @@ impl Default for Model {
             current_workout: None,
             workout_timer_seconds: 0,
             timer_running: false,
+            workout_summary: None,
+            pending_summary_workout: None,
+            preferred_weight_unit: WeightUnit::default(),
 
             // History
             workout_history: Vec::new(),
+            selected_history_workout: None,
+            history_detail_loaded: false,
+            editing_history_workout: None,
+            pending_delete_workout_id: None,
+            history_filter: HistoryFilter::default(),
+            history_has_more: false,
+            last_history_load: None,
+            quarantined_workouts: Vec::new(),
+            exercise_history: Vec::new(),
+            exercises_with_history: Vec::new(),
 
             // Navigation - explicitly start on Workout tab
             selected_tab: Tab::Workout,
@@ -104,6 +202,10 @@ impl Default for Model {
             // Modals - all closed initially
             showing_add_exercise: false,
             showing_import: false,
+            csv_import_preview: None,
+            pending_import: None,
+            import_summary: None,
+            pending_import_workouts: Vec::new(),
             showing_stopwatch: false,
             showing_rest_timer: None,
             showing_plate_calculator: false,
@@ -111,13 +213,30 @@ impl Default for Model {
             // Plate calculator
             plate_calculation: None,
 
+            // Sync - state is loaded from storage on Initialize
+            api: ApiSession::default(),
+            sync: SyncState::default(),
+            is_syncing: false,
+            sync_sent_counter: 0,
+            last_synced_at: None,
+
             // Loading/Error state
             is_loading: false,
-            error_message: None,
+            errors: Vec::new(),
+            next_error_id: 0,
         }
     }
 }
 
+/// Summary of one history load from the database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryLoadReport {
+    /// Number of workouts that were read and added to the history
+    pub loaded: usize,
+    /// Workouts that were rejected, by the shell or by validation
+    pub failures: Vec<WorkoutLoadFailure>,
+}
+
 impl Model {
     /// Get the current workout or create a new one if none exists.
     ///
@@ -130,29 +249,130 @@ impl Model {
         self.current_workout.as_mut().expect("Just created workout")
     }
 
-    /// Find an exercise by ID in the current workout.
+    /// Get the workout that exercise and set events should modify.
+    ///
+    /// This is the history workout being edited if there is one, otherwise
+    /// the current workout. Returns None if neither exists.
+    pub fn editable_workout_mut(&mut self) -> Option<&mut Workout> {
+        self.editing_history_workout
+            .as_mut()
+            .or(self.current_workout.as_mut())
+    }
+
+    /// Find an exercise by ID in the editable workout.
     ///
     /// Returns None if no workout is active or if the exercise is not found.
     pub fn find_exercise_mut(&mut self, exercise_id: &Id) -> Option<&mut Exercise> {
-        self.current_workout
-            .as_mut()?
+        self.editable_workout_mut()?
             .exercises
             .iter_mut()
             .find(|e| e.id == *exercise_id)
     }
 
-    /// Find a set by ID across all exercises in the current workout.
+    /// Find a set by ID across all exercises in the editable workout.
     ///
     /// Returns None if no workout is active or if the set is not found.
     pub fn find_set_mut(&mut self, set_id: &Id) -> Option<&mut ExerciseSet> {
-        self.current_workout
-            .as_mut()?
+        self.editable_workout_mut()?
             .exercises
             .iter_mut()
             .flat_map(|e| e.sets.iter_mut())
             .find(|s| s.id == *set_id)
     }
 
+    /// Find every past session of an exercise in the loaded exercise history.
+    ///
+    /// Exercises are matched by name (case-insensitive) because each workout
+    /// gives its exercises fresh IDs. Sessions are returned newest first,
+    /// in the same order as `exercise_history`.
+    pub fn exercise_sessions(&self, exercise_name: &str) -> Vec<(&Workout, &Exercise)> {
+        self.exercise_history
+            .iter()
+            .flat_map(|workout| {
+                workout
+                    .exercises
+                    .iter()
+                    .filter(|e| e.is_named(exercise_name))
+                    .map(move |exercise| (workout, exercise))
+            })
+            .collect()
+    }
+
+    /// Whether an exercise has any stored session (matched case-insensitively).
+    pub fn has_exercise_history(&self, exercise_name: &str) -> bool {
+        self.exercises_with_history
+            .iter()
+            .any(|name| exercise_name_key(name) == exercise_name_key(exercise_name))
+    }
+
+    /// Build the query for the next page of history with the active filter.
+    ///
+    /// The first page has no cursor; later pages continue after the oldest
+    /// workout already loaded.
+    pub fn history_page_query(&self, first_page: bool) -> HistoryQuery {
+        let cursor = if first_page {
+            None
+        } else {
+            self.workout_history.last().map(HistoryCursor::after)
+        };
+        HistoryQuery {
+            cursor,
+            limit: HISTORY_PAGE_SIZE,
+            filter: self.history_filter.clone(),
+        }
+    }
+
+    /// Add or replace a workout in the loaded history, keeping it ordered
+    /// newest first.
+    ///
+    /// A workout outside the active filter isn't listed, and one older than
+    /// every loaded workout is left for a later page when there is one.
+    pub fn upsert_history_workout(&mut self, workout: Workout) {
+        if self
+            .selected_history_workout
+            .as_ref()
+            .is_some_and(|w| w.id == workout.id)
+        {
+            self.selected_history_workout = Some(workout.clone());
+            self.history_detail_loaded = true;
+        }
+
+        self.workout_history.retain(|w| w.id != workout.id);
+        if !self.history_filter.matches(&workout) {
+            return;
+        }
+        let key = |w: &Workout| (w.start_timestamp, w.id.as_str().to_string());
+        match self.workout_history.iter().position(|w| key(w) < key(&workout)) {
+            Some(index) => self.workout_history.insert(index, workout),
+            None if !self.history_has_more => self.workout_history.push(workout),
+            None => {}
+        }
+    }
+
+    /// Remove a workout from the loaded history, the detail view, the edit
+    /// in progress and the navigation stack.
+    pub fn remove_history_workout(&mut self, id: &Id) {
+        self.workout_history.retain(|w| w.id != *id);
+        if self
+            .selected_history_workout
+            .as_ref()
+            .is_some_and(|w| w.id == *id)
+        {
+            self.selected_history_workout = None;
+        }
+        if self
+            .editing_history_workout
+            .as_ref()
+            .is_some_and(|w| w.id == *id)
+        {
+            self.editing_history_workout = None;
+        }
+        self.navigation_stack.retain(|destination| {
+            !matches!(destination, NavigationDestination::HistoryDetail { workout_id }
+                if workout_id == id.as_str())
+        });
+    }
+
     /// Calculate total volume for the current workout.
     ///
     /// Volume is calculated as the sum of (weight × reps) for all completed sets.
@@ -174,6 +394,45 @@ impl Model {
             .unwrap_or(0)
     }
 
+    /// Queue an error with its default severity.
+    pub fn report_error(&mut self, error: AppError) {
+        let severity = error.severity();
+        self.report_error_with_severity(error, severity);
+    }
+
+    /// Queue an error with an explicit severity.
+    ///
+    /// The source event is filled in by `update` once the event is handled.
+    pub fn report_error_with_severity(&mut self, error: AppError, severity: ErrorSeverity) {
+        self.errors.push(ErrorRecord {
+            id: self.next_error_id,
+            error,
+            severity,
+            source_event: "",
+        });
+        self.next_error_id += 1;
+    }
+
+    /// Attribute errors reported since `first_id` to the event that raised them.
+    pub fn attribute_errors(&mut self, first_id: u32, source_event: &'static str) {
+        for record in self.errors.iter_mut().filter(|r| r.id >= first_id) {
+            record.source_event = source_event;
+        }
+    }
+
+    /// Remove recoverable errors after a successful action.
+    ///
+    /// Fatal errors stay queued until the user dismisses them.
+    pub fn clear_recoverable_errors(&mut self) {
+        self.errors
+            .retain(|record| record.severity == ErrorSeverity::Fatal);
+    }
+
+    /// Message of the most recently reported error, if any.
+    pub fn latest_error_message(&self) -> Option<&str> {
+        self.errors.last().map(|record| record.error.message())
+    }
+
     /// Format the workout timer duration as "MM:SS".
     ///
     /// Example: 323 seconds -> "05:23"
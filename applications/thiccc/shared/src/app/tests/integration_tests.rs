@@ -1,4 +1,14 @@
 use super::super::*;
+use crate::operations::{HistoryCursor, HistoryFilter, HistoryQuery};
+use crate::operations::TimerOutput;
+use crate::bulk_import::{ImportEntry, ImportSummary};
+use crate::csv_import::CsvImportOptions;
+use crate::export::ExportFormat;
+use crate::operations::{ShareOperation, ShareResult};
+use crate::schema::WORKOUT_SCHEMA_VERSION;
+use crate::http::ApiSession;
+use crate::operations::{HttpMethod, HttpOperation, HttpRequest, HttpResponse, HttpResult};
+use crate::sync::{self, Clock, SyncRejection, SyncRequest, SyncResponse, SyncState};
 
 // -------------------------------------------------------------------------
 // Integration Tests (Update + View Cycle)
@@ -152,6 +162,103 @@ fn test_finish_workout_flow() {
     assert_eq!(view.history_view.workouts.len(), 1);
 }
 
+#[test]
+fn test_update_set_actual_rejects_out_of_range_values() {
+    let app = Thiccc;
+    let mut model = Model::default();
+    app.update(Event::StartWorkout, &mut model, &());
+    app.update(
+        Event::AddExercise {
+            name: "Squat".to_string(),
+            exercise_type: "barbell".to_string(),
+            muscle_group: "legs".to_string(),
+        },
+        &mut model,
+        &(),
+    );
+    let exercise_id = model.current_workout.as_ref().unwrap().exercises[0]
+        .id
+        .to_string();
+    app.update(Event::AddSet { exercise_id }, &mut model, &());
+    let set_id = model.current_workout.as_ref().unwrap().exercises[0].sets[0]
+        .id
+        .to_string();
+    let valid = SetActual::with_weight_and_reps(225.0, 5);
+    app.update(
+        Event::UpdateSetActual {
+            set_id: set_id.clone(),
+            actual: valid.clone(),
+        },
+        &mut model,
+        &(),
+    );
+
+    let invalid = [
+        (SetActual::with_weight_and_reps(-5.0, 5), "Weight -5"),
+        (SetActual::with_weight_and_reps(f64::NAN, 5), "Weight NaN"),
+        (
+            SetActual {
+                rpe: Some(11.0),
+                ..valid.clone()
+            },
+            "RPE 11",
+        ),
+        (
+            SetActual {
+                rpe: Some(0.0),
+                ..valid.clone()
+            },
+            "RPE 0",
+        ),
+    ];
+    for (actual, message) in invalid {
+        app.update(
+            Event::UpdateSetActual {
+                set_id: set_id.clone(),
+                actual,
+            },
+            &mut model,
+            &(),
+        );
+        let error = model.latest_error_message().unwrap();
+        assert!(
+            error.starts_with("Invalid set values: ") && error.contains(message),
+            "{}",
+            error
+        );
+        assert_eq!(
+            model.current_workout.as_ref().unwrap().exercises[0].sets[0].actual,
+            valid,
+            "the set keeps its last valid values"
+        );
+    }
+}
+
+#[test]
+fn test_finish_workout_keeps_an_invalid_workout_open() {
+    let app = Thiccc;
+    let mut workout = Workout::with_name("Restored");
+    workout.add_exercise("Bench").add_set().actual.rpe = Some(15.0);
+    let mut model = Model {
+        current_workout: Some(workout.clone()),
+        ..Model::default()
+    };
+
+    let mut cmd = app.update(Event::FinishWorkout, &mut model, &());
+
+    assert_eq!(model.current_workout, Some(workout));
+    assert!(model.workout_history.is_empty());
+    assert!(model.sync.pending.is_empty());
+    assert!(model
+        .latest_error_message()
+        .unwrap()
+        .starts_with("Invalid workout data: RPE 15"));
+    assert!(!cmd.effects().any(|effect| matches!(
+        effect,
+        Effect::Database(request) if matches!(request.operation, DatabaseOperation::SaveWorkout(_))
+    )));
+}
+
 #[test]
 fn test_timer_tick_flow() {
     let app = Thiccc;
@@ -261,19 +368,17 @@ fn test_delete_set_with_invalid_index_shows_error() {
     );
 
     // Verify error message was set
-    assert!(model.error_message.is_some(), "Error message should be set");
+    assert!(model.latest_error_message().is_some(), "Error message should be set");
     assert!(
         model
-            .error_message
-            .as_ref()
+            .latest_error_message()
             .unwrap()
             .contains("Cannot delete set"),
         "Error should mention deletion failure"
     );
     assert!(
         model
-            .error_message
-            .as_ref()
+            .latest_error_message()
             .unwrap()
             .contains("out of bounds"),
         "Error should mention out of bounds"
@@ -328,19 +433,17 @@ fn test_move_exercise_with_invalid_indices_shows_error() {
     );
 
     // Verify error message was set
-    assert!(model.error_message.is_some(), "Error message should be set");
+    assert!(model.latest_error_message().is_some(), "Error message should be set");
     assert!(
         model
-            .error_message
-            .as_ref()
+            .latest_error_message()
             .unwrap()
             .contains("Cannot move exercise"),
         "Error should mention move failure"
     );
     assert!(
         model
-            .error_message
-            .as_ref()
+            .latest_error_message()
             .unwrap()
             .contains("invalid position"),
         "Error should mention invalid position"
@@ -377,13 +480,13 @@ fn test_error_message_cleared_on_start_workout() {
     let mut model = Model::default();
 
     // Set an error message
-    model.error_message = Some("Previous error".to_string());
+    model.report_error(AppError::validation("Previous error"));
 
     // Start workout (should clear error on success)
     app.update(Event::StartWorkout, &mut model, &());
 
     // Verify error was cleared
-    assert!(model.error_message.is_none(), "Error should be cleared on successful StartWorkout");
+    assert!(model.latest_error_message().is_none(), "Error should be cleared on successful StartWorkout");
 }
 
 #[test]
@@ -395,7 +498,7 @@ fn test_error_message_cleared_on_add_exercise() {
     app.update(Event::StartWorkout, &mut model, &());
 
     // Set an error message
-    model.error_message = Some("Previous error".to_string());
+    model.report_error(AppError::validation("Previous error"));
 
     // Add exercise (should clear error)
     app.update(
@@ -409,7 +512,7 @@ fn test_error_message_cleared_on_add_exercise() {
     );
 
     // Verify error was cleared
-    assert!(model.error_message.is_none(), "Error should be cleared on successful AddExercise");
+    assert!(model.latest_error_message().is_none(), "Error should be cleared on successful AddExercise");
 }
 
 #[test]
@@ -434,13 +537,13 @@ fn test_error_message_cleared_on_add_set() {
         .to_string();
 
     // Set an error message
-    model.error_message = Some("Previous error".to_string());
+    model.report_error(AppError::validation("Previous error"));
 
     // Add set (should clear error)
     app.update(Event::AddSet { exercise_id }, &mut model, &());
 
     // Verify error was cleared
-    assert!(model.error_message.is_none(), "Error should be cleared on successful AddSet");
+    assert!(model.latest_error_message().is_none(), "Error should be cleared on successful AddSet");
 }
 
 #[test]
@@ -449,13 +552,13 @@ fn test_error_message_cleared_on_change_tab() {
     let mut model = Model::default();
 
     // Set an error message
-    model.error_message = Some("Previous error".to_string());
+    model.report_error(AppError::validation("Previous error"));
 
     // Change tab (should clear error)
     app.update(Event::ChangeTab { tab: Tab::History }, &mut model, &());
 
     // Verify error was cleared
-    assert!(model.error_message.is_none(), "Error should be cleared when changing tabs");
+    assert!(model.latest_error_message().is_none(), "Error should be cleared when changing tabs");
 }
 
 #[test]
@@ -467,13 +570,13 @@ fn test_error_message_cleared_on_finish_workout() {
     app.update(Event::StartWorkout, &mut model, &());
 
     // Set an error message
-    model.error_message = Some("Previous error".to_string());
+    model.report_error(AppError::validation("Previous error"));
 
     // Finish workout (should clear error)
     app.update(Event::FinishWorkout, &mut model, &());
 
     // Verify error was cleared
-    assert!(model.error_message.is_none(), "Error should be cleared on FinishWorkout");
+    assert!(model.latest_error_message().is_none(), "Error should be cleared on FinishWorkout");
 }
 
 #[test]
@@ -485,13 +588,13 @@ fn test_error_message_cleared_on_discard_workout() {
     app.update(Event::StartWorkout, &mut model, &());
 
     // Set an error message
-    model.error_message = Some("Previous error".to_string());
+    model.report_error(AppError::validation("Previous error"));
 
     // Discard workout (should clear error)
     app.update(Event::DiscardWorkout, &mut model, &());
 
     // Verify error was cleared
-    assert!(model.error_message.is_none(), "Error should be cleared on DiscardWorkout");
+    assert!(model.latest_error_message().is_none(), "Error should be cleared on DiscardWorkout");
 }
 
 #[test]
@@ -537,10 +640,9 @@ fn test_plate_calculator_rejects_negative_target_weight() {
     );
 
     // Verify error message was set
-    assert!(model.error_message.is_some());
+    assert!(model.latest_error_message().is_some());
     assert!(model
-        .error_message
-        .as_ref()
+        .latest_error_message()
         .unwrap()
         .contains("Target weight must be greater than 0"));
     assert!(model.plate_calculation.is_none());
@@ -562,10 +664,9 @@ fn test_plate_calculator_rejects_zero_target_weight() {
     );
 
     // Verify error message was set
-    assert!(model.error_message.is_some());
+    assert!(model.latest_error_message().is_some());
     assert!(model
-        .error_message
-        .as_ref()
+        .latest_error_message()
         .unwrap()
         .contains("Target weight must be greater than 0"));
     assert!(model.plate_calculation.is_none());
@@ -587,10 +688,9 @@ fn test_plate_calculator_rejects_negative_bar_weight() {
     );
 
     // Verify error message was set
-    assert!(model.error_message.is_some());
+    assert!(model.latest_error_message().is_some());
     assert!(model
-        .error_message
-        .as_ref()
+        .latest_error_message()
         .unwrap()
         .contains("Bar weight must be greater than 0"));
     assert!(model.plate_calculation.is_none());
@@ -612,10 +712,9 @@ fn test_plate_calculator_rejects_negative_percentage() {
     );
 
     // Verify error message was set
-    assert!(model.error_message.is_some());
+    assert!(model.latest_error_message().is_some());
     assert!(model
-        .error_message
-        .as_ref()
+        .latest_error_message()
         .unwrap()
         .contains("Percentage must be between 0 and 100"));
     assert!(model.plate_calculation.is_none());
@@ -637,15 +736,13 @@ fn test_plate_calculator_rejects_percentage_over_100() {
     );
 
     // Verify error message was set
-    assert!(model.error_message.is_some());
+    assert!(model.latest_error_message().is_some());
     assert!(model
-        .error_message
-        .as_ref()
+        .latest_error_message()
         .unwrap()
         .contains("Percentage must be between 0 and 100"));
     assert!(model
-        .error_message
-        .as_ref()
+        .latest_error_message()
         .unwrap()
         .contains("150"));
     assert!(model.plate_calculation.is_none());
@@ -667,7 +764,7 @@ fn test_plate_calculator_accepts_percentage_100() {
     );
 
     // Verify calculation succeeded (100% of 225 = 225)
-    assert!(model.error_message.is_none());
+    assert!(model.latest_error_message().is_none());
     assert!(model.plate_calculation.is_some());
     assert_eq!(model.plate_calculation.as_ref().unwrap().total_weight, 225.0);
 }
@@ -687,7 +784,7 @@ fn test_import_workout_flow() {
     // Verify it was imported
     assert!(model.current_workout.is_some());
     assert_eq!(model.current_workout.as_ref().unwrap().name, "Test Workout");
-    assert!(model.error_message.is_none());
+    assert!(model.latest_error_message().is_none());
 }
 
 #[test]
@@ -705,10 +802,9 @@ fn test_import_invalid_workout_shows_error() {
     );
 
     // Verify error was set
-    assert!(model.error_message.is_some());
+    assert!(model.latest_error_message().is_some());
     assert!(model
-        .error_message
-        .as_ref()
+        .latest_error_message()
         .unwrap()
         .contains("Failed to import"));
 }
@@ -740,22 +836,2455 @@ fn test_import_workout_with_invalid_uuid_is_rejected() {
 
     // Verify the malformed UUID was caught and rejected
     assert!(model.current_workout.is_none(), "Workout with invalid UUID should not be imported");
-    assert!(model.error_message.is_some(), "Error message should be set");
+    assert!(model.latest_error_message().is_some(), "Error message should be set");
     assert!(
         model
-            .error_message
-            .as_ref()
+            .latest_error_message()
             .unwrap()
             .contains("Invalid workout data"),
         "Error should mention invalid workout data"
     );
     assert!(
         model
-            .error_message
-            .as_ref()
+            .latest_error_message()
             .unwrap()
             .contains("Invalid workout ID"),
         "Error should specifically mention the workout ID"
     );
 }
 
+#[test]
+fn test_view_exercise_history_flow() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    // Two past sessions of Bench Press (history is newest first)
+    let mut older = Workout::with_name("Push A");
+    older.start_timestamp = "2025-01-01T12:00:00Z".parse().unwrap();
+    let exercise = older.add_exercise("Bench Press");
+    exercise.add_set().complete(SetActual::with_weight_and_reps(185.0, 5));
+
+    let mut newer = Workout::with_name("Push B");
+    newer.start_timestamp = "2025-01-08T12:00:00Z".parse().unwrap();
+    let exercise = newer.add_exercise("Bench Press");
+    exercise.add_set().complete(SetActual::with_weight_and_reps(225.0, 10));
+    exercise.add_set().complete(SetActual::with_weight_and_reps(205.0, 8));
+
+    // The database knows which exercises have been performed
+    app.update(
+        Event::DatabaseResponse {
+            result: DatabaseResult::ExerciseNamesLoaded {
+                names: vec!["bench press".to_string()],
+            },
+        },
+        &mut model,
+        &(),
+    );
+
+    // Start a workout with Bench Press and a new exercise
+    app.update(Event::StartWorkout, &mut model, &());
+    for name in ["Bench Press", "Dips"] {
+        app.update(
+            Event::AddExercise {
+                name: name.to_string(),
+                exercise_type: "barbell".to_string(),
+                muscle_group: "chest".to_string(),
+            },
+            &mut model,
+            &(),
+        );
+    }
+
+    // Only exercises with past sessions offer the drill-down
+    let view = app.view(&model);
+    assert!(view.workout_view.exercises[0].has_history);
+    assert!(!view.workout_view.exercises[1].has_history);
+    assert!(view.exercise_history_view.is_none());
+
+    let exercise_id = model.current_workout.as_ref().unwrap().exercises[0]
+        .id
+        .to_string();
+    let mut cmd = app.update(Event::ViewExerciseHistory { exercise_id }, &mut model, &());
+
+    assert_eq!(
+        model.navigation_stack.last(),
+        Some(&NavigationDestination::ExerciseHistory {
+            exercise_name: "Bench Press".to_string()
+        })
+    );
+
+    // Sessions are loaded from the whole database, not the history page
+    let effects: Vec<Effect> = cmd.effects().collect();
+    assert!(effects.iter().any(|effect| matches!(
+        effect,
+        Effect::Database(request)
+            if request.operation
+                == DatabaseOperation::LoadExerciseHistory(vec!["Bench Press".to_string()])
+    )));
+    assert!(model.workout_history.is_empty());
+
+    app.update(
+        Event::DatabaseResponse {
+            result: DatabaseResult::ExerciseHistoryLoaded {
+                exercise_names: vec!["Bench Press".to_string()],
+                workouts: vec![newer, older],
+                failures: vec![],
+            },
+        },
+        &mut model,
+        &(),
+    );
+
+    let view = app.view(&model);
+    let history = view.exercise_history_view.expect("Exercise history should be shown");
+    assert_eq!(history.exercise_name, "Bench Press");
+
+    // Sessions are newest first with best set, e1RM, volume and per-set breakdown
+    assert_eq!(history.sessions.len(), 2);
+    let latest = &history.sessions[0];
+    assert_eq!(latest.workout_name, "Push B");
+    assert_eq!(latest.date, "Jan 08, 2025");
+    assert_eq!(latest.best_set.as_deref(), Some("225 lb × 10 reps"));
+    assert!((latest.estimated_one_rep_max.unwrap() - 300.0).abs() < 0.01);
+    assert_eq!(latest.total_volume, 225 * 10 + 205 * 8);
+    assert_eq!(latest.sets.len(), 2);
+    assert_eq!(latest.sets[1].set_number, 2);
+    assert_eq!(latest.sets[1].display_text, "205 lb × 8 reps");
+
+    // Chart series are oldest first
+    assert!((history.best_estimated_one_rep_max.unwrap() - 300.0).abs() < 0.01);
+    assert_eq!(history.estimated_one_rep_max_series.len(), 2);
+    assert_eq!(history.estimated_one_rep_max_series[0].label, "Jan 01");
+    assert!(
+        history.estimated_one_rep_max_series[0].timestamp
+            < history.estimated_one_rep_max_series[1].timestamp
+    );
+    assert_eq!(history.volume_series[0].value, 925.0);
+    assert_eq!(history.max_weight_series[1].value, 225.0);
+
+    // Navigating back closes the exercise history
+    app.update(Event::NavigateBack, &mut model, &());
+    assert!(app.view(&model).exercise_history_view.is_none());
+    assert!(model.exercise_history.is_empty());
+}
+
+#[test]
+fn test_stale_exercise_history_is_ignored() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    let mut past = Workout::with_name("Push A");
+    past.add_exercise("Bench Press")
+        .add_set()
+        .complete(SetActual::with_weight_and_reps(185.0, 5));
+
+    // A result arriving after the user left the screen is dropped
+    app.update(
+        Event::DatabaseResponse {
+            result: DatabaseResult::ExerciseHistoryLoaded {
+                exercise_names: vec!["Bench Press".to_string()],
+                workouts: vec![past],
+                failures: vec![],
+            },
+        },
+        &mut model,
+        &(),
+    );
+
+    assert!(model.exercise_history.is_empty());
+}
+
+#[test]
+fn test_view_exercise_history_with_invalid_id_shows_error() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    app.update(
+        Event::ViewExerciseHistory {
+            exercise_id: "not-a-uuid".to_string(),
+        },
+        &mut model,
+        &(),
+    );
+
+    assert!(model.navigation_stack.is_empty());
+    assert!(model
+        .latest_error_message()
+        .unwrap()
+        .contains("Invalid exercise ID"));
+}
+
+#[test]
+fn test_view_history_item_loads_workout_by_id() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    // A finished workout in history
+    let mut past = Workout::with_name("Leg Day");
+    past.start_timestamp = "2025-01-01T15:45:00Z".parse().unwrap();
+    let exercise = past.add_exercise("Squat");
+    exercise.add_set().complete(SetActual::with_weight_and_reps(315.0, 5));
+    past.duration = Some(2723);
+    model.workout_history = vec![past.clone()];
+
+    // An active workout that must not be clobbered
+    app.update(Event::StartWorkout, &mut model, &());
+    let active_id = model.current_workout.as_ref().unwrap().id.clone();
+
+    // View the past workout - should request it from the database
+    let workout_id = past.id.to_string();
+    let mut cmd = app.update(
+        Event::ViewHistoryItem {
+            workout_id: workout_id.clone(),
+        },
+        &mut model,
+        &(),
+    );
+    let effects: Vec<Effect> = cmd.effects().collect();
+    assert!(effects.iter().any(|effect| matches!(
+        effect,
+        Effect::Database(request)
+            if request.operation == DatabaseOperation::LoadWorkoutById(workout_id.clone())
+    )));
+    assert_eq!(
+        model.navigation_stack.last(),
+        Some(&NavigationDestination::HistoryDetail {
+            workout_id: workout_id.clone()
+        })
+    );
+
+    // Cached copy is shown while loading
+    let view = app.view(&model);
+    let detail = view.history_detail_view.expect("Detail should be shown");
+    assert_eq!(detail.workout_name, "Leg Day");
+
+    // Database returns an updated copy
+    let mut loaded = past.clone();
+    loaded.name = "Leg Day (edited)".to_string();
+    app.update(
+        Event::DatabaseResponse {
+            result: DatabaseResult::WorkoutLoaded {
+                workout: Some(loaded),
+            },
+        },
+        &mut model,
+        &(),
+    );
+
+    let view = app.view(&model);
+    let detail = view.history_detail_view.expect("Detail should be shown");
+    assert_eq!(detail.id, workout_id);
+    assert_eq!(detail.workout_name, "Leg Day (edited)");
+    assert_eq!(detail.formatted_date, "Jan 01, 2025 at 3:45 PM");
+    assert_eq!(detail.duration.as_deref(), Some("45:23"));
+    assert_eq!(detail.exercises[0].name, "Squat");
+    assert_eq!(detail.exercises[0].sets[0].display_text, "315 lb × 5 reps");
+    assert_eq!(detail.total_volume, 1575);
+    assert_eq!(detail.total_sets, 1);
+
+    // Active workout is untouched
+    assert_eq!(model.current_workout.as_ref().unwrap().id, active_id);
+
+    // Navigating back clears the selected workout
+    app.update(Event::NavigateBack, &mut model, &());
+    assert!(model.selected_history_workout.is_none());
+    assert!(app.view(&model).history_detail_view.is_none());
+}
+
+#[test]
+fn test_workout_loaded_after_navigating_away_is_ignored() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    let past = Workout::with_name("Push Day");
+    app.update(
+        Event::ViewHistoryItem {
+            workout_id: past.id.to_string(),
+        },
+        &mut model,
+        &(),
+    );
+    app.update(Event::NavigateBack, &mut model, &());
+
+    app.update(
+        Event::DatabaseResponse {
+            result: DatabaseResult::WorkoutLoaded {
+                workout: Some(past),
+            },
+        },
+        &mut model,
+        &(),
+    );
+
+    assert!(model.selected_history_workout.is_none());
+    assert!(model.current_workout.is_none());
+}
+
+#[test]
+fn test_workout_loaded_not_found_pops_detail() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    app.update(
+        Event::ViewHistoryItem {
+            workout_id: Id::new().to_string(),
+        },
+        &mut model,
+        &(),
+    );
+    app.update(
+        Event::DatabaseResponse {
+            result: DatabaseResult::WorkoutLoaded { workout: None },
+        },
+        &mut model,
+        &(),
+    );
+
+    assert!(model.navigation_stack.is_empty());
+    assert!(model.selected_history_workout.is_none());
+    assert_eq!(model.latest_error_message(), Some("Workout not found"));
+}
+
+#[test]
+fn test_corrupt_workout_loaded_for_detail_is_quarantined() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    let mut corrupt = Workout::with_name("Push Day");
+    corrupt.add_exercise("Bench Press").add_set().actual.rpe = Some(15.0);
+    app.update(
+        Event::ViewHistoryItem {
+            workout_id: corrupt.id.to_string(),
+        },
+        &mut model,
+        &(),
+    );
+    app.update(
+        Event::DatabaseResponse {
+            result: DatabaseResult::WorkoutLoaded {
+                workout: Some(corrupt.clone()),
+            },
+        },
+        &mut model,
+        &(),
+    );
+
+    assert!(model.navigation_stack.is_empty());
+    assert!(model.selected_history_workout.is_none());
+    assert!(model
+        .latest_error_message()
+        .is_some_and(|message| message.starts_with("Failed to load workout")));
+    assert_eq!(model.quarantined_workouts.len(), 1);
+    assert_eq!(model.quarantined_workouts[0].workout_id, corrupt.id.as_str());
+    assert!(app.view(&model).history_view.load_problems.is_some());
+}
+
+#[test]
+fn test_unreadable_workout_for_detail_is_quarantined() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    let workout_id = Id::new().into_string();
+    app.update(
+        Event::ViewHistoryItem {
+            workout_id: workout_id.clone(),
+        },
+        &mut model,
+        &(),
+    );
+    app.update(
+        Event::DatabaseResponse {
+            result: DatabaseResult::WorkoutLoadFailed {
+                failure: WorkoutLoadFailure {
+                    workout_id: workout_id.clone(),
+                    error: "Unrecognised stored value 'trebuchet'".to_string(),
+                    raw_payload: "[id:...]".to_string(),
+                },
+            },
+        },
+        &mut model,
+        &(),
+    );
+
+    assert!(model.navigation_stack.is_empty());
+    assert_eq!(
+        model.latest_error_message(),
+        Some("Failed to load workout: Unrecognised stored value 'trebuchet'")
+    );
+    assert_eq!(model.quarantined_workouts.len(), 1);
+    assert_eq!(model.quarantined_workouts[0].workout_id, workout_id);
+}
+
+#[test]
+fn test_view_history_item_with_invalid_id_shows_error() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    app.update(
+        Event::ViewHistoryItem {
+            workout_id: "not-a-uuid".to_string(),
+        },
+        &mut model,
+        &(),
+    );
+
+    assert!(model.navigation_stack.is_empty());
+    assert!(model
+        .latest_error_message()
+        .unwrap()
+        .contains("Invalid workout ID"));
+}
+
+/// Creates a finished workout with one completed Bench Press set.
+fn finished_bench_workout(name: &str) -> Workout {
+    let mut workout = Workout::with_name(name);
+    let exercise = workout.add_exercise("Bench Press");
+    exercise
+        .add_set()
+        .complete(SetActual::with_weight_and_reps(135.0, 10));
+    workout.finish(1800);
+    workout
+}
+
+/// Opens a workout in the history detail view and answers its load.
+fn open_history_workout(app: &Thiccc, model: &mut Model, workout: &Workout) {
+    app.update(
+        Event::ViewHistoryItem {
+            workout_id: workout.id.to_string(),
+        },
+        model,
+        &(),
+    );
+    app.update(
+        Event::DatabaseResponse {
+            result: DatabaseResult::WorkoutLoaded {
+                workout: Some(workout.clone()),
+            },
+        },
+        model,
+        &(),
+    );
+}
+
+#[test]
+fn test_delete_history_workout_requires_confirmation() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    let keep = finished_bench_workout("Keep");
+    let remove = finished_bench_workout("Remove");
+    let remove_id = remove.id.to_string();
+    model.workout_history = vec![keep, remove];
+
+    // View the workout, then ask to delete it
+    app.update(
+        Event::ViewHistoryItem {
+            workout_id: remove_id.clone(),
+        },
+        &mut model,
+        &(),
+    );
+    app.update(
+        Event::DeleteHistoryWorkout {
+            workout_id: remove_id.clone(),
+        },
+        &mut model,
+        &(),
+    );
+
+    // Nothing deleted yet - confirmation is shown
+    assert_eq!(model.workout_history.len(), 2);
+    let view = app.view(&model);
+    let confirmation = view
+        .history_view
+        .delete_confirmation
+        .expect("Confirmation should be shown");
+    assert_eq!(confirmation.workout_id, remove_id);
+    assert_eq!(confirmation.workout_name, "Remove");
+
+    // Confirm - removes from history, leaves the detail view and hits the database
+    let mut cmd = app.update(Event::ConfirmDeleteHistoryWorkout, &mut model, &());
+    let effects: Vec<Effect> = cmd.effects().collect();
+    assert!(effects.iter().any(|effect| matches!(
+        effect,
+        Effect::Database(request)
+            if request.operation == DatabaseOperation::DeleteWorkout(remove_id.clone())
+    )));
+
+    assert_eq!(model.workout_history.len(), 1);
+    assert_eq!(model.workout_history[0].name, "Keep");
+    assert!(model.navigation_stack.is_empty());
+    assert!(model.selected_history_workout.is_none());
+    assert!(app.view(&model).history_view.delete_confirmation.is_none());
+}
+
+#[test]
+fn test_cancel_delete_history_workout_keeps_workout() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    let workout = finished_bench_workout("Keep");
+    let workout_id = workout.id.to_string();
+    model.workout_history = vec![workout];
+
+    app.update(Event::DeleteHistoryWorkout { workout_id }, &mut model, &());
+    app.update(Event::CancelDeleteHistoryWorkout, &mut model, &());
+
+    // Confirming after cancel is a no-op
+    app.update(Event::ConfirmDeleteHistoryWorkout, &mut model, &());
+
+    assert!(model.pending_delete_workout_id.is_none());
+    assert_eq!(model.workout_history.len(), 1);
+}
+
+#[test]
+fn test_delete_workout_outside_history_page_records_sync_delete() {
+    let app = Thiccc;
+    let mut model = synced_model();
+
+    // Opened from an exercise's history, not from the loaded page
+    let older = finished_bench_workout("Older");
+    let workout_id = older.id.to_string();
+    app.update(
+        Event::ViewHistoryItem {
+            workout_id: workout_id.clone(),
+        },
+        &mut model,
+        &(),
+    );
+    app.update(
+        Event::DatabaseResponse {
+            result: DatabaseResult::WorkoutLoaded {
+                workout: Some(older),
+            },
+        },
+        &mut model,
+        &(),
+    );
+    assert!(model.workout_history.is_empty());
+
+    app.update(
+        Event::DeleteHistoryWorkout {
+            workout_id: workout_id.clone(),
+        },
+        &mut model,
+        &(),
+    );
+    app.update(Event::ConfirmDeleteHistoryWorkout, &mut model, &());
+
+    assert!(model.selected_history_workout.is_none());
+    assert!(model
+        .sync
+        .pending
+        .iter()
+        .any(|change| change.workout_id == workout_id && change.is_delete()));
+}
+
+#[test]
+fn test_edit_history_workout_flow() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    let past = finished_bench_workout("Push Day");
+    let workout_id = past.id.to_string();
+    model.workout_history = vec![past.clone()];
+    open_history_workout(&app, &mut model, &past);
+
+    // An active workout that must not receive the edits
+    app.update(Event::StartWorkout, &mut model, &());
+
+    app.update(
+        Event::EditHistoryWorkout {
+            workout_id: workout_id.clone(),
+        },
+        &mut model,
+        &(),
+    );
+
+    let view = app.view(&model);
+    let edit = view.history_edit_view.expect("Edit view should be shown");
+    assert_eq!(edit.id, workout_id);
+    assert_eq!(edit.exercises[0].sets[0].weight, "135");
+
+    // Fix a typo in the logged set using the live-workout events
+    let set_id = edit.exercises[0].sets[0].id.clone();
+    app.update(
+        Event::UpdateSetActual {
+            set_id,
+            actual: SetActual::with_weight_and_reps(155.0, 10),
+        },
+        &mut model,
+        &(),
+    );
+    app.update(
+        Event::UpdateWorkoutName {
+            name: "Push Day (fixed)".to_string(),
+        },
+        &mut model,
+        &(),
+    );
+    app.update(
+        Event::AddExercise {
+            name: "Dips".to_string(),
+            exercise_type: "bodyweight".to_string(),
+            muscle_group: "chest".to_string(),
+        },
+        &mut model,
+        &(),
+    );
+
+    // Active workout is untouched
+    let active = model.current_workout.as_ref().unwrap();
+    assert!(active.exercises.is_empty());
+    assert!(active.name.is_empty());
+
+    // History is unchanged until the edits are saved
+    assert_eq!(model.workout_history[0].name, "Push Day");
+
+    let mut cmd = app.update(Event::SaveHistoryWorkoutEdits, &mut model, &());
+    let effects: Vec<Effect> = cmd.effects().collect();
+    let saved = effects
+        .iter()
+        .find_map(|effect| match effect {
+            Effect::Database(request) => match &request.operation {
+                DatabaseOperation::UpdateWorkout(workout) => Some(workout.clone()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .expect("Should request UpdateWorkout");
+    assert_eq!(saved.name, "Push Day (fixed)");
+    assert_eq!(saved.exercises.len(), 2);
+
+    assert!(model.editing_history_workout.is_none());
+    assert_eq!(model.workout_history[0].name, "Push Day (fixed)");
+    assert_eq!(model.workout_history[0].total_volume(), 1550.0);
+
+    app.update(
+        Event::DatabaseResponse {
+            result: DatabaseResult::WorkoutUpdated,
+        },
+        &mut model,
+        &(),
+    );
+    assert!(model.latest_error_message().is_none());
+}
+
+#[test]
+fn test_cancel_history_workout_edits_discards_changes() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    let past = finished_bench_workout("Push Day");
+    let workout_id = past.id.to_string();
+    model.workout_history = vec![past.clone()];
+    open_history_workout(&app, &mut model, &past);
+
+    app.update(Event::EditHistoryWorkout { workout_id }, &mut model, &());
+    app.update(
+        Event::UpdateWorkoutName {
+            name: "Renamed".to_string(),
+        },
+        &mut model,
+        &(),
+    );
+    app.update(Event::CancelHistoryWorkoutEdits, &mut model, &());
+
+    assert!(model.editing_history_workout.is_none());
+    assert_eq!(model.workout_history[0].name, "Push Day");
+    assert!(app.view(&model).history_edit_view.is_none());
+}
+
+#[test]
+fn test_edit_unknown_history_workout_shows_error() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    app.update(
+        Event::EditHistoryWorkout {
+            workout_id: Id::new().to_string(),
+        },
+        &mut model,
+        &(),
+    );
+    assert_eq!(model.latest_error_message(), Some("Workout not found"));
+
+    app.update(
+        Event::EditHistoryWorkout {
+            workout_id: "not-a-uuid".to_string(),
+        },
+        &mut model,
+        &(),
+    );
+    assert!(model
+        .latest_error_message()
+        .unwrap()
+        .contains("Invalid workout ID"));
+    assert!(model.editing_history_workout.is_none());
+}
+
+#[test]
+fn test_edit_needs_the_fully_loaded_history_workout() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    // The history list holds a summary without exercises
+    let stored = finished_bench_workout("Push Day");
+    let mut summary = stored.clone();
+    summary.exercises.clear();
+    model.workout_history = vec![summary];
+    let workout_id = stored.id.to_string();
+
+    // Neither the list copy nor the detail view's placeholder can be edited
+    app.update(
+        Event::EditHistoryWorkout {
+            workout_id: workout_id.clone(),
+        },
+        &mut model,
+        &(),
+    );
+    app.update(
+        Event::ViewHistoryItem {
+            workout_id: workout_id.clone(),
+        },
+        &mut model,
+        &(),
+    );
+    app.update(
+        Event::EditHistoryWorkout {
+            workout_id: workout_id.clone(),
+        },
+        &mut model,
+        &(),
+    );
+    assert!(model.editing_history_workout.is_none());
+    assert_eq!(
+        model.latest_error_message(),
+        Some("Open the workout and wait for it to load before editing it")
+    );
+
+    // Once loaded, the edit starts from the full workout
+    app.update(
+        Event::DatabaseResponse {
+            result: DatabaseResult::WorkoutLoaded {
+                workout: Some(stored),
+            },
+        },
+        &mut model,
+        &(),
+    );
+    app.update(Event::EditHistoryWorkout { workout_id }, &mut model, &());
+    let editing = model.editing_history_workout.as_ref().unwrap();
+    assert_eq!(editing.exercises[0].sets.len(), 1);
+}
+
+/// Builds a finished workout for history paging tests.
+fn history_workout(name: &str, start: &str, exercise: &str) -> Workout {
+    let mut workout = Workout::with_name(name);
+    workout.start_timestamp = start.parse().unwrap();
+    workout.add_exercise(exercise);
+    workout
+}
+
+/// Extracts the single LoadWorkoutsPage query from a command's effects.
+fn page_query(cmd: &mut Command<Effect, Event>) -> HistoryQuery {
+    cmd.effects()
+        .find_map(|effect| match effect {
+            Effect::Database(request) => match &request.operation {
+                DatabaseOperation::LoadWorkoutsPage(query) => Some(query.clone()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .expect("Expected a LoadWorkoutsPage request")
+}
+
+fn page_loaded(query: HistoryQuery, workouts: &[&Workout], has_more: bool) -> Event {
+    Event::DatabaseResponse {
+        result: DatabaseResult::HistoryPageLoaded {
+            query,
+            workouts: workouts.iter().map(|w| (*w).clone()).collect(),
+            failures: Vec::new(),
+            has_more,
+        },
+    }
+}
+
+#[test]
+fn test_history_pagination_flow() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    let newest = history_workout("Push", "2025-01-03T10:00:00Z", "Bench Press");
+    let middle = history_workout("Pull", "2025-01-02T10:00:00Z", "Row");
+    let oldest = history_workout("Legs", "2025-01-01T10:00:00Z", "Squat");
+
+    // First page has no cursor
+    let mut cmd = app.update(Event::LoadHistory, &mut model, &());
+    let query = page_query(&mut cmd);
+    assert_eq!(query.cursor, None);
+    assert_eq!(query.limit, HISTORY_PAGE_SIZE);
+    assert!(model.is_loading);
+
+    app.update(page_loaded(query, &[&newest, &middle], true), &mut model, &());
+    let view = app.view(&model);
+    assert_eq!(view.history_view.workouts.len(), 2);
+    assert!(view.history_view.has_more);
+    assert!(!view.history_view.is_filtered);
+
+    // Next page continues after the oldest loaded workout
+    let mut cmd = app.update(Event::LoadMoreHistory, &mut model, &());
+    let query = page_query(&mut cmd);
+    assert_eq!(query.cursor, Some(HistoryCursor::after(&middle)));
+
+    // Overlapping rows are not duplicated
+    app.update(page_loaded(query, &[&middle, &oldest], false), &mut model, &());
+    let names: Vec<_> = model.workout_history.iter().map(|w| w.name.as_str()).collect();
+    assert_eq!(names, ["Push", "Pull", "Legs"]);
+    assert!(!app.view(&model).history_view.has_more);
+
+    // Nothing left to load
+    let mut cmd = app.update(Event::LoadMoreHistory, &mut model, &());
+    assert!(!cmd
+        .effects()
+        .any(|effect| matches!(effect, Effect::Database(_))));
+}
+
+#[test]
+fn test_history_filter_reloads_and_ignores_stale_pages() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    let push = history_workout("Push", "2025-01-03T10:00:00Z", "Bench Press");
+    let legs = history_workout("Legs", "2025-01-01T10:00:00Z", "Squat");
+    model.workout_history = vec![push.clone(), legs.clone()];
+
+    let mut cmd = app.update(Event::LoadHistory, &mut model, &());
+    let unfiltered_query = page_query(&mut cmd);
+
+    // Filtering requests a fresh first page with the filter attached
+    let filter = HistoryFilter {
+        exercise_name: Some("Squat".to_string()),
+        ..Default::default()
+    };
+    let mut cmd = app.update(
+        Event::SetHistoryFilter {
+            filter: filter.clone(),
+        },
+        &mut model,
+        &(),
+    );
+    let filtered_query = page_query(&mut cmd);
+    assert_eq!(filtered_query.cursor, None);
+    assert_eq!(filtered_query.filter, filter);
+
+    // The unfiltered page arrives late and is dropped
+    app.update(page_loaded(unfiltered_query, &[&push, &legs], false), &mut model, &());
+    app.update(page_loaded(filtered_query, &[&legs], false), &mut model, &());
+
+    let view = app.view(&model);
+    assert_eq!(view.history_view.workouts.len(), 1);
+    assert_eq!(view.history_view.workouts[0].name, "Legs");
+    assert!(view.history_view.is_filtered);
+    assert_eq!(view.history_view.active_filter, filter);
+
+    // A finished workout that doesn't match stays out of the filtered list
+    app.update(Event::StartWorkout, &mut model, &());
+    app.update(Event::FinishWorkout, &mut model, &());
+    assert_eq!(model.workout_history.len(), 1);
+
+    // Clearing the filter reloads everything
+    let mut cmd = app.update(Event::ClearHistoryFilter, &mut model, &());
+    let query = page_query(&mut cmd);
+    assert!(query.filter.is_empty());
+    assert!(!app.view(&model).history_view.is_filtered);
+}
+
+#[test]
+fn test_history_load_quarantines_unreadable_workouts() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    let good = history_workout("Push", "2025-01-03T10:00:00Z", "Bench Press");
+    let mut bad_ids = history_workout("Pull", "2025-01-02T10:00:00Z", "Row");
+    // Deserialization bypasses Id validation, like a corrupt database row would
+    bad_ids.exercises[0].id = serde_json::from_str("\"not-a-uuid\"").unwrap();
+
+    // The shell also reports a row it could not decode at all
+    let unreadable = WorkoutLoadFailure {
+        workout_id: "legacy-row-7".to_string(),
+        error: "startTimestamp is NULL".to_string(),
+        raw_payload: "id=legacy-row-7 name=Legs startTimestamp=NULL".to_string(),
+    };
+
+    app.update(
+        Event::DatabaseResponse {
+            result: DatabaseResult::HistoryLoaded {
+                workouts: vec![good.clone(), bad_ids.clone()],
+                failures: vec![unreadable.clone()],
+            },
+        },
+        &mut model,
+        &(),
+    );
+
+    // Only the valid workout is listed
+    assert_eq!(model.workout_history.len(), 1);
+    assert_eq!(model.workout_history[0].id, good.id);
+
+    // The load report has the count and every failure
+    let report = model.last_history_load.as_ref().expect("Load report recorded");
+    assert_eq!(report.loaded, 1);
+    let failed_ids: Vec<_> = report.failures.iter().map(|f| f.workout_id.as_str()).collect();
+    assert_eq!(failed_ids, ["legacy-row-7", bad_ids.id.as_str()]);
+    assert!(report.failures[1].error.contains("Invalid exercise ID"));
+    assert_eq!(model.quarantined_workouts.len(), 2);
+
+    // The view surfaces the problem with an exportable payload
+    let view = app.view(&model);
+    let problems = view.history_view.load_problems.expect("Problems shown");
+    assert_eq!(problems.message, "2 workouts could not be read");
+    assert_eq!(problems.workouts.len(), 2);
+    assert!(problems.export_payload.contains("startTimestamp=NULL"));
+    assert!(problems.export_payload.contains("not-a-uuid"));
+
+    // Dismissing clears the quarantine
+    app.update(Event::DismissCorruptWorkouts, &mut model, &());
+    assert!(model.quarantined_workouts.is_empty());
+    assert!(app.view(&model).history_view.load_problems.is_none());
+}
+
+#[test]
+fn test_history_pages_accumulate_quarantine_until_reload() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    let failure = |id: &str| WorkoutLoadFailure {
+        workout_id: id.to_string(),
+        error: "unreadable".to_string(),
+        raw_payload: String::new(),
+    };
+    let page = |cursor: Option<HistoryCursor>, failures: Vec<WorkoutLoadFailure>| {
+        Event::DatabaseResponse {
+            result: DatabaseResult::HistoryPageLoaded {
+                query: HistoryQuery {
+                    cursor,
+                    limit: HISTORY_PAGE_SIZE,
+                    filter: HistoryFilter::default(),
+                },
+                workouts: Vec::new(),
+                failures,
+                has_more: true,
+            },
+        }
+    };
+    let cursor = HistoryCursor::after(&Workout::new());
+
+    app.update(page(None, vec![failure("a")]), &mut model, &());
+    app.update(page(Some(cursor.clone()), vec![failure("b")]), &mut model, &());
+    assert_eq!(model.quarantined_workouts.len(), 2);
+    assert_eq!(
+        app.view(&model).history_view.load_problems.unwrap().message,
+        "2 workouts could not be read"
+    );
+
+    // A fresh first page starts a new quarantine
+    app.update(page(None, vec![failure("c")]), &mut model, &());
+    assert_eq!(model.quarantined_workouts.len(), 1);
+    assert_eq!(
+        app.view(&model).history_view.load_problems.unwrap().message,
+        "1 workout could not be read"
+    );
+}
+
+#[test]
+fn test_loaded_workouts_are_upgraded_to_current_schema() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    // Rows written before versioning report schema version 1
+    let mut legacy = history_workout("Push", "2025-01-03T10:00:00Z", "Bench Press");
+    legacy.schema_version = 1;
+    // Rows from a newer build can't be trusted and are quarantined
+    let mut future = history_workout("Pull", "2025-01-02T10:00:00Z", "Row");
+    future.schema_version = WORKOUT_SCHEMA_VERSION + 1;
+
+    app.update(
+        Event::DatabaseResponse {
+            result: DatabaseResult::HistoryLoaded {
+                workouts: vec![legacy.clone(), future.clone()],
+                failures: Vec::new(),
+            },
+        },
+        &mut model,
+        &(),
+    );
+
+    assert_eq!(model.workout_history.len(), 1);
+    assert_eq!(model.workout_history[0].id, legacy.id);
+    assert_eq!(model.workout_history[0].schema_version, WORKOUT_SCHEMA_VERSION);
+    assert_eq!(model.quarantined_workouts.len(), 1);
+    assert_eq!(model.quarantined_workouts[0].workout_id, future.id.as_str());
+    assert!(model.quarantined_workouts[0].error.contains("newer than supported"));
+}
+
+#[test]
+fn test_restoring_workout_from_newer_schema_shows_error() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    let mut workout = Workout::with_name("From the future");
+    workout.schema_version = WORKOUT_SCHEMA_VERSION + 1;
+
+    let _ = app.update(
+        Event::StorageResponse {
+            result: StorageResult::CurrentWorkoutLoaded {
+                workout: Some(workout),
+            },
+        },
+        &mut model,
+        &(),
+    );
+
+    assert!(model.current_workout.is_none());
+    assert!(!model.timer_running);
+    assert!(model
+        .latest_error_message()
+        .unwrap()
+        .contains("Failed to restore workout"));
+}
+
+#[test]
+fn test_import_legacy_unversioned_workout_is_migrated() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    // Exports from before versioning have no schema_version field
+    let legacy_json = r#"{
+        "id": "11111111-1111-4111-8111-111111111111",
+        "name": "Legacy Workout",
+        "note": null,
+        "duration": null,
+        "start_timestamp": "2025-01-01T12:00:00Z",
+        "end_timestamp": null,
+        "exercises": []
+    }"#;
+
+    app.update(
+        Event::ImportWorkout {
+            json_data: legacy_json.to_string(),
+        },
+        &mut model,
+        &(),
+    );
+
+    let workout = model.current_workout.as_ref().expect("Legacy workout imported");
+    assert_eq!(workout.name, "Legacy Workout");
+    assert_eq!(workout.schema_version, WORKOUT_SCHEMA_VERSION);
+    assert!(model.latest_error_message().is_none());
+}
+
+#[test]
+fn test_import_rejects_workout_failing_domain_validation() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    let mut workout = Workout::with_name("Bad Data");
+    let exercise = workout.add_exercise("Squat");
+    exercise.add_set().actual.rpe = Some(12.0);
+    exercise.add_set().set_index = 5;
+
+    app.update(
+        Event::ImportWorkout {
+            json_data: serde_json::to_string(&workout).unwrap(),
+        },
+        &mut model,
+        &(),
+    );
+
+    assert!(model.current_workout.is_none());
+    let message = model.latest_error_message().expect("Validation error shown");
+    assert!(message.starts_with("Invalid workout data"));
+    assert!(message.contains("RPE 12 at exercise 0 set 0"));
+    assert!(message.contains("has set_index 5, expected 1"));
+}
+
+#[test]
+fn test_restore_rejects_workout_failing_domain_validation() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    let mut workout = Workout::with_name("Corrupt Restore");
+    workout.add_exercise("Deadlift").workout_id = Id::new();
+
+    let _ = app.update(
+        Event::StorageResponse {
+            result: StorageResult::CurrentWorkoutLoaded {
+                workout: Some(workout),
+            },
+        },
+        &mut model,
+        &(),
+    );
+
+    assert!(model.current_workout.is_none());
+    let message = model.latest_error_message().expect("Validation error shown");
+    assert!(message.contains("Exercise at index 0 belongs to a different workout"));
+}
+
+#[test]
+fn test_errors_queue_instead_of_overwriting() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    app.update(
+        Event::ImportWorkout {
+            json_data: "{ invalid json }".to_string(),
+        },
+        &mut model,
+        &(),
+    );
+    app.update(
+        Event::DatabaseResponse {
+            result: DatabaseResult::Error {
+                message: "disk I/O error".to_string(),
+            },
+        },
+        &mut model,
+        &(),
+    );
+
+    assert_eq!(model.errors.len(), 2);
+    assert!(matches!(model.errors[0].error, AppError::Import { .. }));
+    assert_eq!(model.errors[0].severity, ErrorSeverity::Recoverable);
+    assert_eq!(model.errors[0].source_event, "ImportWorkout");
+    assert_eq!(model.errors[1].error, AppError::database("disk I/O error"));
+    assert_eq!(model.errors[1].severity, ErrorSeverity::Fatal);
+    assert_eq!(model.errors[1].source_event, "DatabaseResponse");
+    assert_ne!(model.errors[0].id, model.errors[1].id);
+}
+
+#[test]
+fn test_successful_action_keeps_fatal_errors() {
+    let app = Thiccc;
+    let mut model = Model::default();
+    model.report_error(AppError::validation("Bad input"));
+    model.report_error(AppError::storage("Could not save workout"));
+
+    app.update(Event::StartWorkout, &mut model, &());
+
+    assert_eq!(model.errors.len(), 1);
+    assert_eq!(model.errors[0].error, AppError::storage("Could not save workout"));
+}
+
+#[test]
+fn test_dismiss_errors() {
+    let app = Thiccc;
+    let mut model = Model::default();
+    model.report_error(AppError::storage("First"));
+    model.report_error(AppError::database("Second"));
+    model.report_error(AppError::import("Third"));
+
+    let first_id = model.errors[0].id;
+    app.update(Event::DismissError { error_id: first_id }, &mut model, &());
+    let remaining: Vec<_> = model.errors.iter().map(|r| r.error.message()).collect();
+    assert_eq!(remaining, ["Second", "Third"]);
+
+    app.update(Event::DismissAllErrors, &mut model, &());
+    assert!(model.errors.is_empty());
+}
+
+#[test]
+fn test_timer_failure_is_reported_as_timer_error() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    app.update(
+        Event::TimerResponse {
+            output: TimerOutput::Error {
+                message: "Run loop unavailable".to_string(),
+            },
+        },
+        &mut model,
+        &(),
+    );
+
+    assert_eq!(model.errors.len(), 1);
+    assert_eq!(model.errors[0].error, AppError::timer("Run loop unavailable"));
+    assert_eq!(model.errors[0].source_event, "TimerResponse");
+}
+
+const STRONG_EXPORT: &str = "\
+Date,Workout Name,Duration,Exercise Name,Set Order,Weight,Reps,Distance,Seconds,Notes,Workout Notes,RPE
+2025-03-01 08:00:00,Legs,1h,Squat (Barbell),1,225,5,0,0,,,
+2025-03-01 08:00:00,Legs,1h,Sissy Squat,1,0,12,0,0,,,
+2025-03-03 08:00:00,Push,50m,Bench Press (Barbell),1,185,5,0,0,,,
+";
+
+fn preview_strong_export(app: &Thiccc, model: &mut Model) {
+    let _ = app.update(
+        Event::PreviewCsvImport {
+            csv_data: STRONG_EXPORT.to_string(),
+            options: CsvImportOptions::default(),
+        },
+        model,
+        &(),
+    );
+}
+
+#[test]
+fn test_csv_import_preview_saves_nothing_until_confirmed() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    preview_strong_export(&app, &mut model);
+
+    assert!(model.workout_history.is_empty());
+    let view = app.view(&model);
+    let preview = view.csv_import_preview.expect("Preview shown");
+    assert_eq!(preview.source, "Strong");
+    assert_eq!(preview.workout_count, 2);
+    assert_eq!(preview.exercise_count, 3);
+    assert_eq!(preview.set_count, 3);
+    assert_eq!(preview.date_range, "Mar 01, 2025 – Mar 03, 2025");
+    assert_eq!(preview.unmatched_exercises, ["Sissy Squat"]);
+    assert_eq!(preview.workouts[0].name, "Push");
+}
+
+/// Extracts the workouts sent in an ImportWorkouts request.
+fn imported_workouts(cmd: &mut Command<Effect, Event>) -> Vec<Workout> {
+    cmd.effects()
+        .find_map(|effect| match effect {
+            Effect::Database(request) => match &request.operation {
+                DatabaseOperation::ImportWorkouts(workouts) => Some(workouts.clone()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .expect("Expected an ImportWorkouts request")
+}
+
+#[test]
+fn test_confirm_csv_import_sends_one_bulk_import() {
+    let app = Thiccc;
+    let mut model = Model::default();
+    let _ = app.update(Event::ShowImportView, &mut model, &());
+
+    preview_strong_export(&app, &mut model);
+    let mut cmd = app.update(Event::ConfirmCsvImport, &mut model, &());
+
+    let names: Vec<_> = imported_workouts(&mut cmd).into_iter().map(|w| w.name).collect();
+    assert_eq!(names, ["Push", "Legs"]);
+    assert!(model.csv_import_preview.is_none());
+    assert!(!model.showing_import);
+    assert!(model.is_loading);
+    // History is reloaded once the shell reports back
+    assert!(model.workout_history.is_empty());
+}
+
+#[test]
+fn test_bulk_import_skips_duplicates_of_loaded_history() {
+    let app = Thiccc;
+    let mut model = Model::default();
+    // Same start time and name as the "Push" row, imported earlier with another id
+    model
+        .workout_history
+        .push(history_workout("Push", "2025-03-03T08:00:00Z", "Barbell Bench Press"));
+
+    preview_strong_export(&app, &mut model);
+    let mut cmd = app.update(Event::ConfirmCsvImport, &mut model, &());
+
+    let sent = imported_workouts(&mut cmd);
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].name, "Legs");
+    let pending = model.pending_import.as_ref().expect("Local summary kept");
+    assert_eq!(pending.skipped.len(), 1);
+    assert_eq!(pending.skipped[0].name, "Push");
+}
+
+#[test]
+fn test_workouts_imported_merges_summary_and_reloads_history() {
+    let app = Thiccc;
+    let mut model = Model::default();
+    model
+        .workout_history
+        .push(history_workout("Push", "2025-03-03T08:00:00Z", "Barbell Bench Press"));
+
+    preview_strong_export(&app, &mut model);
+    let mut cmd = app.update(Event::ConfirmCsvImport, &mut model, &());
+    let sent = imported_workouts(&mut cmd);
+
+    let mut cmd = app.update(
+        Event::DatabaseResponse {
+            result: DatabaseResult::WorkoutsImported {
+                summary: ImportSummary {
+                    inserted: sent.iter().map(ImportEntry::of).collect(),
+                    ..Default::default()
+                },
+            },
+        },
+        &mut model,
+        &(),
+    );
+
+    let query = page_query(&mut cmd);
+    assert!(query.cursor.is_none());
+    assert!(model.pending_import.is_none());
+    assert!(!model.is_loading);
+
+    let summary = app.view(&model).import_summary.expect("Summary shown");
+    assert_eq!(summary.message, "Imported 1 workout, skipped 1 duplicate");
+    assert_eq!(summary.inserted_count, 1);
+    assert_eq!(summary.skipped_count, 1);
+
+    let _ = app.update(Event::DismissImportSummary, &mut model, &());
+    assert!(app.view(&model).import_summary.is_none());
+}
+
+#[test]
+fn test_bulk_import_reports_conflicts_without_reloading() {
+    let app = Thiccc;
+    let mut model = Model::default();
+    let conflicting = history_workout("Pull", "2025-03-02T08:00:00Z", "Deadlift");
+
+    let mut cmd = app.update(
+        Event::DatabaseResponse {
+            result: DatabaseResult::WorkoutsImported {
+                summary: ImportSummary {
+                    conflicting: vec![ImportEntry::of(&conflicting)],
+                    ..Default::default()
+                },
+            },
+        },
+        &mut model,
+        &(),
+    );
+
+    assert!(!cmd
+        .effects()
+        .any(|effect| matches!(effect, Effect::Database(_))));
+    let summary = app.view(&model).import_summary.expect("Summary shown");
+    assert_eq!(summary.message, "Imported 0 workouts, 1 conflict not imported");
+    assert_eq!(summary.conflicts, ["Pull"]);
+}
+
+#[test]
+fn test_import_workout_array_goes_to_history() {
+    let app = Thiccc;
+    let mut model = Model::default();
+    let workouts = vec![
+        history_workout("Push", "2025-03-03T08:00:00Z", "Barbell Bench Press"),
+        history_workout("Pull", "2025-03-02T08:00:00Z", "Deadlift"),
+    ];
+
+    let mut cmd = app.update(
+        Event::ImportWorkout {
+            json_data: serde_json::to_string(&workouts).unwrap(),
+        },
+        &mut model,
+        &(),
+    );
+
+    assert_eq!(imported_workouts(&mut cmd), workouts);
+    assert!(model.current_workout.is_none());
+}
+
+#[test]
+fn test_import_workout_array_rejects_invalid_entry() {
+    let app = Thiccc;
+    let mut model = Model::default();
+    let mut bad = history_workout("Bad", "2025-03-02T08:00:00Z", "Deadlift");
+    bad.exercises[0].add_set().actual.rpe = Some(11.0);
+    let workouts = vec![history_workout("Push", "2025-03-03T08:00:00Z", "Dips"), bad];
+
+    let mut cmd = app.update(
+        Event::ImportWorkout {
+            json_data: serde_json::to_string(&workouts).unwrap(),
+        },
+        &mut model,
+        &(),
+    );
+
+    assert!(!cmd
+        .effects()
+        .any(|effect| matches!(effect, Effect::Database(_))));
+    let message = model.latest_error_message().expect("Import error shown");
+    assert!(message.starts_with("Failed to import workouts: Workout 1:"));
+}
+
+#[test]
+fn test_csv_import_errors_and_cancel() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    let _ = app.update(
+        Event::PreviewCsvImport {
+            csv_data: "name,weight\nBench,100\n".to_string(),
+            options: CsvImportOptions::default(),
+        },
+        &mut model,
+        &(),
+    );
+    assert!(model.csv_import_preview.is_none());
+    let message = model.latest_error_message().expect("Import error shown");
+    assert!(message.starts_with("Failed to import CSV: Unrecognized CSV format"));
+    assert_eq!(model.errors[0].source_event, "PreviewCsvImport");
+
+    preview_strong_export(&app, &mut model);
+    assert!(model.errors.is_empty());
+
+    let _ = app.update(Event::CancelCsvImport, &mut model, &());
+    assert!(model.csv_import_preview.is_none());
+    assert!(model.workout_history.is_empty());
+}
+
+// =============================================================================
+// MARK: - History Export
+// =============================================================================
+
+/// Extracts the ShareFile request from a command's effects.
+fn shared_file(cmd: &mut Command<Effect, Event>) -> (String, String, String) {
+    cmd.effects()
+        .find_map(|effect| match effect {
+            Effect::Share(request) => match &request.operation {
+                ShareOperation::ShareFile {
+                    file_name,
+                    mime_type,
+                    contents,
+                } => Some((file_name.clone(), mime_type.clone(), contents.clone())),
+                _ => None,
+            },
+            _ => None,
+        })
+        .expect("Expected a ShareFile request")
+}
+
+#[test]
+fn test_export_history_loads_full_history() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    let mut cmd = app.update(
+        Event::ExportHistory {
+            format: ExportFormat::Csv,
+        },
+        &mut model,
+        &(),
+    );
+
+    assert!(model.is_loading);
+    assert!(cmd.effects().any(|effect| matches!(
+        effect,
+        Effect::Database(request) if request.operation == DatabaseOperation::LoadAllWorkouts
+    )));
+}
+
+#[test]
+fn test_exported_archive_shares_and_imports_back() {
+    let app = Thiccc;
+    let mut model = Model::default();
+    let workouts = vec![
+        history_workout("Push", "2025-03-03T08:00:00Z", "Barbell Bench Press"),
+        history_workout("Pull", "2025-03-02T08:00:00Z", "Deadlift"),
+    ];
+    let unreadable = WorkoutLoadFailure {
+        workout_id: "broken".to_string(),
+        error: "Unreadable".to_string(),
+        raw_payload: "{}".to_string(),
+    };
+
+    let mut cmd = app.update(
+        Event::ExportHistoryLoaded {
+            format: ExportFormat::JsonArchive,
+            result: DatabaseResult::HistoryLoaded {
+                workouts: workouts.clone(),
+                failures: vec![unreadable],
+            },
+        },
+        &mut model,
+        &(),
+    );
+
+    let (file_name, mime_type, contents) = shared_file(&mut cmd);
+    assert!(file_name.starts_with("thiccc-history-") && file_name.ends_with(".json"));
+    assert_eq!(mime_type, "application/json");
+    // Exporting doesn't replace the loaded history or quarantine anything
+    assert!(model.workout_history.is_empty());
+    assert!(model.quarantined_workouts.is_empty());
+
+    let mut cmd = app.update(
+        Event::ImportWorkout {
+            json_data: contents,
+        },
+        &mut model,
+        &(),
+    );
+    assert_eq!(imported_workouts(&mut cmd), workouts);
+}
+
+#[test]
+fn test_export_csv_and_markdown_contents() {
+    let app = Thiccc;
+    let mut model = Model::default();
+    let mut workout = history_workout("Push", "2025-03-03T08:00:00Z", "Barbell Bench Press");
+    let set = workout.exercises[0].add_set();
+    set.actual.weight = Some(185.0);
+    set.actual.reps = Some(5);
+
+    let mut cmd = app.update(
+        Event::ExportHistoryLoaded {
+            format: ExportFormat::Csv,
+            result: DatabaseResult::HistoryLoaded {
+                workouts: vec![workout.clone()],
+                failures: vec![],
+            },
+        },
+        &mut model,
+        &(),
+    );
+    let (file_name, mime_type, contents) = shared_file(&mut cmd);
+    assert!(file_name.ends_with(".csv"));
+    assert_eq!(mime_type, "text/csv");
+    assert_eq!(contents.lines().count(), 2);
+    assert!(contents.lines().nth(1).unwrap().contains("Barbell Bench Press"));
+
+    let mut cmd = app.update(
+        Event::ExportHistoryLoaded {
+            format: ExportFormat::Markdown,
+            result: DatabaseResult::HistoryLoaded {
+                workouts: vec![workout],
+                failures: vec![],
+            },
+        },
+        &mut model,
+        &(),
+    );
+    let (file_name, _, contents) = shared_file(&mut cmd);
+    assert!(file_name.ends_with(".md"));
+    assert!(contents.contains("## Push"));
+    assert!(contents.contains("### Barbell Bench Press"));
+}
+
+#[test]
+fn test_export_and_share_failures_are_reported() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    let _ = app.update(
+        Event::ExportHistoryLoaded {
+            format: ExportFormat::Csv,
+            result: DatabaseResult::Error {
+                message: "disk I/O error".to_string(),
+            },
+        },
+        &mut model,
+        &(),
+    );
+    assert_eq!(
+        model.latest_error_message(),
+        Some("Failed to load history: disk I/O error")
+    );
+    assert_eq!(model.errors[0].error.title(), "Export Failed");
+
+    let _ = app.update(
+        Event::ShareResponse {
+            result: ShareResult::Cancelled,
+        },
+        &mut model,
+        &(),
+    );
+    assert_eq!(model.errors.len(), 1);
+
+    let _ = app.update(
+        Event::ShareResponse {
+            result: ShareResult::Failed {
+                message: "Could not write file".to_string(),
+            },
+        },
+        &mut model,
+        &(),
+    );
+    assert_eq!(model.latest_error_message(), Some("Could not write file"));
+}
+
+// =============================================================================
+// MARK: - Workout Summary
+// =============================================================================
+
+/// Extracts the text of a ShareText or CopyText request.
+fn shared_text(cmd: &mut Command<Effect, Event>) -> ShareOperation {
+    cmd.effects()
+        .find_map(|effect| match effect {
+            Effect::Share(request) => Some(request.operation.clone()),
+            _ => None,
+        })
+        .expect("Expected a Share request")
+}
+
+/// Finishes the active workout and answers its request for past sessions.
+fn finish_with_sessions(app: &Thiccc, model: &mut Model, sessions: Vec<Workout>) {
+    let mut cmd = app.update(Event::FinishWorkout, model, &());
+    let exercise_names = cmd
+        .effects()
+        .find_map(|effect| match effect {
+            Effect::Database(request) => match &request.operation {
+                DatabaseOperation::LoadExerciseHistory(names) => Some(names.clone()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .expect("Expected a LoadExerciseHistory request");
+    assert!(app.view(model).workout_summary.is_none());
+
+    let _ = app.update(
+        Event::DatabaseResponse {
+            result: DatabaseResult::ExerciseHistoryLoaded {
+                exercise_names,
+                workouts: sessions,
+                failures: vec![],
+            },
+        },
+        model,
+        &(),
+    );
+}
+
+#[test]
+fn test_finish_workout_builds_summary_with_records() {
+    let app = Thiccc;
+    let mut model = Model::default();
+    let mut earlier = history_workout("Push", "2025-03-01T08:00:00Z", "Bench Press");
+    earlier.exercises[0]
+        .add_set()
+        .complete(SetActual::with_weight_and_reps(175.0, 5));
+
+    let _ = app.update(Event::StartWorkout, &mut model, &());
+    let workout = model.current_workout.as_mut().unwrap();
+    workout.name = "Push".to_string();
+    let exercise = workout.add_exercise("Bench Press");
+    exercise.add_set().complete(SetActual::with_weight_and_reps(185.0, 5));
+    exercise.add_set();
+    finish_with_sessions(&app, &mut model, vec![earlier]);
+
+    let view = app.view(&model);
+    let summary = view.workout_summary.expect("Summary shown after finishing");
+    assert_eq!(summary.workout_name, "Push");
+    assert_eq!(summary.personal_records, ["Bench Press · e1RM 215.8 lb"]);
+    assert!(summary.text.contains("1 set · Volume 925 lb"));
+    assert!(summary.text.contains("Best: 185 lb × 5 reps (PR)"));
+
+    let mut cmd = app.update(Event::ShareWorkoutSummary, &mut model, &());
+    assert_eq!(
+        shared_text(&mut cmd),
+        ShareOperation::ShareText {
+            text: summary.text.clone()
+        }
+    );
+    let mut cmd = app.update(Event::CopyWorkoutSummary, &mut model, &());
+    assert_eq!(
+        shared_text(&mut cmd),
+        ShareOperation::CopyText { text: summary.text }
+    );
+
+    let _ = app.update(Event::DismissWorkoutSummary, &mut model, &());
+    assert!(app.view(&model).workout_summary.is_none());
+    let mut cmd = app.update(Event::ShareWorkoutSummary, &mut model, &());
+    assert!(!cmd.effects().any(|effect| matches!(effect, Effect::Share(_))));
+}
+
+#[test]
+fn test_summary_records_use_sessions_outside_history_page() {
+    let app = Thiccc;
+    // A full first page of lighter recent sessions
+    let page: Vec<Workout> = (0..HISTORY_PAGE_SIZE)
+        .map(|day| {
+            let start = format!("2025-04-{:02}T08:00:00Z", day + 1);
+            let mut recent = history_workout("Push", &start, "Bench Press");
+            recent.exercises[0]
+                .add_set()
+                .complete(SetActual::with_weight_and_reps(135.0, 5));
+            recent
+        })
+        .collect();
+    let mut model = Model {
+        workout_history: page,
+        history_has_more: true,
+        ..Default::default()
+    };
+    // An older best that only the database knows about
+    let mut older = history_workout("Push", "2025-01-01T08:00:00Z", "Bench Press");
+    older.exercises[0]
+        .add_set()
+        .complete(SetActual::with_weight_and_reps(225.0, 5));
+    let mut sessions = model.workout_history.clone();
+    sessions.push(older);
+
+    let _ = app.update(Event::StartWorkout, &mut model, &());
+    model
+        .current_workout
+        .as_mut()
+        .unwrap()
+        .add_exercise("Bench Press")
+        .add_set()
+        .complete(SetActual::with_weight_and_reps(185.0, 5));
+    finish_with_sessions(&app, &mut model, sessions);
+
+    let summary = app.view(&model).workout_summary.expect("Summary shown");
+    assert!(summary.personal_records.is_empty());
+    assert!(!summary.text.contains("(PR)"));
+}
+
+#[test]
+fn test_summary_without_completed_sets_needs_no_sessions() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    let _ = app.update(Event::StartWorkout, &mut model, &());
+    model.current_workout.as_mut().unwrap().add_exercise("Squat").add_set();
+    let mut cmd = app.update(Event::FinishWorkout, &mut model, &());
+
+    assert!(!cmd.effects().any(|effect| matches!(
+        effect,
+        Effect::Database(request)
+            if matches!(request.operation, DatabaseOperation::LoadExerciseHistory(_))
+    )));
+    assert!(app.view(&model).workout_summary.is_some());
+}
+
+#[test]
+fn test_summary_volume_uses_preferred_unit() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    let _ = app.update(Event::SetPreferredWeightUnit { unit: WeightUnit::Kg }, &mut model, &());
+    let _ = app.update(Event::StartWorkout, &mut model, &());
+    model
+        .current_workout
+        .as_mut()
+        .unwrap()
+        .add_exercise("Deadlift")
+        .add_set()
+        .complete(SetActual::with_weight_and_reps(225.0, 2));
+    finish_with_sessions(&app, &mut model, vec![]);
+
+    let view = app.view(&model);
+    assert_eq!(view.preferred_weight_unit, WeightUnit::Kg);
+    // 450 lb of volume is about 204 kg; the set itself keeps its own unit
+    let text = view.workout_summary.unwrap().text;
+    assert!(text.contains("Volume 204 kg"));
+    assert!(text.contains("Best: 225 lb × 2 reps"));
+}
+
+// =============================================================================
+// MARK: - Watch Import
+// =============================================================================
+
+#[test]
+fn test_import_fit_file_goes_to_history() {
+    let app = Thiccc;
+    let mut model = Model::default();
+    let _ = app.update(Event::ShowImportView, &mut model, &());
+
+    let mut cmd = app.update(
+        Event::ImportWatchFile {
+            data: include_bytes!("../../../fixtures/watch/strength.fit").to_vec(),
+        },
+        &mut model,
+        &(),
+    );
+
+    let workouts = imported_workouts(&mut cmd);
+    assert_eq!(workouts.len(), 1);
+    assert_eq!(workouts[0].exercises[0].name, "Barbell Bench Press");
+    assert!(!model.showing_import);
+    assert!(model.is_loading);
+}
+
+#[test]
+fn test_import_watch_file_without_strength_workouts_reports_why() {
+    let app = Thiccc;
+    let mut model = Model::default();
+    let json = r#"{"workouts": [{"workoutActivityType": "HKWorkoutActivityTypeRunning",
+        "startDate": "2025-03-04T07:00:00Z"}]}"#;
+
+    let mut cmd = app.update(
+        Event::ImportWatchFile {
+            data: json.as_bytes().to_vec(),
+        },
+        &mut model,
+        &(),
+    );
+
+    assert!(!cmd
+        .effects()
+        .any(|effect| matches!(effect, Effect::Database(_))));
+    assert_eq!(
+        model.latest_error_message(),
+        Some(
+            "No strength workouts to import from Apple Health: \
+             Workout 1: HKWorkoutActivityTypeRunning is not a strength workout"
+        )
+    );
+
+    let _ = app.update(
+        Event::ImportWatchFile {
+            data: b"not a workout file".to_vec(),
+        },
+        &mut model,
+        &(),
+    );
+    assert!(model
+        .latest_error_message()
+        .unwrap()
+        .starts_with("Failed to import watch file: Not a FIT file"));
+}
+
+// -------------------------------------------------------------------------
+// Sync
+// -------------------------------------------------------------------------
+
+/// Extracts the request body of the single sync exchange in a command.
+fn sync_request(cmd: &mut Command<Effect, Event>) -> SyncRequest {
+    cmd.effects()
+        .find_map(|effect| match effect {
+            Effect::Http(request) => match &request.operation {
+                HttpOperation::Send(request) => {
+                    Some(serde_json::from_str(request.body.as_deref().unwrap()).unwrap())
+                }
+            },
+            _ => None,
+        })
+        .expect("Expected a sync exchange")
+}
+
+fn saved_sync_state(cmd: &mut Command<Effect, Event>) -> Option<SyncState> {
+    cmd.effects().find_map(|effect| match effect {
+        Effect::Storage(request) => match &request.operation {
+            StorageOperation::SaveSyncState(state) => Some(state.clone()),
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
+fn signed_in() -> ApiSession {
+    ApiSession {
+        base_url: Some("https://api.example.com".to_string()),
+        token: Some("token-123".to_string()),
+    }
+}
+
+/// A signed-in model whose sync state has loaded and whose history is
+/// already recorded.
+fn synced_model() -> Model {
+    Model {
+        api: signed_in(),
+        sync: SyncState {
+            seeded: true,
+            ..SyncState::new_replica()
+        },
+        ..Model::default()
+    }
+}
+
+fn http_response(status: u16, body: &str) -> HttpResult {
+    HttpResult::Response(HttpResponse {
+        status,
+        headers: vec![],
+        body: body.to_string(),
+    })
+}
+
+fn sync_ok_result(response: &SyncResponse) -> HttpResult {
+    http_response(200, &serde_json::json!({ "data": response }).to_string())
+}
+
+fn sync_ok(response: &SyncResponse) -> Event {
+    Event::SyncResponse {
+        result: sync_ok_result(response),
+    }
+}
+
+/// Plays the shell for HTTP: answers each request with the next scripted
+/// result, records what was sent, and feeds resulting events back into the
+/// app. Other effects are left unanswered.
+struct FakeHttpShell {
+    results: std::collections::VecDeque<HttpResult>,
+    sent: Vec<HttpRequest>,
+}
+
+impl FakeHttpShell {
+    fn new(results: impl IntoIterator<Item = HttpResult>) -> Self {
+        Self {
+            results: results.into_iter().collect(),
+            sent: Vec::new(),
+        }
+    }
+
+    fn run(&mut self, app: &Thiccc, model: &mut Model, event: Event) {
+        let mut commands = vec![app.update(event, model, &())];
+        while let Some(mut cmd) = commands.pop() {
+            loop {
+                let mut progressed = false;
+                let effects: Vec<_> = cmd.effects().collect();
+                for effect in effects {
+                    if let Effect::Http(mut request) = effect {
+                        let HttpOperation::Send(sent) = request.operation.clone();
+                        self.sent.push(sent);
+                        let result = self.results.pop_front().expect("Unexpected HTTP request");
+                        request.resolve(result).unwrap();
+                        progressed = true;
+                    }
+                }
+                let events: Vec<_> = cmd.events().collect();
+                for event in events {
+                    commands.push(app.update(event, model, &()));
+                    progressed = true;
+                }
+                if !progressed {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_initialize_restores_sync_state_or_creates_replica() {
+    let app = Thiccc;
+    let mut model = Model::default();
+
+    let mut cmd = app.update(Event::Initialize, &mut model, &());
+    assert!(cmd.effects().any(|effect| matches!(
+        effect,
+        Effect::Storage(request) if request.operation == StorageOperation::LoadSyncState
+    )));
+
+    // Nothing can be sent until the state has loaded
+    let mut cmd = app.update(Event::SyncNow, &mut model, &());
+    assert_eq!(cmd.effects().count(), 0);
+
+    let mut cmd = app.update(
+        Event::StorageResponse {
+            result: StorageResult::SyncStateLoaded { state: None },
+        },
+        &mut model,
+        &(),
+    );
+    assert!(!model.sync.replica_id.is_empty());
+    assert_eq!(saved_sync_state(&mut cmd), Some(model.sync.clone()));
+
+    let stored = SyncState {
+        cursor: 7,
+        ..SyncState::new_replica()
+    };
+    app.update(
+        Event::StorageResponse {
+            result: StorageResult::SyncStateLoaded {
+                state: Some(stored.clone()),
+            },
+        },
+        &mut model,
+        &(),
+    );
+    assert_eq!(model.sync, stored);
+}
+
+#[test]
+fn test_first_sync_records_existing_history() {
+    let app = Thiccc;
+    let mut model = Model {
+        api: signed_in(),
+        sync: SyncState::new_replica(),
+        ..Model::default()
+    };
+    let stored = history_workout("Push", "2025-03-01T08:00:00Z", "Bench Press");
+
+    let mut cmd = app.update(Event::SyncNow, &mut model, &());
+    assert!(model.is_syncing);
+    assert!(cmd.effects().any(|effect| matches!(
+        effect,
+        Effect::Database(request) if request.operation == DatabaseOperation::LoadAllWorkouts
+    )));
+
+    let mut cmd = app.update(
+        Event::SyncHistoryLoaded {
+            result: DatabaseResult::HistoryLoaded {
+                workouts: vec![stored.clone()],
+                failures: vec![],
+            },
+        },
+        &mut model,
+        &(),
+    );
+    assert!(model.sync.seeded);
+    let request = sync_request(&mut cmd);
+    assert_eq!(request.cursor, 0);
+    assert_eq!(
+        sync::apply_changes(None, &request.changes),
+        Ok(Some(stored))
+    );
+}
+
+#[test]
+fn test_first_sync_reports_unreadable_workouts() {
+    let app = Thiccc;
+    let mut model = Model {
+        api: signed_in(),
+        sync: SyncState::new_replica(),
+        ..Model::default()
+    };
+    let stored = history_workout("Push", "2025-03-01T08:00:00Z", "Bench Press");
+    let unreadable = WorkoutLoadFailure {
+        workout_id: Id::new().into_string(),
+        error: "Unrecognised stored value 'trebuchet'".to_string(),
+        raw_payload: "[id:...]".to_string(),
+    };
+
+    app.update(Event::SyncNow, &mut model, &());
+    let mut cmd = app.update(
+        Event::SyncHistoryLoaded {
+            result: DatabaseResult::HistoryLoaded {
+                workouts: vec![stored],
+                failures: vec![unreadable.clone()],
+            },
+        },
+        &mut model,
+        &(),
+    );
+
+    // The readable workout still syncs
+    assert!(!sync_request(&mut cmd).changes.is_empty());
+    assert_eq!(
+        model.errors.last().unwrap().error,
+        AppError::sync("1 workout could not be read and was not synced")
+    );
+    assert_eq!(model.quarantined_workouts, vec![unreadable]);
+}
+
+#[test]
+fn test_sync_never_writes_a_partial_workout() {
+    let app = Thiccc;
+    let mut model = synced_model();
+    let mut stored = history_workout("Legs", "2025-03-02T08:00:00Z", "Squat");
+    stored.exercises[0].add_set();
+    model.workout_history = vec![stored.clone()];
+
+    let tablet = Clock {
+        counter: 40,
+        replica: "tablet".to_string(),
+    };
+    // A set the server can't build, and a rename of a workout it never sent
+    let set_id = stored.exercises[0].sets[0].id.as_str();
+    let mut changes: Vec<_> = sync::diff(None, Some(&stored), &tablet)
+        .into_iter()
+        .filter(|change| !(change.entity_id == set_id && change.field == "actual"))
+        .collect();
+    let elsewhere = history_workout("Arms", "2025-03-01T08:00:00Z", "Curl");
+    let mut renamed = elsewhere.clone();
+    renamed.name = "Arm Day".to_string();
+    changes.extend(sync::diff(Some(&elsewhere), Some(&renamed), &tablet));
+
+    app.update(Event::SyncNow, &mut model, &());
+    let response = SyncResponse {
+        cursor: 3,
+        changes,
+        ..Default::default()
+    };
+    let mut cmd = app.update(sync_ok(&response), &mut model, &());
+
+    assert!(!cmd.effects().any(|effect| matches!(
+        effect,
+        Effect::Database(request) if matches!(
+            request.operation,
+            DatabaseOperation::UpdateWorkout(_) | DatabaseOperation::DeleteWorkout(_)
+        )
+    )));
+    assert_eq!(model.workout_history, vec![stored]);
+    assert_eq!(model.errors.len(), 2);
+    assert!(model
+        .latest_error_message()
+        .unwrap()
+        .ends_with("the server sent only part of it"));
+}
+
+#[test]
+fn test_edits_are_recorded_and_acknowledged() {
+    let app = Thiccc;
+    let mut model = synced_model();
+
+    app.update(Event::StartWorkout, &mut model, &());
+    let mut cmd = app.update(Event::FinishWorkout, &mut model, &());
+    let recorded = model.sync.pending.len();
+    assert!(recorded > 0);
+    assert_eq!(saved_sync_state(&mut cmd), Some(model.sync.clone()));
+
+    let mut cmd = app.update(Event::SyncNow, &mut model, &());
+    let request = sync_request(&mut cmd);
+    assert_eq!(request.changes.len(), recorded);
+    assert_eq!(app.view(&model).sync.pending_changes, recorded);
+
+    // A second trigger while the first is running does nothing
+    let mut again = app.update(Event::SyncNow, &mut model, &());
+    assert_eq!(again.effects().count(), 0);
+
+    // An edit made while the request is in flight stays pending
+    let finished = model.workout_history[0].clone();
+    let workout_id = finished.id.as_str().to_string();
+    open_history_workout(&app, &mut model, &finished);
+    app.update(
+        Event::EditHistoryWorkout {
+            workout_id: workout_id.clone(),
+        },
+        &mut model,
+        &(),
+    );
+    app.update(
+        Event::UpdateWorkoutName {
+            name: "Evening Push".to_string(),
+        },
+        &mut model,
+        &(),
+    );
+    app.update(Event::SaveHistoryWorkoutEdits, &mut model, &());
+
+    let mut cmd = app.update(
+        sync_ok(&SyncResponse {
+            cursor: 12,
+            changes: request.changes.clone(),
+            ..Default::default()
+        }),
+        &mut model,
+        &(),
+    );
+
+    assert!(!model.is_syncing);
+    assert_eq!(model.sync.cursor, 12);
+    assert_eq!(model.sync.pending.len(), 1);
+    assert_eq!(model.sync.pending[0].field, "name");
+    assert!(model.last_synced_at.is_some());
+    // The server's state plus the pending rename is written back
+    let written = cmd
+        .effects()
+        .find_map(|effect| match effect {
+            Effect::Database(request) => match &request.operation {
+                DatabaseOperation::UpdateWorkout(workout) => Some(workout.clone()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .expect("Expected the synced workout to be written");
+    assert_eq!(written.id, finished.id);
+    assert_eq!(written.name, "Evening Push");
+    assert_eq!(model.workout_history[0].name, "Evening Push");
+}
+
+#[test]
+fn test_sync_applies_remote_workouts_and_deletes() {
+    let app = Thiccc;
+    let mut model = synced_model();
+    let kept = history_workout("Legs", "2025-03-02T08:00:00Z", "Squat");
+    let deleted = history_workout("Arms", "2025-03-01T08:00:00Z", "Curl");
+    model.workout_history = vec![kept.clone(), deleted.clone()];
+
+    let remote = history_workout("Pull", "2025-03-03T08:00:00Z", "Deadlift");
+    let tablet = Clock {
+        counter: 40,
+        replica: "tablet".to_string(),
+    };
+    let mut changes = sync::diff(None, Some(&remote), &tablet);
+    changes.extend(sync::diff(Some(&deleted), None, &tablet));
+
+    app.update(Event::SyncNow, &mut model, &());
+    let response = SyncResponse {
+        cursor: 3,
+        changes,
+        ..Default::default()
+    };
+    let mut cmd = app.update(sync_ok(&response), &mut model, &());
+
+    let names: Vec<_> = model.workout_history.iter().map(|w| w.name.as_str()).collect();
+    assert_eq!(names, ["Pull", "Legs"]);
+    assert!(model.sync.counter >= 40, "the clock moves past remote changes");
+    assert!(cmd.effects().any(|effect| matches!(
+        effect,
+        Effect::Database(request)
+            if request.operation == DatabaseOperation::DeleteWorkout(deleted.id.as_str().to_string())
+    )));
+}
+
+#[test]
+fn test_sync_reports_refused_changes_and_takes_the_server_copy() {
+    let app = Thiccc;
+    let mut model = synced_model();
+    let stored = history_workout("Legs", "2025-03-02T08:00:00Z", "Squat");
+    let mut edited = stored.clone();
+    edited.name = "Leg Day".into();
+    model.workout_history = vec![edited.clone()];
+    model.sync.record(Some(&stored), Some(&edited));
+
+    let server = Clock {
+        counter: 5,
+        replica: "server".to_string(),
+    };
+    app.update(Event::SyncNow, &mut model, &());
+    let response = SyncResponse {
+        cursor: 4,
+        changes: sync::diff(None, Some(&stored), &server),
+        rejected: vec![SyncRejection {
+            workout_id: stored.id.as_str().to_string(),
+            error: "RPE 15 at exercise 0 set 0 must be between 1 and 10".to_string(),
+        }],
+    };
+    let mut cmd = app.update(sync_ok(&response), &mut model, &());
+
+    assert_eq!(
+        model.latest_error_message(),
+        Some(
+            format!(
+                "The server refused changes to workout {}: RPE 15 at exercise 0 set 0 must be between 1 and 10",
+                stored.id.as_str()
+            )
+            .as_str()
+        )
+    );
+    assert!(model.sync.pending.is_empty(), "refused changes are dropped");
+    assert_eq!(model.workout_history[0].name, "Legs");
+    assert!(cmd.effects().any(|effect| matches!(
+        effect,
+        Effect::Database(request)
+            if request.operation == DatabaseOperation::UpdateWorkout(stored.clone())
+    )));
+}
+
+#[test]
+fn test_sync_reloads_exercise_names_once_after_its_writes() {
+    let app = Thiccc;
+    let mut model = synced_model();
+    let deleted = history_workout("Arms", "2025-03-01T08:00:00Z", "Curl");
+    model.workout_history = vec![deleted.clone()];
+
+    let tablet = Clock {
+        counter: 40,
+        replica: "tablet".to_string(),
+    };
+    let mut changes = Vec::new();
+    for name in ["Pull", "Legs"] {
+        let remote = history_workout(name, "2025-03-03T08:00:00Z", "Deadlift");
+        changes.extend(sync::diff(None, Some(&remote), &tablet));
+    }
+    changes.extend(sync::diff(Some(&deleted), None, &tablet));
+
+    app.update(Event::SyncNow, &mut model, &());
+    let response = SyncResponse {
+        cursor: 3,
+        changes,
+        ..Default::default()
+    };
+    let mut cmd = app.update(sync_ok(&response), &mut model, &());
+
+    let writes: Vec<_> = cmd
+        .effects()
+        .filter_map(|effect| match effect {
+            Effect::Database(request) => Some(request),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(writes.len(), 3, "two updates and a delete");
+    for mut write in writes {
+        let result = match write.operation {
+            DatabaseOperation::DeleteWorkout(_) => DatabaseResult::WorkoutDeleted,
+            _ => DatabaseResult::WorkoutUpdated,
+        };
+        write.resolve(result).unwrap();
+    }
+    for event in cmd.events().collect::<Vec<_>>() {
+        app.update(event, &mut model, &());
+    }
+
+    // One reload once every write is done
+    let reloads: Vec<_> = cmd
+        .effects()
+        .filter(|effect| matches!(
+            effect,
+            Effect::Database(request) if request.operation == DatabaseOperation::LoadExerciseNames
+        ))
+        .collect();
+    assert_eq!(reloads.len(), 1);
+}
+
+#[test]
+fn test_exercise_names_leave_the_loading_state_alone() {
+    let app = Thiccc;
+    // A history page is still loading when the names come back
+    let mut model = Model {
+        is_loading: true,
+        ..Default::default()
+    };
+    app.update(
+        Event::DatabaseResponse {
+            result: DatabaseResult::ExerciseNamesLoaded {
+                names: vec!["Squat".to_string()],
+            },
+        },
+        &mut model,
+        &(),
+    );
+
+    assert!(model.is_loading);
+    assert!(model.has_exercise_history("squat"));
+}
+
+#[test]
+fn test_sync_failures_are_reported_and_keep_changes() {
+    let app = Thiccc;
+    let mut model = synced_model();
+    app.update(Event::StartWorkout, &mut model, &());
+    app.update(Event::FinishWorkout, &mut model, &());
+    let pending = model.sync.pending.clone();
+
+    for (result, message) in [
+        (http_response(401, ""), "Sign in to sync your workouts"),
+        (
+            http_response(500, "Database error"),
+            "The server returned an error (500): Database error",
+        ),
+        (HttpResult::TimedOut, "The server took too long to respond"),
+        (
+            HttpResult::Failed {
+                message: "The Internet connection appears to be offline.".to_string(),
+            },
+            "Couldn't reach the server: The Internet connection appears to be offline.",
+        ),
+    ] {
+        app.update(Event::SyncNow, &mut model, &());
+        app.update(Event::SyncResponse { result }, &mut model, &());
+
+        assert!(!model.is_syncing);
+        assert_eq!(model.errors.last().unwrap().error, AppError::sync(message));
+        assert_eq!(model.sync.pending, pending);
+    }
+}
+
+#[test]
+fn test_sync_sends_an_authenticated_request() {
+    let app = Thiccc;
+    let mut model = synced_model();
+    app.update(Event::StartWorkout, &mut model, &());
+    app.update(Event::FinishWorkout, &mut model, &());
+    let pending = model.sync.pending.clone();
+
+    let mut shell = FakeHttpShell::new([sync_ok_result(&SyncResponse {
+        cursor: 7,
+        changes: pending.clone(),
+        ..Default::default()
+    })]);
+    shell.run(&app, &mut model, Event::SyncNow);
+
+    let [request] = shell.sent.as_slice() else {
+        panic!("Expected one request, sent {:?}", shell.sent);
+    };
+    assert_eq!(request.method, HttpMethod::Post);
+    assert_eq!(request.url, "https://api.example.com/api/sync");
+    assert_eq!(request.header_value("Authorization"), Some("Bearer token-123"));
+    assert_eq!(request.header_value("Content-Type"), Some("application/json"));
+    assert_eq!(request.delay_ms, 0);
+    assert!(request.timeout_ms > 0);
+    let body: SyncRequest = serde_json::from_str(request.body.as_deref().unwrap()).unwrap();
+    assert_eq!(body.changes, pending);
+
+    assert!(!model.is_syncing);
+    assert_eq!(model.sync.cursor, 7);
+    assert!(model.sync.pending.is_empty());
+}
+
+#[test]
+fn test_sync_retries_transient_failures_with_backoff() {
+    let app = Thiccc;
+    let mut model = synced_model();
+
+    let mut shell = FakeHttpShell::new([
+        HttpResult::Failed {
+            message: "offline".to_string(),
+        },
+        http_response(503, ""),
+        sync_ok_result(&SyncResponse {
+            cursor: 2,
+            changes: vec![],
+            ..Default::default()
+        }),
+    ]);
+    shell.run(&app, &mut model, Event::SyncNow);
+
+    let delays: Vec<_> = shell.sent.iter().map(|request| request.delay_ms).collect();
+    assert_eq!(delays, [0, 500, 1000]);
+    assert!(shell.sent.iter().all(|request| request.body == shell.sent[0].body));
+    assert_eq!(model.sync.cursor, 2);
+    assert!(model.errors.is_empty());
+}
+
+#[test]
+fn test_sync_gives_up_after_the_last_attempt() {
+    let app = Thiccc;
+    let mut model = synced_model();
+
+    let mut shell = FakeHttpShell::new([
+        HttpResult::TimedOut,
+        HttpResult::TimedOut,
+        HttpResult::TimedOut,
+    ]);
+    shell.run(&app, &mut model, Event::SyncNow);
+
+    assert_eq!(shell.sent.len(), 3);
+    assert!(!model.is_syncing);
+    assert_eq!(
+        model.errors.last().unwrap().error,
+        AppError::sync("The server took too long to respond")
+    );
+}
+
+#[test]
+fn test_sync_does_not_retry_rejected_requests() {
+    let app = Thiccc;
+    let mut model = synced_model();
+
+    let mut shell = FakeHttpShell::new([http_response(401, "")]);
+    shell.run(&app, &mut model, Event::SyncNow);
+
+    assert_eq!(shell.sent.len(), 1);
+    assert_eq!(
+        model.errors.last().unwrap().error,
+        AppError::sync("Sign in to sync your workouts")
+    );
+}
+
+#[test]
+fn test_sync_waits_until_signed_in() {
+    let app = Thiccc;
+    let mut model = Model {
+        api: ApiSession::default(),
+        ..synced_model()
+    };
+
+    let mut shell = FakeHttpShell::new([sync_ok_result(&SyncResponse {
+        cursor: 1,
+        changes: vec![],
+        ..Default::default()
+    })]);
+    shell.run(&app, &mut model, Event::SyncNow);
+    assert!(shell.sent.is_empty());
+    assert!(model.errors.is_empty());
+
+    // Signing in syncs right away
+    shell.run(
+        &app,
+        &mut model,
+        Event::ApiSessionChanged {
+            base_url: Some("https://api.example.com".to_string()),
+            token: Some("fresh".to_string()),
+        },
+    );
+    assert_eq!(shell.sent.len(), 1);
+    assert_eq!(shell.sent[0].header_value("Authorization"), Some("Bearer fresh"));
+    assert_eq!(model.sync.cursor, 1);
+}
@@ -15,6 +15,9 @@ fn test_model_default() {
 
     // History should be empty
     assert!(model.workout_history.is_empty());
+    assert!(model.selected_history_workout.is_none());
+    assert!(model.editing_history_workout.is_none());
+    assert!(model.pending_delete_workout_id.is_none());
 
     // Should start on Workout tab
     assert_eq!(model.selected_tab, Tab::Workout);
@@ -32,7 +35,7 @@ fn test_model_default() {
 
     // No loading or error state
     assert!(!model.is_loading);
-    assert!(model.error_message.is_none());
+    assert!(model.errors.is_empty());
 }
 
 #[test]
@@ -95,6 +98,24 @@ fn test_model_find_set_mut() {
     assert!(model.find_set_mut(&another_id).is_none());
 }
 
+#[test]
+fn test_model_editable_workout_prefers_history_edit() {
+    let mut model = Model::default();
+    assert!(model.editable_workout_mut().is_none());
+
+    // Falls back to the current workout
+    let current_id = model.get_or_create_workout().id.clone();
+    assert_eq!(model.editable_workout_mut().unwrap().id, current_id);
+
+    // A history workout being edited takes precedence
+    let mut past = Workout::with_name("Past");
+    let set_id = past.add_exercise("Row").add_set().id.clone();
+    let past_id = past.id.clone();
+    model.editing_history_workout = Some(past);
+    assert_eq!(model.editable_workout_mut().unwrap().id, past_id);
+    assert!(model.find_set_mut(&set_id).is_some());
+}
+
 #[test]
 fn test_model_calculate_total_volume() {
     let mut model = Model::default();
@@ -137,6 +158,51 @@ fn test_model_calculate_total_sets() {
     assert_eq!(model.calculate_total_sets(), 3);
 }
 
+#[test]
+fn test_model_exercise_sessions() {
+    let mut older = Workout::with_name("Push A");
+    older.add_exercise("Bench Press");
+    older.add_exercise("Overhead Press");
+
+    let mut newer = Workout::with_name("Push B");
+    newer.add_exercise("bench press");
+
+    // Loaded exercise history is stored newest first
+    let model = Model {
+        exercise_history: vec![newer, older],
+        exercises_with_history: vec!["Bench Press".to_string()],
+        ..Default::default()
+    };
+
+    assert!(model.has_exercise_history("bench press"));
+    assert!(!model.has_exercise_history("Squat"));
+
+    // Matches by name, case-insensitively, preserving history order
+    let sessions = model.exercise_sessions("Bench Press");
+    assert_eq!(sessions.len(), 2);
+    assert_eq!(sessions[0].0.name, "Push B");
+    assert_eq!(sessions[1].0.name, "Push A");
+
+    assert_eq!(model.exercise_sessions("Overhead Press").len(), 1);
+    assert!(model.exercise_sessions("Squat").is_empty());
+}
+
+#[test]
+fn test_model_exercise_names_fold_non_ascii_case() {
+    let mut older = Workout::with_name("Beine");
+    older.add_exercise("Übergriff Kniebeuge");
+
+    let model = Model {
+        exercise_history: vec![older],
+        exercises_with_history: vec!["Übergriff Kniebeuge".to_string()],
+        ..Default::default()
+    };
+
+    // Case folding covers non-ASCII names, like the API server's
+    assert!(model.has_exercise_history("übergriff kniebeuge"));
+    assert_eq!(model.exercise_sessions("ÜBERGRIFF KNIEBEUGE").len(), 1);
+}
+
 #[test]
 fn test_model_format_duration() {
     // Test various durations
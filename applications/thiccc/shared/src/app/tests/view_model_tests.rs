@@ -12,11 +12,14 @@ fn test_database_result_serialization() {
         serde_json::from_str(&json).expect("Failed to deserialize");
     assert_eq!(result, deserialized);
 
-    // Test HistoryLoaded with JSON strings
-    let workout = Workout::new();
-    let workout_json = serde_json::to_string(&workout).expect("Failed to serialize workout");
+    // Test HistoryLoaded with typed workouts
     let result2 = DatabaseResult::HistoryLoaded {
-        workouts_json: vec![workout_json],
+        workouts: vec![Workout::new()],
+        failures: vec![WorkoutLoadFailure {
+            workout_id: "bad-id".to_string(),
+            error: "Unreadable row".to_string(),
+            raw_payload: "{}".to_string(),
+        }],
     };
     let json2 = serde_json::to_string(&result2).expect("Failed to serialize");
     let deserialized2: DatabaseResult =
@@ -42,6 +45,20 @@ fn test_storage_result_serialization() {
     assert_eq!(result, deserialized);
 }
 
+#[test]
+fn test_storage_result_bincode_roundtrip() {
+    // Bincode is the format the shell hands back to the core
+    let mut workout = Workout::with_name("In Progress");
+    workout.add_exercise("Deadlift").add_set();
+    let result = StorageResult::CurrentWorkoutLoaded {
+        workout: Some(workout),
+    };
+    let bytes = bincode::serialize(&result).expect("Failed to serialize");
+    let deserialized: StorageResult =
+        bincode::deserialize(&bytes).expect("Failed to deserialize");
+    assert_eq!(result, deserialized);
+}
+
 // -------------------------------------------------------------------------
 // ViewModel Tests
 // -------------------------------------------------------------------------
@@ -57,8 +74,13 @@ fn test_view_model_default() {
     assert!(!vm.workout_view.has_active_workout);
     assert!(vm.history_view.workouts.is_empty());
 
+    // Should not be drilled into a past workout or exercise history
+    assert!(vm.history_detail_view.is_none());
+    assert!(vm.exercise_history_view.is_none());
+
     // Should have no error or loading state
-    assert!(vm.error_message.is_none());
+    assert!(vm.errors.recoverable.is_empty());
+    assert!(vm.errors.fatal.is_none());
     assert!(!vm.is_loading);
 }
 
@@ -100,6 +122,7 @@ fn test_exercise_view_model_serialization() {
         id: Id::new().as_str().to_string(),
         name: "Bench Press".to_string(),
         sets: vec![],
+        has_history: false,
     };
 
     let json = serde_json::to_string(&vm).expect("Failed to serialize");
@@ -150,3 +173,25 @@ fn test_history_item_view_model_serialization() {
     assert_eq!(vm.exercise_count, deserialized.exercise_count);
 }
 
+
+#[test]
+fn test_error_queue_view_separates_recoverable_and_fatal() {
+    let app = Thiccc;
+    let mut model = Model::default();
+    model.report_error(AppError::validation("Bad weight"));
+    model.report_error(AppError::database("Database unavailable"));
+    model.report_error(AppError::storage("Could not save workout"));
+    model.attribute_errors(0, "Initialize");
+
+    let view = app.view(&model);
+
+    assert_eq!(view.errors.recoverable.len(), 1);
+    assert_eq!(view.errors.recoverable[0].title, "Invalid Input");
+    assert_eq!(view.errors.recoverable[0].message, "Bad weight");
+
+    let fatal = view.errors.fatal.expect("Oldest fatal error shown");
+    assert_eq!(fatal.id, model.errors[1].id);
+    assert_eq!(fatal.title, "Database Error");
+    assert_eq!(fatal.source_event, "Initialize");
+    assert_eq!(view.errors.pending_fatal_count, 1);
+}
@@ -6,6 +6,9 @@
 use serde::{Deserialize, Serialize};
 
 use super::events::Tab;
+use crate::models::WeightUnit;
+use crate::operations::HistoryFilter;
+use crate::timestamp::Timestamp;
 
 // =============================================================================
 // MARK: - ViewModels
@@ -26,8 +29,24 @@ pub struct ViewModel {
     pub workout_view: WorkoutViewModel,
     /// ViewModel for the history tab
     pub history_view: HistoryViewModel,
-    /// Current error message to display (if any)
-    pub error_message: Option<String>,
+    /// ViewModel for the history detail view (when viewing a past workout)
+    pub history_detail_view: Option<HistoryDetailViewModel>,
+    /// ViewModel for editing a past workout (when reopened from history)
+    pub history_edit_view: Option<HistoryEditViewModel>,
+    /// ViewModel for the exercise history view (when drilled into an exercise)
+    pub exercise_history_view: Option<ExerciseHistoryViewModel>,
+    /// Preview of a CSV import awaiting confirmation
+    pub csv_import_preview: Option<CsvImportPreviewViewModel>,
+    /// Outcome of the last bulk import (None once dismissed)
+    pub import_summary: Option<ImportSummaryViewModel>,
+    /// Summary of the just-finished workout (None once dismissed)
+    pub workout_summary: Option<WorkoutSummaryViewModel>,
+    /// Unit used for totals such as workout volume
+    pub preferred_weight_unit: WeightUnit,
+    /// Queued errors, split by how the UI should present them
+    pub errors: ErrorQueueViewModel,
+    /// Sync status
+    pub sync: SyncViewModel,
     /// Whether a loading operation is in progress
     pub is_loading: bool,
 }
@@ -90,6 +109,8 @@ pub struct ExerciseViewModel {
     pub name: String,
     /// Sets for this exercise
     pub sets: Vec<SetViewModel>,
+    /// Whether past sessions exist (enables the exercise history drill-down)
+    pub has_history: bool,
 }
 
 /// ViewModel for an individual set within an exercise.
@@ -130,6 +151,171 @@ pub struct HistoryViewModel {
     pub workouts: Vec<HistoryItemViewModel>,
     /// Whether history is currently loading from database
     pub is_loading: bool,
+    /// Pending delete confirmation (None if no delete was requested)
+    pub delete_confirmation: Option<DeleteConfirmationViewModel>,
+    /// Notice about stored workouts that could not be read (None if all loaded)
+    pub load_problems: Option<LoadProblemsViewModel>,
+    /// Whether more workouts can be loaded with LoadMoreHistory
+    pub has_more: bool,
+    /// Filter currently applied to the list
+    pub active_filter: HistoryFilter,
+    /// Whether any filter is applied (for showing a "clear filters" control)
+    pub is_filtered: bool,
+}
+
+/// ViewModel for the "delete this workout?" confirmation.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: A confirmation always refers to a specific workout the user
+/// asked to delete. No meaningful default exists.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DeleteConfirmationViewModel {
+    /// ID of the workout to delete
+    pub workout_id: String, // UUID as string for easier Swift interop
+    /// Workout name (for the confirmation message)
+    pub workout_name: String,
+}
+
+/// ViewModel for the "N workouts could not be read" notice in history.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: Only shown when at least one workout is quarantined; it is
+/// always built from real load failures.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct LoadProblemsViewModel {
+    /// Summary message (e.g., "2 workouts could not be read")
+    pub message: String,
+    /// The workouts that could not be read
+    pub workouts: Vec<CorruptWorkoutViewModel>,
+    /// JSON of every failure including its raw payload, for export/sharing
+    pub export_payload: String,
+}
+
+/// ViewModel for one workout that could not be read.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: Always describes a specific load failure.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CorruptWorkoutViewModel {
+    /// Stored workout ID (may be malformed)
+    pub workout_id: String,
+    /// Why it could not be read
+    pub error: String,
+}
+
+/// ViewModel for the queue of errors waiting to be shown.
+///
+/// **Default Trait: IMPLEMENTED**
+///
+/// Reasoning: An empty queue (no errors) is the normal state, and the root
+/// ViewModel derives Default.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct ErrorQueueViewModel {
+    /// Recoverable errors, oldest first (shown inline, e.g. as banners)
+    pub recoverable: Vec<ErrorViewModel>,
+    /// The oldest fatal error (shown as a blocking alert until dismissed)
+    pub fatal: Option<ErrorViewModel>,
+    /// Number of fatal errors queued behind `fatal`
+    pub pending_fatal_count: usize,
+}
+
+/// ViewModel for the sync status.
+///
+/// **Default Trait: IMPLEMENTED**
+///
+/// Reasoning: "Not syncing, nothing pending, never synced" is the state of a
+/// fresh install, and the root ViewModel derives Default.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct SyncViewModel {
+    /// Whether a sync is running
+    pub is_syncing: bool,
+    /// Number of changes not yet sent to the server
+    pub pending_changes: usize,
+    /// When the last sync finished (None if this device never synced)
+    pub last_synced_at: Option<Timestamp>,
+}
+
+/// ViewModel for one queued error.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: Always built from a real `ErrorRecord`; its id is needed to
+/// dismiss it.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ErrorViewModel {
+    /// Error id, sent back with `Event::DismissError`
+    pub id: u32,
+    /// Short title for the error category (e.g., "Import Failed")
+    pub title: String,
+    /// User-facing message
+    pub message: String,
+    /// Name of the event that raised the error (for diagnostics)
+    pub source_event: String,
+}
+
+/// ViewModel for reviewing a CSV import before it is saved.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: A preview only exists after a CSV file has been parsed. Each
+/// instance should be constructed from a real CsvImportPreview.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CsvImportPreviewViewModel {
+    /// App the export came from (e.g. "Strong")
+    pub source: String,
+    /// Number of workouts that will be imported
+    pub workout_count: usize,
+    /// Number of exercises across all workouts
+    pub exercise_count: usize,
+    /// Number of sets across all workouts
+    pub set_count: usize,
+    /// Formatted date range (e.g. "Jan 15, 2024 – Mar 02, 2024")
+    pub date_range: String,
+    /// Exercise names that aren't in the catalog and will be imported as-is
+    pub unmatched_exercises: Vec<String>,
+    /// Rows that will be left out, as "Line N: reason"
+    pub skipped_rows: Vec<String>,
+    /// Workouts that will be imported, newest first
+    pub workouts: Vec<HistoryItemViewModel>,
+}
+
+/// ViewModel for the outcome of a bulk import.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: A summary only exists after an import has finished. Each
+/// instance should be constructed from a real ImportSummary.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ImportSummaryViewModel {
+    /// One-line summary (e.g. "Imported 3 workouts, skipped 2 duplicates")
+    pub message: String,
+    /// Number of workouts written to history
+    pub inserted_count: usize,
+    /// Number of workouts already in history
+    pub skipped_count: usize,
+    /// Number of workouts whose id belongs to a different stored workout
+    pub conflicting_count: usize,
+    /// Names of the conflicting workouts
+    pub conflicts: Vec<String>,
+}
+
+/// ViewModel for the summary shown after finishing a workout.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: A summary only exists after a workout has been finished. Each
+/// instance should be constructed from a real WorkoutSummary.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct WorkoutSummaryViewModel {
+    /// Name of the finished workout
+    pub workout_name: String,
+    /// Full summary text, as shared or copied
+    pub text: String,
+    /// Exercises with a new personal record (e.g. "Bench Press · e1RM 215.8 lb")
+    pub personal_records: Vec<String>,
 }
 
 /// ViewModel for a single item in the history list.
@@ -164,6 +350,8 @@ pub struct HistoryItemViewModel {
 /// No meaningful default exists.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct HistoryDetailViewModel {
+    /// Unique identifier for this workout
+    pub id: String, // UUID as string for easier Swift interop
     /// Workout name
     pub workout_name: String,
     /// Formatted date (e.g., "Nov 26, 2025 at 3:45 PM")
@@ -180,6 +368,29 @@ pub struct HistoryDetailViewModel {
     pub total_sets: usize,
 }
 
+/// ViewModel for editing a past workout.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: HistoryEditViewModel is built from a specific workout reopened
+/// from history. Exercises reuse ExerciseViewModel so the shell can drive
+/// edits with the same set/exercise events as the live workout.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct HistoryEditViewModel {
+    /// Unique identifier for this workout
+    pub id: String, // UUID as string for easier Swift interop
+    /// Workout name
+    pub workout_name: String,
+    /// Workout notes
+    pub notes: Option<String>,
+    /// Exercises with editable sets
+    pub exercises: Vec<ExerciseViewModel>,
+    /// Total volume
+    pub total_volume: i32,
+    /// Total number of sets
+    pub total_sets: usize,
+}
+
 /// ViewModel for an exercise in the history detail view.
 ///
 /// **Default Trait: NOT implemented (Explicit Construction)**
@@ -208,6 +419,68 @@ pub struct SetDetailViewModel {
     pub display_text: String,
 }
 
+/// ViewModel for the history of a single exercise across past workouts.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: ExerciseHistoryViewModel is always built for a specific
+/// exercise the user drilled into. No meaningful default exists.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ExerciseHistoryViewModel {
+    /// Exercise name
+    pub exercise_name: String,
+    /// Past sessions of this exercise (newest first)
+    pub sessions: Vec<ExerciseSessionViewModel>,
+    /// Best estimated one-rep max across all sessions
+    pub best_estimated_one_rep_max: Option<f64>,
+    /// Estimated one-rep max per session (oldest first, for charting)
+    pub estimated_one_rep_max_series: Vec<ChartPointViewModel>,
+    /// Volume per session (oldest first, for charting)
+    pub volume_series: Vec<ChartPointViewModel>,
+    /// Heaviest weight per session (oldest first, for charting)
+    pub max_weight_series: Vec<ChartPointViewModel>,
+}
+
+/// ViewModel for one past session of an exercise.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: ExerciseSessionViewModel is constructed from an Exercise within
+/// a specific past Workout. No meaningful default exists.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ExerciseSessionViewModel {
+    /// ID of the workout this session belongs to
+    pub workout_id: String, // UUID as string for easier Swift interop
+    /// Workout name
+    pub workout_name: String,
+    /// Formatted date (e.g., "Nov 26, 2025")
+    pub date: String,
+    /// Best set display text (e.g., "225 lb × 10 reps @ 8.0 RPE")
+    pub best_set: Option<String>,
+    /// Estimated one-rep max of the best set
+    pub estimated_one_rep_max: Option<f64>,
+    /// Total volume for this exercise in the session
+    pub total_volume: i32,
+    /// Per-set breakdown
+    pub sets: Vec<SetDetailViewModel>,
+}
+
+/// A single data point in a chart series.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: ChartPointViewModel always represents a real measurement
+/// taken from a past session. No meaningful default exists.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ChartPointViewModel {
+    /// Session start time as Unix seconds (x-axis)
+    pub timestamp: i64,
+    /// Short axis label (e.g., "Nov 26")
+    pub label: String,
+    /// Measured value (y-axis)
+    pub value: f64,
+}
+
 /// ViewModel for the plate calculator.
 ///
 /// **Default Trait: IMPLEMENTED**
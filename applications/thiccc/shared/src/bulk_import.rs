@@ -0,0 +1,245 @@
+//! Deduplication rules for importing many workouts into history at once.
+//!
+//! A workout being imported is compared against stored workouts in two ways:
+//!
+//! - **By id** – the same workout exported from this app and imported again.
+//!   If the stored copy has the same fingerprint it's skipped; otherwise the
+//!   id is taken by a different workout and the import is a conflict (the
+//!   stored workout is never overwritten).
+//! - **By fingerprint** – the start time and (case-insensitive) name. Other
+//!   apps' exports don't carry our ids, so re-importing the same CSV creates
+//!   fresh ids every time; a fingerprint match means it's already stored and
+//!   it's skipped.
+//!
+//! The core applies these rules within the batch and against the history it
+//! has loaded; the shell applies the same rules against the whole database
+//! in the `ImportWorkouts` transaction and reports what it did.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::Workout;
+use crate::timestamp::Timestamp;
+
+/// Identifies "the same workout" across apps: start time and name.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WorkoutFingerprint {
+    /// When the workout started
+    pub start_timestamp: Timestamp,
+    /// Workout name, trimmed and lowercased
+    pub name: String,
+}
+
+impl WorkoutFingerprint {
+    /// Returns the fingerprint of a workout.
+    pub fn of(workout: &Workout) -> Self {
+        Self {
+            start_timestamp: workout.start_timestamp,
+            name: workout.name.trim().to_lowercase(),
+        }
+    }
+}
+
+/// A workout mentioned in an import summary.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: An entry always refers to a specific imported workout.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ImportEntry {
+    /// ID of the imported workout
+    pub workout_id: String,
+    /// Name of the imported workout
+    pub name: String,
+}
+
+impl ImportEntry {
+    /// Creates an entry for a workout.
+    pub fn of(workout: &Workout) -> Self {
+        Self {
+            workout_id: workout.id.as_str().to_string(),
+            name: workout.name.clone(),
+        }
+    }
+}
+
+/// Outcome of a bulk import.
+///
+/// **Default Trait: IMPLEMENTED**
+///
+/// Reasoning: An empty summary (nothing inserted, skipped or conflicting) is
+/// the natural starting point that results are merged into.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct ImportSummary {
+    /// Workouts written to the database
+    pub inserted: Vec<ImportEntry>,
+    /// Workouts already stored (same id or same fingerprint), left out
+    pub skipped: Vec<ImportEntry>,
+    /// Workouts whose id belongs to a different stored workout, left out
+    pub conflicting: Vec<ImportEntry>,
+}
+
+impl ImportSummary {
+    /// Adds another summary's entries to this one.
+    pub fn merge(&mut self, other: ImportSummary) {
+        self.inserted.extend(other.inserted);
+        self.skipped.extend(other.skipped);
+        self.conflicting.extend(other.conflicting);
+    }
+
+    /// Returns the total number of workouts accounted for.
+    pub fn total(&self) -> usize {
+        self.inserted.len() + self.skipped.len() + self.conflicting.len()
+    }
+}
+
+/// Workouts to send to the database, plus those already ruled out.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BulkImportPlan {
+    /// Workouts to send in `DatabaseOperation::ImportWorkouts`
+    pub to_insert: Vec<Workout>,
+    /// Workouts skipped or conflicting before reaching the database
+    /// (`inserted` is always empty)
+    pub summary: ImportSummary,
+}
+
+/// Applies the deduplication rules to `incoming` against `existing`
+/// workouts and earlier workouts in the same batch.
+///
+/// # Examples
+///
+/// ```
+/// use shared::bulk_import::plan_bulk_import;
+/// use shared::models::Workout;
+///
+/// let stored = Workout::with_name("Push");
+/// let mut reimported = Workout::with_name("push");
+/// reimported.start_timestamp = stored.start_timestamp;
+///
+/// let plan = plan_bulk_import(&[stored], vec![reimported, Workout::with_name("Pull")]);
+/// assert_eq!(plan.to_insert.len(), 1);
+/// assert_eq!(plan.summary.skipped.len(), 1);
+/// ```
+pub fn plan_bulk_import(existing: &[Workout], incoming: Vec<Workout>) -> BulkImportPlan {
+    let mut by_id: HashMap<String, WorkoutFingerprint> = existing
+        .iter()
+        .map(|w| (w.id.as_str().to_string(), WorkoutFingerprint::of(w)))
+        .collect();
+    let mut fingerprints: Vec<WorkoutFingerprint> = by_id.values().cloned().collect();
+
+    let mut to_insert = Vec::with_capacity(incoming.len());
+    let mut summary = ImportSummary::default();
+
+    for workout in incoming {
+        let fingerprint = WorkoutFingerprint::of(&workout);
+        match by_id.get(workout.id.as_str()) {
+            Some(stored) if *stored == fingerprint => summary.skipped.push(ImportEntry::of(&workout)),
+            Some(_) => summary.conflicting.push(ImportEntry::of(&workout)),
+            None if fingerprints.contains(&fingerprint) => {
+                summary.skipped.push(ImportEntry::of(&workout))
+            }
+            None => {
+                by_id.insert(workout.id.as_str().to_string(), fingerprint.clone());
+                fingerprints.push(fingerprint);
+                to_insert.push(workout);
+            }
+        }
+    }
+
+    BulkImportPlan { to_insert, summary }
+}
+
+// =============================================================================
+// MARK: - Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workout(name: &str, start: &str) -> Workout {
+        let mut workout = Workout::with_name(name);
+        workout.start_timestamp = start.parse().unwrap();
+        workout
+    }
+
+    #[test]
+    fn test_new_workouts_are_inserted() {
+        let stored = workout("Push", "2025-01-01T08:00:00Z");
+        let plan = plan_bulk_import(
+            &[stored],
+            vec![
+                workout("Pull", "2025-01-02T08:00:00Z"),
+                workout("Push", "2025-01-03T08:00:00Z"),
+            ],
+        );
+
+        assert_eq!(plan.to_insert.len(), 2);
+        assert_eq!(plan.summary, ImportSummary::default());
+    }
+
+    #[test]
+    fn test_same_id_and_fingerprint_is_skipped() {
+        let stored = workout("Push", "2025-01-01T08:00:00Z");
+        let plan = plan_bulk_import(std::slice::from_ref(&stored), vec![stored.clone()]);
+
+        assert!(plan.to_insert.is_empty());
+        assert_eq!(plan.summary.skipped, [ImportEntry::of(&stored)]);
+    }
+
+    #[test]
+    fn test_same_id_with_different_workout_conflicts() {
+        let stored = workout("Push", "2025-01-01T08:00:00Z");
+        let mut edited = stored.clone();
+        edited.name = "Legs".to_string();
+
+        let plan = plan_bulk_import(&[stored], vec![edited.clone()]);
+
+        assert!(plan.to_insert.is_empty());
+        assert_eq!(plan.summary.conflicting, [ImportEntry::of(&edited)]);
+    }
+
+    #[test]
+    fn test_fingerprint_match_with_new_id_is_skipped() {
+        let stored = workout("Push Day", "2025-01-01T08:00:00Z");
+        let reimported = workout("  push day ", "2025-01-01T08:00:00Z");
+
+        let plan = plan_bulk_import(&[stored], vec![reimported]);
+
+        assert!(plan.to_insert.is_empty());
+        assert_eq!(plan.summary.skipped.len(), 1);
+    }
+
+    #[test]
+    fn test_duplicates_within_batch_are_skipped() {
+        let first = workout("Push", "2025-01-01T08:00:00Z");
+        let plan = plan_bulk_import(
+            &[],
+            vec![
+                first.clone(),
+                first.clone(),
+                workout("Push", "2025-01-01T08:00:00Z"),
+            ],
+        );
+
+        assert_eq!(plan.to_insert, [first]);
+        assert_eq!(plan.summary.skipped.len(), 2);
+    }
+
+    #[test]
+    fn test_merge_combines_entries() {
+        let mut summary = ImportSummary {
+            skipped: vec![ImportEntry::of(&workout("A", "2025-01-01T08:00:00Z"))],
+            ..Default::default()
+        };
+        summary.merge(ImportSummary {
+            inserted: vec![ImportEntry::of(&workout("B", "2025-01-02T08:00:00Z"))],
+            conflicting: vec![ImportEntry::of(&workout("C", "2025-01-03T08:00:00Z"))],
+            ..Default::default()
+        });
+
+        assert_eq!(summary.total(), 3);
+        assert_eq!(summary.inserted[0].name, "B");
+    }
+}
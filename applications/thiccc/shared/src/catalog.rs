@@ -0,0 +1,220 @@
+//! Built-in exercise catalog and exercise-name matching.
+//!
+//! Mirrors the exercise library offered by the shell's "Add Exercise" screen
+//! so imported workouts can be mapped onto the same exercise names the user
+//! picks in the app. Other apps name exercises differently (Strong and Hevy
+//! use "Bench Press (Barbell)", watches use "Bench Press"), so matching works
+//! on normalized names rather than exact strings.
+
+use crate::models::{ExerciseType, GlobalExercise};
+
+/// Catalog entries as (name, type, muscle group).
+const CATALOG: &[(&str, &str, &str)] = &[
+    // Chest
+    ("Barbell Bench Press", "Compound", "Chest"),
+    ("Dumbbell Bench Press", "Compound", "Chest"),
+    ("Incline Barbell Bench Press", "Compound", "Chest"),
+    ("Incline Dumbbell Bench Press", "Compound", "Chest"),
+    ("Decline Barbell Bench Press", "Compound", "Chest"),
+    ("Dumbbell Fly", "Isolation", "Chest"),
+    ("Cable Fly", "Isolation", "Chest"),
+    ("Push-ups", "Bodyweight", "Chest"),
+    // Back
+    ("Barbell Row", "Compound", "Back"),
+    ("Dumbbell Row", "Compound", "Back"),
+    ("Pull-ups", "Bodyweight", "Back"),
+    ("Chin-ups", "Bodyweight", "Back"),
+    ("Lat Pulldown", "Compound", "Back"),
+    ("Seated Cable Row", "Compound", "Back"),
+    ("T-Bar Row", "Compound", "Back"),
+    ("Deadlift", "Compound", "Back"),
+    ("Romanian Deadlift", "Compound", "Back"),
+    // Legs
+    ("Barbell Squat", "Compound", "Legs"),
+    ("Front Squat", "Compound", "Legs"),
+    ("Leg Press", "Compound", "Legs"),
+    ("Leg Extension", "Isolation", "Legs"),
+    ("Leg Curl", "Isolation", "Legs"),
+    ("Bulgarian Split Squat", "Compound", "Legs"),
+    ("Lunges", "Compound", "Legs"),
+    ("Calf Raise", "Isolation", "Legs"),
+    // Shoulders
+    ("Overhead Press", "Compound", "Shoulders"),
+    ("Dumbbell Shoulder Press", "Compound", "Shoulders"),
+    ("Lateral Raise", "Isolation", "Shoulders"),
+    ("Front Raise", "Isolation", "Shoulders"),
+    ("Rear Delt Fly", "Isolation", "Shoulders"),
+    ("Face Pull", "Isolation", "Shoulders"),
+    ("Shrugs", "Isolation", "Shoulders"),
+    // Arms
+    ("Barbell Curl", "Isolation", "Arms"),
+    ("Dumbbell Curl", "Isolation", "Arms"),
+    ("Hammer Curl", "Isolation", "Arms"),
+    ("Preacher Curl", "Isolation", "Arms"),
+    ("Tricep Pushdown", "Isolation", "Arms"),
+    ("Overhead Tricep Extension", "Isolation", "Arms"),
+    ("Skull Crushers", "Isolation", "Arms"),
+    ("Dips", "Compound", "Arms"),
+    // Core
+    ("Plank", "Isometric", "Core"),
+    ("Crunches", "Isolation", "Core"),
+    ("Russian Twists", "Isolation", "Core"),
+    ("Leg Raises", "Isolation", "Core"),
+    ("Cable Woodchop", "Isolation", "Core"),
+];
+
+/// Returns every exercise in the catalog.
+pub fn exercise_catalog() -> Vec<GlobalExercise> {
+    CATALOG
+        .iter()
+        .map(|(name, exercise_type, muscle_group)| {
+            GlobalExercise::new(*name, *exercise_type, *muscle_group)
+        })
+        .collect()
+}
+
+/// Result of matching an external exercise name against the catalog.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExerciseMatch {
+    /// Catalog name, or None if nothing matched
+    pub catalog_name: Option<&'static str>,
+    /// Equipment parsed from the name (e.g. "(Barbell)"), if any
+    pub exercise_type: ExerciseType,
+}
+
+/// Matches an exercise name from another app against the catalog.
+///
+/// Handles the "Name (Equipment)" style used by Strong and Hevy by trying
+/// "Equipment Name" first and then the bare name, and ignores case,
+/// punctuation and plural "s" (so "Pull Up (Assisted)" matches "Pull-ups").
+///
+/// # Examples
+///
+/// ```
+/// use shared::catalog::match_exercise;
+///
+/// let matched = match_exercise("Bench Press (Barbell)");
+/// assert_eq!(matched.catalog_name, Some("Barbell Bench Press"));
+///
+/// assert_eq!(match_exercise("Deadlift (Barbell)").catalog_name, Some("Deadlift"));
+/// assert_eq!(match_exercise("Zercher Carry").catalog_name, None);
+/// ```
+pub fn match_exercise(name: &str) -> ExerciseMatch {
+    let (base, equipment) = split_equipment(name);
+    let exercise_type = equipment
+        .map(equipment_type)
+        .unwrap_or(ExerciseType::Unknown);
+
+    let candidates = equipment
+        .map(|equipment| format!("{} {}", equipment, base))
+        .into_iter()
+        .chain(std::iter::once(base.to_string()));
+
+    for candidate in candidates {
+        let key = normalize(&candidate);
+        if let Some((catalog_name, _, _)) = CATALOG.iter().find(|(n, _, _)| normalize(n) == key) {
+            return ExerciseMatch {
+                catalog_name: Some(catalog_name),
+                exercise_type,
+            };
+        }
+    }
+
+    ExerciseMatch {
+        catalog_name: None,
+        exercise_type,
+    }
+}
+
+/// Splits "Bench Press (Barbell)" into ("Bench Press", Some("Barbell")).
+fn split_equipment(name: &str) -> (&str, Option<&str>) {
+    let name = name.trim();
+    if let Some(stripped) = name.strip_suffix(')') {
+        if let Some(open) = stripped.rfind('(') {
+            let equipment = stripped[open + 1..].trim();
+            if !equipment.is_empty() {
+                return (stripped[..open].trim(), Some(equipment));
+            }
+        }
+    }
+    (name, None)
+}
+
+/// Maps an equipment label from another app to an `ExerciseType`.
+fn equipment_type(equipment: &str) -> ExerciseType {
+    match equipment.to_lowercase().as_str() {
+        "barbell" | "ez bar" | "olympic bar" => ExerciseType::Barbell,
+        "dumbbell" | "dumbbells" => ExerciseType::Dumbbell,
+        "kettlebell" => ExerciseType::Kettlebell,
+        "trap bar" | "hex bar" => ExerciseType::Hexbar,
+        "machine" | "cable" | "smith machine" | "plate loaded" => ExerciseType::Machine,
+        "bodyweight" | "assisted" | "weighted" => ExerciseType::Bodyweight,
+        _ => ExerciseType::Unknown,
+    }
+}
+
+/// Lowercases, drops punctuation and trailing plural "s" from each word.
+fn normalize(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            if word.len() >= 3 && word.ends_with('s') && !word.ends_with("ss") {
+                &word[..word.len() - 1]
+            } else {
+                word
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// =============================================================================
+// MARK: - Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_and_case_insensitive_matches() {
+        assert_eq!(match_exercise("Lat Pulldown").catalog_name, Some("Lat Pulldown"));
+        assert_eq!(match_exercise("lat pulldown").catalog_name, Some("Lat Pulldown"));
+    }
+
+    #[test]
+    fn test_equipment_suffix_is_moved_and_typed() {
+        let matched = match_exercise("Squat (Barbell)");
+        assert_eq!(matched.catalog_name, Some("Barbell Squat"));
+        assert_eq!(matched.exercise_type, ExerciseType::Barbell);
+
+        let matched = match_exercise("Lateral Raise (Dumbbell)");
+        assert_eq!(matched.catalog_name, Some("Lateral Raise"));
+        assert_eq!(matched.exercise_type, ExerciseType::Dumbbell);
+    }
+
+    #[test]
+    fn test_punctuation_and_plurals_are_ignored() {
+        assert_eq!(match_exercise("Pull Up").catalog_name, Some("Pull-ups"));
+        assert_eq!(match_exercise("Chin Up (Assisted)").catalog_name, Some("Chin-ups"));
+        assert_eq!(match_exercise("Skullcrusher").catalog_name, None);
+        assert_eq!(match_exercise("Skull Crusher").catalog_name, Some("Skull Crushers"));
+    }
+
+    #[test]
+    fn test_unmatched_exercise_keeps_equipment() {
+        let matched = match_exercise("Zercher Squat (Barbell)");
+        assert_eq!(matched.catalog_name, None);
+        assert_eq!(matched.exercise_type, ExerciseType::Barbell);
+    }
+
+    #[test]
+    fn test_catalog_names_are_unique_after_normalizing() {
+        let mut keys: Vec<_> = CATALOG.iter().map(|(name, _, _)| normalize(name)).collect();
+        keys.sort();
+        keys.dedup();
+        assert_eq!(keys.len(), CATALOG.len());
+        assert_eq!(exercise_catalog().len(), CATALOG.len());
+    }
+}
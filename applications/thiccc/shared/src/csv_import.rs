@@ -0,0 +1,709 @@
+//! Importers for CSV exports from other workout apps (Strong, Hevy).
+//!
+//! Both apps export one row per set. Rows are grouped into workouts by start
+//! time and workout name, exercises keep the order they first appear in, and
+//! weights are converted to the user's preferred unit. Exercise names are
+//! mapped onto the built-in catalog where possible; names that don't match
+//! are kept as-is and listed in the preview so the user can review them
+//! before anything is written to the database.
+
+use std::collections::{BTreeSet, HashMap};
+
+use chrono::{NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::catalog::match_exercise;
+use crate::id::Id;
+use crate::models::{Exercise, ExerciseSet, SetActual, SetType, WeightUnit, Workout};
+use crate::timestamp::Timestamp;
+use crate::validation;
+
+/// Date format used by Strong (e.g. "2024-01-15 07:30:00").
+const STRONG_DATE_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Date format used by Hevy (e.g. "15 Jan 2024, 07:30").
+const HEVY_DATE_FORMAT: &str = "%d %b %Y, %H:%M";
+
+// =============================================================================
+// MARK: - Types
+// =============================================================================
+
+/// App that produced a CSV export.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: The source is detected from the CSV header; there is no
+/// sensible fallback format.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CsvSource {
+    /// Strong (strong.app) "Export Data" CSV
+    Strong,
+    /// Hevy (hevyapp.com) "Export Workouts" CSV
+    Hevy,
+}
+
+impl CsvSource {
+    /// Returns the app's display name.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            CsvSource::Strong => "Strong",
+            CsvSource::Hevy => "Hevy",
+        }
+    }
+}
+
+/// Options for a CSV import.
+///
+/// **Default Trait: IMPLEMENTED**
+///
+/// Reasoning: Pounds are the app's default unit, so importing a Strong
+/// export recorded in pounds needs no configuration.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CsvImportOptions {
+    /// Unit of the weights in a Strong export (Strong doesn't label them;
+    /// Hevy exports name the unit in the column header)
+    pub source_weight_unit: WeightUnit,
+    /// Unit imported weights are converted to
+    pub target_weight_unit: WeightUnit,
+}
+
+impl Default for CsvImportOptions {
+    fn default() -> Self {
+        Self {
+            source_weight_unit: WeightUnit::Lb,
+            target_weight_unit: WeightUnit::Lb,
+        }
+    }
+}
+
+/// A CSV row (or group of rows) that was left out of the import.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsvSkippedRow {
+    /// 1-based line number in the CSV file
+    pub line: u64,
+    /// Why the row was skipped
+    pub message: String,
+}
+
+/// Parsed CSV export, ready to be reviewed before saving.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: A preview only exists as the result of parsing a CSV file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsvImportPreview {
+    /// App the export came from
+    pub source: CsvSource,
+    /// Workouts built from the export, newest first
+    pub workouts: Vec<Workout>,
+    /// Exercise names that didn't match the catalog, sorted
+    pub unmatched_exercises: Vec<String>,
+    /// Rows that couldn't be imported
+    pub skipped_rows: Vec<CsvSkippedRow>,
+}
+
+impl CsvImportPreview {
+    /// Returns the number of exercises across all workouts.
+    pub fn exercise_count(&self) -> usize {
+        self.workouts.iter().map(|w| w.exercises.len()).sum()
+    }
+
+    /// Returns the number of sets across all workouts.
+    pub fn set_count(&self) -> usize {
+        self.workouts.iter().map(|w| w.total_sets()).sum()
+    }
+}
+
+// =============================================================================
+// MARK: - Parsing
+// =============================================================================
+
+/// Parses a Strong or Hevy CSV export into workouts for preview.
+///
+/// The format is detected from the header row. Rows that can't be read are
+/// reported in `skipped_rows` rather than failing the whole import, and
+/// every workout is checked with `validate_workout`.
+///
+/// # Errors
+///
+/// Returns an error if the header isn't a recognized export format or if no
+/// workouts could be built from the file.
+///
+/// # Examples
+///
+/// ```
+/// use shared::csv_import::{parse_csv_export, CsvImportOptions, CsvSource};
+///
+/// let csv = "Date,Workout Name,Duration,Exercise Name,Set Order,Weight,Reps,Distance,Seconds,Notes,Workout Notes,RPE\n\
+///            2024-01-15 07:30:00,Push,1h 5m,Bench Press (Barbell),1,135,10,0,0,,,\n";
+/// let preview = parse_csv_export(csv, &CsvImportOptions::default()).unwrap();
+///
+/// assert_eq!(preview.source, CsvSource::Strong);
+/// assert_eq!(preview.workouts[0].exercises[0].name, "Barbell Bench Press");
+/// ```
+pub fn parse_csv_export(
+    csv_data: &str,
+    options: &CsvImportOptions,
+) -> Result<CsvImportPreview, String> {
+    let csv_data = csv_data.trim_start_matches('\u{feff}');
+    let first_line = csv_data.lines().next().unwrap_or_default();
+    // Strong uses ";" in locales where "," is the decimal separator
+    let delimiter = if first_line.matches(';').count() > first_line.matches(',').count() {
+        b';'
+    } else {
+        b','
+    };
+
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .flexible(true)
+        .from_reader(csv_data.as_bytes());
+
+    let headers = reader
+        .headers()
+        .map_err(|e| format!("Could not read CSV header: {}", e))?;
+    let columns = Columns::new(headers);
+    let format = Format::detect(&columns, options)
+        .ok_or_else(|| "Unrecognized CSV format: expected a Strong or Hevy export".to_string())?;
+
+    let mut builder = WorkoutGrouper::new(options.target_weight_unit.clone());
+    let mut skipped_rows = Vec::new();
+
+    for record in reader.records() {
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                let line = e.position().map(|p| p.line()).unwrap_or_default();
+                skipped_rows.push(CsvSkippedRow {
+                    line,
+                    message: e.to_string(),
+                });
+                continue;
+            }
+        };
+        let line = record.position().map(|p| p.line()).unwrap_or_default();
+        let row = RawRow {
+            columns: &columns,
+            record: &record,
+        };
+
+        match format.parse_row(&row) {
+            Ok(Some(set_row)) => builder.push(line, set_row),
+            Ok(None) => {}
+            Err(message) => skipped_rows.push(CsvSkippedRow { line, message }),
+        }
+    }
+
+    let (workouts, unmatched_exercises, mut invalid) = builder.finish();
+    skipped_rows.append(&mut invalid);
+    skipped_rows.sort_by_key(|row| row.line);
+
+    if workouts.is_empty() {
+        return Err(match skipped_rows.first() {
+            Some(row) => format!(
+                "No workouts found in CSV (line {}: {})",
+                row.line, row.message
+            ),
+            None => "No workouts found in CSV".to_string(),
+        });
+    }
+
+    Ok(CsvImportPreview {
+        source: format.source(),
+        workouts,
+        unmatched_exercises,
+        skipped_rows,
+    })
+}
+
+/// Header lookup by case-insensitive column name.
+struct Columns {
+    indices: HashMap<String, usize>,
+}
+
+impl Columns {
+    fn new(headers: &csv::StringRecord) -> Self {
+        let indices = headers
+            .iter()
+            .enumerate()
+            .map(|(index, name)| (name.trim().to_lowercase(), index))
+            .collect();
+        Self { indices }
+    }
+
+    fn has(&self, name: &str) -> bool {
+        self.indices.contains_key(name)
+    }
+}
+
+/// A CSV record with access to fields by column name.
+struct RawRow<'a> {
+    columns: &'a Columns,
+    record: &'a csv::StringRecord,
+}
+
+impl RawRow<'_> {
+    /// Returns the trimmed field, or None if the column is missing or empty.
+    fn get(&self, column: &str) -> Option<&str> {
+        let index = *self.columns.indices.get(column)?;
+        let value = self.record.get(index)?.trim();
+        (!value.is_empty()).then_some(value)
+    }
+
+    fn require(&self, column: &str) -> Result<&str, String> {
+        self.get(column)
+            .ok_or_else(|| format!("Missing value for '{}'", column))
+    }
+
+    /// Parses a decimal field, accepting "," as the decimal separator.
+    fn number(&self, column: &str) -> Result<Option<f64>, String> {
+        self.get(column)
+            .map(|value| {
+                value
+                    .replace(',', ".")
+                    .parse::<f64>()
+                    .map_err(|_| format!("Invalid number for '{}': {}", column, value))
+            })
+            .transpose()
+    }
+}
+
+/// One set read from a CSV row, with its workout and exercise context.
+struct SetRow {
+    workout_name: String,
+    start: Timestamp,
+    end: Option<Timestamp>,
+    duration: Option<i32>,
+    workout_note: Option<String>,
+    exercise_name: String,
+    exercise_note: Option<String>,
+    superset_id: Option<i32>,
+    set_type: SetType,
+    /// Weight in the import's source unit
+    weight: Option<f64>,
+    source_unit: WeightUnit,
+    reps: Option<i32>,
+    seconds: Option<i32>,
+    rpe: Option<f64>,
+}
+
+/// Detected export format.
+enum Format {
+    Strong { weight_unit: WeightUnit },
+    Hevy { weight_column: &'static str, weight_unit: WeightUnit },
+}
+
+impl Format {
+    fn detect(columns: &Columns, options: &CsvImportOptions) -> Option<Self> {
+        if columns.has("workout name") && columns.has("exercise name") && columns.has("date") {
+            return Some(Format::Strong {
+                weight_unit: options.source_weight_unit.clone(),
+            });
+        }
+        if columns.has("title") && columns.has("exercise_title") && columns.has("start_time") {
+            let (weight_column, weight_unit) = if columns.has("weight_kg") {
+                ("weight_kg", WeightUnit::Kg)
+            } else {
+                ("weight_lbs", WeightUnit::Lb)
+            };
+            return Some(Format::Hevy {
+                weight_column,
+                weight_unit,
+            });
+        }
+        None
+    }
+
+    fn source(&self) -> CsvSource {
+        match self {
+            Format::Strong { .. } => CsvSource::Strong,
+            Format::Hevy { .. } => CsvSource::Hevy,
+        }
+    }
+
+    /// Parses a row into a set. Returns `Ok(None)` for rows that carry no set
+    /// (Strong's rest timer rows).
+    fn parse_row(&self, row: &RawRow) -> Result<Option<SetRow>, String> {
+        match self {
+            Format::Strong { weight_unit } => parse_strong_row(row, weight_unit),
+            Format::Hevy {
+                weight_column,
+                weight_unit,
+            } => parse_hevy_row(row, weight_column, weight_unit).map(Some),
+        }
+    }
+}
+
+fn parse_strong_row(row: &RawRow, weight_unit: &WeightUnit) -> Result<Option<SetRow>, String> {
+    let set_order = row.get("set order").unwrap_or_default();
+    let set_type = match set_order.to_ascii_uppercase().as_str() {
+        "REST TIMER" => return Ok(None),
+        "W" => SetType::WarmUp,
+        "D" => SetType::DropSet,
+        "F" => SetType::Failure,
+        _ => SetType::Working,
+    };
+
+    let start = parse_date(row.require("date")?, STRONG_DATE_FORMAT)?;
+    let duration = row.get("duration").map(parse_strong_duration).transpose()?;
+    let end = duration.and_then(|seconds| {
+        Timestamp::from_millis(start.as_millis() + i64::from(seconds) * 1000)
+    });
+
+    Ok(Some(SetRow {
+        workout_name: row.require("workout name")?.to_string(),
+        start,
+        end,
+        duration,
+        workout_note: row.get("workout notes").map(str::to_string),
+        exercise_name: row.require("exercise name")?.to_string(),
+        exercise_note: row.get("notes").map(str::to_string),
+        superset_id: None,
+        set_type,
+        weight: row.number("weight")?,
+        source_unit: weight_unit.clone(),
+        reps: parse_count(row, "reps")?,
+        seconds: parse_count(row, "seconds")?,
+        rpe: row.number("rpe")?,
+    }))
+}
+
+fn parse_hevy_row(
+    row: &RawRow,
+    weight_column: &str,
+    weight_unit: &WeightUnit,
+) -> Result<SetRow, String> {
+    let set_type = match row.get("set_type").unwrap_or_default() {
+        "warmup" => SetType::WarmUp,
+        "dropset" => SetType::DropSet,
+        "failure" => SetType::Failure,
+        _ => SetType::Working,
+    };
+
+    let start = parse_date(row.require("start_time")?, HEVY_DATE_FORMAT)?;
+    let end = row
+        .get("end_time")
+        .map(|value| parse_date(value, HEVY_DATE_FORMAT))
+        .transpose()?;
+    let duration = end.and_then(|end| i32::try_from(end.as_seconds() - start.as_seconds()).ok());
+    let superset_id = row
+        .get("superset_id")
+        .and_then(|value| value.parse::<i32>().ok());
+
+    Ok(SetRow {
+        workout_name: row.require("title")?.to_string(),
+        start,
+        end,
+        duration,
+        workout_note: row.get("description").map(str::to_string),
+        exercise_name: row.require("exercise_title")?.to_string(),
+        exercise_note: row.get("exercise_notes").map(str::to_string),
+        superset_id,
+        set_type,
+        weight: row.number(weight_column)?,
+        source_unit: weight_unit.clone(),
+        reps: parse_count(row, "reps")?,
+        seconds: parse_count(row, "duration_seconds")?,
+        rpe: row.number("rpe")?,
+    })
+}
+
+/// Parses a local date-time as UTC (neither export includes a time zone).
+fn parse_date(value: &str, format: &str) -> Result<Timestamp, String> {
+    NaiveDateTime::parse_from_str(value, format)
+        .map(|naive| Timestamp::from(Utc.from_utc_datetime(&naive)))
+        .map_err(|_| format!("Invalid date: {}", value))
+}
+
+/// Parses Strong's duration ("1h 5m", "45m", "30s") into seconds.
+fn parse_strong_duration(value: &str) -> Result<i32, String> {
+    let invalid = || format!("Invalid duration: {}", value);
+    let mut seconds = 0;
+    for part in value.split_whitespace() {
+        let (number, multiplier) = if let Some(n) = part.strip_suffix('h') {
+            (n, 3600)
+        } else if let Some(n) = part.strip_suffix('m') {
+            (n, 60)
+        } else if let Some(n) = part.strip_suffix('s') {
+            (n, 1)
+        } else {
+            return Err(invalid());
+        };
+        seconds += number.parse::<i32>().map_err(|_| invalid())? * multiplier;
+    }
+    Ok(seconds)
+}
+
+/// Parses a whole-number field; zero means "not recorded" in both exports.
+fn parse_count(row: &RawRow, column: &str) -> Result<Option<i32>, String> {
+    Ok(row
+        .number(column)?
+        .map(|value| value.round() as i32)
+        .filter(|value| *value != 0))
+}
+
+/// Converts a weight between units, rounded to two decimals.
+fn convert_weight(weight: f64, from: &WeightUnit, to: &WeightUnit) -> f64 {
+    let converted = from.convert(weight, to);
+    (converted * 100.0).round() / 100.0
+}
+
+// =============================================================================
+// MARK: - Grouping
+// =============================================================================
+
+/// A workout being assembled, with the first CSV line it came from.
+struct PendingWorkout {
+    line: u64,
+    workout: Workout,
+}
+
+/// Groups set rows into workouts keyed by (start time, workout name).
+struct WorkoutGrouper {
+    target_unit: WeightUnit,
+    workouts: Vec<PendingWorkout>,
+    index: HashMap<(i64, String), usize>,
+    unmatched: BTreeSet<String>,
+}
+
+impl WorkoutGrouper {
+    fn new(target_unit: WeightUnit) -> Self {
+        Self {
+            target_unit,
+            workouts: Vec::new(),
+            index: HashMap::new(),
+            unmatched: BTreeSet::new(),
+        }
+    }
+
+    fn push(&mut self, line: u64, row: SetRow) {
+        let key = (row.start.as_millis(), row.workout_name.clone());
+        let position = *self.index.entry(key).or_insert_with(|| {
+            let mut workout = Workout::with_name(row.workout_name.clone());
+            workout.start_timestamp = row.start;
+            workout.end_timestamp = row.end;
+            workout.duration = row.duration;
+            workout.note = row.workout_note.clone();
+            self.workouts.push(PendingWorkout { line, workout });
+            self.workouts.len() - 1
+        });
+        let workout = &mut self.workouts[position].workout;
+
+        let matched = match_exercise(&row.exercise_name);
+        let name = match matched.catalog_name {
+            Some(catalog_name) => catalog_name.to_string(),
+            None => {
+                self.unmatched.insert(row.exercise_name.clone());
+                row.exercise_name.clone()
+            }
+        };
+
+        let exercise_index = match workout.exercises.iter().position(|e| e.name == name) {
+            Some(index) => index,
+            None => {
+                let mut exercise = Exercise::new(name, workout.id.clone());
+                exercise.exercise_type = matched.exercise_type;
+                exercise.superset_id = row.superset_id;
+                exercise.weight_unit = Some(self.target_unit.clone());
+                workout.exercises.push(exercise);
+                workout.exercises.len() - 1
+            }
+        };
+        let workout_id: Id = workout.id.clone();
+        let exercise = &mut workout.exercises[exercise_index];
+
+        if let Some(note) = row.exercise_note {
+            if !exercise.notes.contains(&note) {
+                exercise.notes.push(note);
+            }
+        }
+
+        let mut set = ExerciseSet::new(
+            exercise.id.clone(),
+            workout_id,
+            exercise.sets.len() as i32,
+        );
+        set.set_type = row.set_type;
+        set.weight_unit = Some(self.target_unit.clone());
+        set.complete(SetActual {
+            weight: row
+                .weight
+                .map(|weight| convert_weight(weight, &row.source_unit, &self.target_unit)),
+            reps: row.reps,
+            duration: row.seconds,
+            rpe: row.rpe,
+            actual_rest_time: None,
+        });
+        exercise.sets.push(set);
+    }
+
+    /// Returns valid workouts (newest first), unmatched names, and a skipped
+    /// entry for each workout that failed validation.
+    fn finish(self) -> (Vec<Workout>, Vec<String>, Vec<CsvSkippedRow>) {
+        let mut workouts = Vec::with_capacity(self.workouts.len());
+        let mut invalid = Vec::new();
+
+        for pending in self.workouts {
+            match validation::validate_workout(&pending.workout) {
+                Ok(()) => workouts.push(pending.workout),
+                Err(errors) => invalid.push(CsvSkippedRow {
+                    line: pending.line,
+                    message: format!(
+                        "Workout '{}' is invalid: {}",
+                        pending.workout.name,
+                        validation::describe_errors(&errors)
+                    ),
+                }),
+            }
+        }
+
+        workouts.sort_by_key(|w| std::cmp::Reverse(w.start_timestamp));
+        (workouts, self.unmatched.into_iter().collect(), invalid)
+    }
+}
+
+// =============================================================================
+// MARK: - Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ExerciseType;
+
+    const STRONG_CSV: &str = "\
+Date,Workout Name,Duration,Exercise Name,Set Order,Weight,Reps,Distance,Seconds,Notes,Workout Notes,RPE
+2024-01-15 07:30:00,Push Day,1h 5m,Bench Press (Barbell),W,95,10,0,0,,Felt good,
+2024-01-15 07:30:00,Push Day,1h 5m,Bench Press (Barbell),1,185,5,0,0,Paused reps,Felt good,8
+2024-01-15 07:30:00,Push Day,1h 5m,Bench Press (Barbell),Rest Timer,0,0,0,90,,Felt good,
+2024-01-15 07:30:00,Push Day,1h 5m,Landmine Press,1,45,12,0,0,,Felt good,
+2024-01-15 07:30:00,Push Day,1h 5m,Bench Press (Barbell),2,185,5,0,0,,Felt good,8.5
+2024-01-17 18:00:00,Pull Day,45m,Pull Up,1,0,8,0,0,,,
+";
+
+    const HEVY_CSV: &str = "\
+title,start_time,end_time,description,exercise_title,superset_id,exercise_notes,set_index,set_type,weight_kg,reps,distance_km,duration_seconds,rpe
+Leg Day,\"20 Feb 2024, 17:00\",\"20 Feb 2024, 18:10\",,Squat (Barbell),,,0,warmup,60,5,,,
+Leg Day,\"20 Feb 2024, 17:00\",\"20 Feb 2024, 18:10\",,Squat (Barbell),,,1,normal,100,5,,,9
+Leg Day,\"20 Feb 2024, 17:00\",\"20 Feb 2024, 18:10\",,Plank,,,0,normal,,,,60,
+";
+
+    #[test]
+    fn test_strong_export_groups_sets_by_workout() {
+        let preview = parse_csv_export(STRONG_CSV, &CsvImportOptions::default()).unwrap();
+
+        assert_eq!(preview.source, CsvSource::Strong);
+        assert_eq!(preview.workouts.len(), 2);
+        assert!(preview.skipped_rows.is_empty());
+
+        // Newest first
+        let pull = &preview.workouts[0];
+        assert_eq!(pull.name, "Pull Day");
+        assert_eq!(pull.exercises[0].name, "Pull-ups");
+        assert_eq!(pull.duration, Some(45 * 60));
+
+        let push = &preview.workouts[1];
+        assert_eq!(push.note.as_deref(), Some("Felt good"));
+        assert_eq!(push.duration, Some(3900));
+        assert_eq!(
+            push.end_timestamp.unwrap().as_seconds() - push.start_timestamp.as_seconds(),
+            3900
+        );
+
+        let names: Vec<_> = push.exercises.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["Barbell Bench Press", "Landmine Press"]);
+
+        let bench = &push.exercises[0];
+        assert_eq!(bench.exercise_type, ExerciseType::Barbell);
+        assert_eq!(bench.notes, ["Paused reps"]);
+        let indices: Vec<_> = bench.sets.iter().map(|s| s.set_index).collect();
+        assert_eq!(indices, [0, 1, 2]);
+        assert_eq!(bench.sets[0].set_type, SetType::WarmUp);
+        assert_eq!(bench.sets[2].actual.rpe, Some(8.5));
+        assert!(bench.sets.iter().all(|s| s.is_completed));
+    }
+
+    #[test]
+    fn test_unmatched_exercises_are_listed() {
+        let preview = parse_csv_export(STRONG_CSV, &CsvImportOptions::default()).unwrap();
+        assert_eq!(preview.unmatched_exercises, ["Landmine Press"]);
+        assert_eq!(preview.exercise_count(), 3);
+        assert_eq!(preview.set_count(), 5);
+    }
+
+    #[test]
+    fn test_strong_weights_are_converted_to_target_unit() {
+        let options = CsvImportOptions {
+            source_weight_unit: WeightUnit::Lb,
+            target_weight_unit: WeightUnit::Kg,
+        };
+        let preview = parse_csv_export(STRONG_CSV, &options).unwrap();
+        let set = &preview.workouts[1].exercises[0].sets[1];
+
+        assert_eq!(set.actual.weight, Some(83.91));
+        assert_eq!(set.weight_unit, Some(WeightUnit::Kg));
+    }
+
+    #[test]
+    fn test_hevy_export_uses_labelled_weight_unit() {
+        let preview = parse_csv_export(HEVY_CSV, &CsvImportOptions::default()).unwrap();
+
+        assert_eq!(preview.source, CsvSource::Hevy);
+        let workout = &preview.workouts[0];
+        assert_eq!(workout.duration, Some(70 * 60));
+
+        let squat = &workout.exercises[0];
+        assert_eq!(squat.name, "Barbell Squat");
+        assert_eq!(squat.sets[0].set_type, SetType::WarmUp);
+        // 100 kg converted to the default pound target
+        assert_eq!(squat.sets[1].actual.weight, Some(220.46));
+
+        let plank = &workout.exercises[1];
+        assert_eq!(plank.sets[0].actual.duration, Some(60));
+        assert_eq!(plank.sets[0].actual.weight, None);
+    }
+
+    #[test]
+    fn test_semicolon_delimited_strong_export() {
+        let csv = "Date;Workout Name;Duration;Exercise Name;Set Order;Weight;Reps;Distance;Seconds;Notes;Workout Notes;RPE\n\
+                   2024-01-15 07:30:00;Push;1h;Dips;1;10,5;8;0;0;;;\n";
+        let preview = parse_csv_export(csv, &CsvImportOptions::default()).unwrap();
+        assert_eq!(preview.workouts[0].exercises[0].sets[0].actual.weight, Some(10.5));
+    }
+
+    #[test]
+    fn test_bad_rows_are_skipped_with_line_numbers() {
+        let csv = "\
+Date,Workout Name,Duration,Exercise Name,Set Order,Weight,Reps,Distance,Seconds,Notes,Workout Notes,RPE
+2024-01-15 07:30:00,Push,1h,Dips,1,0,8,0,0,,,
+yesterday,Push,1h,Dips,2,0,8,0,0,,,
+2024-01-16 07:30:00,Legs,1h,Leg Press,1,200,10,0,0,,,14
+";
+        let preview = parse_csv_export(csv, &CsvImportOptions::default()).unwrap();
+
+        assert_eq!(preview.workouts.len(), 1);
+        assert_eq!(preview.skipped_rows.len(), 2);
+        assert_eq!(preview.skipped_rows[0].line, 3);
+        assert!(preview.skipped_rows[0].message.contains("Invalid date"));
+        // RPE 14 fails validation, dropping the whole workout
+        assert_eq!(preview.skipped_rows[1].line, 4);
+        assert!(preview.skipped_rows[1].message.contains("Legs"));
+    }
+
+    #[test]
+    fn test_unrecognized_or_empty_exports_are_rejected() {
+        let error = parse_csv_export("a,b,c\n1,2,3\n", &CsvImportOptions::default()).unwrap_err();
+        assert!(error.contains("Unrecognized CSV format"));
+
+        let header_only = "Date,Workout Name,Exercise Name\n";
+        let error = parse_csv_export(header_only, &CsvImportOptions::default()).unwrap_err();
+        assert!(error.contains("No workouts found"));
+    }
+
+    #[test]
+    fn test_strong_duration_parsing() {
+        assert_eq!(parse_strong_duration("1h 5m"), Ok(3900));
+        assert_eq!(parse_strong_duration("30s"), Ok(30));
+        assert!(parse_strong_duration("an hour").is_err());
+    }
+}
@@ -0,0 +1,357 @@
+//! Exporting workout history as files the user can keep or share.
+//!
+//! Three formats are produced from the full history:
+//!
+//! - **JSON archive** – every workout in the current schema, wrapped in a
+//!   versioned envelope. This is the lossless backup format and can be
+//!   imported again with `Event::ImportWorkout`.
+//! - **CSV** – one row per set, for spreadsheets and analysis tools.
+//! - **Markdown** – a readable log of each workout and its sets.
+//!
+//! The shell only receives the finished file (see `ShareOperation`).
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::app::WorkoutLoadFailure;
+use crate::models::{Workout, WeightUnit};
+use crate::schema;
+use crate::timestamp::Timestamp;
+
+/// Version of the archive envelope written by this build.
+///
+/// Workouts inside the archive carry their own `schema_version`; this only
+/// versions the envelope around them.
+pub const EXPORT_ARCHIVE_VERSION: u32 = 1;
+
+/// CSV columns, one row per set.
+const CSV_HEADER: [&str; 14] = [
+    "workout_id",
+    "workout_name",
+    "start_time",
+    "end_time",
+    "duration_seconds",
+    "exercise_name",
+    "set_number",
+    "set_type",
+    "weight",
+    "weight_unit",
+    "reps",
+    "set_duration_seconds",
+    "rpe",
+    "completed",
+];
+
+// =============================================================================
+// MARK: - Types
+// =============================================================================
+
+/// File format for a history export.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: The user always picks a format explicitly.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Versioned JSON archive of every workout (re-importable)
+    JsonArchive,
+    /// Flat CSV with one row per set
+    Csv,
+    /// Human-readable Markdown log
+    Markdown,
+}
+
+/// Envelope of a JSON history archive.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ExportArchive {
+    /// Envelope version (`EXPORT_ARCHIVE_VERSION` when written)
+    pub archive_version: u32,
+    /// When the archive was created
+    pub exported_at: Timestamp,
+    /// Every readable workout, newest first
+    pub workouts: Vec<Workout>,
+    /// Stored workouts that couldn't be read, kept so nothing is lost
+    pub unreadable: Vec<WorkoutLoadFailure>,
+}
+
+/// A generated export file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExportedFile {
+    /// Suggested file name (e.g. "thiccc-history-2025-03-03.csv")
+    pub file_name: String,
+    /// MIME type of the contents
+    pub mime_type: String,
+    /// File contents
+    pub contents: String,
+}
+
+// =============================================================================
+// MARK: - Export
+// =============================================================================
+
+/// Builds an export file of the given workouts.
+///
+/// `unreadable` workouts are only included in the JSON archive, since the
+/// other formats need readable sets.
+///
+/// # Errors
+///
+/// Returns an error if serialization fails.
+pub fn export_history(
+    format: ExportFormat,
+    workouts: &[Workout],
+    unreadable: &[WorkoutLoadFailure],
+    exported_at: Timestamp,
+) -> Result<ExportedFile, String> {
+    let date = exported_at.to_datetime().format("%Y-%m-%d");
+    let (extension, mime_type, contents) = match format {
+        ExportFormat::JsonArchive => {
+            let archive = ExportArchive {
+                archive_version: EXPORT_ARCHIVE_VERSION,
+                exported_at,
+                workouts: workouts.to_vec(),
+                unreadable: unreadable.to_vec(),
+            };
+            let json = serde_json::to_string_pretty(&archive).map_err(|e| e.to_string())?;
+            ("json", "application/json", json)
+        }
+        ExportFormat::Csv => ("csv", "text/csv", history_csv(workouts)?),
+        ExportFormat::Markdown => ("md", "text/markdown", history_markdown(workouts, exported_at)),
+    };
+
+    Ok(ExportedFile {
+        file_name: format!("thiccc-history-{}.{}", date, extension),
+        mime_type: mime_type.to_string(),
+        contents,
+    })
+}
+
+/// Writes one CSV row per set.
+fn history_csv(workouts: &[Workout]) -> Result<String, String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(CSV_HEADER).map_err(|e| e.to_string())?;
+
+    let optional = |value: Option<String>| value.unwrap_or_default();
+    for workout in workouts {
+        for exercise in &workout.exercises {
+            for (index, set) in exercise.sets.iter().enumerate() {
+                let unit = set
+                    .weight_unit
+                    .as_ref()
+                    .or(exercise.weight_unit.as_ref())
+                    .map(WeightUnit::as_str);
+                writer
+                    .write_record([
+                        workout.id.as_str().to_string(),
+                        workout.name.clone(),
+                        workout.start_timestamp.to_string(),
+                        optional(workout.end_timestamp.map(|t| t.to_string())),
+                        optional(workout.duration.map(|d| d.to_string())),
+                        exercise.name.clone(),
+                        (index + 1).to_string(),
+                        serde_json::to_value(&set.set_type)
+                            .ok()
+                            .and_then(|v| v.as_str().map(str::to_string))
+                            .unwrap_or_default(),
+                        optional(set.actual.weight.map(|w| w.to_string())),
+                        unit.unwrap_or_default().to_string(),
+                        optional(set.actual.reps.map(|r| r.to_string())),
+                        optional(set.actual.duration.map(|d| d.to_string())),
+                        optional(set.actual.rpe.map(|r| r.to_string())),
+                        set.is_completed.to_string(),
+                    ])
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+    }
+
+    let bytes = writer.into_inner().map_err(|e| e.to_string())?;
+    String::from_utf8(bytes).map_err(|e| e.to_string())
+}
+
+/// Writes a readable log of each workout, newest first.
+fn history_markdown(workouts: &[Workout], exported_at: Timestamp) -> String {
+    let mut text = String::from("# Workout History\n\n");
+    text.push_str(&format!(
+        "Exported {} · {} workout{}\n",
+        exported_at.to_datetime().format("%b %d, %Y"),
+        workouts.len(),
+        if workouts.len() == 1 { "" } else { "s" }
+    ));
+
+    for workout in workouts {
+        text.push_str(&format!("\n## {}\n\n", workout.name));
+
+        let mut details = vec![workout
+            .start_timestamp
+            .to_datetime()
+            .format("%b %d, %Y at %-I:%M %p")
+            .to_string()];
+        if let Some(secs) = workout.duration {
+            details.push(format!("{:02}:{:02}", secs / 60, secs % 60));
+        }
+        details.push(format!("{} sets", workout.total_sets()));
+        details.push(format!("Volume {}", workout.total_volume() as i32));
+        text.push_str(&details.join(" · "));
+        text.push('\n');
+
+        if let Some(note) = &workout.note {
+            text.push_str(&format!("\n> {}\n", note));
+        }
+
+        for exercise in &workout.exercises {
+            text.push_str(&format!("\n### {}\n\n", exercise.name));
+            for (index, set) in exercise.sets.iter().enumerate() {
+                text.push_str(&format!("{}. {}\n", index + 1, set.format_set()));
+            }
+        }
+    }
+
+    text
+}
+
+// =============================================================================
+// MARK: - Archive Import
+// =============================================================================
+
+/// Returns whether a JSON document is a history archive.
+pub fn is_archive(value: &Value) -> bool {
+    value.get("archive_version").is_some()
+}
+
+/// Reads the workouts from a JSON history archive.
+///
+/// Each workout is upgraded from its own schema version, so archives from
+/// older builds still import.
+///
+/// # Errors
+///
+/// Returns an error if the archive is malformed, from a newer build, or any
+/// workout can't be read (naming its index).
+pub fn workouts_from_archive(value: Value) -> Result<Vec<Workout>, String> {
+    let version = value
+        .get("archive_version")
+        .and_then(Value::as_u64)
+        .ok_or_else(|| "Invalid archive_version".to_string())?;
+    if version > u64::from(EXPORT_ARCHIVE_VERSION) {
+        return Err(format!(
+            "Archive version {} is newer than supported version {}",
+            version, EXPORT_ARCHIVE_VERSION
+        ));
+    }
+
+    let workouts = value
+        .get("workouts")
+        .and_then(Value::as_array)
+        .ok_or_else(|| "Archive has no workouts list".to_string())?;
+    workouts
+        .iter()
+        .enumerate()
+        .map(|(index, workout)| {
+            schema::migrate_workout_value(workout.clone())
+                .and_then(|value| serde_json::from_value(value).map_err(|e| e.to_string()))
+                .map_err(|e| format!("Workout {}: {}", index, e))
+        })
+        .collect()
+}
+
+// =============================================================================
+// MARK: - Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{SetActual, SetType};
+
+    fn exported_at() -> Timestamp {
+        "2025-03-04T09:00:00Z".parse().unwrap()
+    }
+
+    fn sample_history() -> Vec<Workout> {
+        let mut workout = Workout::with_name("Push, Heavy");
+        workout.start_timestamp = "2025-03-03T08:00:00Z".parse().unwrap();
+        workout.duration = Some(3900);
+        workout.note = Some("Felt strong".to_string());
+
+        let exercise = workout.add_exercise("Bench Press");
+        exercise.weight_unit = Some(WeightUnit::Lb);
+        let warmup = exercise.add_set();
+        warmup.set_type = SetType::WarmUp;
+        warmup.weight_unit = Some(WeightUnit::Lb);
+        warmup.complete(SetActual::with_weight_and_reps(95.0, 10));
+        let working = exercise.add_set();
+        working.weight_unit = Some(WeightUnit::Lb);
+        working.complete(SetActual {
+            rpe: Some(8.0),
+            ..SetActual::with_weight_and_reps(185.0, 5)
+        });
+
+        vec![workout]
+    }
+
+    #[test]
+    fn test_json_archive_round_trips() {
+        let workouts = sample_history();
+        let file = export_history(ExportFormat::JsonArchive, &workouts, &[], exported_at()).unwrap();
+
+        assert_eq!(file.file_name, "thiccc-history-2025-03-04.json");
+        assert_eq!(file.mime_type, "application/json");
+
+        let value: Value = serde_json::from_str(&file.contents).unwrap();
+        assert!(is_archive(&value));
+        assert_eq!(value["archive_version"], EXPORT_ARCHIVE_VERSION);
+        assert_eq!(workouts_from_archive(value), Ok(workouts));
+    }
+
+    #[test]
+    fn test_json_archive_keeps_unreadable_workouts() {
+        let failure = WorkoutLoadFailure {
+            workout_id: "broken".to_string(),
+            error: "bad data".to_string(),
+            raw_payload: "{}".to_string(),
+        };
+        let file = export_history(ExportFormat::JsonArchive, &[], &[failure], exported_at()).unwrap();
+
+        let archive: ExportArchive = serde_json::from_str(&file.contents).unwrap();
+        assert_eq!(archive.unreadable[0].workout_id, "broken");
+    }
+
+    #[test]
+    fn test_archive_from_newer_build_is_rejected() {
+        let value = serde_json::json!({ "archive_version": EXPORT_ARCHIVE_VERSION + 1, "workouts": [] });
+        assert!(workouts_from_archive(value).unwrap_err().contains("newer than supported"));
+    }
+
+    #[test]
+    fn test_csv_has_one_row_per_set() {
+        let workouts = sample_history();
+        let file = export_history(ExportFormat::Csv, &workouts, &[], exported_at()).unwrap();
+        assert_eq!(file.mime_type, "text/csv");
+
+        let mut reader = csv::Reader::from_reader(file.contents.as_bytes());
+        assert_eq!(reader.headers().unwrap().iter().collect::<Vec<_>>(), CSV_HEADER);
+
+        let rows: Vec<csv::StringRecord> = reader.records().map(Result::unwrap).collect();
+        assert_eq!(rows.len(), 2);
+        // Commas in names are quoted rather than splitting the row
+        assert_eq!(&rows[0][1], "Push, Heavy");
+        assert_eq!(&rows[0][2], "2025-03-03T08:00:00.000Z");
+        assert_eq!(&rows[0][7], "warmUp");
+        assert_eq!(&rows[1][6], "2");
+        assert_eq!(&rows[1][8], "185");
+        assert_eq!(&rows[1][9], "lb");
+        assert_eq!(&rows[1][12], "8");
+    }
+
+    #[test]
+    fn test_markdown_lists_workouts_and_sets() {
+        let file = export_history(ExportFormat::Markdown, &sample_history(), &[], exported_at()).unwrap();
+
+        assert_eq!(file.file_name, "thiccc-history-2025-03-04.md");
+        assert!(file.contents.starts_with("# Workout History\n\nExported Mar 04, 2025 · 1 workout\n"));
+        assert!(file.contents.contains("## Push, Heavy\n\nMar 03, 2025 at 8:00 AM · 65:00 · 2 sets · Volume 1875\n"));
+        assert!(file.contents.contains("> Felt strong\n"));
+        assert!(file.contents.contains("### Bench Press\n\n1. 95 lb × 10 reps\n2. 185 lb × 5 reps @ 8.0 RPE\n"));
+    }
+}
@@ -0,0 +1,315 @@
+//! Minimal decoder for Garmin FIT files.
+//!
+//! Decodes the record stream of a FIT file into messages of integer fields,
+//! which is all the strength-training import needs (sessions and sets). The
+//! file header and trailing CRC are checked; string, float and signed fields,
+//! developer fields and chained files after the first are skipped.
+//!
+//! See the FIT protocol description for the wire format: a header, then
+//! definition messages describing the layout of each local message type,
+//! then data messages in that layout, then a CRC-16 over everything before it.
+
+use std::collections::HashMap;
+
+/// Seconds between the Unix epoch and the FIT epoch (1989-12-31T00:00:00Z).
+pub const FIT_EPOCH_OFFSET: i64 = 631_065_600;
+
+/// Global message numbers used by the importer.
+pub mod message {
+    /// Session summary (start time, elapsed time, sport)
+    pub const SESSION: u16 = 18;
+    /// One set of a strength-training activity
+    pub const SET: u16 = 225;
+}
+
+/// A decoded data message: its global number and integer fields.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FitMessage {
+    /// Global message number (e.g. `message::SET`)
+    pub global: u16,
+    /// Field number to values; arrays keep every valid element, and fields
+    /// whose values are all invalid are left out
+    pub fields: HashMap<u8, Vec<u64>>,
+}
+
+impl FitMessage {
+    /// Returns the first value of a field, if present.
+    pub fn value(&self, field: u8) -> Option<u64> {
+        self.fields.get(&field).and_then(|values| values.first().copied())
+    }
+}
+
+/// Returns true if `data` starts with a FIT file header.
+pub fn is_fit(data: &[u8]) -> bool {
+    data.len() >= 12 && &data[8..12] == b".FIT"
+}
+
+/// Decodes every data message in a FIT file.
+///
+/// # Errors
+///
+/// Returns an error if the header or CRC is wrong, or a record is truncated
+/// or refers to a local message type that was never defined.
+pub fn decode(data: &[u8]) -> Result<Vec<FitMessage>, String> {
+    if !is_fit(data) {
+        return Err("Not a FIT file".to_string());
+    }
+    let header_size = usize::from(data[0]);
+    if header_size < 12 {
+        return Err(format!("Invalid FIT header size {}", header_size));
+    }
+    let data_size = u32::from_le_bytes([data[4], data[5], data[6], data[7]]) as usize;
+    let end = header_size + data_size;
+    if data.len() < end + 2 {
+        return Err("FIT file is truncated".to_string());
+    }
+    let expected_crc = u16::from_le_bytes([data[end], data[end + 1]]);
+    if crc16(&data[..end]) != expected_crc {
+        return Err("FIT file is corrupt (CRC mismatch)".to_string());
+    }
+
+    let mut reader = Reader {
+        data: &data[..end],
+        position: header_size,
+    };
+    let mut definitions: HashMap<u8, Definition> = HashMap::new();
+    let mut messages = Vec::new();
+
+    while reader.position < end {
+        let header = reader.byte()?;
+        if header & 0x80 != 0 {
+            // Compressed timestamp header: data message, local type in bits 5-6
+            let local = (header >> 5) & 0x03;
+            messages.push(read_data(&mut reader, &definitions, local)?);
+        } else if header & 0x40 != 0 {
+            let local = header & 0x0F;
+            let has_developer_fields = header & 0x20 != 0;
+            definitions.insert(local, read_definition(&mut reader, has_developer_fields)?);
+        } else {
+            messages.push(read_data(&mut reader, &definitions, header & 0x0F)?);
+        }
+    }
+
+    Ok(messages)
+}
+
+/// CRC-16 as used by FIT files.
+pub fn crc16(bytes: &[u8]) -> u16 {
+    const TABLE: [u16; 16] = [
+        0x0000, 0xCC01, 0xD801, 0x1400, 0xF001, 0x3C00, 0x2800, 0xE401, 0xA001, 0x6C00, 0x7800,
+        0xB401, 0x5000, 0x9C01, 0x8801, 0x4400,
+    ];
+    bytes.iter().fold(0u16, |mut crc, &byte| {
+        for nibble in [byte & 0x0F, byte >> 4] {
+            let tmp = TABLE[usize::from(crc & 0x0F)];
+            crc = ((crc >> 4) & 0x0FFF) ^ tmp ^ TABLE[usize::from(nibble)];
+        }
+        crc
+    })
+}
+
+// =============================================================================
+// MARK: - Records
+// =============================================================================
+
+/// Layout of a local message type.
+struct Definition {
+    global: u16,
+    big_endian: bool,
+    fields: Vec<FieldDefinition>,
+    /// Total size of developer fields, skipped when reading data
+    developer_size: usize,
+}
+
+struct FieldDefinition {
+    number: u8,
+    size: usize,
+    base_type: u8,
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8], String> {
+        let bytes = self
+            .data
+            .get(self.position..self.position + count)
+            .ok_or_else(|| format!("FIT record truncated at byte {}", self.position))?;
+        self.position += count;
+        Ok(bytes)
+    }
+
+    fn byte(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+}
+
+fn read_definition(reader: &mut Reader, has_developer_fields: bool) -> Result<Definition, String> {
+    let _reserved = reader.byte()?;
+    let big_endian = reader.byte()? == 1;
+    let number = reader.take(2)?;
+    let global = if big_endian {
+        u16::from_be_bytes([number[0], number[1]])
+    } else {
+        u16::from_le_bytes([number[0], number[1]])
+    };
+
+    let field_count = reader.byte()?;
+    let mut fields = Vec::with_capacity(usize::from(field_count));
+    for _ in 0..field_count {
+        let field = reader.take(3)?;
+        fields.push(FieldDefinition {
+            number: field[0],
+            size: usize::from(field[1]),
+            base_type: field[2],
+        });
+    }
+
+    let mut developer_size = 0;
+    if has_developer_fields {
+        let developer_count = reader.byte()?;
+        for _ in 0..developer_count {
+            developer_size += usize::from(reader.take(3)?[1]);
+        }
+    }
+
+    Ok(Definition {
+        global,
+        big_endian,
+        fields,
+        developer_size,
+    })
+}
+
+fn read_data(
+    reader: &mut Reader,
+    definitions: &HashMap<u8, Definition>,
+    local: u8,
+) -> Result<FitMessage, String> {
+    let definition = definitions
+        .get(&local)
+        .ok_or_else(|| format!("FIT data message uses undefined local type {}", local))?;
+
+    let mut fields = HashMap::new();
+    for field in &definition.fields {
+        let bytes = reader.take(field.size)?;
+        if let Some(values) = decode_unsigned(bytes, field.base_type, definition.big_endian) {
+            if !values.is_empty() {
+                fields.insert(field.number, values);
+            }
+        }
+    }
+    reader.take(definition.developer_size)?;
+
+    Ok(FitMessage {
+        global: definition.global,
+        fields,
+    })
+}
+
+/// Decodes an unsigned (or enum) field into its valid values.
+///
+/// Returns None for base types the importer doesn't read.
+fn decode_unsigned(bytes: &[u8], base_type: u8, big_endian: bool) -> Option<Vec<u64>> {
+    // (element size, invalid value); "z" types use zero as invalid
+    let (size, invalid) = match base_type & 0x1F {
+        0x00 | 0x02 | 0x0D => (1, 0xFF),
+        0x0A => (1, 0),
+        0x04 => (2, 0xFFFF),
+        0x0B => (2, 0),
+        0x06 => (4, 0xFFFF_FFFF),
+        0x0C => (4, 0),
+        0x0F => (8, u64::MAX),
+        0x10 => (8, 0),
+        _ => return None,
+    };
+
+    Some(
+        bytes
+            .chunks_exact(size)
+            .map(|chunk| {
+                let mut value = 0u64;
+                for i in 0..size {
+                    let byte = if big_endian { chunk[i] } else { chunk[size - 1 - i] };
+                    value = (value << 8) | u64::from(byte);
+                }
+                value
+            })
+            .filter(|value| *value != invalid)
+            .collect(),
+    )
+}
+
+// =============================================================================
+// MARK: - Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Wraps records in a FIT header and trailing CRC.
+    fn fit_file(records: &[u8]) -> Vec<u8> {
+        let mut file = vec![12, 0x20, 0x08, 0x08];
+        file.extend((records.len() as u32).to_le_bytes());
+        file.extend(b".FIT");
+        file.extend(records);
+        let crc = crc16(&file);
+        file.extend(crc.to_le_bytes());
+        file
+    }
+
+    #[test]
+    fn test_decodes_definition_and_data_messages() {
+        let records = [
+            // Definition, local 0: global 225, uint16 field 3, uint16 array field 7
+            0x40, 0, 0, 225, 0, 2, 3, 2, 0x84, 7, 4, 0x84,
+            // Data, local 0: reps 5, categories [0, invalid]
+            0x00, 5, 0, 0, 0, 0xFF, 0xFF,
+        ];
+
+        let messages = decode(&fit_file(&records)).unwrap();
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].global, message::SET);
+        assert_eq!(messages[0].value(3), Some(5));
+        assert_eq!(messages[0].fields[&7], [0]);
+    }
+
+    #[test]
+    fn test_big_endian_developer_fields_and_compressed_headers() {
+        let records = [
+            // Definition, local 1, big endian, one developer field of 2 bytes
+            0x61, 0, 1, 0, 18, 1, 7, 4, 0x86, 1, 0, 2, 0,
+            // Normal data message
+            0x01, 0, 0, 0x0B, 0xB8, 0xAA, 0xBB,
+            // Compressed timestamp header for local 1
+            0xA3, 0, 0, 0, 60, 0xAA, 0xBB,
+        ];
+
+        let messages = decode(&fit_file(&records)).unwrap();
+
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].global, message::SESSION);
+        assert_eq!(messages[0].value(7), Some(3000));
+        assert_eq!(messages[1].value(7), Some(60));
+    }
+
+    #[test]
+    fn test_rejects_corrupt_files() {
+        let mut file = fit_file(&[0x40, 0, 0, 225, 0, 0]);
+        assert!(decode(&file).is_ok());
+
+        let last = file.len() - 1;
+        file[last] ^= 0xFF;
+        assert!(decode(&file).unwrap_err().contains("CRC"));
+
+        assert_eq!(decode(b"{\"workouts\": []}").unwrap_err(), "Not a FIT file");
+        assert!(decode(&fit_file(&[0x00, 1, 2]))
+            .unwrap_err()
+            .contains("undefined local type 0"));
+    }
+}
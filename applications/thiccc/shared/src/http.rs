@@ -0,0 +1,451 @@
+//! HTTP client logic for talking to `thiccc-api` from the core.
+//!
+//! The shell only performs single requests (`HttpOperation`). Everything
+//! else happens here so it's shared across platforms and testable without a
+//! network:
+//!
+//! - `ApiSession` - server address and the signed-in user's token, which is
+//!   added to every request as `Authorization: Bearer <token>`
+//! - `RetryPolicy` - which failures are retried and how long to back off
+//! - `send` - a request command that retries according to a policy
+//! - `decode` - turns a result into the response's `data` or an `HttpError`
+
+use crux_core::{command::RequestBuilder, Request};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::future::Future;
+
+use crate::operations::{
+    HttpHeader, HttpMethod, HttpOperation, HttpRequest, HttpResponse, HttpResult,
+};
+
+/// Timeout for API requests unless a request sets its own.
+pub const DEFAULT_TIMEOUT_MS: u32 = 30_000;
+
+// =============================================================================
+// MARK: - Session
+// =============================================================================
+
+/// Where the API is and who is signed in, as last reported by the shell.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct ApiSession {
+    /// Base URL of the API server (e.g. `https://api.thiccc.app`)
+    pub base_url: Option<String>,
+    /// Session token of the signed-in user
+    pub token: Option<String>,
+}
+
+impl ApiSession {
+    /// Whether requests can be sent (the server is known and a user is
+    /// signed in).
+    pub fn is_ready(&self) -> bool {
+        self.base_url.is_some() && self.token.is_some()
+    }
+
+    /// Builds an authenticated request for a path on the API server.
+    pub fn request(&self, method: HttpMethod, path: &str) -> Result<HttpRequest, HttpError> {
+        let base_url = self.base_url.as_deref().ok_or(HttpError::NotConfigured)?;
+        let token = self.token.as_deref().ok_or(HttpError::Unauthorized)?;
+        let url = format!(
+            "{}/{}",
+            base_url.trim_end_matches('/'),
+            path.trim_start_matches('/')
+        );
+        Ok(HttpRequest::new(method, url)
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Accept", "application/json"))
+    }
+}
+
+impl HttpRequest {
+    /// Creates a request with no headers or body and the default timeout.
+    pub fn new(method: HttpMethod, url: impl Into<String>) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+            timeout_ms: DEFAULT_TIMEOUT_MS,
+            delay_ms: 0,
+        }
+    }
+
+    /// Adds a header.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push(HttpHeader {
+            name: name.into(),
+            value: value.into(),
+        });
+        self
+    }
+
+    /// Sets a JSON body and its content type.
+    pub fn json<T: Serialize>(self, body: &T) -> Result<Self, HttpError> {
+        let body = serde_json::to_string(body).map_err(|e| HttpError::InvalidRequest {
+            message: e.to_string(),
+        })?;
+        let mut request = self.header("Content-Type", "application/json");
+        request.body = Some(body);
+        Ok(request)
+    }
+
+    /// The value of a header (names are case-insensitive).
+    pub fn header_value(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+}
+
+impl HttpResponse {
+    /// The value of a header (names are case-insensitive).
+    pub fn header_value(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    /// Whether the status is 2xx.
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+fn find_header<'a>(headers: &'a [HttpHeader], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|header| header.name.eq_ignore_ascii_case(name))
+        .map(|header| header.value.as_str())
+}
+
+// =============================================================================
+// MARK: - Retries
+// =============================================================================
+
+/// How often and how patiently to retry a request.
+///
+/// Failures to get a response, timeouts, 408, 429 and 5xx responses are
+/// retried; any other response is final. Delays double from `initial_delay_ms`
+/// up to `max_delay_ms`, or follow the server's `Retry-After` when it asks
+/// for longer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Attempts in total, including the first (at least 1)
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub initial_delay_ms: u32,
+    /// Longest delay between attempts
+    pub max_delay_ms: u32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_delay_ms: 500,
+            max_delay_ms: 8_000,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Sends the request once, for requests that must not be repeated.
+    pub fn never() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Whether a result is worth retrying.
+    pub fn is_retryable(result: &HttpResult) -> bool {
+        match result {
+            HttpResult::Response(response) => {
+                matches!(response.status, 408 | 429) || response.status >= 500
+            }
+            HttpResult::TimedOut | HttpResult::Failed { .. } => true,
+        }
+    }
+
+    /// The delay before another attempt after `attempt` attempts ended in
+    /// `result`, or None when the request should not be retried.
+    pub fn next_delay(&self, attempt: u32, result: &HttpResult) -> Option<u32> {
+        if attempt >= self.max_attempts || !Self::is_retryable(result) {
+            return None;
+        }
+        let backoff = self
+            .initial_delay_ms
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_delay_ms);
+        let retry_after = match result {
+            HttpResult::Response(response) => response
+                .header_value("Retry-After")
+                .and_then(|value| value.trim().parse::<u32>().ok())
+                .map(|seconds| seconds.saturating_mul(1000).min(self.max_delay_ms)),
+            _ => None,
+        };
+        Some(backoff.max(retry_after.unwrap_or(0)))
+    }
+}
+
+/// A command that sends the request, retrying per `policy`, and resolves to
+/// the last result.
+///
+/// Retries are sent as new requests with `delay_ms` set, so the shell waits
+/// before sending them.
+pub fn send<Effect, Event>(
+    request: HttpRequest,
+    policy: RetryPolicy,
+) -> RequestBuilder<Effect, Event, impl Future<Output = HttpResult>>
+where
+    Effect: Send + From<Request<HttpOperation>> + 'static,
+    Event: Send + 'static,
+{
+    RequestBuilder::new(move |ctx| async move {
+        let mut request = request;
+        let mut attempt = 1;
+        loop {
+            let result = ctx
+                .request_from_shell(HttpOperation::Send(request.clone()))
+                .await;
+            match policy.next_delay(attempt, &result) {
+                Some(delay_ms) => {
+                    request.delay_ms = delay_ms;
+                    attempt += 1;
+                }
+                None => return result,
+            }
+        }
+    })
+}
+
+// =============================================================================
+// MARK: - Responses
+// =============================================================================
+
+/// Why a request didn't produce the expected data.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HttpError {
+    /// No API server is configured on this device
+    NotConfigured,
+    /// Nobody is signed in, or the session was rejected (401)
+    Unauthorized,
+    /// The request could not be built
+    InvalidRequest { message: String },
+    /// The server answered with an error status
+    Status { status: u16, message: String },
+    /// The request timed out
+    TimedOut,
+    /// The request failed without a response (e.g. offline)
+    Network { message: String },
+    /// The response body wasn't the expected JSON
+    InvalidResponse { message: String },
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpError::NotConfigured => write!(f, "No server is set up on this device"),
+            HttpError::Unauthorized => write!(f, "You are not signed in"),
+            HttpError::InvalidRequest { message } => write!(f, "Invalid request: {}", message),
+            HttpError::Status { status, message } => {
+                write!(f, "The server returned an error ({}): {}", status, message)
+            }
+            HttpError::TimedOut => write!(f, "The server took too long to respond"),
+            HttpError::Network { message } => write!(f, "Couldn't reach the server: {}", message),
+            HttpError::InvalidResponse { message } => {
+                write!(f, "Unexpected response from the server: {}", message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+/// Body of a successful API response.
+#[derive(Deserialize)]
+struct DataEnvelope<T> {
+    data: T,
+}
+
+/// Body of an API error response.
+#[derive(Deserialize)]
+struct ErrorEnvelope {
+    error: ErrorBody,
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    message: String,
+}
+
+/// Decodes a result into the `data` of a successful response.
+pub fn decode<T: DeserializeOwned>(result: HttpResult) -> Result<T, HttpError> {
+    let response = match result {
+        HttpResult::Response(response) => response,
+        HttpResult::TimedOut => return Err(HttpError::TimedOut),
+        HttpResult::Failed { message } => return Err(HttpError::Network { message }),
+    };
+    if response.status == 401 {
+        return Err(HttpError::Unauthorized);
+    }
+    if !response.is_success() {
+        return Err(HttpError::Status {
+            status: response.status,
+            message: error_message(&response.body),
+        });
+    }
+    serde_json::from_str::<DataEnvelope<T>>(&response.body)
+        .map(|envelope| envelope.data)
+        .map_err(|e| HttpError::InvalidResponse {
+            message: e.to_string(),
+        })
+}
+
+/// The message of an error response: the `error.message` of the API's error
+/// envelope, or the body itself when it's plain text.
+fn error_message(body: &str) -> String {
+    match serde_json::from_str::<ErrorEnvelope>(body) {
+        Ok(envelope) => envelope.error.message,
+        Err(_) if body.trim().is_empty() => "no details".to_string(),
+        Err(_) => body.trim().to_string(),
+    }
+}
+
+// =============================================================================
+// MARK: - Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn response(status: u16, body: &str) -> HttpResult {
+        HttpResult::Response(HttpResponse {
+            status,
+            headers: vec![],
+            body: body.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_session_builds_authenticated_requests() {
+        let session = ApiSession {
+            base_url: Some("https://api.example.com/".to_string()),
+            token: Some("abc".to_string()),
+        };
+        let request = session
+            .request(HttpMethod::Post, "/api/sync")
+            .unwrap()
+            .json(&json!({ "cursor": 0 }))
+            .unwrap();
+
+        assert_eq!(request.url, "https://api.example.com/api/sync");
+        assert_eq!(request.header_value("authorization"), Some("Bearer abc"));
+        assert_eq!(request.header_value("Content-Type"), Some("application/json"));
+        assert_eq!(request.body.as_deref(), Some(r#"{"cursor":0}"#));
+        assert_eq!(request.timeout_ms, DEFAULT_TIMEOUT_MS);
+        assert_eq!(request.delay_ms, 0);
+    }
+
+    #[test]
+    fn test_session_needs_a_server_and_a_user() {
+        let signed_out = ApiSession {
+            base_url: Some("https://api.example.com".to_string()),
+            token: None,
+        };
+        assert_eq!(
+            signed_out.request(HttpMethod::Get, "api/me"),
+            Err(HttpError::Unauthorized)
+        );
+        assert_eq!(
+            ApiSession::default().request(HttpMethod::Get, "api/me"),
+            Err(HttpError::NotConfigured)
+        );
+        assert!(!signed_out.is_ready());
+    }
+
+    #[test]
+    fn test_retry_policy_backs_off_exponentially() {
+        let policy = RetryPolicy {
+            max_attempts: 5,
+            initial_delay_ms: 100,
+            max_delay_ms: 300,
+        };
+        let unavailable = response(503, "");
+
+        assert_eq!(policy.next_delay(1, &unavailable), Some(100));
+        assert_eq!(policy.next_delay(2, &unavailable), Some(200));
+        assert_eq!(policy.next_delay(3, &HttpResult::TimedOut), Some(300));
+        assert_eq!(policy.next_delay(5, &unavailable), None);
+        assert_eq!(RetryPolicy::never().next_delay(1, &unavailable), None);
+    }
+
+    #[test]
+    fn test_retry_policy_only_retries_transient_failures() {
+        let policy = RetryPolicy::default();
+        for retryable in [
+            response(500, ""),
+            response(408, ""),
+            response(429, ""),
+            HttpResult::Failed { message: "offline".to_string() },
+        ] {
+            assert!(policy.next_delay(1, &retryable).is_some(), "{:?}", retryable);
+        }
+        for last in [response(200, ""), response(400, ""), response(401, ""), response(422, "")] {
+            assert_eq!(policy.next_delay(1, &last), None, "{:?}", last);
+        }
+    }
+
+    #[test]
+    fn test_retry_policy_honors_retry_after() {
+        let policy = RetryPolicy::default();
+        let limited = HttpResult::Response(HttpResponse {
+            status: 429,
+            headers: vec![HttpHeader {
+                name: "retry-after".to_string(),
+                value: "2".to_string(),
+            }],
+            body: String::new(),
+        });
+        assert_eq!(policy.next_delay(1, &limited), Some(2_000));
+
+        let patient = HttpResult::Response(HttpResponse {
+            status: 503,
+            headers: vec![HttpHeader {
+                name: "Retry-After".to_string(),
+                value: "3600".to_string(),
+            }],
+            body: String::new(),
+        });
+        assert_eq!(policy.next_delay(1, &patient), Some(policy.max_delay_ms));
+    }
+
+    #[test]
+    fn test_decode_unwraps_data_and_maps_errors() {
+        let data: Vec<u32> = decode(response(200, r#"{"data":[1,2]}"#)).unwrap();
+        assert_eq!(data, vec![1, 2]);
+
+        assert_eq!(decode::<Vec<u32>>(response(401, "")), Err(HttpError::Unauthorized));
+        assert_eq!(
+            decode::<Vec<u32>>(response(
+                422,
+                r#"{"error":{"code":422,"message":"RPE must be 1-10"}}"#
+            )),
+            Err(HttpError::Status {
+                status: 422,
+                message: "RPE must be 1-10".to_string()
+            })
+        );
+        assert_eq!(
+            decode::<Vec<u32>>(response(500, "Database error")),
+            Err(HttpError::Status {
+                status: 500,
+                message: "Database error".to_string()
+            })
+        );
+        assert_eq!(decode::<Vec<u32>>(HttpResult::TimedOut), Err(HttpError::TimedOut));
+        assert!(matches!(
+            decode::<Vec<u32>>(response(200, "[1,2]")),
+            Err(HttpError::InvalidResponse { .. })
+        ));
+    }
+}
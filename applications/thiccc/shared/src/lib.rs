@@ -8,14 +8,38 @@
 //! # Architecture
 //!
 //! - `app` - The Crux application with events, model, and update logic
+//! - `bulk_import` - Deduplication rules for importing many workouts into history
+//! - `catalog` - Built-in exercise catalog and exercise-name matching
+//! - `csv_import` - Importers for Strong and Hevy CSV exports
+//! - `export` - History export as a JSON archive, per-set CSV or Markdown
 //! - `models` - Core data models (Workout, Exercise, ExerciseSet, etc.)
+//! - `fit` - Minimal decoder for Garmin FIT files
+//! - `http` - API session, retry policy and response decoding for HTTP requests
 //! - `id` - Type-safe ID wrapper with UUID validation
 //! - `operations` - Platform operations for shell communication
+//! - `schema` - Workout schema versioning and migrations of stored payloads
+//! - `summary` - Shareable text summary of a finished workout
+//! - `sync` - Field-level change log and merge rules for syncing with the API
+//! - `timestamp` - UTC timestamp that serializes cleanly across the FFI boundary
+//! - `validation` - Domain validation of workouts (references, value ranges)
+//! - `watch_import` - Importers for Garmin FIT and Apple Health strength workouts
 
 pub mod app;
+pub mod bulk_import;
+pub mod catalog;
+pub mod csv_import;
+pub mod export;
+pub mod fit;
+pub mod http;
 pub mod id;
 pub mod models;
 pub mod operations;
+pub mod schema;
+pub mod summary;
+pub mod sync;
+pub mod timestamp;
+pub mod validation;
+pub mod watch_import;
 
 use std::sync::LazyLock;
 
@@ -23,9 +47,15 @@ pub use crux_core::{bridge::Bridge, Core, Request};
 
 // Re-export all public types
 pub use app::*;
+pub use bulk_import::{ImportEntry, ImportSummary};
+pub use csv_import::{CsvImportOptions, CsvSource};
+pub use export::ExportFormat;
 pub use id::Id;
 pub use models::*;
 pub use operations::*;
+pub use schema::WORKOUT_SCHEMA_VERSION;
+pub use timestamp::Timestamp;
+pub use validation::ValidationError;
 
 // TODO hide this plumbing
 
@@ -5,7 +5,8 @@
 //! cross-platform communication between the Rust core and Swift shell.
 
 use crate::id::Id;
-use chrono::{DateTime, Utc};
+use crate::schema::WORKOUT_SCHEMA_VERSION;
+use crate::timestamp::Timestamp;
 use serde::{Deserialize, Serialize};
 
 // =============================================================================
@@ -36,6 +37,9 @@ pub enum ExerciseType {
     Unknown,
 }
 
+/// Pounds per kilogram.
+pub const LB_PER_KG: f64 = 2.204_622_621_8;
+
 /// Unit of weight measurement.
 ///
 /// Used to specify whether weights are in kilograms, pounds, or bodyweight.
@@ -51,6 +55,28 @@ pub enum WeightUnit {
     Bodyweight,
 }
 
+impl WeightUnit {
+    /// Returns the short display label for this unit (e.g., "lb", "kg").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WeightUnit::Kg => "kg",
+            WeightUnit::Lb => "lb",
+            WeightUnit::Bodyweight => "bw",
+        }
+    }
+
+    /// Converts a weight in this unit to `to`.
+    ///
+    /// Only kg and lb convert; bodyweight loads are returned unchanged.
+    pub fn convert(&self, weight: f64, to: &WeightUnit) -> f64 {
+        match (self, to) {
+            (WeightUnit::Lb, WeightUnit::Kg) => weight / LB_PER_KG,
+            (WeightUnit::Kg, WeightUnit::Lb) => weight * LB_PER_KG,
+            _ => weight,
+        }
+    }
+}
+
 /// Type of set within an exercise.
 ///
 /// Different set types affect how the set is tracked and displayed,
@@ -222,6 +248,18 @@ impl SetActual {
             _ => None,
         }
     }
+
+    /// Estimates the one-rep max (e1RM) for this set using the Epley formula.
+    ///
+    /// e1RM = weight × (1 + reps / 30). A single rep returns the weight as-is.
+    /// Returns `None` unless both weight and reps are set and positive.
+    pub fn estimated_one_rep_max(&self) -> Option<f64> {
+        match (self.weight, self.reps) {
+            (Some(w), Some(1)) if w > 0.0 => Some(w),
+            (Some(w), Some(r)) if w > 0.0 && r > 0 => Some(w * (1.0 + f64::from(r) / 30.0)),
+            _ => None,
+        }
+    }
 }
 
 /// A single set within an exercise.
@@ -293,6 +331,27 @@ impl ExerciseSet {
         self.actual = actual;
         self.is_completed = true;
     }
+
+    /// Formats this set for display (e.g., "225 lb × 10 reps @ 8.0 RPE").
+    ///
+    /// Uses actual values, falling back to suggested values for anything
+    /// not recorded. The RPE suffix is omitted when no RPE is available.
+    pub fn format_set(&self) -> String {
+        let weight = self.actual.weight.or(self.suggest.weight).unwrap_or(0.0);
+        let reps = self.actual.reps.or(self.suggest.reps).unwrap_or(0);
+        let rpe = self.actual.rpe.or(self.suggest.rpe);
+        let unit = self.weight_unit.clone().unwrap_or_default();
+
+        let mut text = if reps > 0 {
+            format!("{} {} × {} reps", weight, unit.as_str(), reps)
+        } else {
+            format!("{} {}", weight, unit.as_str())
+        };
+        if let Some(rpe) = rpe {
+            text.push_str(&format!(" @ {:.1} RPE", rpe));
+        }
+        text
+    }
 }
 
 // =============================================================================
@@ -335,7 +394,24 @@ pub struct Exercise {
     pub body_part: Option<BodyPart>,
 }
 
+/// Key that exercise names are matched by across workouts.
+///
+/// Each workout gives its exercises fresh IDs, so history, records and
+/// filters match exercises by name, ignoring case. Lowercasing is
+/// Unicode-aware ("Ücker Press" matches "ücker press"); the core, the API
+/// server and the iOS shell all compare names through this key (or its
+/// Swift equivalent, `lowercased()`) rather than a database `lower()`.
+pub fn exercise_name_key(name: &str) -> String {
+    name.to_lowercase()
+}
+
 impl Exercise {
+    /// Whether this exercise has the given name, ignoring case (see
+    /// `exercise_name_key`).
+    pub fn is_named(&self, name: &str) -> bool {
+        exercise_name_key(&self.name) == exercise_name_key(name)
+    }
+
     /// Creates a new exercise with the given name and workout ID.
     pub fn new(name: String, workout_id: Id) -> Self {
         Self {
@@ -395,6 +471,30 @@ impl Exercise {
             .sum()
     }
 
+    /// Returns the completed set with the highest estimated one-rep max.
+    ///
+    /// Returns `None` if no completed set has both weight and reps recorded.
+    pub fn best_set(&self) -> Option<&ExerciseSet> {
+        self.sets
+            .iter()
+            .filter(|set| set.is_completed)
+            .filter_map(|set| set.actual.estimated_one_rep_max().map(|e1rm| (set, e1rm)))
+            .fold(None, |best: Option<(&ExerciseSet, f64)>, (set, e1rm)| match best {
+                Some((_, best_e1rm)) if best_e1rm >= e1rm => best,
+                _ => Some((set, e1rm)),
+            })
+            .map(|(set, _)| set)
+    }
+
+    /// Returns the heaviest weight lifted in any completed set.
+    pub fn max_weight(&self) -> Option<f64> {
+        self.sets
+            .iter()
+            .filter(|set| set.is_completed)
+            .filter_map(|set| set.actual.weight)
+            .fold(None, |max: Option<f64>, w| Some(max.map_or(w, |m| m.max(w))))
+    }
+
     /// Adds a new empty set to this exercise.
     pub fn add_set(&mut self) -> &mut ExerciseSet {
         let set_index = self.sets.len() as i32;
@@ -414,6 +514,10 @@ impl Exercise {
 /// Tracks the workout name, notes, duration, and all exercises performed.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct Workout {
+    /// Schema version this workout was serialized with (see `schema`)
+    ///
+    /// Kept as the first field so binary payloads start with the version.
+    pub schema_version: u32,
     /// Unique identifier for this workout
     pub id: Id,
     /// User-provided name for the workout (e.g., "Push Day", "Leg Day")
@@ -422,10 +526,10 @@ pub struct Workout {
     pub note: Option<String>,
     /// Total duration of the workout in seconds
     pub duration: Option<i32>,
-    /// When the workout started (UTC, ISO8601 format)
-    pub start_timestamp: DateTime<Utc>,
-    /// When the workout ended (UTC, ISO8601 format)
-    pub end_timestamp: Option<DateTime<Utc>>,
+    /// When the workout started
+    pub start_timestamp: Timestamp,
+    /// When the workout ended
+    pub end_timestamp: Option<Timestamp>,
     /// Exercises performed in this workout
     pub exercises: Vec<Exercise>,
 }
@@ -434,11 +538,12 @@ impl Workout {
     /// Creates a new empty workout with the current timestamp.
     pub fn new() -> Self {
         Self {
+            schema_version: WORKOUT_SCHEMA_VERSION,
             id: Id::new(),
             name: String::new(),
             note: None,
             duration: None,
-            start_timestamp: Utc::now(),
+            start_timestamp: Timestamp::now(),
             end_timestamp: None,
             exercises: Vec::new(),
         }
@@ -482,7 +587,7 @@ impl Workout {
     /// # Arguments
     /// * `elapsed_seconds` - The actual elapsed time in seconds (excluding paused time)
     pub fn finish(&mut self, elapsed_seconds: i32) {
-        self.end_timestamp = Some(Utc::now());
+        self.end_timestamp = Some(Timestamp::now());
         self.duration = Some(elapsed_seconds);
     }
 
@@ -869,6 +974,91 @@ mod tests {
         assert_eq!(empty.volume(), None);
     }
 
+    #[test]
+    fn test_set_actual_estimated_one_rep_max() {
+        // Single rep returns the weight unchanged
+        assert_eq!(
+            SetActual::with_weight_and_reps(315.0, 1).estimated_one_rep_max(),
+            Some(315.0)
+        );
+
+        // Epley: 225 × (1 + 10/30) = 300
+        let e1rm = SetActual::with_weight_and_reps(225.0, 10)
+            .estimated_one_rep_max()
+            .unwrap();
+        assert!((e1rm - 300.0).abs() < 0.01);
+
+        // Missing or non-positive values produce no estimate
+        assert_eq!(SetActual::default().estimated_one_rep_max(), None);
+        assert_eq!(
+            SetActual::with_weight_and_reps(225.0, 0).estimated_one_rep_max(),
+            None
+        );
+        assert_eq!(
+            SetActual::with_weight_and_reps(0.0, 5).estimated_one_rep_max(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_exercise_set_format_set() {
+        let mut set = ExerciseSet::new(Id::new(), Id::new(), 0);
+        set.complete(SetActual {
+            weight: Some(225.0),
+            reps: Some(10),
+            rpe: Some(8.0),
+            ..Default::default()
+        });
+        assert_eq!(set.format_set(), "225 lb × 10 reps @ 8.0 RPE");
+
+        // Falls back to suggested values and omits a missing RPE
+        let mut planned = ExerciseSet::new(Id::new(), Id::new(), 1);
+        planned.weight_unit = Some(WeightUnit::Kg);
+        planned.suggest = SetSuggest::with_weight_and_reps(100.0, 5);
+        assert_eq!(planned.format_set(), "100 kg × 5 reps");
+
+        // No reps shows weight only
+        let empty = ExerciseSet::new(Id::new(), Id::new(), 2);
+        assert_eq!(empty.format_set(), "0 lb");
+    }
+
+    #[test]
+    fn test_exercise_best_set_and_max_weight() {
+        let mut exercise = Exercise::new("Bench Press".to_string(), Id::new());
+        assert!(exercise.best_set().is_none());
+        assert_eq!(exercise.max_weight(), None);
+
+        // e1RM 225 × (1 + 10/30) = 300
+        exercise
+            .add_set()
+            .complete(SetActual::with_weight_and_reps(225.0, 10));
+        // e1RM 275 × (1 + 3/30) = 302.5 (best)
+        exercise
+            .add_set()
+            .complete(SetActual::with_weight_and_reps(275.0, 3));
+        // Heavier but not completed - ignored
+        exercise.add_set().actual = SetActual::with_weight_and_reps(405.0, 1);
+
+        let best = exercise.best_set().expect("Should have a best set");
+        assert_eq!(best.set_index, 1);
+        assert_eq!(exercise.max_weight(), Some(275.0));
+    }
+
+    #[test]
+    fn test_weight_unit_as_str() {
+        assert_eq!(WeightUnit::Kg.as_str(), "kg");
+        assert_eq!(WeightUnit::Lb.as_str(), "lb");
+        assert_eq!(WeightUnit::Bodyweight.as_str(), "bw");
+    }
+
+    #[test]
+    fn test_weight_unit_convert() {
+        assert!((WeightUnit::Kg.convert(100.0, &WeightUnit::Lb) - 220.462).abs() < 0.001);
+        assert!((WeightUnit::Lb.convert(220.462_262_18, &WeightUnit::Kg) - 100.0).abs() < 1e-9);
+        assert_eq!(WeightUnit::Kg.convert(60.0, &WeightUnit::Kg), 60.0);
+        assert_eq!(WeightUnit::Bodyweight.convert(25.0, &WeightUnit::Kg), 25.0);
+    }
+
     #[test]
     fn test_exercise_set_new_warmup() {
         let exercise_id = Id::new();
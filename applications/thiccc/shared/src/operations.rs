@@ -14,15 +14,18 @@
 //!
 //! # Note on Workout Data
 //!
-//! Database and Storage operations use JSON strings for Workout data instead
-//! of the Workout type directly. This is because TypeGen has issues tracing
-//! Request<T> when T contains complex nested types like chrono::DateTime.
-//! The shell deserializes the JSON to reconstruct Workout objects.
+//! Database and Storage operations carry `Workout` values directly. Workout
+//! timestamps use [`Timestamp`], which TypeGen can trace (unlike
+//! `chrono::DateTime`), so the shell gets generated Swift types instead of
+//! JSON it has to build and parse by hand.
 
 use crux_core::capability::Operation;
 use serde::{Deserialize, Serialize};
 
 use crate::app::{DatabaseResult, StorageResult};
+use crate::models::Workout;
+use crate::sync::SyncState;
+use crate::timestamp::Timestamp;
 
 // =============================================================================
 // MARK: - Database Operations
@@ -32,24 +35,43 @@ use crate::app::{DatabaseResult, StorageResult};
 ///
 /// The database stores completed workouts with their exercises and sets.
 /// On iOS, this is implemented using GRDB (SQLite).
-///
-/// **Note**: SaveWorkout uses JSON-encoded workout data to avoid TypeGen
-/// tracing issues with complex nested types in Request<T>.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
 pub enum DatabaseOperation {
     /// Save a completed workout to the database.
     ///
-    /// The String is a JSON-encoded Workout object.
     /// This includes all exercises and sets within the workout.
     /// Called when user finishes a workout.
-    SaveWorkout(String),
+    SaveWorkout(Workout),
 
-    /// Load all workouts from the database for the history view.
+    /// Load every stored workout with its exercises and sets.
     ///
-    /// Returns workouts in reverse chronological order (newest first).
+    /// Returns workouts in reverse chronological order (newest first). Used
+    /// for export and to record history for the first sync, which both need
+    /// whole workouts, so the shell must not return list summaries here.
     #[default]
     LoadAllWorkouts,
 
+    /// Load one page of workout history matching a filter.
+    ///
+    /// Returns up to `query.limit` workouts older than `query.cursor`, newest
+    /// first, ordered by (start_timestamp DESC, id DESC). The shell echoes the
+    /// query back in the result so the core can drop stale pages.
+    LoadWorkoutsPage(HistoryQuery),
+
+    /// Load every stored session of the named exercises.
+    ///
+    /// Names match by `exercise_name_key`. Returns the workouts containing any of
+    /// them, newest first, each with only the matching exercises and their
+    /// sets. Used for exercise history, which must cover the whole database
+    /// rather than the page of history loaded so far.
+    LoadExerciseHistory(Vec<String>),
+
+    /// Load the name of every exercise with at least one stored session.
+    ///
+    /// Each name is returned once per `exercise_name_key`, in any case it
+    /// was logged with.
+    LoadExerciseNames,
+
     /// Load a specific workout by its ID.
     ///
     /// Used when viewing workout details from history.
@@ -61,32 +83,128 @@ pub enum DatabaseOperation {
     /// Removes the workout and all associated exercises and sets.
     /// The String is the UUID in lowercase string format.
     DeleteWorkout(String),
+
+    /// Replace a previously saved workout with an edited copy.
+    ///
+    /// Exercises and sets that are no longer present in the edited workout
+    /// must be removed. Called when user saves edits to a workout from history.
+    UpdateWorkout(Workout),
+
+    /// Insert many finished workouts into history in one transaction.
+    ///
+    /// The shell applies the rules in `bulk_import` against every stored
+    /// workout: a workout whose id or (start time, name) fingerprint is
+    /// already stored is skipped, and one whose id belongs to a different
+    /// workout is a conflict. Stored workouts are never overwritten.
+    ImportWorkouts(Vec<Workout>),
 }
 
 impl Operation for DatabaseOperation {
     type Output = DatabaseResult;
 }
 
+/// A request for one page of workout history.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct HistoryQuery {
+    /// Position to continue from (None for the first page)
+    pub cursor: Option<HistoryCursor>,
+    /// Maximum number of workouts to return
+    pub limit: u32,
+    /// Which workouts to include
+    pub filter: HistoryFilter,
+}
+
+/// Keyset pagination cursor: the last workout of the previous page.
+///
+/// The workout ID breaks ties between workouts with the same start time.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct HistoryCursor {
+    /// Start time of the last workout returned
+    pub start_timestamp: Timestamp,
+    /// ID of the last workout returned
+    pub workout_id: String,
+}
+
+impl HistoryCursor {
+    /// Creates a cursor positioned after the given workout.
+    pub fn after(workout: &Workout) -> Self {
+        Self {
+            start_timestamp: workout.start_timestamp,
+            workout_id: workout.id.as_str().to_string(),
+        }
+    }
+}
+
+/// Filters for the workout history list.
+///
+/// All fields are optional; an empty filter matches every workout.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct HistoryFilter {
+    /// Only workouts starting at or after this time
+    pub from_timestamp: Option<Timestamp>,
+    /// Only workouts starting before this time
+    pub to_timestamp: Option<Timestamp>,
+    /// Only workouts containing this exercise (case-insensitive exact match,
+    /// folding Unicode case like `exercise_name_key`)
+    pub exercise_name: Option<String>,
+    /// Only workouts whose name contains this text (case-insensitive,
+    /// folding Unicode case; `%` and `_` are literal characters)
+    pub name_search: Option<String>,
+}
+
+impl HistoryFilter {
+    /// Returns whether no filter criteria are set.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns whether a workout satisfies this filter.
+    ///
+    /// Mirrors the query the shell runs against the database, so workouts
+    /// added locally (e.g. on FinishWorkout) can be placed consistently.
+    pub fn matches(&self, workout: &Workout) -> bool {
+        let start = workout.start_timestamp;
+
+        if self.from_timestamp.is_some_and(|from| start < from) {
+            return false;
+        }
+        if self.to_timestamp.is_some_and(|to| start >= to) {
+            return false;
+        }
+        if let Some(exercise_name) = &self.exercise_name {
+            if !workout
+                .exercises
+                .iter()
+                .any(|e| e.is_named(exercise_name))
+            {
+                return false;
+            }
+        }
+        if let Some(search) = &self.name_search {
+            if !workout.name.to_lowercase().contains(&search.to_lowercase()) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
 // =============================================================================
 // MARK: - Storage Operations
 // =============================================================================
 
 /// Operations for file-based storage of the current workout.
 ///
-/// The current in-progress workout is persisted to a JSON file so it can
+/// The current in-progress workout is persisted to a file so it can
 /// be restored if the app is terminated. This is separate from the database
 /// which only stores completed workouts.
-///
-/// **Note**: SaveCurrentWorkout uses JSON-encoded workout data to avoid TypeGen
-/// tracing issues with complex nested types in Request<T>.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
 pub enum StorageOperation {
     /// Save the current in-progress workout to file storage.
     ///
-    /// The String is a JSON-encoded Workout object.
     /// Called periodically and when the app goes to background.
     /// Overwrites any existing saved workout.
-    SaveCurrentWorkout(String),
+    SaveCurrentWorkout(Workout),
 
     /// Load the current workout from file storage.
     ///
@@ -99,12 +217,131 @@ pub enum StorageOperation {
     ///
     /// Called when a workout is finished or discarded.
     DeleteCurrentWorkout,
+
+    /// Save the sync bookkeeping (replica id, cursor and unsent changes).
+    ///
+    /// Called whenever a saved workout changes and after each sync.
+    /// Overwrites any existing saved state.
+    SaveSyncState(SyncState),
+
+    /// Load the sync bookkeeping saved by `SaveSyncState`.
+    ///
+    /// Called when the app launches. Returns None if nothing was saved.
+    LoadSyncState,
 }
 
 impl Operation for StorageOperation {
     type Output = StorageResult;
 }
 
+// =============================================================================
+// MARK: - HTTP Operations
+// =============================================================================
+
+/// HTTP request methods.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum HttpMethod {
+    #[default]
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+}
+
+/// One HTTP header.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+pub struct HttpHeader {
+    pub name: String,
+    pub value: String,
+}
+
+/// A request for the shell to send exactly as given.
+///
+/// The core adds the session token and decides on retries (see `http`).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+pub struct HttpRequest {
+    pub method: HttpMethod,
+    /// Absolute URL
+    pub url: String,
+    pub headers: Vec<HttpHeader>,
+    /// UTF-8 body (JSON for the API)
+    pub body: Option<String>,
+    /// Give up and return `HttpResult::TimedOut` after this long
+    pub timeout_ms: u32,
+    /// Wait this long before sending (backoff before a retry)
+    pub delay_ms: u32,
+}
+
+/// Operations for talking to HTTP servers (the API server).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum HttpOperation {
+    /// Send one request and return whatever happened.
+    Send(HttpRequest),
+}
+
+impl Operation for HttpOperation {
+    type Output = HttpResult;
+}
+
+/// A response with any status.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<HttpHeader>,
+    /// Body decoded as UTF-8
+    pub body: String,
+}
+
+/// Result of an HTTP operation.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum HttpResult {
+    /// The server responded (with any status)
+    Response(HttpResponse),
+    /// No response within the request's timeout
+    TimedOut,
+    /// The request could not be sent or the connection failed
+    Failed { message: String },
+}
+
+// =============================================================================
+// MARK: - Share Operations
+// =============================================================================
+
+/// Operations for handing generated content to the platform share sheet.
+///
+/// The core produces the finished content; the shell only presents it (share
+/// sheet, "Save to Files", AirDrop) and reports what the user did.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum ShareOperation {
+    /// Share a file with the given name, MIME type and text contents.
+    ShareFile {
+        file_name: String,
+        mime_type: String,
+        contents: String,
+    },
+    /// Share plain text (e.g. a workout summary).
+    ShareText { text: String },
+    /// Copy plain text to the clipboard.
+    CopyText { text: String },
+}
+
+impl Operation for ShareOperation {
+    type Output = ShareResult;
+}
+
+/// Result of a share operation.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+pub enum ShareResult {
+    /// The user shared or saved the content (or it was copied)
+    #[default]
+    Shared,
+    /// The user dismissed the share sheet
+    Cancelled,
+    /// The content could not be shared (e.g. the file couldn't be written)
+    Failed { message: String },
+}
+
 // =============================================================================
 // MARK: - Timer Operations
 // =============================================================================
@@ -123,6 +360,9 @@ pub enum TimerOutput {
 
     /// Timer was successfully stopped.
     Stopped,
+
+    /// The timer could not be started or stopped.
+    Error { message: String },
 }
 
 /// Operations for the workout timer.
@@ -158,9 +398,20 @@ mod tests {
 
     #[test]
     fn test_database_operation_serialization() {
-        // SaveWorkout now takes a JSON string (not a Workout directly)
-        let workout_json = r#"{"id":"123","name":"Test"}"#.to_string();
-        let op = DatabaseOperation::SaveWorkout(workout_json);
+        let mut workout = Workout::with_name("Test");
+        workout.add_exercise("Squat").add_set();
+        let op = DatabaseOperation::SaveWorkout(workout);
+
+        let json = serde_json::to_string(&op).expect("Failed to serialize");
+        let deserialized: DatabaseOperation =
+            serde_json::from_str(&json).expect("Failed to deserialize");
+
+        assert_eq!(op, deserialized);
+    }
+
+    #[test]
+    fn test_database_operation_update_workout_serialization() {
+        let op = DatabaseOperation::UpdateWorkout(Workout::with_name("Edited"));
 
         let json = serde_json::to_string(&op).expect("Failed to serialize");
         let deserialized: DatabaseOperation =
@@ -169,6 +420,21 @@ mod tests {
         assert_eq!(op, deserialized);
     }
 
+    #[test]
+    fn test_database_operation_bincode_roundtrip() {
+        // Bincode is the format the shell actually sees
+        let mut workout = Workout::with_name("Test");
+        workout.add_exercise("Squat").add_set();
+        workout.finish(600);
+        let op = DatabaseOperation::SaveWorkout(workout);
+
+        let bytes = bincode::serialize(&op).expect("Failed to serialize");
+        let deserialized: DatabaseOperation =
+            bincode::deserialize(&bytes).expect("Failed to deserialize");
+
+        assert_eq!(op, deserialized);
+    }
+
     #[test]
     fn test_database_operation_load_all() {
         let op = DatabaseOperation::LoadAllWorkouts;
@@ -180,11 +446,130 @@ mod tests {
         assert_eq!(op, deserialized);
     }
 
+    #[test]
+    fn test_database_operation_load_page_serialization() {
+        let op = DatabaseOperation::LoadWorkoutsPage(HistoryQuery {
+            cursor: Some(HistoryCursor {
+                start_timestamp: "2025-01-01T12:00:00Z".parse().unwrap(),
+                workout_id: "550e8400-e29b-41d4-a716-446655440000".to_string(),
+            }),
+            limit: 20,
+            filter: HistoryFilter {
+                exercise_name: Some("Squat".to_string()),
+                ..Default::default()
+            },
+        });
+
+        let json = serde_json::to_string(&op).expect("Failed to serialize");
+        let deserialized: DatabaseOperation =
+            serde_json::from_str(&json).expect("Failed to deserialize");
+
+        assert_eq!(op, deserialized);
+    }
+
+    #[test]
+    fn test_database_operation_load_exercise_history_serialization() {
+        let op = DatabaseOperation::LoadExerciseHistory(vec!["Bench Press".to_string()]);
+
+        let json = serde_json::to_string(&op).expect("Failed to serialize");
+        let deserialized: DatabaseOperation =
+            serde_json::from_str(&json).expect("Failed to deserialize");
+
+        assert_eq!(op, deserialized);
+    }
+
+    #[test]
+    fn test_history_cursor_after_workout() {
+        let mut workout = Workout::new();
+        workout.start_timestamp = "2025-01-01T12:00:00Z".parse().unwrap();
+
+        let cursor = HistoryCursor::after(&workout);
+        assert_eq!(cursor.start_timestamp.as_millis(), 1_735_732_800_000);
+        assert_eq!(cursor.workout_id, workout.id.as_str());
+    }
+
+    #[test]
+    fn test_history_filter_matches() {
+        let mut workout = Workout::with_name("Heavy Leg Day");
+        workout.start_timestamp = "2025-01-01T12:00:00Z".parse().unwrap();
+        workout.add_exercise("Squat");
+        let start = workout.start_timestamp;
+        let later = Timestamp::from_millis(start.as_millis() + 1).unwrap();
+
+        // Empty filter matches everything
+        let empty = HistoryFilter::default();
+        assert!(empty.is_empty());
+        assert!(empty.matches(&workout));
+
+        // Date range is inclusive start, exclusive end
+        let in_range = HistoryFilter {
+            from_timestamp: Some(start),
+            to_timestamp: Some(later),
+            ..Default::default()
+        };
+        assert!(!in_range.is_empty());
+        assert!(in_range.matches(&workout));
+        let too_late = HistoryFilter {
+            to_timestamp: Some(start),
+            ..Default::default()
+        };
+        assert!(!too_late.matches(&workout));
+        let too_early = HistoryFilter {
+            from_timestamp: Some(later),
+            ..Default::default()
+        };
+        assert!(!too_early.matches(&workout));
+
+        // Exercise name is an exact, case-insensitive match
+        let squat = HistoryFilter {
+            exercise_name: Some("squat".to_string()),
+            ..Default::default()
+        };
+        assert!(squat.matches(&workout));
+        let bench = HistoryFilter {
+            exercise_name: Some("Bench Press".to_string()),
+            ..Default::default()
+        };
+        assert!(!bench.matches(&workout));
+
+        // Name search is a case-insensitive substring match
+        let search = HistoryFilter {
+            name_search: Some("leg".to_string()),
+            ..Default::default()
+        };
+        assert!(search.matches(&workout));
+        let miss = HistoryFilter {
+            name_search: Some("push".to_string()),
+            ..Default::default()
+        };
+        assert!(!miss.matches(&workout));
+    }
+
+    #[test]
+    fn test_history_filter_folds_unicode_and_keeps_wildcards_literal() {
+        let mut workout = Workout::with_name("Über 100% Push");
+        workout.add_exercise("Überzug");
+
+        let search = |text: &str| HistoryFilter {
+            name_search: Some(text.to_string()),
+            ..Default::default()
+        };
+        assert!(search("über").matches(&workout));
+        assert!(search("ÜBER").matches(&workout));
+        assert!(search("100%").matches(&workout));
+        assert!(!search("1%0").matches(&workout), "% is not a wildcard");
+        assert!(!search("_ber").matches(&workout), "_ is not a wildcard");
+
+        let exercise = HistoryFilter {
+            exercise_name: Some("ÜBERZUG".to_string()),
+            ..Default::default()
+        };
+        assert!(exercise.matches(&workout));
+    }
+
     #[test]
     fn test_storage_operation_serialization() {
-        // SaveCurrentWorkout now takes a JSON string (not a Workout directly)
-        let workout_json = r#"{"id":"123","name":"Test"}"#.to_string();
-        let op = StorageOperation::SaveCurrentWorkout(workout_json);
+        let op = StorageOperation::SaveCurrentWorkout(Workout::with_name("Test"));
 
         let json = serde_json::to_string(&op).expect("Failed to serialize");
         let deserialized: StorageOperation =
@@ -204,6 +589,19 @@ mod tests {
         assert_eq!(op, deserialized);
     }
 
+    #[test]
+    fn test_storage_operation_sync_state_bincode_roundtrip() {
+        let mut state = SyncState::new_replica();
+        state.record(None, Some(&Workout::with_name("Test")));
+        let op = StorageOperation::SaveSyncState(state);
+
+        let bytes = bincode::serialize(&op).expect("Failed to serialize");
+        let deserialized: StorageOperation =
+            bincode::deserialize(&bytes).expect("Failed to deserialize");
+
+        assert_eq!(op, deserialized);
+    }
+
     #[test]
     fn test_timer_operation_serialization() {
         let op = TimerOperation::Start;
@@ -233,4 +631,3 @@ mod tests {
         assert_eq!(op, StorageOperation::LoadCurrentWorkout);
     }
 }
-
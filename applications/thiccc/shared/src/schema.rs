@@ -0,0 +1,253 @@
+//! Versioned workout schema and migrations.
+//!
+//! Every serialized `Workout` carries a `schema_version`. When the shape of
+//! `Workout`, `Exercise` or `ExerciseSet` changes, bump
+//! `WORKOUT_SCHEMA_VERSION` and append a migration to `MIGRATIONS` that
+//! upgrades the previous version's JSON. Payloads are then upgraded on load
+//! instead of failing to deserialize.
+//!
+//! # Version History
+//!
+//! | Version | Change |
+//! |---------|--------|
+//! | 1 | Original layout, written before versioning (no `schema_version` field) |
+//! | 2 | Adds `schema_version` |
+//!
+//! Golden files for each version live in `shared/golden/` and are checked by
+//! the tests at the bottom of this module.
+
+use crate::models::Workout;
+use serde_json::{Map, Value};
+
+/// The schema version written by this build.
+pub const WORKOUT_SCHEMA_VERSION: u32 = 2;
+
+/// Version assumed for payloads without a `schema_version` field.
+const UNVERSIONED_SCHEMA_VERSION: u32 = 1;
+
+/// Upgrades a workout JSON object from one version to the next.
+type Migration = fn(&mut Map<String, Value>) -> Result<(), String>;
+
+/// Migration chain. `MIGRATIONS[i]` upgrades version `i + 1` to `i + 2`.
+const MIGRATIONS: [Migration; (WORKOUT_SCHEMA_VERSION - 1) as usize] = [migrate_v1_to_v2];
+
+/// v1 → v2: No structural change; the version stamp is added by the driver.
+fn migrate_v1_to_v2(_workout: &mut Map<String, Value>) -> Result<(), String> {
+    Ok(())
+}
+
+/// Reads the schema version of a workout JSON object.
+///
+/// Returns version 1 for payloads written before versioning existed.
+///
+/// # Errors
+///
+/// Returns an error if the payload is not an object or the version is not a
+/// positive integer.
+pub fn schema_version_of(value: &Value) -> Result<u32, String> {
+    let object = value
+        .as_object()
+        .ok_or_else(|| "Workout payload is not a JSON object".to_string())?;
+
+    match object.get("schema_version") {
+        None => Ok(UNVERSIONED_SCHEMA_VERSION),
+        Some(version) => version
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .filter(|v| *v >= 1)
+            .ok_or_else(|| format!("Invalid schema_version: {}", version)),
+    }
+}
+
+/// Upgrades a workout JSON object to `WORKOUT_SCHEMA_VERSION`.
+///
+/// # Errors
+///
+/// Returns an error if the payload was written by a newer build, or if a
+/// migration fails.
+pub fn migrate_workout_value(mut value: Value) -> Result<Value, String> {
+    let mut version = schema_version_of(&value)?;
+    if version > WORKOUT_SCHEMA_VERSION {
+        return Err(format!(
+            "Workout schema version {} is newer than supported version {}",
+            version, WORKOUT_SCHEMA_VERSION
+        ));
+    }
+
+    let object = value
+        .as_object_mut()
+        .expect("schema_version_of rejects non-objects");
+    while version < WORKOUT_SCHEMA_VERSION {
+        MIGRATIONS[(version - 1) as usize](object)
+            .map_err(|e| format!("Migration from schema version {} failed: {}", version, e))?;
+        version += 1;
+        object.insert("schema_version".to_string(), Value::from(version));
+    }
+
+    Ok(value)
+}
+
+/// Parses a workout from JSON, upgrading older schema versions.
+///
+/// # Errors
+///
+/// Returns an error if the JSON is malformed, from an unsupported version,
+/// or does not match the current `Workout` shape after migration.
+pub fn workout_from_json(json: &str) -> Result<Workout, String> {
+    let value: Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    let value = migrate_workout_value(value)?;
+    serde_json::from_value(value).map_err(|e| e.to_string())
+}
+
+/// Parses a JSON array of workouts, upgrading each from its own version.
+///
+/// # Errors
+///
+/// Returns an error naming the index of the first workout that can't be
+/// read, or if the JSON is not an array.
+pub fn workouts_from_json(json: &str) -> Result<Vec<Workout>, String> {
+    let values: Vec<Value> = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    values
+        .into_iter()
+        .enumerate()
+        .map(|(index, value)| {
+            migrate_workout_value(value)
+                .and_then(|value| serde_json::from_value(value).map_err(|e| e.to_string()))
+                .map_err(|e| format!("Workout {}: {}", index, e))
+        })
+        .collect()
+}
+
+/// Brings a typed workout from the shell up to the current schema version.
+///
+/// Typed payloads already match the current shape, but may be stamped with
+/// an older version (e.g. database rows written before versioning).
+///
+/// # Errors
+///
+/// Returns an error if the workout was written by a newer build, or if a
+/// migration fails.
+pub fn upgrade_workout(workout: Workout) -> Result<Workout, String> {
+    match workout.schema_version {
+        WORKOUT_SCHEMA_VERSION => Ok(workout),
+        version if version > WORKOUT_SCHEMA_VERSION => Err(format!(
+            "Workout schema version {} is newer than supported version {}",
+            version, WORKOUT_SCHEMA_VERSION
+        )),
+        _ => {
+            let value = serde_json::to_value(&workout).map_err(|e| e.to_string())?;
+            let value = migrate_workout_value(value)?;
+            serde_json::from_value(value).map_err(|e| e.to_string())
+        }
+    }
+}
+
+// =============================================================================
+// MARK: - Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::id::Id;
+    use crate::models::{Exercise, SetActual, SetSuggest, WeightUnit};
+    use crate::timestamp::Timestamp;
+
+    const GOLDEN_V1: &str = include_str!("../golden/workout_v1.json");
+    const GOLDEN_V2: &str = include_str!("../golden/workout_v2.json");
+
+    fn id(s: &str) -> Id {
+        Id::from_string(s.to_string()).unwrap()
+    }
+
+    /// The workout every golden file describes.
+    fn golden_workout() -> Workout {
+        let workout_id = id("11111111-1111-4111-8111-111111111111");
+        let mut exercise = Exercise::new("Bench Press".to_string(), workout_id.clone());
+        exercise.id = id("22222222-2222-4222-8222-222222222222");
+        exercise.weight_unit = Some(WeightUnit::Lb);
+
+        let set = exercise.add_set();
+        set.id = id("33333333-3333-4333-8333-333333333333");
+        set.suggest = SetSuggest::with_weight_and_reps(225.0, 5);
+        set.complete(SetActual::with_weight_and_reps(225.0, 5));
+
+        Workout {
+            schema_version: WORKOUT_SCHEMA_VERSION,
+            id: workout_id,
+            name: "Push Day".to_string(),
+            note: Some("Felt strong".to_string()),
+            duration: Some(3600),
+            start_timestamp: "2025-01-01T12:00:00Z".parse::<Timestamp>().unwrap(),
+            end_timestamp: Some("2025-01-01T13:00:00Z".parse::<Timestamp>().unwrap()),
+            exercises: vec![exercise],
+        }
+    }
+
+    #[test]
+    fn test_golden_files_cover_every_version() {
+        assert_eq!(MIGRATIONS.len() + 1, WORKOUT_SCHEMA_VERSION as usize);
+        for (version, golden) in [(1, GOLDEN_V1), (2, GOLDEN_V2)] {
+            let value: Value = serde_json::from_str(golden).unwrap();
+            assert_eq!(schema_version_of(&value), Ok(version));
+        }
+    }
+
+    #[test]
+    fn test_golden_v1_migrates_to_current() {
+        assert_eq!(workout_from_json(GOLDEN_V1), Ok(golden_workout()));
+    }
+
+    #[test]
+    fn test_golden_v2_loads() {
+        assert_eq!(workout_from_json(GOLDEN_V2), Ok(golden_workout()));
+    }
+
+    #[test]
+    fn test_current_serialization_matches_latest_golden() {
+        // If this fails, the Workout shape changed: bump WORKOUT_SCHEMA_VERSION,
+        // add a migration and a new golden file instead of editing this one.
+        let serialized = serde_json::to_value(golden_workout()).unwrap();
+        let golden: Value = serde_json::from_str(GOLDEN_V2).unwrap();
+        assert_eq!(serialized, golden);
+    }
+
+    #[test]
+    fn test_newer_schema_version_is_rejected() {
+        let mut value: Value = serde_json::from_str(GOLDEN_V2).unwrap();
+        value["schema_version"] = Value::from(WORKOUT_SCHEMA_VERSION + 1);
+
+        let error = workout_from_json(&value.to_string()).unwrap_err();
+        assert!(error.contains("newer than supported"));
+    }
+
+    #[test]
+    fn test_invalid_schema_version_is_rejected() {
+        assert!(schema_version_of(&serde_json::json!({ "schema_version": 0 })).is_err());
+        assert!(schema_version_of(&serde_json::json!({ "schema_version": "2" })).is_err());
+        assert!(schema_version_of(&serde_json::json!([])).is_err());
+    }
+
+    #[test]
+    fn test_workouts_from_json_migrates_each_entry() {
+        let json = format!("[{}, {}]", GOLDEN_V1, GOLDEN_V2);
+        assert_eq!(
+            workouts_from_json(&json),
+            Ok(vec![golden_workout(), golden_workout()])
+        );
+
+        let error = workouts_from_json(&format!("[{}, {{}}]", GOLDEN_V2)).unwrap_err();
+        assert!(error.starts_with("Workout 1:"));
+    }
+
+    #[test]
+    fn test_upgrade_workout_stamps_current_version() {
+        let mut workout = golden_workout();
+        workout.schema_version = 1;
+        assert_eq!(upgrade_workout(workout), Ok(golden_workout()));
+
+        let mut future = golden_workout();
+        future.schema_version = WORKOUT_SCHEMA_VERSION + 1;
+        assert!(upgrade_workout(future).is_err());
+    }
+}
@@ -0,0 +1,298 @@
+//! Shareable text summary of a finished workout.
+//!
+//! Built when a workout is finished and handed to the shell as plain text for
+//! the share sheet or clipboard. Sets are written with `ExerciseSet::format_set`,
+//! the same text the history detail view shows.
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{Exercise, ExerciseSet, WeightUnit, Workout};
+
+/// A new best estimated one-rep max for an exercise.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: A record always belongs to a specific exercise and only exists
+/// when it beats an earlier session.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PersonalRecord {
+    /// Exercise name as logged in the finished workout
+    pub exercise_name: String,
+    /// New best estimated one-rep max, in the summary's unit
+    pub estimated_one_rep_max: f64,
+    /// Previous best estimated one-rep max, in the summary's unit
+    pub previous_best: f64,
+}
+
+/// Text summary of a finished workout.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: A summary always describes a specific finished workout.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct WorkoutSummary {
+    /// ID of the summarized workout
+    pub workout_id: String,
+    /// Name of the summarized workout
+    pub workout_name: String,
+    /// Unit of the volume and records
+    pub unit: WeightUnit,
+    /// Personal records set in this workout
+    pub personal_records: Vec<PersonalRecord>,
+    /// The full summary as plain text
+    pub text: String,
+}
+
+/// Summarizes a finished workout for sharing.
+///
+/// Lists each exercise with completed sets and its best set, the workout's
+/// duration and volume (converted to `unit`), and personal records: an
+/// exercise's best estimated one-rep max beating every earlier session of
+/// the same exercise in `previous`. An exercise's first session is not a
+/// record. `previous` may include `workout` itself; it is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use shared::models::{SetActual, WeightUnit, Workout};
+/// use shared::summary::summarize_workout;
+///
+/// let mut workout = Workout::with_name("Push");
+/// workout.add_exercise("Dips").add_set().complete(SetActual::with_weight_and_reps(20.0, 8));
+///
+/// let summary = summarize_workout(&workout, &[], &WeightUnit::Lb);
+/// assert!(summary.text.contains("Best: 20 lb × 8 reps"));
+/// assert!(summary.personal_records.is_empty());
+/// ```
+pub fn summarize_workout(workout: &Workout, previous: &[Workout], unit: &WeightUnit) -> WorkoutSummary {
+    let performed: Vec<&Exercise> = workout
+        .exercises
+        .iter()
+        .filter(|exercise| exercise.completed_sets_count() > 0)
+        .collect();
+
+    let personal_records: Vec<PersonalRecord> = performed
+        .iter()
+        .filter_map(|exercise| {
+            let best = set_one_rep_max(exercise.best_set()?, unit)?;
+            let previous_best = previous
+                .iter()
+                .filter(|w| w.id != workout.id)
+                .flat_map(|w| &w.exercises)
+                .filter(|e| e.is_named(&exercise.name))
+                .filter_map(|e| set_one_rep_max(e.best_set()?, unit))
+                .fold(None, |max: Option<f64>, e1rm| Some(max.map_or(e1rm, |m| m.max(e1rm))))?;
+            (best > previous_best).then(|| PersonalRecord {
+                exercise_name: exercise.name.clone(),
+                estimated_one_rep_max: best,
+                previous_best,
+            })
+        })
+        .collect();
+
+    let mut lines = vec![
+        workout.name.clone(),
+        workout
+            .start_timestamp
+            .to_datetime()
+            .format("%b %d, %Y at %-I:%M %p")
+            .to_string(),
+    ];
+
+    let mut details = Vec::new();
+    if let Some(secs) = workout.duration {
+        details.push(format!("Duration {:02}:{:02}", secs / 60, secs % 60));
+    }
+    details.push(sets_label(workout.completed_sets()));
+    details.push(format!("Volume {:.0} {}", volume(workout, unit), unit.as_str()));
+    lines.push(details.join(" · "));
+
+    for exercise in &performed {
+        lines.push(String::new());
+        lines.push(format!("{} ({})", exercise.name, sets_label(exercise.completed_sets_count())));
+        if let Some(best) = exercise.best_set() {
+            let is_record = personal_records
+                .iter()
+                .any(|record| record.exercise_name == exercise.name);
+            let marker = if is_record { " (PR)" } else { "" };
+            lines.push(format!("  Best: {}{}", best.format_set(), marker));
+        }
+    }
+
+    if !personal_records.is_empty() {
+        lines.push(String::new());
+        let records: Vec<String> = personal_records
+            .iter()
+            .map(|record| {
+                format!(
+                    "{} (e1RM {:.1} {})",
+                    record.exercise_name,
+                    record.estimated_one_rep_max,
+                    unit.as_str()
+                )
+            })
+            .collect();
+        lines.push(format!("New PRs: {}", records.join(", ")));
+    }
+
+    WorkoutSummary {
+        workout_id: workout.id.as_str().to_string(),
+        workout_name: workout.name.clone(),
+        unit: unit.clone(),
+        personal_records,
+        text: lines.join("\n"),
+    }
+}
+
+/// Names of the exercises in `workout` that can set records (those with
+/// completed sets), once each, in workout order.
+///
+/// These are the exercises whose earlier sessions `summarize_workout` needs.
+pub fn record_exercise_names(workout: &Workout) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for exercise in &workout.exercises {
+        if exercise.completed_sets_count() > 0
+            && !names.iter().any(|name| exercise.is_named(name))
+        {
+            names.push(exercise.name.clone());
+        }
+    }
+    names
+}
+
+/// "1 set" / "3 sets".
+fn sets_label(count: usize) -> String {
+    format!("{} set{}", count, if count == 1 { "" } else { "s" })
+}
+
+/// Estimated one-rep max of a set, converted to `unit`.
+fn set_one_rep_max(set: &ExerciseSet, unit: &WeightUnit) -> Option<f64> {
+    let e1rm = set.actual.estimated_one_rep_max()?;
+    Some(set.weight_unit.clone().unwrap_or_default().convert(e1rm, unit))
+}
+
+/// Volume of all completed sets, each converted from its own unit to `unit`.
+fn volume(workout: &Workout, unit: &WeightUnit) -> f64 {
+    workout
+        .exercises
+        .iter()
+        .flat_map(|exercise| &exercise.sets)
+        .filter(|set| set.is_completed)
+        .filter_map(|set| {
+            let volume = set.actual.volume()?;
+            Some(set.weight_unit.clone().unwrap_or_default().convert(volume, unit))
+        })
+        .sum()
+}
+
+// =============================================================================
+// MARK: - Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SetActual;
+
+    fn workout(name: &str, start: &str, lifts: &[(&str, f64, i32)]) -> Workout {
+        let mut workout = Workout::with_name(name);
+        workout.start_timestamp = start.parse().unwrap();
+        for (exercise, weight, reps) in lifts {
+            let index = match workout.exercises.iter().position(|e| e.name == *exercise) {
+                Some(index) => index,
+                None => {
+                    workout.add_exercise(*exercise);
+                    workout.exercises.len() - 1
+                }
+            };
+            workout.exercises[index]
+                .add_set()
+                .complete(SetActual::with_weight_and_reps(*weight, *reps));
+        }
+        workout
+    }
+
+    #[test]
+    fn test_summary_text_lists_exercises_and_totals() {
+        let mut push = workout(
+            "Push",
+            "2025-03-03T08:00:00Z",
+            &[("Bench Press", 135.0, 10), ("Bench Press", 185.0, 5), ("Dips", 0.0, 12)],
+        );
+        push.duration = Some(2700);
+        push.add_exercise("Skipped").add_set();
+
+        let summary = summarize_workout(&push, &[], &WeightUnit::Lb);
+
+        assert_eq!(
+            summary.text,
+            "Push\n\
+             Mar 03, 2025 at 8:00 AM\n\
+             Duration 45:00 · 3 sets · Volume 2275 lb\n\
+             \n\
+             Bench Press (2 sets)\n  Best: 185 lb × 5 reps\n\
+             \n\
+             Dips (1 set)"
+        );
+    }
+
+    #[test]
+    fn test_record_needs_an_earlier_session_to_beat() {
+        let earlier = workout("Push", "2025-03-01T08:00:00Z", &[("Bench Press", 175.0, 5)]);
+        let today = workout(
+            "Push",
+            "2025-03-03T08:00:00Z",
+            &[("Bench Press", 185.0, 5), ("Overhead Press", 95.0, 5)],
+        );
+
+        let summary = summarize_workout(&today, std::slice::from_ref(&earlier), &WeightUnit::Lb);
+
+        assert_eq!(summary.personal_records.len(), 1);
+        let record = &summary.personal_records[0];
+        assert_eq!(record.exercise_name, "Bench Press");
+        assert!((record.previous_best - 175.0 * (1.0 + 5.0 / 30.0)).abs() < 1e-9);
+        assert!(summary.text.contains("Best: 185 lb × 5 reps (PR)"));
+        assert!(summary.text.ends_with("New PRs: Bench Press (e1RM 215.8 lb)"));
+
+        // Matching the earlier best isn't a record
+        let repeat = workout("Push", "2025-03-05T08:00:00Z", &[("bench press", 175.0, 5)]);
+        assert!(summarize_workout(&repeat, &[earlier], &WeightUnit::Lb)
+            .personal_records
+            .is_empty());
+    }
+
+    #[test]
+    fn test_workout_itself_is_not_its_own_previous_best() {
+        let today = workout("Push", "2025-03-03T08:00:00Z", &[("Bench Press", 185.0, 5)]);
+        let summary = summarize_workout(&today, std::slice::from_ref(&today), &WeightUnit::Lb);
+        assert!(summary.personal_records.is_empty());
+    }
+
+    #[test]
+    fn test_volume_and_records_use_preferred_unit() {
+        let mut earlier = workout("Legs", "2025-03-01T08:00:00Z", &[("Squat", 225.0, 5)]);
+        earlier.exercises[0].sets[0].weight_unit = Some(WeightUnit::Lb);
+        let mut today = workout("Legs", "2025-03-03T08:00:00Z", &[("Squat", 105.0, 5)]);
+        today.exercises[0].sets[0].weight_unit = Some(WeightUnit::Kg);
+
+        let summary = summarize_workout(&today, &[earlier], &WeightUnit::Kg);
+
+        // 105 kg beats 225 lb (≈102 kg)
+        assert_eq!(summary.personal_records.len(), 1);
+        assert!(summary.text.contains("Volume 525 kg"));
+        assert!(summary.text.contains("Best: 105 kg × 5 reps (PR)"));
+    }
+
+    #[test]
+    fn test_record_exercise_names_skip_unperformed_and_repeats() {
+        let mut today = workout(
+            "Push",
+            "2025-03-03T08:00:00Z",
+            &[("Bench Press", 185.0, 5), ("Dips", 20.0, 8)],
+        );
+        today.add_exercise("bench press").add_set().complete(SetActual::with_weight_and_reps(135.0, 8));
+        today.add_exercise("Flyes").add_set();
+
+        assert_eq!(record_exercise_names(&today), ["Bench Press", "Dips"]);
+    }
+}
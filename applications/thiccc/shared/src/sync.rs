@@ -0,0 +1,754 @@
+//! Offline-first sync of workout history between devices and the API.
+//!
+//! Every edit to a saved workout is recorded as field-level [`Change`]s:
+//! one per workout, exercise or set field that changed, stamped with a
+//! Lamport [`Clock`]. Devices keep unsent changes in [`SyncState`] (persisted
+//! by the shell) and exchange them with `POST /api/sync`:
+//!
+//! 1. The device sends its pending changes and the cursor of its last sync.
+//! 2. The server appends them to its change log, then returns the winning
+//!    value of every field of each workout another replica changed since the
+//!    cursor, plus a new cursor. Changes that would leave a workout invalid
+//!    are refused per workout and reported back; the rest are still stored.
+//! 3. The device replaces those workouts with the returned state and
+//!    re-applies any changes it recorded while the request was in flight.
+//!
+//! # Conflict rules
+//!
+//! - **Last writer wins per field** – concurrent edits to different fields
+//!   both survive; for the same field the change with the higher clock wins
+//!   (the replica id breaks ties, so every replica picks the same winner).
+//! - **Set-level merge** – exercises and sets are separate entities, so sets
+//!   added concurrently on two devices are both kept. Sets are ordered by
+//!   `set_index` (then id) and renumbered after a merge.
+//! - **Deletes win** – a deleted workout, exercise or set stays deleted;
+//!   later edits to it are ignored.
+//!
+//! An entity that doesn't exist yet is only created from changes that cover
+//! all of its fields (as recorded when it was first saved), so edits to a
+//! workout deleted elsewhere can't resurrect a half-empty copy.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::id::Id;
+use crate::models::{Exercise, ExerciseSet, Workout};
+
+/// Field recorded when an entity is deleted (value `true`).
+pub const DELETED_FIELD: &str = "deleted";
+
+/// Synthetic exercise field holding its index within the workout.
+pub const POSITION_FIELD: &str = "position";
+
+/// Replica id the API server uses for edits made through the REST API.
+pub const SERVER_REPLICA: &str = "server";
+
+/// Workout fields that are entities of their own or identify the entity.
+const WORKOUT_SKIPPED: &[&str] = &["id", "schema_version", "exercises"];
+const EXERCISE_SKIPPED: &[&str] = &["id", "workout_id", "sets"];
+const SET_SKIPPED: &[&str] = &["id", "exercise_id", "workout_id"];
+
+// =============================================================================
+// MARK: - Changes
+// =============================================================================
+
+/// A Lamport clock reading.
+///
+/// Ordered by counter, then replica id, so concurrent changes have a single
+/// winner on every replica.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Clock {
+    /// Logical time: one more than the highest counter the replica has seen
+    pub counter: u64,
+    /// Id of the replica (device, or `SERVER_REPLICA`) that made the change
+    pub replica: String,
+}
+
+/// The kind of entity a change applies to.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum EntityKind {
+    Workout,
+    Exercise,
+    Set,
+}
+
+/// A new value for one field of a workout, exercise or set.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: A change always names a specific entity, field and clock.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Change {
+    /// Kind of entity changed
+    pub kind: EntityKind,
+    /// ID of the workout, exercise or set
+    pub entity_id: String,
+    /// ID of the workout the entity belongs to
+    pub workout_id: String,
+    /// ID of the exercise the set belongs to (sets only)
+    pub exercise_id: Option<String>,
+    /// Field name as serialized in the shared models (or `deleted`/`position`)
+    pub field: String,
+    /// The new value, JSON-encoded
+    pub value: String,
+    /// When the change was made
+    pub clock: Clock,
+}
+
+impl Change {
+    /// Whether this change deletes its entity.
+    pub fn is_delete(&self) -> bool {
+        self.field == DELETED_FIELD
+    }
+
+    fn deleted(kind: EntityKind, entity_id: &Id, workout_id: &Id, exercise_id: Option<&Id>, clock: &Clock) -> Self {
+        Self {
+            kind,
+            entity_id: entity_id.as_str().to_string(),
+            workout_id: workout_id.as_str().to_string(),
+            exercise_id: exercise_id.map(|id| id.as_str().to_string()),
+            field: DELETED_FIELD.to_string(),
+            value: "true".to_string(),
+            clock: clock.clone(),
+        }
+    }
+}
+
+/// Returns the changes that turn `before` into `after`, stamped with `clock`.
+///
+/// A new workout (`before` is None) records every field of every entity; a
+/// deleted workout (`after` is None) records a single delete.
+///
+/// # Examples
+///
+/// ```
+/// use shared::models::Workout;
+/// use shared::sync::{diff, Clock};
+///
+/// let clock = Clock { counter: 1, replica: "phone".to_string() };
+/// let before = Workout::with_name("Push");
+/// let mut after = before.clone();
+/// after.name = "Push Day".to_string();
+///
+/// let changes = diff(Some(&before), Some(&after), &clock);
+/// assert_eq!(changes.len(), 1);
+/// assert_eq!(changes[0].field, "name");
+/// assert_eq!(changes[0].value, "\"Push Day\"");
+/// ```
+pub fn diff(before: Option<&Workout>, after: Option<&Workout>, clock: &Clock) -> Vec<Change> {
+    let Some(after) = after else {
+        return before
+            .map(|workout| vec![Change::deleted(EntityKind::Workout, &workout.id, &workout.id, None, clock)])
+            .unwrap_or_default();
+    };
+
+    let workout_id = &after.id;
+    let mut changes = Vec::new();
+    let mut deletes = Vec::new();
+    let mut push_fields = |kind, entity_id: &Id, exercise_id: Option<&Id>, old: Option<Fields>, new: Fields| {
+        for (field, value) in new {
+            if old.as_ref().and_then(|old| old.get(&field)) != Some(&value) {
+                changes.push(Change {
+                    kind,
+                    entity_id: entity_id.as_str().to_string(),
+                    workout_id: workout_id.as_str().to_string(),
+                    exercise_id: exercise_id.map(|id| id.as_str().to_string()),
+                    field,
+                    value,
+                    clock: clock.clone(),
+                });
+            }
+        }
+    };
+
+    push_fields(
+        EntityKind::Workout,
+        workout_id,
+        None,
+        before.map(workout_fields),
+        workout_fields(after),
+    );
+
+    let old_exercises: Vec<&Exercise> = before.map(|b| b.exercises.iter().collect()).unwrap_or_default();
+    for (position, exercise) in after.exercises.iter().enumerate() {
+        let old = old_exercises
+            .iter()
+            .enumerate()
+            .find(|(_, old)| old.id == exercise.id);
+        push_fields(
+            EntityKind::Exercise,
+            &exercise.id,
+            None,
+            old.map(|(old_position, old)| exercise_fields(old, old_position)),
+            exercise_fields(exercise, position),
+        );
+
+        for set in &exercise.sets {
+            let old_set = old.and_then(|(_, old)| old.sets.iter().find(|s| s.id == set.id));
+            push_fields(
+                EntityKind::Set,
+                &set.id,
+                Some(&exercise.id),
+                old_set.map(set_fields),
+                set_fields(set),
+            );
+        }
+        if let Some((_, old)) = old {
+            for removed in old.sets.iter().filter(|s| !exercise.sets.iter().any(|n| n.id == s.id)) {
+                deletes.push(Change::deleted(EntityKind::Set, &removed.id, workout_id, Some(&exercise.id), clock));
+            }
+        }
+    }
+    for removed in old_exercises
+        .iter()
+        .filter(|old| !after.exercises.iter().any(|e| e.id == old.id))
+    {
+        deletes.push(Change::deleted(EntityKind::Exercise, &removed.id, workout_id, None, clock));
+    }
+
+    changes.extend(deletes);
+    changes
+}
+
+/// Applies `changes` (all for one workout) to `workout` and returns the result.
+///
+/// Changes are applied in clock order, so for each field the latest change
+/// wins. Returns None when the workout is deleted, or when it doesn't exist
+/// and the changes don't cover all of its fields.
+///
+/// # Errors
+///
+/// Returns an error if an id or value can't be read.
+pub fn apply_changes(workout: Option<Workout>, changes: &[Change]) -> Result<Option<Workout>, String> {
+    let mut sorted: Vec<&Change> = changes.iter().collect();
+    sorted.sort_by(|a, b| a.clock.cmp(&b.clock));
+
+    // Latest change per (kind, entity, field)
+    let mut latest: BTreeMap<(EntityKind, &str), BTreeMap<&str, &Change>> = BTreeMap::new();
+    for change in sorted {
+        latest
+            .entry((change.kind, change.entity_id.as_str()))
+            .or_default()
+            .insert(change.field.as_str(), change);
+    }
+    let entities = |kind: EntityKind| {
+        latest
+            .iter()
+            .filter(move |((k, _), _)| *k == kind)
+            .map(|((_, id), fields)| (*id, fields))
+    };
+
+    let mut workout = workout;
+    for (id, fields) in entities(EntityKind::Workout) {
+        if fields.contains_key(DELETED_FIELD) {
+            return Ok(None);
+        }
+        let current = match workout.take() {
+            Some(current) => current,
+            None => {
+                let mut blank = Workout::new();
+                blank.id = parse_id(id)?;
+                if !covers_all(&workout_fields(&blank), fields) {
+                    return Ok(None);
+                }
+                blank
+            }
+        };
+        workout = Some(set_values(current, fields)?);
+    }
+    let Some(mut workout) = workout else {
+        return Ok(None);
+    };
+
+    // Exercises, keyed by position so concurrent moves settle deterministically
+    let mut exercises: Vec<(i64, Exercise)> = std::mem::take(&mut workout.exercises)
+        .into_iter()
+        .enumerate()
+        .map(|(index, exercise)| (index as i64, exercise))
+        .collect();
+    for (id, fields) in entities(EntityKind::Exercise) {
+        let index = exercises.iter().position(|(_, e)| e.id.as_str() == id);
+        if fields.contains_key(DELETED_FIELD) {
+            if let Some(index) = index {
+                exercises.remove(index);
+            }
+            continue;
+        }
+        let (position, current) = match index {
+            Some(index) => exercises.remove(index),
+            None => {
+                let mut blank = Exercise::new(String::new(), workout.id.clone());
+                blank.id = parse_id(id)?;
+                if !covers_all(&exercise_fields(&blank, 0), fields) {
+                    continue;
+                }
+                (0, blank)
+            }
+        };
+        let position = match fields.get(POSITION_FIELD) {
+            Some(change) => decode(&change.value)?,
+            None => position,
+        };
+        exercises.push((position, set_values(current, fields)?));
+    }
+    exercises.sort_by(|(a, x), (b, y)| a.cmp(b).then_with(|| x.id.as_str().cmp(y.id.as_str())));
+    workout.exercises = exercises.into_iter().map(|(_, exercise)| exercise).collect();
+
+    for (id, fields) in entities(EntityKind::Set) {
+        let Some(exercise_id) = fields.values().find_map(|c| c.exercise_id.as_deref()) else {
+            return Err(format!("Set {} has no exercise id", id));
+        };
+        let Some(exercise) = workout.exercises.iter_mut().find(|e| e.id.as_str() == exercise_id) else {
+            continue;
+        };
+        let index = exercise.sets.iter().position(|s| s.id.as_str() == id);
+        if fields.contains_key(DELETED_FIELD) {
+            if let Some(index) = index {
+                exercise.sets.remove(index);
+            }
+            continue;
+        }
+        let current = match index {
+            Some(index) => exercise.sets.remove(index),
+            None => {
+                let mut blank = ExerciseSet::new(exercise.id.clone(), workout.id.clone(), 0);
+                blank.id = parse_id(id)?;
+                if !covers_all(&set_fields(&blank), fields) {
+                    continue;
+                }
+                blank
+            }
+        };
+        exercise.sets.push(set_values(current, fields)?);
+    }
+
+    // Set-level merge: order by index, then id, and renumber
+    for exercise in &mut workout.exercises {
+        exercise.workout_id = workout.id.clone();
+        exercise
+            .sets
+            .sort_by(|a, b| a.set_index.cmp(&b.set_index).then_with(|| a.id.as_str().cmp(b.id.as_str())));
+        for (index, set) in exercise.sets.iter_mut().enumerate() {
+            set.set_index = index as i32;
+            set.exercise_id = exercise.id.clone();
+            set.workout_id = workout.id.clone();
+        }
+    }
+
+    Ok(Some(workout))
+}
+
+/// Returns whether `workout`, built from `changes` by `apply_changes`, holds
+/// every exercise and set those changes describe and don't delete.
+///
+/// `apply_changes` skips an entity that doesn't exist yet when its changes
+/// don't cover all of its fields. Writing such a workout over a stored copy
+/// would drop the skipped exercises and sets, so callers check this first.
+pub fn is_complete(workout: &Workout, changes: &[Change]) -> bool {
+    let deleted: BTreeSet<&str> = changes
+        .iter()
+        .filter(|change| change.is_delete())
+        .map(|change| change.entity_id.as_str())
+        .collect();
+    let has_exercise = |id: &str| workout.exercises.iter().any(|e| e.id.as_str() == id);
+    let has_set = |id: &str| {
+        workout
+            .exercises
+            .iter()
+            .flat_map(|e| &e.sets)
+            .any(|s| s.id.as_str() == id)
+    };
+
+    changes
+        .iter()
+        .filter(|change| !deleted.contains(change.entity_id.as_str()))
+        .all(|change| match change.kind {
+            EntityKind::Workout => true,
+            EntityKind::Exercise => has_exercise(&change.entity_id),
+            EntityKind::Set => match change.exercise_id.as_deref() {
+                Some(exercise_id) if deleted.contains(exercise_id) => true,
+                _ => has_set(&change.entity_id),
+            },
+        })
+}
+
+/// Groups changes by the workout they belong to, keeping their order.
+pub fn group_by_workout(changes: Vec<Change>) -> BTreeMap<String, Vec<Change>> {
+    let mut groups: BTreeMap<String, Vec<Change>> = BTreeMap::new();
+    for change in changes {
+        groups.entry(change.workout_id.clone()).or_default().push(change);
+    }
+    groups
+}
+
+// =============================================================================
+// MARK: - Protocol
+// =============================================================================
+
+/// Body of `POST /api/sync`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct SyncRequest {
+    /// Cursor from the previous response (0 on the first sync)
+    pub cursor: i64,
+    /// Changes made on this device since they were last sent
+    pub changes: Vec<Change>,
+}
+
+/// Response to `POST /api/sync`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct SyncResponse {
+    /// Cursor to send with the next request
+    pub cursor: i64,
+    /// The winning change for every field of each workout that another
+    /// replica changed since the request's cursor, or whose pushed changes
+    /// were rejected. The request's own changes aren't echoed back.
+    pub changes: Vec<Change>,
+    /// Workouts whose pushed changes the server refused (and didn't store)
+    #[serde(default)]
+    pub rejected: Vec<SyncRejection>,
+}
+
+/// Pushed changes to one workout that the server refused.
+///
+/// **Default Trait: NOT implemented (Explicit Construction)**
+///
+/// Reasoning: A rejection always names a specific workout and reason.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SyncRejection {
+    /// ID of the workout the changes were for
+    pub workout_id: String,
+    /// Why the changes were refused
+    pub error: String,
+}
+
+/// A device's sync bookkeeping, persisted by the shell between launches.
+///
+/// **Default Trait: IMPLEMENTED (for TypeGen compatibility)**
+///
+/// Reasoning: The model needs a value before the stored state is loaded.
+/// The default has no replica id; `SyncState::new_replica` creates a real one.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct SyncState {
+    /// Unique id of this device's replica
+    pub replica_id: String,
+    /// Highest clock counter this replica has used or seen
+    pub counter: u64,
+    /// Cursor from the last successful sync
+    pub cursor: i64,
+    /// Whether history saved before sync existed has been recorded
+    pub seeded: bool,
+    /// Changes not yet acknowledged by the server, oldest first
+    pub pending: Vec<Change>,
+}
+
+impl SyncState {
+    /// Creates the state for a new replica with a random id.
+    pub fn new_replica() -> Self {
+        Self {
+            replica_id: Id::new().into_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Records the changes that turn `before` into `after` and returns how
+    /// many there were.
+    ///
+    /// Pending changes to the same field are dropped (the new value wins
+    /// anyway), as is everything pending for a workout being deleted.
+    pub fn record(&mut self, before: Option<&Workout>, after: Option<&Workout>) -> usize {
+        self.counter += 1;
+        let clock = Clock {
+            counter: self.counter,
+            replica: self.replica_id.clone(),
+        };
+        let changes = diff(before, after, &clock);
+        for change in &changes {
+            self.pending.retain(|pending| {
+                let same_workout = pending.workout_id == change.workout_id;
+                let deletes_workout = change.kind == EntityKind::Workout && change.is_delete();
+                !(same_workout && deletes_workout
+                    || pending.kind == change.kind
+                        && pending.entity_id == change.entity_id
+                        && pending.field == change.field)
+            });
+        }
+        let count = changes.len();
+        self.pending.extend(changes);
+        count
+    }
+
+    /// Replaces a state that hasn't loaded yet with the stored one (or a new
+    /// replica when nothing was stored), keeping changes recorded meanwhile.
+    pub fn restore(&mut self, stored: Option<SyncState>) {
+        let early = std::mem::take(&mut self.pending);
+        *self = stored.unwrap_or_else(SyncState::new_replica);
+        if !early.is_empty() {
+            self.counter += 1;
+            for mut change in early {
+                change.clock = Clock {
+                    counter: self.counter,
+                    replica: self.replica_id.clone(),
+                };
+                self.pending.push(change);
+            }
+        }
+    }
+
+    /// Builds the request for the next exchange.
+    pub fn request(&self) -> SyncRequest {
+        SyncRequest {
+            cursor: self.cursor,
+            changes: self.pending.clone(),
+        }
+    }
+
+    /// Applies a successful response to a request sent when the counter was
+    /// `sent_counter`: drops the changes it carried and advances the cursor
+    /// and clock.
+    pub fn acknowledge(&mut self, sent_counter: u64, response: &SyncResponse) {
+        self.pending.retain(|change| change.clock.counter > sent_counter);
+        self.cursor = response.cursor;
+        let seen = response.changes.iter().map(|c| c.clock.counter).max().unwrap_or(0);
+        self.counter = self.counter.max(seen);
+    }
+
+    /// Pending changes for one workout.
+    pub fn pending_for(&self, workout_id: &str) -> Vec<Change> {
+        self.pending
+            .iter()
+            .filter(|change| change.workout_id == workout_id)
+            .cloned()
+            .collect()
+    }
+}
+
+// =============================================================================
+// MARK: - Field Encoding
+// =============================================================================
+
+/// JSON-encoded field values of one entity, by serialized field name.
+type Fields = BTreeMap<String, String>;
+
+fn workout_fields(workout: &Workout) -> Fields {
+    fields_of(workout, WORKOUT_SKIPPED)
+}
+
+fn exercise_fields(exercise: &Exercise, position: usize) -> Fields {
+    let mut fields = fields_of(exercise, EXERCISE_SKIPPED);
+    fields.insert(POSITION_FIELD.to_string(), position.to_string());
+    fields
+}
+
+fn set_fields(set: &ExerciseSet) -> Fields {
+    fields_of(set, SET_SKIPPED)
+}
+
+fn fields_of<T: Serialize>(entity: &T, skipped: &[&str]) -> Fields {
+    match serde_json::to_value(entity) {
+        Ok(Value::Object(object)) => object
+            .into_iter()
+            .filter(|(field, _)| !skipped.contains(&field.as_str()))
+            .map(|(field, value)| (field, value.to_string()))
+            .collect(),
+        _ => Fields::new(),
+    }
+}
+
+/// Whether `changes` set every field of a blank entity.
+fn covers_all(blank: &Fields, changes: &BTreeMap<&str, &Change>) -> bool {
+    blank.keys().all(|field| changes.contains_key(field.as_str()))
+}
+
+/// Writes the changed fields into `entity`. Unknown fields are ignored.
+fn set_values<T: Serialize + DeserializeOwned>(entity: T, changes: &BTreeMap<&str, &Change>) -> Result<T, String> {
+    let mut object = match serde_json::to_value(entity) {
+        Ok(Value::Object(object)) => object,
+        _ => Map::new(),
+    };
+    for (field, change) in changes {
+        if let Some(slot) = object.get_mut(*field) {
+            *slot = decode(&change.value)?;
+        }
+    }
+    serde_json::from_value(Value::Object(object)).map_err(|e| format!("Invalid change: {}", e))
+}
+
+fn decode<T: DeserializeOwned>(value: &str) -> Result<T, String> {
+    serde_json::from_str(value).map_err(|e| format!("Invalid change value {}: {}", value, e))
+}
+
+fn parse_id(id: &str) -> Result<Id, String> {
+    Id::from_string(id.to_string())
+}
+
+// =============================================================================
+// MARK: - Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SetActual;
+
+    fn clock(counter: u64, replica: &str) -> Clock {
+        Clock {
+            counter,
+            replica: replica.to_string(),
+        }
+    }
+
+    fn sample_workout() -> Workout {
+        let mut workout = Workout::with_name("Legs");
+        workout.start_timestamp = "2025-03-01T08:00:00Z".parse().unwrap();
+        let squat = workout.add_exercise("Squat");
+        squat.add_set().complete(SetActual::with_weight_and_reps(225.0, 5));
+        squat.add_set();
+        workout.add_exercise("Leg Press");
+        workout
+    }
+
+    #[test]
+    fn test_new_workout_round_trips_through_changes() {
+        let workout = sample_workout();
+        let changes = diff(None, Some(&workout), &clock(1, "phone"));
+
+        assert!(changes.iter().any(|c| c.kind == EntityKind::Set && c.field == "actual"));
+        assert_eq!(apply_changes(None, &changes), Ok(Some(workout)));
+    }
+
+    #[test]
+    fn test_diff_records_only_changed_fields() {
+        let before = sample_workout();
+        let mut after = before.clone();
+        after.exercises[0].sets[1].complete(SetActual::with_weight_and_reps(225.0, 4));
+        after.exercises.remove(1);
+
+        let changes = diff(Some(&before), Some(&after), &clock(2, "phone"));
+        let summary: Vec<_> = changes.iter().map(|c| (c.kind, c.field.as_str())).collect();
+        assert_eq!(
+            summary,
+            [
+                (EntityKind::Set, "actual"),
+                (EntityKind::Set, "is_completed"),
+                (EntityKind::Exercise, DELETED_FIELD),
+            ]
+        );
+        assert_eq!(apply_changes(Some(before), &changes), Ok(Some(after)));
+
+        assert!(diff(Some(&sample_workout()), None, &clock(3, "phone"))[0].is_delete());
+    }
+
+    #[test]
+    fn test_last_writer_wins_per_field() {
+        let base = sample_workout();
+        let mut renamed = base.clone();
+        renamed.name = "Leg Day".to_string();
+        let mut noted = base.clone();
+        noted.name = "Quads".to_string();
+        noted.note = Some("Felt strong".to_string());
+
+        let mut changes = diff(Some(&base), Some(&noted), &clock(5, "tablet"));
+        changes.extend(diff(Some(&base), Some(&renamed), &clock(7, "phone")));
+
+        // Same result whichever order the changes arrive in
+        let merged = apply_changes(Some(base.clone()), &changes).unwrap().unwrap();
+        changes.reverse();
+        assert_eq!(apply_changes(Some(base), &changes).unwrap().unwrap(), merged);
+
+        assert_eq!(merged.name, "Leg Day");
+        assert_eq!(merged.note.as_deref(), Some("Felt strong"));
+    }
+
+    #[test]
+    fn test_concurrent_sets_are_both_kept() {
+        let base = sample_workout();
+        let mut phone = base.clone();
+        phone.exercises[0].add_set().complete(SetActual::with_weight_and_reps(205.0, 8));
+        let mut tablet = base.clone();
+        tablet.exercises[0].add_set().complete(SetActual::with_weight_and_reps(185.0, 10));
+
+        let mut changes = diff(Some(&base), Some(&phone), &clock(4, "phone"));
+        changes.extend(diff(Some(&base), Some(&tablet), &clock(4, "tablet")));
+        let merged = apply_changes(Some(base), &changes).unwrap().unwrap();
+
+        let sets = &merged.exercises[0].sets;
+        assert_eq!(sets.len(), 4);
+        assert_eq!(sets.iter().map(|s| s.set_index).collect::<Vec<_>>(), [0, 1, 2, 3]);
+        let weights: Vec<_> = sets[2..].iter().map(|s| s.actual.weight.unwrap()).collect();
+        assert!(weights.contains(&205.0) && weights.contains(&185.0));
+    }
+
+    #[test]
+    fn test_deletes_win_and_partial_changes_dont_resurrect() {
+        let base = sample_workout();
+        let mut edited = base.clone();
+        edited.name = "Edited".to_string();
+
+        let mut changes = diff(Some(&base), None, &clock(1, "phone"));
+        changes.extend(diff(Some(&base), Some(&edited), &clock(9, "tablet")));
+        assert_eq!(apply_changes(Some(base.clone()), &changes), Ok(None));
+
+        let rename = diff(Some(&base), Some(&edited), &clock(9, "tablet"));
+        assert_eq!(apply_changes(None, &rename), Ok(None));
+    }
+
+    #[test]
+    fn test_is_complete_catches_skipped_entities() {
+        let workout = sample_workout();
+        let changes = diff(None, Some(&workout), &clock(1, "phone"));
+        assert!(is_complete(&workout, &changes));
+
+        // A set missing a field can't be created, so the result lacks it
+        let set_id = workout.exercises[0].sets[0].id.as_str();
+        let partial: Vec<Change> = changes
+            .iter()
+            .filter(|c| !(c.entity_id == set_id && c.field == "actual"))
+            .cloned()
+            .collect();
+        let built = apply_changes(None, &partial).unwrap().unwrap();
+        assert_eq!(built.exercises[0].sets.len(), 1);
+        assert!(!is_complete(&built, &partial));
+
+        // Sets of a deleted exercise are meant to be gone
+        let mut after = workout.clone();
+        after.exercises.remove(0);
+        let mut deleted = changes.clone();
+        deleted.extend(diff(Some(&workout), Some(&after), &clock(2, "phone")));
+        let built = apply_changes(None, &deleted).unwrap().unwrap();
+        assert!(is_complete(&built, &deleted));
+    }
+
+    #[test]
+    fn test_record_compacts_and_acknowledge_keeps_later_changes() {
+        let mut state = SyncState::new_replica();
+        let workout = sample_workout();
+        let count = state.record(None, Some(&workout));
+        assert_eq!(state.pending.len(), count);
+
+        let mut renamed = workout.clone();
+        renamed.name = "Leg Day".to_string();
+        state.record(Some(&workout), Some(&renamed));
+        assert_eq!(state.pending.len(), count, "the older name change is dropped");
+
+        let sent = state.counter;
+        let mut again = renamed.clone();
+        again.note = Some("Later".to_string());
+        state.record(Some(&renamed), Some(&again));
+
+        let response = SyncResponse {
+            cursor: 42,
+            ..Default::default()
+        };
+        state.acknowledge(sent, &response);
+        assert_eq!(state.cursor, 42);
+        assert_eq!(state.pending.len(), 1);
+        assert_eq!(state.pending[0].field, "note");
+
+        state.record(Some(&again), None);
+        assert_eq!(state.pending.len(), 1);
+        assert!(state.pending[0].is_delete());
+    }
+}
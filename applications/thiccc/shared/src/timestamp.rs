@@ -0,0 +1,181 @@
+//! Serialization-friendly timestamp type.
+//!
+//! `chrono::DateTime` serializes as a string in every format, which TypeGen
+//! cannot trace (it has no valid string to feed the deserializer). This module
+//! provides a UTC timestamp that:
+//! - Serializes as Unix milliseconds (a `Timestamp` newtype) in binary formats,
+//!   so shells receive a plain `Int64`
+//! - Serializes as an RFC 3339 string in JSON, so saved and imported workout
+//!   files stay readable and backwards compatible
+//! - Converts to and from `chrono::DateTime<Utc>` for formatting and arithmetic
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// A point in time (UTC) with millisecond precision.
+///
+/// **Validation:** Always within the range `chrono::DateTime<Utc>` can
+/// represent, so converting to a `DateTime` never fails.
+///
+/// **Serialization:** Unix milliseconds for bincode/TypeGen, RFC 3339 for JSON.
+///
+/// # Examples
+///
+/// ```
+/// use shared::Timestamp;
+///
+/// let ts: Timestamp = "2025-01-01T12:00:00Z".parse().unwrap();
+/// assert_eq!(ts.as_millis(), 1_735_732_800_000);
+/// assert_eq!(ts.to_string(), "2025-01-01T12:00:00.000Z");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(i64);
+
+impl Timestamp {
+    /// Returns the current time, truncated to milliseconds.
+    pub fn now() -> Self {
+        Self(Utc::now().timestamp_millis())
+    }
+
+    /// Creates a timestamp from Unix milliseconds.
+    ///
+    /// Returns None if the value is outside the range chrono can represent.
+    pub fn from_millis(millis: i64) -> Option<Self> {
+        DateTime::<Utc>::from_timestamp_millis(millis).map(|_| Self(millis))
+    }
+
+    /// Returns the timestamp as Unix milliseconds.
+    pub fn as_millis(&self) -> i64 {
+        self.0
+    }
+
+    /// Returns the timestamp as whole Unix seconds.
+    pub fn as_seconds(&self) -> i64 {
+        self.0.div_euclid(1000)
+    }
+
+    /// Converts to a `chrono::DateTime<Utc>` for formatting and arithmetic.
+    pub fn to_datetime(&self) -> DateTime<Utc> {
+        DateTime::<Utc>::from_timestamp_millis(self.0)
+            .expect("Timestamp is always within chrono's range")
+    }
+}
+
+impl From<DateTime<Utc>> for Timestamp {
+    fn from(datetime: DateTime<Utc>) -> Self {
+        Self(datetime.timestamp_millis())
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = chrono::ParseError;
+
+    /// Parses an RFC 3339 string (any offset is converted to UTC).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DateTime::parse_from_rfc3339(s).map(|dt| Self::from(dt.with_timezone(&Utc)))
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_datetime().to_rfc3339_opts(SecondsFormat::Millis, true))
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_newtype_struct("Timestamp", &self.0)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            DateTime::<Utc>::deserialize(deserializer).map(Self::from)
+        } else {
+            deserializer.deserialize_newtype_struct("Timestamp", MillisVisitor)
+        }
+    }
+}
+
+/// Reads the binary `Timestamp(i64)` newtype.
+struct MillisVisitor;
+
+impl<'de> Visitor<'de> for MillisVisitor {
+    type Value = Timestamp;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a Timestamp of Unix milliseconds")
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> Result<Timestamp, D::Error> {
+        let millis = i64::deserialize(deserializer)?;
+        Timestamp::from_millis(millis)
+            .ok_or_else(|| de::Error::custom(format!("Timestamp out of range: {}", millis)))
+    }
+}
+
+// =============================================================================
+// MARK: - Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timestamp_json_is_rfc3339() {
+        let ts: Timestamp = "2025-01-01T12:00:00Z".parse().unwrap();
+        let json = serde_json::to_string(&ts).unwrap();
+        assert_eq!(json, "\"2025-01-01T12:00:00.000Z\"");
+
+        let deserialized: Timestamp = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, ts);
+    }
+
+    #[test]
+    fn test_timestamp_json_accepts_offsets_and_nanoseconds() {
+        // Files written before Timestamp existed used chrono's nanosecond format
+        let ts: Timestamp = serde_json::from_str("\"2025-01-01T12:00:00.123456789Z\"").unwrap();
+        assert_eq!(ts.as_millis(), 1_735_732_800_123);
+
+        let ts: Timestamp = serde_json::from_str("\"2025-01-01T14:00:00+02:00\"").unwrap();
+        assert_eq!(ts.as_millis(), 1_735_732_800_000);
+    }
+
+    #[test]
+    fn test_timestamp_bincode_is_millis() {
+        let ts = Timestamp::from_millis(1_735_732_800_000).unwrap();
+        let bytes = bincode::serialize(&ts).unwrap();
+        assert_eq!(bytes, 1_735_732_800_000_i64.to_le_bytes());
+
+        let deserialized: Timestamp = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(deserialized, ts);
+    }
+
+    #[test]
+    fn test_timestamp_rejects_out_of_range_millis() {
+        assert!(Timestamp::from_millis(i64::MAX).is_none());
+
+        let bytes = bincode::serialize(&i64::MAX).unwrap();
+        assert!(bincode::deserialize::<Timestamp>(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_timestamp_conversions() {
+        let ts = Timestamp::from_millis(-1).unwrap();
+        assert_eq!(ts.as_seconds(), -1);
+        assert_eq!(ts.to_datetime().timestamp_millis(), -1);
+
+        let now = Utc::now();
+        assert_eq!(Timestamp::from(now).as_millis(), now.timestamp_millis());
+        assert!(Timestamp::now() >= Timestamp::from(now));
+    }
+}
@@ -0,0 +1,446 @@
+//! Domain validation for workouts.
+//!
+//! Serde only checks that a payload has the right shape. This module checks
+//! that the data inside makes sense before it is accepted into the model:
+//! - All ids are valid UUIDs (`Id` deserialization does not validate)
+//! - Exercises and sets reference their parent workout/exercise
+//! - Set indexes are contiguous from 0 within each exercise
+//! - Values are in range (RPE 1-10, non-negative weight and reps)
+//! - The workout does not end before it starts
+//!
+//! Used on import, when restoring the in-progress workout, and when loading
+//! history.
+
+use crate::id::Id;
+use crate::models::{ExerciseSet, Workout};
+use std::fmt;
+
+/// Lowest valid RPE (Rate of Perceived Exertion).
+pub const MIN_RPE: f64 = 1.0;
+
+/// Highest valid RPE (Rate of Perceived Exertion).
+pub const MAX_RPE: f64 = 10.0;
+
+/// A single problem found while validating a workout.
+///
+/// Indexes are positions in `Workout::exercises` and `Exercise::sets`, so
+/// each error points at the offending item. `Display` gives a user-facing
+/// message.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValidationError {
+    /// An id is not a valid UUID
+    InvalidId {
+        /// Which id is invalid (e.g. "exercise ID at index 0")
+        location: String,
+        /// Why the id failed to parse
+        error: String,
+    },
+    /// An exercise's `workout_id` does not match the workout containing it
+    ExerciseWorkoutMismatch { exercise_index: usize },
+    /// A set's `exercise_id` does not match the exercise containing it
+    SetExerciseMismatch { exercise_index: usize, set_index: usize },
+    /// A set's `workout_id` does not match the workout containing it
+    SetWorkoutMismatch { exercise_index: usize, set_index: usize },
+    /// A set's `set_index` does not match its position in the exercise
+    NonContiguousSetIndex {
+        exercise_index: usize,
+        set_index: usize,
+        found: i32,
+    },
+    /// An RPE value is outside 1-10
+    RpeOutOfRange {
+        exercise_index: usize,
+        set_index: usize,
+        value: f64,
+    },
+    /// A weight value is negative (or not a number)
+    NegativeWeight {
+        exercise_index: usize,
+        set_index: usize,
+        value: f64,
+    },
+    /// A reps value is negative
+    NegativeReps {
+        exercise_index: usize,
+        set_index: usize,
+        value: i32,
+    },
+    /// The workout's end timestamp is before its start timestamp
+    EndBeforeStart,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::InvalidId { location, error } => {
+                write!(f, "Invalid {}: {}", location, error)
+            }
+            ValidationError::ExerciseWorkoutMismatch { exercise_index } => write!(
+                f,
+                "Exercise at index {} belongs to a different workout",
+                exercise_index
+            ),
+            ValidationError::SetExerciseMismatch {
+                exercise_index,
+                set_index,
+            } => write!(
+                f,
+                "Set at exercise {} set {} belongs to a different exercise",
+                exercise_index, set_index
+            ),
+            ValidationError::SetWorkoutMismatch {
+                exercise_index,
+                set_index,
+            } => write!(
+                f,
+                "Set at exercise {} set {} belongs to a different workout",
+                exercise_index, set_index
+            ),
+            ValidationError::NonContiguousSetIndex {
+                exercise_index,
+                set_index,
+                found,
+            } => write!(
+                f,
+                "Set at exercise {} set {} has set_index {}, expected {}",
+                exercise_index, set_index, found, set_index
+            ),
+            ValidationError::RpeOutOfRange {
+                exercise_index,
+                set_index,
+                value,
+            } => write!(
+                f,
+                "RPE {} at exercise {} set {} must be between {} and {}",
+                value, exercise_index, set_index, MIN_RPE, MAX_RPE
+            ),
+            ValidationError::NegativeWeight {
+                exercise_index,
+                set_index,
+                value,
+            } => write!(
+                f,
+                "Weight {} at exercise {} set {} must not be negative",
+                value, exercise_index, set_index
+            ),
+            ValidationError::NegativeReps {
+                exercise_index,
+                set_index,
+                value,
+            } => write!(
+                f,
+                "Reps {} at exercise {} set {} must not be negative",
+                value, exercise_index, set_index
+            ),
+            ValidationError::EndBeforeStart => {
+                write!(f, "Workout ends before it starts")
+            }
+        }
+    }
+}
+
+/// Validates a workout, returning every problem found.
+///
+/// # Errors
+///
+/// Returns all `ValidationError`s (not just the first) so callers can
+/// report everything wrong with a payload at once.
+pub fn validate_workout(workout: &Workout) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::new();
+
+    check_id(&mut errors, &workout.id, || "workout ID".to_string());
+
+    if workout
+        .end_timestamp
+        .is_some_and(|end| end < workout.start_timestamp)
+    {
+        errors.push(ValidationError::EndBeforeStart);
+    }
+
+    for (exercise_index, exercise) in workout.exercises.iter().enumerate() {
+        check_id(&mut errors, &exercise.id, || {
+            format!("exercise ID at index {}", exercise_index)
+        });
+        check_id(&mut errors, &exercise.workout_id, || {
+            format!("workout_id in exercise at index {}", exercise_index)
+        });
+        if exercise.workout_id != workout.id {
+            errors.push(ValidationError::ExerciseWorkoutMismatch { exercise_index });
+        }
+
+        for (set_index, set) in exercise.sets.iter().enumerate() {
+            check_id(&mut errors, &set.id, || {
+                format!("set ID at exercise {} set {}", exercise_index, set_index)
+            });
+            check_id(&mut errors, &set.exercise_id, || {
+                format!(
+                    "exercise_id in set at exercise {} set {}",
+                    exercise_index, set_index
+                )
+            });
+            check_id(&mut errors, &set.workout_id, || {
+                format!(
+                    "workout_id in set at exercise {} set {}",
+                    exercise_index, set_index
+                )
+            });
+
+            if set.exercise_id != exercise.id {
+                errors.push(ValidationError::SetExerciseMismatch {
+                    exercise_index,
+                    set_index,
+                });
+            }
+            if set.workout_id != workout.id {
+                errors.push(ValidationError::SetWorkoutMismatch {
+                    exercise_index,
+                    set_index,
+                });
+            }
+            if usize::try_from(set.set_index).ok() != Some(set_index) {
+                errors.push(ValidationError::NonContiguousSetIndex {
+                    exercise_index,
+                    set_index,
+                    found: set.set_index,
+                });
+            }
+
+            check_set_values(&mut errors, set, exercise_index, set_index);
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Validates the values of one set, e.g. before applying a user's edit.
+///
+/// Uses the same ranges as `validate_workout`; the indexes only locate the
+/// set in the errors.
+///
+/// # Errors
+///
+/// Returns every out-of-range value of the set.
+pub fn validate_set(
+    set: &ExerciseSet,
+    exercise_index: usize,
+    set_index: usize,
+) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::new();
+    check_set_values(&mut errors, set, exercise_index, set_index);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Joins validation errors into a single message for `error_message`.
+pub fn describe_errors(errors: &[ValidationError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Records an `InvalidId` error if the id is not a valid UUID.
+fn check_id(errors: &mut Vec<ValidationError>, id: &Id, location: impl FnOnce() -> String) {
+    if let Err(error) = Id::from_string(id.as_str().to_string()) {
+        errors.push(ValidationError::InvalidId {
+            location: location(),
+            error,
+        });
+    }
+}
+
+/// Checks the suggested and actual values of a set.
+fn check_set_values(
+    errors: &mut Vec<ValidationError>,
+    set: &ExerciseSet,
+    exercise_index: usize,
+    set_index: usize,
+) {
+    let weights = [set.suggest.weight, set.actual.weight];
+    let reps = [set.suggest.reps, set.actual.reps];
+    let rpes = [set.suggest.rpe, set.actual.rpe];
+
+    for value in weights.into_iter().flatten() {
+        if value.is_nan() || value < 0.0 {
+            errors.push(ValidationError::NegativeWeight {
+                exercise_index,
+                set_index,
+                value,
+            });
+        }
+    }
+    for value in reps.into_iter().flatten() {
+        if value < 0 {
+            errors.push(ValidationError::NegativeReps {
+                exercise_index,
+                set_index,
+                value,
+            });
+        }
+    }
+    for value in rpes.into_iter().flatten() {
+        if !(MIN_RPE..=MAX_RPE).contains(&value) {
+            errors.push(ValidationError::RpeOutOfRange {
+                exercise_index,
+                set_index,
+                value,
+            });
+        }
+    }
+}
+
+// =============================================================================
+// MARK: - Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{SetActual, SetSuggest};
+
+    fn valid_workout() -> Workout {
+        let mut workout = Workout::with_name("Push Day");
+        let exercise = workout.add_exercise("Bench Press");
+        exercise.add_set().complete(SetActual {
+            weight: Some(225.0),
+            reps: Some(5),
+            rpe: Some(8.5),
+            ..Default::default()
+        });
+        exercise.add_set().suggest = SetSuggest::with_weight_and_reps(225.0, 5);
+        workout
+    }
+
+    #[test]
+    fn test_valid_workout_passes() {
+        assert_eq!(validate_workout(&valid_workout()), Ok(()));
+    }
+
+    #[test]
+    fn test_invalid_ids_are_reported() {
+        let mut workout = valid_workout();
+        workout.exercises[0].sets[1].id = serde_json::from_str("\"not-a-uuid\"").unwrap();
+
+        let errors = validate_workout(&workout).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0]
+            .to_string()
+            .starts_with("Invalid set ID at exercise 0 set 1"));
+    }
+
+    #[test]
+    fn test_referential_integrity() {
+        let mut workout = valid_workout();
+        let other = Id::new();
+        workout.exercises[0].workout_id = other.clone();
+        workout.exercises[0].sets[0].exercise_id = other.clone();
+        workout.exercises[0].sets[1].workout_id = other;
+
+        assert_eq!(
+            validate_workout(&workout).unwrap_err(),
+            vec![
+                ValidationError::ExerciseWorkoutMismatch { exercise_index: 0 },
+                ValidationError::SetExerciseMismatch {
+                    exercise_index: 0,
+                    set_index: 0,
+                },
+                ValidationError::SetWorkoutMismatch {
+                    exercise_index: 0,
+                    set_index: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_set_index_must_be_contiguous() {
+        let mut workout = valid_workout();
+        workout.exercises[0].sets[1].set_index = 2;
+
+        assert_eq!(
+            validate_workout(&workout).unwrap_err(),
+            vec![ValidationError::NonContiguousSetIndex {
+                exercise_index: 0,
+                set_index: 1,
+                found: 2,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_value_ranges() {
+        let mut workout = valid_workout();
+        let set = &mut workout.exercises[0].sets[0];
+        set.actual.rpe = Some(11.0);
+        set.actual.weight = Some(-5.0);
+        set.suggest.reps = Some(-1);
+        workout.exercises[0].sets[1].suggest.weight = Some(f64::NAN);
+
+        let errors = validate_workout(&workout).unwrap_err();
+        assert_eq!(errors.len(), 4);
+        assert!(errors.contains(&ValidationError::RpeOutOfRange {
+            exercise_index: 0,
+            set_index: 0,
+            value: 11.0,
+        }));
+        assert!(errors.contains(&ValidationError::NegativeWeight {
+            exercise_index: 0,
+            set_index: 0,
+            value: -5.0,
+        }));
+        assert!(errors.contains(&ValidationError::NegativeReps {
+            exercise_index: 0,
+            set_index: 0,
+            value: -1,
+        }));
+        assert!(matches!(
+            errors[3],
+            ValidationError::NegativeWeight { set_index: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn test_rpe_bounds_are_inclusive() {
+        let mut workout = valid_workout();
+        workout.exercises[0].sets[0].actual.rpe = Some(MIN_RPE);
+        workout.exercises[0].sets[1].suggest.rpe = Some(MAX_RPE);
+        assert_eq!(validate_workout(&workout), Ok(()));
+
+        workout.exercises[0].sets[0].actual.rpe = Some(0.5);
+        assert!(validate_workout(&workout).is_err());
+    }
+
+    #[test]
+    fn test_end_must_not_precede_start() {
+        let mut workout = valid_workout();
+        workout.end_timestamp = Some(workout.start_timestamp);
+        assert_eq!(validate_workout(&workout), Ok(()));
+
+        workout.end_timestamp =
+            crate::timestamp::Timestamp::from_millis(workout.start_timestamp.as_millis() - 1);
+        assert_eq!(
+            validate_workout(&workout),
+            Err(vec![ValidationError::EndBeforeStart])
+        );
+    }
+
+    #[test]
+    fn test_describe_errors_joins_messages() {
+        let errors = [
+            ValidationError::EndBeforeStart,
+            ValidationError::ExerciseWorkoutMismatch { exercise_index: 2 },
+        ];
+        assert_eq!(
+            describe_errors(&errors),
+            "Workout ends before it starts; Exercise at index 2 belongs to a different workout"
+        );
+    }
+}
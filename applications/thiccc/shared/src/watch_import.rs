@@ -0,0 +1,567 @@
+//! Importers for strength workouts recorded on watches.
+//!
+//! Two sources are supported:
+//!
+//! - **Garmin FIT** activity files. Each active `set` message becomes a set;
+//!   the rest message that follows it becomes the set's rest time. Exercises
+//!   are identified by the FIT exercise category, mapped to the category's
+//!   most common catalog exercise (sub-categories aren't distinguished).
+//! - **Apple Health** workouts as JSON. HealthKit itself doesn't store sets,
+//!   so this reads a JSON export of `HKWorkout`s with the sets a companion
+//!   app recorded alongside them (see `HealthExport`).
+//!
+//! Both produce finished workouts for a bulk import into history, with
+//! exercise names matched against the catalog like the CSV importers.
+
+use std::collections::BTreeSet;
+
+use serde::Deserialize;
+
+use crate::catalog::match_exercise;
+use crate::fit::{self, message, FitMessage, FIT_EPOCH_OFFSET};
+use crate::models::{Exercise, ExerciseSet, SetActual, WeightUnit, Workout};
+use crate::timestamp::Timestamp;
+use crate::validation;
+
+/// Name given to FIT workouts, which carry no title.
+const FIT_WORKOUT_NAME: &str = "Strength Training";
+
+/// FIT exercise categories as (category, exercise name).
+///
+/// Names found in the catalog are used where the category has an obvious
+/// catalog counterpart; the rest are the category's own name.
+const FIT_EXERCISE_CATEGORIES: &[(u64, &str)] = &[
+    (0, "Barbell Bench Press"),
+    (1, "Calf Raise"),
+    (2, "Cardio"),
+    (3, "Carry"),
+    (4, "Cable Woodchop"),
+    (5, "Core"),
+    (6, "Crunches"),
+    (7, "Barbell Curl"),
+    (8, "Deadlift"),
+    (9, "Dumbbell Fly"),
+    (10, "Hip Raise"),
+    (11, "Hip Stability"),
+    (12, "Hip Swing"),
+    (13, "Hyperextension"),
+    (14, "Lateral Raise"),
+    (15, "Leg Curl"),
+    (16, "Leg Raises"),
+    (17, "Lunges"),
+    (18, "Olympic Lift"),
+    (19, "Plank"),
+    (20, "Plyometrics"),
+    (21, "Pull-ups"),
+    (22, "Push-ups"),
+    (23, "Barbell Row"),
+    (24, "Overhead Press"),
+    (25, "Shoulder Stability"),
+    (26, "Shrugs"),
+    (27, "Sit-ups"),
+    (28, "Barbell Squat"),
+    (29, "Total Body"),
+    (30, "Overhead Tricep Extension"),
+    (31, "Warm Up"),
+    (32, "Run"),
+];
+
+/// HealthKit activity types treated as strength training (lowercase, without
+/// the `HKWorkoutActivityType` prefix).
+const HEALTH_STRENGTH_TYPES: &[&str] = &[
+    "traditionalstrengthtraining",
+    "functionalstrengthtraining",
+    "coretraining",
+];
+
+// =============================================================================
+// MARK: - Types
+// =============================================================================
+
+/// Where a watch import came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WatchSource {
+    /// Garmin FIT activity file
+    GarminFit,
+    /// Apple Health JSON export
+    AppleHealth,
+}
+
+impl WatchSource {
+    /// Returns the display name of the source.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            WatchSource::GarminFit => "Garmin",
+            WatchSource::AppleHealth => "Apple Health",
+        }
+    }
+}
+
+/// Result of parsing a watch file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WatchImport {
+    /// Which format the file was in
+    pub source: WatchSource,
+    /// Valid strength workouts, newest first
+    pub workouts: Vec<Workout>,
+    /// Exercise names that didn't match the catalog (kept as-is)
+    pub unmatched_exercises: Vec<String>,
+    /// Why workouts in the file were left out (not strength training, invalid)
+    pub skipped: Vec<String>,
+}
+
+/// Apple Health export: HealthKit workouts with their recorded sets.
+///
+/// ```json
+/// {
+///   "workouts": [{
+///     "uuid": "3C1F…",
+///     "workoutActivityType": "HKWorkoutActivityTypeTraditionalStrengthTraining",
+///     "startDate": "2025-03-03T08:00:00Z",
+///     "endDate": "2025-03-03T08:45:00Z",
+///     "name": "Push Day",
+///     "sets": [{ "exercise": "Bench Press (Barbell)", "reps": 5, "weight": 100, "unit": "kg" }]
+///   }]
+/// }
+/// ```
+#[derive(Deserialize, Debug)]
+pub struct HealthExport {
+    /// Exported workouts, in any order
+    pub workouts: Vec<HealthWorkout>,
+}
+
+/// One HealthKit workout.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthWorkout {
+    /// HealthKit workout UUID (informational)
+    #[serde(default)]
+    pub uuid: Option<String>,
+    /// `HKWorkoutActivityType` case name, with or without the prefix
+    pub workout_activity_type: String,
+    /// RFC 3339 start time
+    pub start_date: String,
+    /// RFC 3339 end time
+    #[serde(default)]
+    pub end_date: Option<String>,
+    /// Workout title, if the recording app set one
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Sets in the order performed
+    #[serde(default)]
+    pub sets: Vec<HealthSet>,
+}
+
+/// One set recorded alongside a HealthKit workout.
+#[derive(Deserialize, Debug)]
+pub struct HealthSet {
+    /// Exercise name as the recording app wrote it
+    pub exercise: String,
+    /// Repetitions
+    #[serde(default)]
+    pub reps: Option<i32>,
+    /// Load in `unit`
+    #[serde(default)]
+    pub weight: Option<f64>,
+    /// "kg" or "lb" (defaults to kg)
+    #[serde(default)]
+    pub unit: Option<String>,
+    /// Duration in seconds, for timed sets
+    #[serde(default)]
+    pub duration: Option<f64>,
+    /// Rest after the set in seconds
+    #[serde(default)]
+    pub rest: Option<f64>,
+}
+
+// =============================================================================
+// MARK: - Parsing
+// =============================================================================
+
+/// Parses a FIT file or an Apple Health JSON export, detected by content.
+///
+/// # Errors
+///
+/// Returns an error if the file is neither format or can't be read. A file
+/// that reads fine but holds no valid strength workout returns an empty
+/// `workouts` list with the reasons in `skipped`.
+pub fn parse_watch_file(data: &[u8]) -> Result<WatchImport, String> {
+    if fit::is_fit(data) {
+        parse_fit(data)
+    } else {
+        let export: HealthExport = serde_json::from_slice(data)
+            .map_err(|e| format!("Not a FIT file or Apple Health export: {}", e))?;
+        Ok(parse_health_export(export))
+    }
+}
+
+/// Parses a Garmin FIT activity file into (at most) one workout.
+///
+/// # Errors
+///
+/// Returns an error if the file can't be decoded or has no start time.
+pub fn parse_fit(data: &[u8]) -> Result<WatchImport, String> {
+    let messages = fit::decode(data)?;
+    let session = messages.iter().find(|m| m.global == message::SESSION);
+    let sets: Vec<&FitMessage> = messages.iter().filter(|m| m.global == message::SET).collect();
+
+    let start = session
+        .and_then(|s| s.value(2))
+        .or_else(|| sets.iter().find_map(|s| s.value(6)))
+        .and_then(fit_timestamp)
+        .ok_or("FIT file has no start time")?;
+
+    let mut builder = WorkoutBuilder::new(FIT_WORKOUT_NAME, start);
+    if let Some(elapsed_ms) = session.and_then(|s| s.value(7)) {
+        builder.set_duration((elapsed_ms as f64 / 1000.0).round() as i32);
+    }
+
+    for set in sets {
+        let duration = set.value(0).map(|ms| (ms as f64 / 1000.0).round() as i32);
+        // set_type 0 is the rest between sets
+        if set.value(5) == Some(0) {
+            if let Some(rest) = duration {
+                builder.add_rest(rest);
+            }
+            continue;
+        }
+
+        let name = set
+            .value(7)
+            .and_then(|category| {
+                FIT_EXERCISE_CATEGORIES
+                    .iter()
+                    .find(|(c, _)| *c == category)
+                    .map(|(_, name)| *name)
+            })
+            .unwrap_or("Unknown Exercise");
+        let unit = match set.value(9) {
+            Some(2) => WeightUnit::Lb,
+            _ => WeightUnit::Kg,
+        };
+        let weight = set
+            .value(4)
+            .filter(|raw| *raw > 0)
+            .map(|raw| fit_weight(raw, &unit));
+        let reps = set.value(3).filter(|reps| *reps > 0).map(|reps| reps as i32);
+
+        builder.add_set(
+            name,
+            unit,
+            SetActual {
+                weight,
+                reps,
+                duration: if reps.is_none() { duration } else { None },
+                rpe: None,
+                actual_rest_time: None,
+            },
+        );
+    }
+
+    let mut import = WatchImport {
+        source: WatchSource::GarminFit,
+        workouts: Vec::new(),
+        unmatched_exercises: Vec::new(),
+        skipped: Vec::new(),
+    };
+    if builder.is_empty() {
+        import
+            .skipped
+            .push("FIT file has no strength-training sets".to_string());
+    } else {
+        builder.finish(&mut import);
+    }
+    Ok(import)
+}
+
+/// Converts an Apple Health export, skipping non-strength workouts.
+pub fn parse_health_export(export: HealthExport) -> WatchImport {
+    let mut import = WatchImport {
+        source: WatchSource::AppleHealth,
+        workouts: Vec::new(),
+        unmatched_exercises: Vec::new(),
+        skipped: Vec::new(),
+    };
+
+    for (index, health) in export.workouts.into_iter().enumerate() {
+        let label = format!("Workout {}", index + 1);
+        let activity = health.workout_activity_type.to_lowercase();
+        let activity = activity.trim_start_matches("hkworkoutactivitytype");
+        if !HEALTH_STRENGTH_TYPES.contains(&activity) {
+            import.skipped.push(format!(
+                "{}: {} is not a strength workout",
+                label, health.workout_activity_type
+            ));
+            continue;
+        }
+        if health.sets.is_empty() {
+            import.skipped.push(format!("{}: no sets recorded", label));
+            continue;
+        }
+
+        let parse_date = |value: &str| {
+            value
+                .parse::<Timestamp>()
+                .map_err(|_| format!("{}: invalid date '{}'", label, value))
+        };
+        let start = match parse_date(&health.start_date) {
+            Ok(start) => start,
+            Err(e) => {
+                import.skipped.push(e);
+                continue;
+            }
+        };
+
+        let name = health
+            .name
+            .filter(|name| !name.trim().is_empty())
+            .unwrap_or_else(|| FIT_WORKOUT_NAME.to_string());
+        let mut builder = WorkoutBuilder::new(&name, start);
+        match health.end_date.as_deref().map(parse_date).transpose() {
+            Ok(Some(end)) => builder.set_duration((end.as_seconds() - start.as_seconds()) as i32),
+            Ok(None) => {}
+            Err(e) => {
+                import.skipped.push(e);
+                continue;
+            }
+        }
+
+        for set in health.sets {
+            let unit = match set.unit.as_deref().map(str::to_lowercase).as_deref() {
+                Some("lb" | "lbs" | "pound" | "pounds") => WeightUnit::Lb,
+                _ => WeightUnit::Kg,
+            };
+            builder.add_set(
+                &set.exercise,
+                unit,
+                SetActual {
+                    weight: set.weight.filter(|weight| *weight > 0.0),
+                    reps: set.reps.filter(|reps| *reps > 0),
+                    duration: set.duration.map(|secs| secs.round() as i32),
+                    rpe: None,
+                    actual_rest_time: set.rest.map(|secs| secs.round() as i32),
+                },
+            );
+        }
+        builder.finish(&mut import);
+    }
+
+    import.workouts.sort_by_key(|w| std::cmp::Reverse(w.start_timestamp));
+    import
+}
+
+/// Converts FIT seconds-since-1989 to a timestamp.
+fn fit_timestamp(seconds: u64) -> Option<Timestamp> {
+    Timestamp::from_millis((seconds as i64 + FIT_EPOCH_OFFSET) * 1000)
+}
+
+/// Converts a FIT weight (kg × 16) to `unit`.
+///
+/// Pounds are rounded to the nearest quarter pound, undoing the rounding of
+/// the kilogram encoding (185 lb is stored as 83.9375 kg).
+fn fit_weight(raw: u64, unit: &WeightUnit) -> f64 {
+    let kg = raw as f64 / 16.0;
+    match unit {
+        WeightUnit::Lb => (WeightUnit::Kg.convert(kg, unit) * 4.0).round() / 4.0,
+        _ => (kg * 100.0).round() / 100.0,
+    }
+}
+
+// =============================================================================
+// MARK: - Building Workouts
+// =============================================================================
+
+/// Accumulates sets into a workout, grouping them by exercise name.
+struct WorkoutBuilder {
+    workout: Workout,
+    unmatched: BTreeSet<String>,
+    /// (exercise index, set index) of the last set, for attaching rest
+    last_set: Option<(usize, usize)>,
+}
+
+impl WorkoutBuilder {
+    fn new(name: &str, start: Timestamp) -> Self {
+        let mut workout = Workout::with_name(name);
+        workout.start_timestamp = start;
+        workout.end_timestamp = Some(start);
+        Self {
+            workout,
+            unmatched: BTreeSet::new(),
+            last_set: None,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.workout.exercises.is_empty()
+    }
+
+    fn set_duration(&mut self, seconds: i32) {
+        let start = self.workout.start_timestamp;
+        self.workout.duration = Some(seconds);
+        self.workout.end_timestamp =
+            Timestamp::from_millis(start.as_millis() + i64::from(seconds) * 1000);
+    }
+
+    fn add_set(&mut self, exercise_name: &str, unit: WeightUnit, actual: SetActual) {
+        let matched = match_exercise(exercise_name);
+        let name = match matched.catalog_name {
+            Some(catalog_name) => catalog_name.to_string(),
+            None => {
+                self.unmatched.insert(exercise_name.to_string());
+                exercise_name.to_string()
+            }
+        };
+
+        let workout = &mut self.workout;
+        let exercise_index = match workout.exercises.iter().position(|e| e.name == name) {
+            Some(index) => index,
+            None => {
+                let mut exercise = Exercise::new(name, workout.id.clone());
+                exercise.exercise_type = matched.exercise_type;
+                exercise.weight_unit = Some(unit.clone());
+                workout.exercises.push(exercise);
+                workout.exercises.len() - 1
+            }
+        };
+        let exercise = &mut workout.exercises[exercise_index];
+
+        let mut set = ExerciseSet::new(
+            exercise.id.clone(),
+            workout.id.clone(),
+            exercise.sets.len() as i32,
+        );
+        set.weight_unit = Some(unit);
+        set.complete(actual);
+        exercise.sets.push(set);
+        self.last_set = Some((exercise_index, exercise.sets.len() - 1));
+    }
+
+    fn add_rest(&mut self, seconds: i32) {
+        if let Some((exercise, set)) = self.last_set {
+            self.workout.exercises[exercise].sets[set].actual.actual_rest_time = Some(seconds);
+        }
+    }
+
+    /// Validates the workout and adds it (or the reason it was skipped).
+    fn finish(self, import: &mut WatchImport) {
+        match validation::validate_workout(&self.workout) {
+            Ok(()) => {
+                for name in self.unmatched {
+                    if !import.unmatched_exercises.contains(&name) {
+                        import.unmatched_exercises.push(name);
+                    }
+                }
+                import.workouts.push(self.workout);
+            }
+            Err(errors) => import.skipped.push(format!(
+                "Workout '{}' is invalid: {}",
+                self.workout.name,
+                validation::describe_errors(&errors)
+            )),
+        }
+    }
+}
+
+// =============================================================================
+// MARK: - Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STRENGTH_FIT: &[u8] = include_bytes!("../fixtures/watch/strength.fit");
+    const HEALTH_JSON: &[u8] = include_bytes!("../fixtures/watch/apple-health.json");
+
+    #[test]
+    fn test_fit_fixture_becomes_one_workout() {
+        let import = parse_watch_file(STRENGTH_FIT).unwrap();
+
+        assert_eq!(import.source, WatchSource::GarminFit);
+        assert!(import.skipped.is_empty());
+        assert_eq!(import.workouts.len(), 1);
+
+        let workout = &import.workouts[0];
+        assert_eq!(workout.name, "Strength Training");
+        assert_eq!(workout.start_timestamp.to_string(), "2025-03-03T08:00:00.000Z");
+        assert_eq!(workout.duration, Some(2700));
+        assert_eq!(
+            workout.end_timestamp.unwrap().to_string(),
+            "2025-03-03T08:45:00.000Z"
+        );
+
+        let names: Vec<_> = workout.exercises.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["Barbell Bench Press", "Barbell Squat", "Plank", "Carry"]);
+        assert_eq!(import.unmatched_exercises, ["Carry"]);
+    }
+
+    #[test]
+    fn test_fit_sets_weights_and_rest() {
+        let import = parse_fit(STRENGTH_FIT).unwrap();
+        let workout = &import.workouts[0];
+
+        let bench = &workout.exercises[0];
+        assert_eq!(bench.sets.len(), 2);
+        assert_eq!(bench.sets[0].actual.weight, Some(60.0));
+        assert_eq!(bench.sets[0].actual.reps, Some(8));
+        assert_eq!(bench.sets[0].actual.actual_rest_time, Some(90));
+        assert_eq!(bench.sets[1].actual.weight, Some(80.0));
+        assert_eq!(bench.sets[1].weight_unit, Some(WeightUnit::Kg));
+        assert!(bench.sets.iter().all(|s| s.is_completed));
+
+        // Shown in pounds on the watch
+        let squat = &workout.exercises[1];
+        assert_eq!(squat.sets[0].actual.weight, Some(225.0));
+        assert_eq!(squat.sets[0].weight_unit, Some(WeightUnit::Lb));
+
+        // Timed hold without reps or weight
+        let plank = &workout.exercises[2].sets[0].actual;
+        assert_eq!((plank.weight, plank.reps, plank.duration), (None, None, Some(60)));
+    }
+
+    #[test]
+    fn test_health_fixture_keeps_strength_workouts() {
+        let import = parse_watch_file(HEALTH_JSON).unwrap();
+
+        assert_eq!(import.source, WatchSource::AppleHealth);
+        let names: Vec<_> = import.workouts.iter().map(|w| w.name.as_str()).collect();
+        assert_eq!(names, ["Strength Training", "Push Day"]);
+        assert_eq!(
+            import.skipped,
+            ["Workout 2: HKWorkoutActivityTypeRunning is not a strength workout"]
+        );
+        assert_eq!(import.unmatched_exercises, ["Landmine Press"]);
+
+        let push = &import.workouts[1];
+        assert_eq!(push.duration, Some(2700));
+        let bench = &push.exercises[0];
+        assert_eq!(bench.name, "Barbell Bench Press");
+        assert_eq!(bench.sets.len(), 2);
+        assert_eq!(bench.sets[1].actual.weight, Some(185.0));
+        assert_eq!(bench.sets[1].weight_unit, Some(WeightUnit::Lb));
+        assert_eq!(bench.sets[0].actual.actual_rest_time, Some(120));
+    }
+
+    #[test]
+    fn test_health_workout_without_sets_or_dates_is_skipped() {
+        let json = br#"{"workouts": [
+            {"workoutActivityType": "coreTraining", "startDate": "2025-03-03T08:00:00Z"},
+            {"workoutActivityType": "coreTraining", "startDate": "yesterday",
+             "sets": [{"exercise": "Plank", "duration": 60}]}
+        ]}"#;
+
+        let import = parse_watch_file(json).unwrap();
+
+        assert!(import.workouts.is_empty());
+        assert_eq!(
+            import.skipped,
+            ["Workout 1: no sets recorded", "Workout 2: invalid date 'yesterday'"]
+        );
+    }
+
+    #[test]
+    fn test_unrecognized_file_is_an_error() {
+        let error = parse_watch_file(b"Date,Workout Name\n").unwrap_err();
+        assert!(error.starts_with("Not a FIT file or Apple Health export"));
+    }
+}
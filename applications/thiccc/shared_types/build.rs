@@ -1,16 +1,21 @@
 use crux_core::typegen::TypeGen;
-use shared::{app::*, models::*, operations::*, Thiccc};
+use shared::{
+    app::*,
+    models::*,
+    operations::*,
+    sync::{EntityKind, SyncState},
+    ExportFormat, ImportEntry, ImportSummary, Thiccc, Timestamp,
+};
 use std::path::PathBuf;
 
 /// Creates a complete sample workout with all nested types populated.
 /// This ensures TypeGen can trace all field types (Exercise, ExerciseSet, etc.).
-#[allow(dead_code)]
 fn sample_workout() -> Workout {
     let mut workout = Workout::new();
     workout.name = "Sample Workout".to_string();
     workout.note = Some("Sample note".to_string());
     workout.duration = Some(3600);
-    workout.end_timestamp = Some(chrono::Utc::now());
+    workout.end_timestamp = Some(Timestamp::now());
     
     // Add an exercise with ALL optional fields populated
     let mut exercise = Exercise::new("Bench Press".to_string(), workout.id.clone());
@@ -47,6 +52,60 @@ fn sample_workout() -> Workout {
     workout
 }
 
+/// Creates a history query with every optional field populated.
+fn sample_history_query() -> HistoryQuery {
+    HistoryQuery {
+        cursor: Some(HistoryCursor {
+            start_timestamp: Timestamp::now(),
+            workout_id: "00000000-0000-0000-0000-000000000000".to_string(),
+        }),
+        limit: 20,
+        filter: HistoryFilter {
+            from_timestamp: Some(Timestamp::now()),
+            to_timestamp: Some(Timestamp::now()),
+            exercise_name: Some("Bench Press".to_string()),
+            name_search: Some("Push".to_string()),
+        },
+    }
+}
+
+/// Creates sync state with pending changes for every entity kind.
+fn sample_sync_state() -> SyncState {
+    let mut state = SyncState::new_replica();
+    state.record(None, Some(&sample_workout()));
+    state.cursor = 1;
+    state.seeded = true;
+    state
+}
+
+/// Creates a request with every optional field populated.
+fn sample_http_request() -> HttpRequest {
+    HttpRequest {
+        method: HttpMethod::Post,
+        url: "https://api.example.com/api/sync".to_string(),
+        headers: vec![sample_http_header()],
+        body: Some("{}".to_string()),
+        timeout_ms: 30_000,
+        delay_ms: 500,
+    }
+}
+
+fn sample_http_header() -> HttpHeader {
+    HttpHeader {
+        name: "Content-Type".to_string(),
+        value: "application/json".to_string(),
+    }
+}
+
+/// Creates a sample history load failure.
+fn sample_load_failure() -> WorkoutLoadFailure {
+    WorkoutLoadFailure {
+        workout_id: "00000000-0000-0000-0000-000000000000".to_string(),
+        error: "Sample error".to_string(),
+        raw_payload: "{}".to_string(),
+    }
+}
+
 fn main() -> anyhow::Result<()> {
     println!("cargo:rerun-if-changed=../shared");
 
@@ -61,44 +120,159 @@ fn main() -> anyhow::Result<()> {
         TimerOutput::Tick,
         TimerOutput::Started,
         TimerOutput::Stopped,
+        TimerOutput::Error { message: "Sample error".to_string() },
+    ])?;
+    type_gen.register_type_with_samples::<AppError>(vec![
+        AppError::validation("Sample error"),
+        AppError::storage("Sample error"),
+        AppError::database("Sample error"),
+        AppError::import("Sample error"),
+        AppError::export("Sample error"),
+        AppError::timer("Sample error"),
+        AppError::sync("Sample error"),
+    ])?;
+    type_gen.register_type_with_samples::<ExportFormat>(vec![
+        ExportFormat::JsonArchive,
+        ExportFormat::Csv,
+        ExportFormat::Markdown,
+    ])?;
+    type_gen.register_type_with_samples::<ShareResult>(vec![
+        ShareResult::Shared,
+        ShareResult::Cancelled,
+        ShareResult::Failed { message: "Sample error".to_string() },
+    ])?;
+    type_gen.register_type_with_samples::<HttpMethod>(vec![
+        HttpMethod::Get,
+        HttpMethod::Post,
+        HttpMethod::Put,
+        HttpMethod::Patch,
+        HttpMethod::Delete,
+    ])?;
+    type_gen.register_type_with_samples::<EntityKind>(vec![
+        EntityKind::Workout,
+        EntityKind::Exercise,
+        EntityKind::Set,
     ])?;
     type_gen.register_type_with_samples::<TimerOperation>(vec![
         TimerOperation::Start,
         TimerOperation::Stop,
     ])?;
+    type_gen.register_type_with_samples::<ExerciseType>(vec![
+        ExerciseType::Dumbbell,
+        ExerciseType::Kettlebell,
+        ExerciseType::Barbell,
+        ExerciseType::Hexbar,
+        ExerciseType::Bodyweight,
+        ExerciseType::Machine,
+        ExerciseType::Unknown,
+    ])?;
+    type_gen.register_type_with_samples::<WeightUnit>(vec![
+        WeightUnit::Kg,
+        WeightUnit::Lb,
+        WeightUnit::Bodyweight,
+    ])?;
+    type_gen.register_type_with_samples::<SetType>(vec![
+        SetType::WarmUp,
+        SetType::Working,
+        SetType::DropSet,
+        SetType::Amrap,
+        SetType::Failure,
+    ])?;
+    type_gen.register_type_with_samples::<BodyPartMain>(vec![
+        BodyPartMain::Chest,
+        BodyPartMain::Legs,
+        BodyPartMain::Arms,
+        BodyPartMain::Back,
+        BodyPartMain::Calves,
+        BodyPartMain::Shoulders,
+        BodyPartMain::Core,
+        BodyPartMain::Cardio,
+        BodyPartMain::FullBody,
+        BodyPartMain::Other,
+    ])?;
 
-    // 2. Register database and storage result types (they use JSON strings)
+    // 2. Register payload types before the results/operations that contain them
+    type_gen.register_type_with_samples::<Workout>(vec![sample_workout()])?;
+    type_gen.register_type_with_samples::<HistoryQuery>(vec![sample_history_query()])?;
+    type_gen.register_type_with_samples::<SyncState>(vec![sample_sync_state()])?;
+    type_gen.register_type_with_samples::<HttpRequest>(vec![sample_http_request()])?;
+    type_gen.register_type_with_samples::<HttpResult>(vec![
+        HttpResult::Response(HttpResponse {
+            status: 200,
+            headers: vec![sample_http_header()],
+            body: "{}".to_string(),
+        }),
+        HttpResult::TimedOut,
+        HttpResult::Failed { message: "Sample error".to_string() },
+    ])?;
+
+    // 3. Register database and storage result types
     type_gen.register_type_with_samples::<DatabaseResult>(vec![
         DatabaseResult::WorkoutSaved,
         DatabaseResult::WorkoutDeleted,
-        DatabaseResult::HistoryLoaded { workouts_json: vec!["{}".to_string()] },
-        DatabaseResult::WorkoutLoaded { workout_json: Some("{}".to_string()) },
-        DatabaseResult::WorkoutLoaded { workout_json: None },
+        DatabaseResult::WorkoutUpdated,
+        DatabaseResult::HistoryLoaded {
+            workouts: vec![sample_workout()],
+            failures: vec![sample_load_failure()],
+        },
+        DatabaseResult::WorkoutLoaded { workout: Some(sample_workout()) },
+        DatabaseResult::WorkoutLoaded { workout: None },
+        DatabaseResult::WorkoutLoadFailed { failure: sample_load_failure() },
+        DatabaseResult::HistoryPageLoaded {
+            query: sample_history_query(),
+            workouts: vec![sample_workout()],
+            failures: vec![sample_load_failure()],
+            has_more: true,
+        },
+        DatabaseResult::ExerciseHistoryLoaded {
+            exercise_names: vec!["Bench Press".to_string()],
+            workouts: vec![sample_workout()],
+            failures: vec![sample_load_failure()],
+        },
+        DatabaseResult::ExerciseNamesLoaded { names: vec!["Bench Press".to_string()] },
+        DatabaseResult::WorkoutsImported {
+            summary: ImportSummary {
+                inserted: vec![ImportEntry::of(&sample_workout())],
+                skipped: vec![ImportEntry::of(&sample_workout())],
+                conflicting: vec![ImportEntry::of(&sample_workout())],
+            },
+        },
         DatabaseResult::Error { message: "Sample error".to_string() },
     ])?;
     type_gen.register_type_with_samples::<StorageResult>(vec![
         StorageResult::CurrentWorkoutSaved,
-        StorageResult::CurrentWorkoutLoaded { workout_json: Some("{}".to_string()) },
-        StorageResult::CurrentWorkoutLoaded { workout_json: None },
+        StorageResult::CurrentWorkoutLoaded { workout: Some(sample_workout()) },
+        StorageResult::CurrentWorkoutLoaded { workout: None },
         StorageResult::CurrentWorkoutDeleted,
+        StorageResult::SyncStateSaved,
+        StorageResult::SyncStateLoaded { state: Some(sample_sync_state()) },
+        StorageResult::SyncStateLoaded { state: None },
     ])?;
 
-    // 3. Register operation types
-    // Note: DatabaseOperation and StorageOperation now use JSON strings for workout data
-    // to avoid TypeGen issues with complex nested types in Request<T>
+    // 4. Register operation types
     type_gen.register_type_with_samples::<DatabaseOperation>(vec![
-        DatabaseOperation::SaveWorkout("{}".to_string()),  // JSON placeholder
+        DatabaseOperation::SaveWorkout(sample_workout()),
         DatabaseOperation::LoadAllWorkouts,
         DatabaseOperation::LoadWorkoutById("00000000-0000-0000-0000-000000000000".to_string()),
         DatabaseOperation::DeleteWorkout("00000000-0000-0000-0000-000000000000".to_string()),
+        DatabaseOperation::UpdateWorkout(sample_workout()),
+        DatabaseOperation::LoadWorkoutsPage(sample_history_query()),
+        DatabaseOperation::ImportWorkouts(vec![sample_workout()]),
+        DatabaseOperation::LoadExerciseHistory(vec!["Bench Press".to_string()]),
+        DatabaseOperation::LoadExerciseNames,
     ])?;
     type_gen.register_type_with_samples::<StorageOperation>(vec![
-        StorageOperation::SaveCurrentWorkout("{}".to_string()),  // JSON placeholder
+        StorageOperation::SaveCurrentWorkout(sample_workout()),
         StorageOperation::LoadCurrentWorkout,
         StorageOperation::DeleteCurrentWorkout,
+        StorageOperation::SaveSyncState(sample_sync_state()),
+        StorageOperation::LoadSyncState,
+    ])?;
+    type_gen.register_type_with_samples::<HttpOperation>(vec![
+        HttpOperation::Send(sample_http_request()),
     ])?;
 
-    // 4. Register the app (auto-discovers Event, ViewModel, Effect and their nested types)
+    // 5. Register the app (auto-discovers Event, ViewModel, Effect and their nested types)
     type_gen.register_app::<Thiccc>()?;
 
     // Generate Swift bindings
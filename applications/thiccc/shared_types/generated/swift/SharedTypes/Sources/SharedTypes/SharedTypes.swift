@@ -1,26 +1,39 @@
 import Serde
 
 
-indirect public enum DatabaseOperation: Hashable {
-    case saveWorkout(String)
-    case loadAllWorkouts
-    case loadWorkoutById(String)
-    case deleteWorkout(String)
+indirect public enum AppError: Hashable {
+    case validation(message: String)
+    case storage(message: String)
+    case database(message: String)
+    case import(message: String)
+    case export(message: String)
+    case timer(message: String)
+    case sync(message: String)
 
     public func serialize<S: Serializer>(serializer: S) throws {
         try serializer.increase_container_depth()
         switch self {
-        case .saveWorkout(let x):
+        case .validation(let message):
             try serializer.serialize_variant_index(value: 0)
-            try serializer.serialize_str(value: x)
-        case .loadAllWorkouts:
+            try serializer.serialize_str(value: message)
+        case .storage(let message):
             try serializer.serialize_variant_index(value: 1)
-        case .loadWorkoutById(let x):
+            try serializer.serialize_str(value: message)
+        case .database(let message):
             try serializer.serialize_variant_index(value: 2)
-            try serializer.serialize_str(value: x)
-        case .deleteWorkout(let x):
+            try serializer.serialize_str(value: message)
+        case .import(let message):
             try serializer.serialize_variant_index(value: 3)
-            try serializer.serialize_str(value: x)
+            try serializer.serialize_str(value: message)
+        case .export(let message):
+            try serializer.serialize_variant_index(value: 4)
+            try serializer.serialize_str(value: message)
+        case .timer(let message):
+            try serializer.serialize_variant_index(value: 5)
+            try serializer.serialize_str(value: message)
+        case .sync(let message):
+            try serializer.serialize_variant_index(value: 6)
+            try serializer.serialize_str(value: message)
         }
         try serializer.decrease_container_depth()
     }
@@ -31,30 +44,43 @@ indirect public enum DatabaseOperation: Hashable {
         return serializer.get_bytes()
     }
 
-    public static func deserialize<D: Deserializer>(deserializer: D) throws -> DatabaseOperation {
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> AppError {
         let index = try deserializer.deserialize_variant_index()
         try deserializer.increase_container_depth()
         switch index {
         case 0:
-            let x = try deserializer.deserialize_str()
+            let message = try deserializer.deserialize_str()
             try deserializer.decrease_container_depth()
-            return .saveWorkout(x)
+            return .validation(message: message)
         case 1:
+            let message = try deserializer.deserialize_str()
             try deserializer.decrease_container_depth()
-            return .loadAllWorkouts
+            return .storage(message: message)
         case 2:
-            let x = try deserializer.deserialize_str()
+            let message = try deserializer.deserialize_str()
             try deserializer.decrease_container_depth()
-            return .loadWorkoutById(x)
+            return .database(message: message)
         case 3:
-            let x = try deserializer.deserialize_str()
+            let message = try deserializer.deserialize_str()
             try deserializer.decrease_container_depth()
-            return .deleteWorkout(x)
-        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for DatabaseOperation: \(index)")
+            return .import(message: message)
+        case 4:
+            let message = try deserializer.deserialize_str()
+            try deserializer.decrease_container_depth()
+            return .export(message: message)
+        case 5:
+            let message = try deserializer.deserialize_str()
+            try deserializer.decrease_container_depth()
+            return .timer(message: message)
+        case 6:
+            let message = try deserializer.deserialize_str()
+            try deserializer.decrease_container_depth()
+            return .sync(message: message)
+        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for AppError: \(index)")
         }
     }
 
-    public static func bincodeDeserialize(input: [UInt8]) throws -> DatabaseOperation {
+    public static func bincodeDeserialize(input: [UInt8]) throws -> AppError {
         let deserializer = BincodeDeserializer.init(input: input);
         let obj = try deserialize(deserializer: deserializer)
         if deserializer.get_buffer_offset() < input.count {
@@ -64,29 +90,85 @@ indirect public enum DatabaseOperation: Hashable {
     }
 }
 
-indirect public enum DatabaseResult: Hashable {
-    case workoutSaved
-    case workoutDeleted
-    case historyLoaded(workouts_json: [String])
-    case workoutLoaded(workout_json: String?)
-    case error(message: String)
+public struct BodyPart: Hashable {
+    @Indirect public var main: SharedTypes.BodyPartMain
+    @Indirect public var detailed: [String]?
+    @Indirect public var scientific: [String]?
+
+    public init(main: SharedTypes.BodyPartMain, detailed: [String]?, scientific: [String]?) {
+        self.main = main
+        self.detailed = detailed
+        self.scientific = scientific
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try self.main.serialize(serializer: serializer)
+        try serialize_option_vector_str(value: self.detailed, serializer: serializer)
+        try serialize_option_vector_str(value: self.scientific, serializer: serializer)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> BodyPart {
+        try deserializer.increase_container_depth()
+        let main = try SharedTypes.BodyPartMain.deserialize(deserializer: deserializer)
+        let detailed = try deserialize_option_vector_str(deserializer: deserializer)
+        let scientific = try deserialize_option_vector_str(deserializer: deserializer)
+        try deserializer.decrease_container_depth()
+        return BodyPart.init(main: main, detailed: detailed, scientific: scientific)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> BodyPart {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+indirect public enum BodyPartMain: Hashable {
+    case chest
+    case legs
+    case arms
+    case back
+    case calves
+    case shoulders
+    case core
+    case cardio
+    case fullBody
+    case other
 
     public func serialize<S: Serializer>(serializer: S) throws {
         try serializer.increase_container_depth()
         switch self {
-        case .workoutSaved:
+        case .chest:
             try serializer.serialize_variant_index(value: 0)
-        case .workoutDeleted:
+        case .legs:
             try serializer.serialize_variant_index(value: 1)
-        case .historyLoaded(let workouts_json):
+        case .arms:
             try serializer.serialize_variant_index(value: 2)
-            try serialize_vector_str(value: workouts_json, serializer: serializer)
-        case .workoutLoaded(let workout_json):
+        case .back:
             try serializer.serialize_variant_index(value: 3)
-            try serialize_option_str(value: workout_json, serializer: serializer)
-        case .error(let message):
+        case .calves:
             try serializer.serialize_variant_index(value: 4)
-            try serializer.serialize_str(value: message)
+        case .shoulders:
+            try serializer.serialize_variant_index(value: 5)
+        case .core:
+            try serializer.serialize_variant_index(value: 6)
+        case .cardio:
+            try serializer.serialize_variant_index(value: 7)
+        case .fullBody:
+            try serializer.serialize_variant_index(value: 8)
+        case .other:
+            try serializer.serialize_variant_index(value: 9)
         }
         try serializer.decrease_container_depth()
     }
@@ -97,33 +179,45 @@ indirect public enum DatabaseResult: Hashable {
         return serializer.get_bytes()
     }
 
-    public static func deserialize<D: Deserializer>(deserializer: D) throws -> DatabaseResult {
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> BodyPartMain {
         let index = try deserializer.deserialize_variant_index()
         try deserializer.increase_container_depth()
         switch index {
         case 0:
             try deserializer.decrease_container_depth()
-            return .workoutSaved
+            return .chest
         case 1:
             try deserializer.decrease_container_depth()
-            return .workoutDeleted
+            return .legs
         case 2:
-            let workouts_json = try deserialize_vector_str(deserializer: deserializer)
             try deserializer.decrease_container_depth()
-            return .historyLoaded(workouts_json: workouts_json)
+            return .arms
         case 3:
-            let workout_json = try deserialize_option_str(deserializer: deserializer)
             try deserializer.decrease_container_depth()
-            return .workoutLoaded(workout_json: workout_json)
+            return .back
         case 4:
-            let message = try deserializer.deserialize_str()
             try deserializer.decrease_container_depth()
-            return .error(message: message)
-        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for DatabaseResult: \(index)")
+            return .calves
+        case 5:
+            try deserializer.decrease_container_depth()
+            return .shoulders
+        case 6:
+            try deserializer.decrease_container_depth()
+            return .core
+        case 7:
+            try deserializer.decrease_container_depth()
+            return .cardio
+        case 8:
+            try deserializer.decrease_container_depth()
+            return .fullBody
+        case 9:
+            try deserializer.decrease_container_depth()
+            return .other
+        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for BodyPartMain: \(index)")
         }
     }
 
-    public static func bincodeDeserialize(input: [UInt8]) throws -> DatabaseResult {
+    public static func bincodeDeserialize(input: [UInt8]) throws -> BodyPartMain {
         let deserializer = BincodeDeserializer.init(input: input);
         let obj = try deserialize(deserializer: deserializer)
         if deserializer.get_buffer_offset() < input.count {
@@ -133,28 +227,82 @@ indirect public enum DatabaseResult: Hashable {
     }
 }
 
-indirect public enum Effect: Hashable {
-    case render(SharedTypes.RenderOperation)
-    case database(SharedTypes.DatabaseOperation)
-    case storage(SharedTypes.StorageOperation)
-    case timer(SharedTypes.TimerOperation)
+public struct Change: Hashable {
+    @Indirect public var kind: SharedTypes.EntityKind
+    @Indirect public var entity_id: String
+    @Indirect public var workout_id: String
+    @Indirect public var exercise_id: String?
+    @Indirect public var field: String
+    @Indirect public var value: String
+    @Indirect public var clock: SharedTypes.Clock
+
+    public init(kind: SharedTypes.EntityKind, entity_id: String, workout_id: String, exercise_id: String?, field: String, value: String, clock: SharedTypes.Clock) {
+        self.kind = kind
+        self.entity_id = entity_id
+        self.workout_id = workout_id
+        self.exercise_id = exercise_id
+        self.field = field
+        self.value = value
+        self.clock = clock
+    }
 
     public func serialize<S: Serializer>(serializer: S) throws {
         try serializer.increase_container_depth()
-        switch self {
-        case .render(let x):
-            try serializer.serialize_variant_index(value: 0)
-            try x.serialize(serializer: serializer)
-        case .database(let x):
-            try serializer.serialize_variant_index(value: 1)
-            try x.serialize(serializer: serializer)
-        case .storage(let x):
-            try serializer.serialize_variant_index(value: 2)
-            try x.serialize(serializer: serializer)
-        case .timer(let x):
-            try serializer.serialize_variant_index(value: 3)
-            try x.serialize(serializer: serializer)
+        try self.kind.serialize(serializer: serializer)
+        try serializer.serialize_str(value: self.entity_id)
+        try serializer.serialize_str(value: self.workout_id)
+        try serialize_option_str(value: self.exercise_id, serializer: serializer)
+        try serializer.serialize_str(value: self.field)
+        try serializer.serialize_str(value: self.value)
+        try self.clock.serialize(serializer: serializer)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> Change {
+        try deserializer.increase_container_depth()
+        let kind = try SharedTypes.EntityKind.deserialize(deserializer: deserializer)
+        let entity_id = try deserializer.deserialize_str()
+        let workout_id = try deserializer.deserialize_str()
+        let exercise_id = try deserialize_option_str(deserializer: deserializer)
+        let field = try deserializer.deserialize_str()
+        let value = try deserializer.deserialize_str()
+        let clock = try SharedTypes.Clock.deserialize(deserializer: deserializer)
+        try deserializer.decrease_container_depth()
+        return Change.init(kind: kind, entity_id: entity_id, workout_id: workout_id, exercise_id: exercise_id, field: field, value: value, clock: clock)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> Change {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
         }
+        return obj
+    }
+}
+
+public struct ChartPointViewModel: Hashable {
+    @Indirect public var timestamp: Int64
+    @Indirect public var label: String
+    @Indirect public var value: Double
+
+    public init(timestamp: Int64, label: String, value: Double) {
+        self.timestamp = timestamp
+        self.label = label
+        self.value = value
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_i64(value: self.timestamp)
+        try serializer.serialize_str(value: self.label)
+        try serializer.serialize_f64(value: self.value)
         try serializer.decrease_container_depth()
     }
 
@@ -164,31 +312,56 @@ indirect public enum Effect: Hashable {
         return serializer.get_bytes()
     }
 
-    public static func deserialize<D: Deserializer>(deserializer: D) throws -> Effect {
-        let index = try deserializer.deserialize_variant_index()
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> ChartPointViewModel {
         try deserializer.increase_container_depth()
-        switch index {
-        case 0:
-            let x = try SharedTypes.RenderOperation.deserialize(deserializer: deserializer)
-            try deserializer.decrease_container_depth()
-            return .render(x)
-        case 1:
-            let x = try SharedTypes.DatabaseOperation.deserialize(deserializer: deserializer)
-            try deserializer.decrease_container_depth()
-            return .database(x)
-        case 2:
-            let x = try SharedTypes.StorageOperation.deserialize(deserializer: deserializer)
-            try deserializer.decrease_container_depth()
-            return .storage(x)
-        case 3:
-            let x = try SharedTypes.TimerOperation.deserialize(deserializer: deserializer)
-            try deserializer.decrease_container_depth()
-            return .timer(x)
-        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for Effect: \(index)")
+        let timestamp = try deserializer.deserialize_i64()
+        let label = try deserializer.deserialize_str()
+        let value = try deserializer.deserialize_f64()
+        try deserializer.decrease_container_depth()
+        return ChartPointViewModel.init(timestamp: timestamp, label: label, value: value)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> ChartPointViewModel {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
         }
+        return obj
+    }
+}
+
+public struct Clock: Hashable {
+    @Indirect public var counter: UInt64
+    @Indirect public var replica: String
+
+    public init(counter: UInt64, replica: String) {
+        self.counter = counter
+        self.replica = replica
     }
 
-    public static func bincodeDeserialize(input: [UInt8]) throws -> Effect {
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_u64(value: self.counter)
+        try serializer.serialize_str(value: self.replica)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> Clock {
+        try deserializer.increase_container_depth()
+        let counter = try deserializer.deserialize_u64()
+        let replica = try deserializer.deserialize_str()
+        try deserializer.decrease_container_depth()
+        return Clock.init(counter: counter, replica: replica)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> Clock {
         let deserializer = BincodeDeserializer.init(input: input);
         let obj = try deserialize(deserializer: deserializer)
         if deserializer.get_buffer_offset() < input.count {
@@ -198,153 +371,189 @@ indirect public enum Effect: Hashable {
     }
 }
 
-indirect public enum Event: Hashable {
-    case startWorkout
-    case finishWorkout
-    case discardWorkout
-    case updateWorkoutName(name: String)
-    case updateWorkoutNotes(notes: String)
-    case addExercise(name: String, exercise_type: String, muscle_group: String)
-    case deleteExercise(exercise_id: String)
-    case moveExercise(from_index: UInt64, to_index: UInt64)
-    case showAddExerciseView
-    case dismissAddExerciseView
-    case addSet(exercise_id: String)
-    case deleteSet(exercise_id: String, set_index: UInt64)
-    case updateSetActual(set_id: String, actual: SharedTypes.SetActual)
-    case toggleSetCompleted(set_id: String)
-    case timerTick
-    case startTimer
-    case stopTimer
-    case toggleTimer
-    case showStopwatch
-    case dismissStopwatch
-    case showRestTimer(duration_seconds: Int32)
-    case dismissRestTimer
-    case loadHistory
-    case viewHistoryItem(workout_id: String)
-    case navigateBack
-    case changeTab(tab: SharedTypes.Tab)
-    case importWorkout(json_data: String)
-    case showImportView
-    case dismissImportView
-    case loadWorkoutTemplate
-    case calculatePlates(target_weight: Double, bar_weight: Double, use_percentage: Double?)
-    case clearPlateCalculation
-    case showPlateCalculator
-    case dismissPlateCalculator
-    case initialize
-    case databaseResponse(result: SharedTypes.DatabaseResult)
-    case storageResponse(result: SharedTypes.StorageResult)
-    case timerResponse(output: SharedTypes.TimerOutput)
-    case error(message: String)
+public struct CorruptWorkoutViewModel: Hashable {
+    @Indirect public var workout_id: String
+    @Indirect public var error: String
+
+    public init(workout_id: String, error: String) {
+        self.workout_id = workout_id
+        self.error = error
+    }
 
     public func serialize<S: Serializer>(serializer: S) throws {
         try serializer.increase_container_depth()
-        switch self {
-        case .startWorkout:
-            try serializer.serialize_variant_index(value: 0)
-        case .finishWorkout:
-            try serializer.serialize_variant_index(value: 1)
-        case .discardWorkout:
-            try serializer.serialize_variant_index(value: 2)
-        case .updateWorkoutName(let name):
-            try serializer.serialize_variant_index(value: 3)
-            try serializer.serialize_str(value: name)
-        case .updateWorkoutNotes(let notes):
-            try serializer.serialize_variant_index(value: 4)
-            try serializer.serialize_str(value: notes)
-        case .addExercise(let name, let exercise_type, let muscle_group):
-            try serializer.serialize_variant_index(value: 5)
-            try serializer.serialize_str(value: name)
-            try serializer.serialize_str(value: exercise_type)
-            try serializer.serialize_str(value: muscle_group)
-        case .deleteExercise(let exercise_id):
+        try serializer.serialize_str(value: self.workout_id)
+        try serializer.serialize_str(value: self.error)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> CorruptWorkoutViewModel {
+        try deserializer.increase_container_depth()
+        let workout_id = try deserializer.deserialize_str()
+        let error = try deserializer.deserialize_str()
+        try deserializer.decrease_container_depth()
+        return CorruptWorkoutViewModel.init(workout_id: workout_id, error: error)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> CorruptWorkoutViewModel {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct CsvImportOptions: Hashable {
+    @Indirect public var source_weight_unit: SharedTypes.WeightUnit
+    @Indirect public var target_weight_unit: SharedTypes.WeightUnit
+
+    public init(source_weight_unit: SharedTypes.WeightUnit, target_weight_unit: SharedTypes.WeightUnit) {
+        self.source_weight_unit = source_weight_unit
+        self.target_weight_unit = target_weight_unit
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try self.source_weight_unit.serialize(serializer: serializer)
+        try self.target_weight_unit.serialize(serializer: serializer)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> CsvImportOptions {
+        try deserializer.increase_container_depth()
+        let source_weight_unit = try SharedTypes.WeightUnit.deserialize(deserializer: deserializer)
+        let target_weight_unit = try SharedTypes.WeightUnit.deserialize(deserializer: deserializer)
+        try deserializer.decrease_container_depth()
+        return CsvImportOptions.init(source_weight_unit: source_weight_unit, target_weight_unit: target_weight_unit)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> CsvImportOptions {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct CsvImportPreviewViewModel: Hashable {
+    @Indirect public var source: String
+    @Indirect public var workout_count: UInt64
+    @Indirect public var exercise_count: UInt64
+    @Indirect public var set_count: UInt64
+    @Indirect public var date_range: String
+    @Indirect public var unmatched_exercises: [String]
+    @Indirect public var skipped_rows: [String]
+    @Indirect public var workouts: [SharedTypes.HistoryItemViewModel]
+
+    public init(source: String, workout_count: UInt64, exercise_count: UInt64, set_count: UInt64, date_range: String, unmatched_exercises: [String], skipped_rows: [String], workouts: [SharedTypes.HistoryItemViewModel]) {
+        self.source = source
+        self.workout_count = workout_count
+        self.exercise_count = exercise_count
+        self.set_count = set_count
+        self.date_range = date_range
+        self.unmatched_exercises = unmatched_exercises
+        self.skipped_rows = skipped_rows
+        self.workouts = workouts
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_str(value: self.source)
+        try serializer.serialize_u64(value: self.workout_count)
+        try serializer.serialize_u64(value: self.exercise_count)
+        try serializer.serialize_u64(value: self.set_count)
+        try serializer.serialize_str(value: self.date_range)
+        try serialize_vector_str(value: self.unmatched_exercises, serializer: serializer)
+        try serialize_vector_str(value: self.skipped_rows, serializer: serializer)
+        try serialize_vector_HistoryItemViewModel(value: self.workouts, serializer: serializer)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> CsvImportPreviewViewModel {
+        try deserializer.increase_container_depth()
+        let source = try deserializer.deserialize_str()
+        let workout_count = try deserializer.deserialize_u64()
+        let exercise_count = try deserializer.deserialize_u64()
+        let set_count = try deserializer.deserialize_u64()
+        let date_range = try deserializer.deserialize_str()
+        let unmatched_exercises = try deserialize_vector_str(deserializer: deserializer)
+        let skipped_rows = try deserialize_vector_str(deserializer: deserializer)
+        let workouts = try deserialize_vector_HistoryItemViewModel(deserializer: deserializer)
+        try deserializer.decrease_container_depth()
+        return CsvImportPreviewViewModel.init(source: source, workout_count: workout_count, exercise_count: exercise_count, set_count: set_count, date_range: date_range, unmatched_exercises: unmatched_exercises, skipped_rows: skipped_rows, workouts: workouts)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> CsvImportPreviewViewModel {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+indirect public enum DatabaseOperation: Hashable {
+    case saveWorkout(SharedTypes.Workout)
+    case loadAllWorkouts
+    case loadWorkoutsPage(SharedTypes.HistoryQuery)
+    case loadExerciseHistory([String])
+    case loadExerciseNames
+    case loadWorkoutById(String)
+    case deleteWorkout(String)
+    case updateWorkout(SharedTypes.Workout)
+    case importWorkouts([SharedTypes.Workout])
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        switch self {
+        case .saveWorkout(let x):
+            try serializer.serialize_variant_index(value: 0)
+            try x.serialize(serializer: serializer)
+        case .loadAllWorkouts:
+            try serializer.serialize_variant_index(value: 1)
+        case .loadWorkoutsPage(let x):
+            try serializer.serialize_variant_index(value: 2)
+            try x.serialize(serializer: serializer)
+        case .loadExerciseHistory(let x):
+            try serializer.serialize_variant_index(value: 3)
+            try serialize_vector_str(value: x, serializer: serializer)
+        case .loadExerciseNames:
+            try serializer.serialize_variant_index(value: 4)
+        case .loadWorkoutById(let x):
+            try serializer.serialize_variant_index(value: 5)
+            try serializer.serialize_str(value: x)
+        case .deleteWorkout(let x):
             try serializer.serialize_variant_index(value: 6)
-            try serializer.serialize_str(value: exercise_id)
-        case .moveExercise(let from_index, let to_index):
+            try serializer.serialize_str(value: x)
+        case .updateWorkout(let x):
             try serializer.serialize_variant_index(value: 7)
-            try serializer.serialize_u64(value: from_index)
-            try serializer.serialize_u64(value: to_index)
-        case .showAddExerciseView:
+            try x.serialize(serializer: serializer)
+        case .importWorkouts(let x):
             try serializer.serialize_variant_index(value: 8)
-        case .dismissAddExerciseView:
-            try serializer.serialize_variant_index(value: 9)
-        case .addSet(let exercise_id):
-            try serializer.serialize_variant_index(value: 10)
-            try serializer.serialize_str(value: exercise_id)
-        case .deleteSet(let exercise_id, let set_index):
-            try serializer.serialize_variant_index(value: 11)
-            try serializer.serialize_str(value: exercise_id)
-            try serializer.serialize_u64(value: set_index)
-        case .updateSetActual(let set_id, let actual):
-            try serializer.serialize_variant_index(value: 12)
-            try serializer.serialize_str(value: set_id)
-            try actual.serialize(serializer: serializer)
-        case .toggleSetCompleted(let set_id):
-            try serializer.serialize_variant_index(value: 13)
-            try serializer.serialize_str(value: set_id)
-        case .timerTick:
-            try serializer.serialize_variant_index(value: 14)
-        case .startTimer:
-            try serializer.serialize_variant_index(value: 15)
-        case .stopTimer:
-            try serializer.serialize_variant_index(value: 16)
-        case .toggleTimer:
-            try serializer.serialize_variant_index(value: 17)
-        case .showStopwatch:
-            try serializer.serialize_variant_index(value: 18)
-        case .dismissStopwatch:
-            try serializer.serialize_variant_index(value: 19)
-        case .showRestTimer(let duration_seconds):
-            try serializer.serialize_variant_index(value: 20)
-            try serializer.serialize_i32(value: duration_seconds)
-        case .dismissRestTimer:
-            try serializer.serialize_variant_index(value: 21)
-        case .loadHistory:
-            try serializer.serialize_variant_index(value: 22)
-        case .viewHistoryItem(let workout_id):
-            try serializer.serialize_variant_index(value: 23)
-            try serializer.serialize_str(value: workout_id)
-        case .navigateBack:
-            try serializer.serialize_variant_index(value: 24)
-        case .changeTab(let tab):
-            try serializer.serialize_variant_index(value: 25)
-            try tab.serialize(serializer: serializer)
-        case .importWorkout(let json_data):
-            try serializer.serialize_variant_index(value: 26)
-            try serializer.serialize_str(value: json_data)
-        case .showImportView:
-            try serializer.serialize_variant_index(value: 27)
-        case .dismissImportView:
-            try serializer.serialize_variant_index(value: 28)
-        case .loadWorkoutTemplate:
-            try serializer.serialize_variant_index(value: 29)
-        case .calculatePlates(let target_weight, let bar_weight, let use_percentage):
-            try serializer.serialize_variant_index(value: 30)
-            try serializer.serialize_f64(value: target_weight)
-            try serializer.serialize_f64(value: bar_weight)
-            try serialize_option_f64(value: use_percentage, serializer: serializer)
-        case .clearPlateCalculation:
-            try serializer.serialize_variant_index(value: 31)
-        case .showPlateCalculator:
-            try serializer.serialize_variant_index(value: 32)
-        case .dismissPlateCalculator:
-            try serializer.serialize_variant_index(value: 33)
-        case .initialize:
-            try serializer.serialize_variant_index(value: 34)
-        case .databaseResponse(let result):
-            try serializer.serialize_variant_index(value: 35)
-            try result.serialize(serializer: serializer)
-        case .storageResponse(let result):
-            try serializer.serialize_variant_index(value: 36)
-            try result.serialize(serializer: serializer)
-        case .timerResponse(let output):
-            try serializer.serialize_variant_index(value: 37)
-            try output.serialize(serializer: serializer)
-        case .error(let message):
-            try serializer.serialize_variant_index(value: 38)
-            try serializer.serialize_str(value: message)
+            try serialize_vector_Workout(value: x, serializer: serializer)
         }
         try serializer.decrease_container_depth()
     }
@@ -355,153 +564,961 @@ indirect public enum Event: Hashable {
         return serializer.get_bytes()
     }
 
-    public static func deserialize<D: Deserializer>(deserializer: D) throws -> Event {
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> DatabaseOperation {
         let index = try deserializer.deserialize_variant_index()
         try deserializer.increase_container_depth()
         switch index {
         case 0:
+            let x = try SharedTypes.Workout.deserialize(deserializer: deserializer)
             try deserializer.decrease_container_depth()
-            return .startWorkout
+            return .saveWorkout(x)
         case 1:
             try deserializer.decrease_container_depth()
-            return .finishWorkout
+            return .loadAllWorkouts
         case 2:
+            let x = try SharedTypes.HistoryQuery.deserialize(deserializer: deserializer)
             try deserializer.decrease_container_depth()
-            return .discardWorkout
+            return .loadWorkoutsPage(x)
         case 3:
-            let name = try deserializer.deserialize_str()
+            let x = try deserialize_vector_str(deserializer: deserializer)
             try deserializer.decrease_container_depth()
-            return .updateWorkoutName(name: name)
+            return .loadExerciseHistory(x)
         case 4:
-            let notes = try deserializer.deserialize_str()
             try deserializer.decrease_container_depth()
-            return .updateWorkoutNotes(notes: notes)
+            return .loadExerciseNames
         case 5:
-            let name = try deserializer.deserialize_str()
-            let exercise_type = try deserializer.deserialize_str()
-            let muscle_group = try deserializer.deserialize_str()
+            let x = try deserializer.deserialize_str()
             try deserializer.decrease_container_depth()
-            return .addExercise(name: name, exercise_type: exercise_type, muscle_group: muscle_group)
+            return .loadWorkoutById(x)
         case 6:
-            let exercise_id = try deserializer.deserialize_str()
+            let x = try deserializer.deserialize_str()
             try deserializer.decrease_container_depth()
-            return .deleteExercise(exercise_id: exercise_id)
+            return .deleteWorkout(x)
         case 7:
-            let from_index = try deserializer.deserialize_u64()
-            let to_index = try deserializer.deserialize_u64()
+            let x = try SharedTypes.Workout.deserialize(deserializer: deserializer)
             try deserializer.decrease_container_depth()
-            return .moveExercise(from_index: from_index, to_index: to_index)
+            return .updateWorkout(x)
         case 8:
+            let x = try deserialize_vector_Workout(deserializer: deserializer)
             try deserializer.decrease_container_depth()
-            return .showAddExerciseView
-        case 9:
-            try deserializer.decrease_container_depth()
-            return .dismissAddExerciseView
-        case 10:
-            let exercise_id = try deserializer.deserialize_str()
-            try deserializer.decrease_container_depth()
-            return .addSet(exercise_id: exercise_id)
-        case 11:
-            let exercise_id = try deserializer.deserialize_str()
-            let set_index = try deserializer.deserialize_u64()
-            try deserializer.decrease_container_depth()
-            return .deleteSet(exercise_id: exercise_id, set_index: set_index)
-        case 12:
-            let set_id = try deserializer.deserialize_str()
-            let actual = try SharedTypes.SetActual.deserialize(deserializer: deserializer)
-            try deserializer.decrease_container_depth()
-            return .updateSetActual(set_id: set_id, actual: actual)
-        case 13:
-            let set_id = try deserializer.deserialize_str()
-            try deserializer.decrease_container_depth()
-            return .toggleSetCompleted(set_id: set_id)
-        case 14:
-            try deserializer.decrease_container_depth()
-            return .timerTick
-        case 15:
-            try deserializer.decrease_container_depth()
-            return .startTimer
-        case 16:
-            try deserializer.decrease_container_depth()
-            return .stopTimer
-        case 17:
-            try deserializer.decrease_container_depth()
-            return .toggleTimer
-        case 18:
-            try deserializer.decrease_container_depth()
-            return .showStopwatch
-        case 19:
-            try deserializer.decrease_container_depth()
-            return .dismissStopwatch
-        case 20:
-            let duration_seconds = try deserializer.deserialize_i32()
+            return .importWorkouts(x)
+        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for DatabaseOperation: \(index)")
+        }
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> DatabaseOperation {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+indirect public enum DatabaseResult: Hashable {
+    case workoutSaved
+    case workoutDeleted
+    case workoutUpdated
+    case historyLoaded(workouts: [SharedTypes.Workout], failures: [SharedTypes.WorkoutLoadFailure])
+    case historyPageLoaded(query: SharedTypes.HistoryQuery, workouts: [SharedTypes.Workout], failures: [SharedTypes.WorkoutLoadFailure], has_more: Bool)
+    case exerciseHistoryLoaded(exercise_names: [String], workouts: [SharedTypes.Workout], failures: [SharedTypes.WorkoutLoadFailure])
+    case exerciseNamesLoaded(names: [String])
+    case workoutLoaded(workout: SharedTypes.Workout?)
+    case workoutLoadFailed(failure: SharedTypes.WorkoutLoadFailure)
+    case workoutsImported(summary: SharedTypes.ImportSummary)
+    case error(message: String)
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        switch self {
+        case .workoutSaved:
+            try serializer.serialize_variant_index(value: 0)
+        case .workoutDeleted:
+            try serializer.serialize_variant_index(value: 1)
+        case .workoutUpdated:
+            try serializer.serialize_variant_index(value: 2)
+        case .historyLoaded(let workouts, let failures):
+            try serializer.serialize_variant_index(value: 3)
+            try serialize_vector_Workout(value: workouts, serializer: serializer)
+            try serialize_vector_WorkoutLoadFailure(value: failures, serializer: serializer)
+        case .historyPageLoaded(let query, let workouts, let failures, let has_more):
+            try serializer.serialize_variant_index(value: 4)
+            try query.serialize(serializer: serializer)
+            try serialize_vector_Workout(value: workouts, serializer: serializer)
+            try serialize_vector_WorkoutLoadFailure(value: failures, serializer: serializer)
+            try serializer.serialize_bool(value: has_more)
+        case .exerciseHistoryLoaded(let exercise_names, let workouts, let failures):
+            try serializer.serialize_variant_index(value: 5)
+            try serialize_vector_str(value: exercise_names, serializer: serializer)
+            try serialize_vector_Workout(value: workouts, serializer: serializer)
+            try serialize_vector_WorkoutLoadFailure(value: failures, serializer: serializer)
+        case .exerciseNamesLoaded(let names):
+            try serializer.serialize_variant_index(value: 6)
+            try serialize_vector_str(value: names, serializer: serializer)
+        case .workoutLoaded(let workout):
+            try serializer.serialize_variant_index(value: 7)
+            try serialize_option_Workout(value: workout, serializer: serializer)
+        case .workoutLoadFailed(let failure):
+            try serializer.serialize_variant_index(value: 8)
+            try failure.serialize(serializer: serializer)
+        case .workoutsImported(let summary):
+            try serializer.serialize_variant_index(value: 9)
+            try summary.serialize(serializer: serializer)
+        case .error(let message):
+            try serializer.serialize_variant_index(value: 10)
+            try serializer.serialize_str(value: message)
+        }
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> DatabaseResult {
+        let index = try deserializer.deserialize_variant_index()
+        try deserializer.increase_container_depth()
+        switch index {
+        case 0:
             try deserializer.decrease_container_depth()
-            return .showRestTimer(duration_seconds: duration_seconds)
-        case 21:
+            return .workoutSaved
+        case 1:
             try deserializer.decrease_container_depth()
-            return .dismissRestTimer
-        case 22:
+            return .workoutDeleted
+        case 2:
             try deserializer.decrease_container_depth()
-            return .loadHistory
-        case 23:
-            let workout_id = try deserializer.deserialize_str()
+            return .workoutUpdated
+        case 3:
+            let workouts = try deserialize_vector_Workout(deserializer: deserializer)
+            let failures = try deserialize_vector_WorkoutLoadFailure(deserializer: deserializer)
             try deserializer.decrease_container_depth()
-            return .viewHistoryItem(workout_id: workout_id)
-        case 24:
+            return .historyLoaded(workouts: workouts, failures: failures)
+        case 4:
+            let query = try SharedTypes.HistoryQuery.deserialize(deserializer: deserializer)
+            let workouts = try deserialize_vector_Workout(deserializer: deserializer)
+            let failures = try deserialize_vector_WorkoutLoadFailure(deserializer: deserializer)
+            let has_more = try deserializer.deserialize_bool()
             try deserializer.decrease_container_depth()
-            return .navigateBack
-        case 25:
-            let tab = try SharedTypes.Tab.deserialize(deserializer: deserializer)
+            return .historyPageLoaded(query: query, workouts: workouts, failures: failures, has_more: has_more)
+        case 5:
+            let exercise_names = try deserialize_vector_str(deserializer: deserializer)
+            let workouts = try deserialize_vector_Workout(deserializer: deserializer)
+            let failures = try deserialize_vector_WorkoutLoadFailure(deserializer: deserializer)
             try deserializer.decrease_container_depth()
-            return .changeTab(tab: tab)
-        case 26:
-            let json_data = try deserializer.deserialize_str()
+            return .exerciseHistoryLoaded(exercise_names: exercise_names, workouts: workouts, failures: failures)
+        case 6:
+            let names = try deserialize_vector_str(deserializer: deserializer)
             try deserializer.decrease_container_depth()
-            return .importWorkout(json_data: json_data)
-        case 27:
+            return .exerciseNamesLoaded(names: names)
+        case 7:
+            let workout = try deserialize_option_Workout(deserializer: deserializer)
             try deserializer.decrease_container_depth()
-            return .showImportView
-        case 28:
+            return .workoutLoaded(workout: workout)
+        case 8:
+            let failure = try SharedTypes.WorkoutLoadFailure.deserialize(deserializer: deserializer)
             try deserializer.decrease_container_depth()
-            return .dismissImportView
-        case 29:
+            return .workoutLoadFailed(failure: failure)
+        case 9:
+            let summary = try SharedTypes.ImportSummary.deserialize(deserializer: deserializer)
             try deserializer.decrease_container_depth()
-            return .loadWorkoutTemplate
-        case 30:
-            let target_weight = try deserializer.deserialize_f64()
-            let bar_weight = try deserializer.deserialize_f64()
-            let use_percentage = try deserialize_option_f64(deserializer: deserializer)
+            return .workoutsImported(summary: summary)
+        case 10:
+            let message = try deserializer.deserialize_str()
             try deserializer.decrease_container_depth()
-            return .calculatePlates(target_weight: target_weight, bar_weight: bar_weight, use_percentage: use_percentage)
-        case 31:
+            return .error(message: message)
+        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for DatabaseResult: \(index)")
+        }
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> DatabaseResult {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct DeleteConfirmationViewModel: Hashable {
+    @Indirect public var workout_id: String
+    @Indirect public var workout_name: String
+
+    public init(workout_id: String, workout_name: String) {
+        self.workout_id = workout_id
+        self.workout_name = workout_name
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_str(value: self.workout_id)
+        try serializer.serialize_str(value: self.workout_name)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> DeleteConfirmationViewModel {
+        try deserializer.increase_container_depth()
+        let workout_id = try deserializer.deserialize_str()
+        let workout_name = try deserializer.deserialize_str()
+        try deserializer.decrease_container_depth()
+        return DeleteConfirmationViewModel.init(workout_id: workout_id, workout_name: workout_name)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> DeleteConfirmationViewModel {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+indirect public enum Effect: Hashable {
+    case render(SharedTypes.RenderOperation)
+    case database(SharedTypes.DatabaseOperation)
+    case storage(SharedTypes.StorageOperation)
+    case timer(SharedTypes.TimerOperation)
+    case share(SharedTypes.ShareOperation)
+    case http(SharedTypes.HttpOperation)
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        switch self {
+        case .render(let x):
+            try serializer.serialize_variant_index(value: 0)
+            try x.serialize(serializer: serializer)
+        case .database(let x):
+            try serializer.serialize_variant_index(value: 1)
+            try x.serialize(serializer: serializer)
+        case .storage(let x):
+            try serializer.serialize_variant_index(value: 2)
+            try x.serialize(serializer: serializer)
+        case .timer(let x):
+            try serializer.serialize_variant_index(value: 3)
+            try x.serialize(serializer: serializer)
+        case .share(let x):
+            try serializer.serialize_variant_index(value: 4)
+            try x.serialize(serializer: serializer)
+        case .http(let x):
+            try serializer.serialize_variant_index(value: 5)
+            try x.serialize(serializer: serializer)
+        }
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> Effect {
+        let index = try deserializer.deserialize_variant_index()
+        try deserializer.increase_container_depth()
+        switch index {
+        case 0:
+            let x = try SharedTypes.RenderOperation.deserialize(deserializer: deserializer)
             try deserializer.decrease_container_depth()
-            return .clearPlateCalculation
-        case 32:
+            return .render(x)
+        case 1:
+            let x = try SharedTypes.DatabaseOperation.deserialize(deserializer: deserializer)
             try deserializer.decrease_container_depth()
-            return .showPlateCalculator
-        case 33:
+            return .database(x)
+        case 2:
+            let x = try SharedTypes.StorageOperation.deserialize(deserializer: deserializer)
             try deserializer.decrease_container_depth()
-            return .dismissPlateCalculator
-        case 34:
+            return .storage(x)
+        case 3:
+            let x = try SharedTypes.TimerOperation.deserialize(deserializer: deserializer)
             try deserializer.decrease_container_depth()
-            return .initialize
-        case 35:
-            let result = try SharedTypes.DatabaseResult.deserialize(deserializer: deserializer)
+            return .timer(x)
+        case 4:
+            let x = try SharedTypes.ShareOperation.deserialize(deserializer: deserializer)
             try deserializer.decrease_container_depth()
-            return .databaseResponse(result: result)
-        case 36:
-            let result = try SharedTypes.StorageResult.deserialize(deserializer: deserializer)
+            return .share(x)
+        case 5:
+            let x = try SharedTypes.HttpOperation.deserialize(deserializer: deserializer)
             try deserializer.decrease_container_depth()
-            return .storageResponse(result: result)
-        case 37:
-            let output = try SharedTypes.TimerOutput.deserialize(deserializer: deserializer)
+            return .http(x)
+        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for Effect: \(index)")
+        }
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> Effect {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+indirect public enum EntityKind: Hashable {
+    case workout
+    case exercise
+    case set
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        switch self {
+        case .workout:
+            try serializer.serialize_variant_index(value: 0)
+        case .exercise:
+            try serializer.serialize_variant_index(value: 1)
+        case .set:
+            try serializer.serialize_variant_index(value: 2)
+        }
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> EntityKind {
+        let index = try deserializer.deserialize_variant_index()
+        try deserializer.increase_container_depth()
+        switch index {
+        case 0:
             try deserializer.decrease_container_depth()
-            return .timerResponse(output: output)
-        case 38:
-            let message = try deserializer.deserialize_str()
+            return .workout
+        case 1:
             try deserializer.decrease_container_depth()
-            return .error(message: message)
-        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for Event: \(index)")
+            return .exercise
+        case 2:
+            try deserializer.decrease_container_depth()
+            return .set
+        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for EntityKind: \(index)")
+        }
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> EntityKind {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct ErrorQueueViewModel: Hashable {
+    @Indirect public var recoverable: [SharedTypes.ErrorViewModel]
+    @Indirect public var fatal: SharedTypes.ErrorViewModel?
+    @Indirect public var pending_fatal_count: UInt64
+
+    public init(recoverable: [SharedTypes.ErrorViewModel], fatal: SharedTypes.ErrorViewModel?, pending_fatal_count: UInt64) {
+        self.recoverable = recoverable
+        self.fatal = fatal
+        self.pending_fatal_count = pending_fatal_count
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serialize_vector_ErrorViewModel(value: self.recoverable, serializer: serializer)
+        try serialize_option_ErrorViewModel(value: self.fatal, serializer: serializer)
+        try serializer.serialize_u64(value: self.pending_fatal_count)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> ErrorQueueViewModel {
+        try deserializer.increase_container_depth()
+        let recoverable = try deserialize_vector_ErrorViewModel(deserializer: deserializer)
+        let fatal = try deserialize_option_ErrorViewModel(deserializer: deserializer)
+        let pending_fatal_count = try deserializer.deserialize_u64()
+        try deserializer.decrease_container_depth()
+        return ErrorQueueViewModel.init(recoverable: recoverable, fatal: fatal, pending_fatal_count: pending_fatal_count)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> ErrorQueueViewModel {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct ErrorViewModel: Hashable {
+    @Indirect public var id: UInt32
+    @Indirect public var title: String
+    @Indirect public var message: String
+    @Indirect public var source_event: String
+
+    public init(id: UInt32, title: String, message: String, source_event: String) {
+        self.id = id
+        self.title = title
+        self.message = message
+        self.source_event = source_event
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_u32(value: self.id)
+        try serializer.serialize_str(value: self.title)
+        try serializer.serialize_str(value: self.message)
+        try serializer.serialize_str(value: self.source_event)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> ErrorViewModel {
+        try deserializer.increase_container_depth()
+        let id = try deserializer.deserialize_u32()
+        let title = try deserializer.deserialize_str()
+        let message = try deserializer.deserialize_str()
+        let source_event = try deserializer.deserialize_str()
+        try deserializer.decrease_container_depth()
+        return ErrorViewModel.init(id: id, title: title, message: message, source_event: source_event)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> ErrorViewModel {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+indirect public enum Event: Hashable {
+    case startWorkout
+    case finishWorkout
+    case discardWorkout
+    case updateWorkoutName(name: String)
+    case updateWorkoutNotes(notes: String)
+    case shareWorkoutSummary
+    case copyWorkoutSummary
+    case dismissWorkoutSummary
+    case setPreferredWeightUnit(unit: SharedTypes.WeightUnit)
+    case addExercise(name: String, exercise_type: String, muscle_group: String)
+    case deleteExercise(exercise_id: String)
+    case moveExercise(from_index: UInt64, to_index: UInt64)
+    case showAddExerciseView
+    case dismissAddExerciseView
+    case addSet(exercise_id: String)
+    case deleteSet(exercise_id: String, set_index: UInt64)
+    case updateSetActual(set_id: String, actual: SharedTypes.SetActual)
+    case toggleSetCompleted(set_id: String)
+    case timerTick
+    case startTimer
+    case stopTimer
+    case toggleTimer
+    case showStopwatch
+    case dismissStopwatch
+    case showRestTimer(duration_seconds: Int32)
+    case dismissRestTimer
+    case loadHistory
+    case loadMoreHistory
+    case setHistoryFilter(filter: SharedTypes.HistoryFilter)
+    case clearHistoryFilter
+    case viewHistoryItem(workout_id: String)
+    case viewExerciseHistory(exercise_id: String)
+    case dismissCorruptWorkouts
+    case navigateBack
+    case changeTab(tab: SharedTypes.Tab)
+    case deleteHistoryWorkout(workout_id: String)
+    case confirmDeleteHistoryWorkout
+    case cancelDeleteHistoryWorkout
+    case editHistoryWorkout(workout_id: String)
+    case saveHistoryWorkoutEdits
+    case cancelHistoryWorkoutEdits
+    case importWorkout(json_data: String)
+    case previewCsvImport(csv_data: String, options: SharedTypes.CsvImportOptions)
+    case confirmCsvImport
+    case cancelCsvImport
+    case importWatchFile(data: [UInt8])
+    case dismissImportSummary
+    case exportHistory(format: SharedTypes.ExportFormat)
+    case showImportView
+    case dismissImportView
+    case loadWorkoutTemplate
+    case calculatePlates(target_weight: Double, bar_weight: Double, use_percentage: Double?)
+    case clearPlateCalculation
+    case showPlateCalculator
+    case dismissPlateCalculator
+    case initialize
+    case apiSessionChanged(base_url: String?, token: String?)
+    case syncNow
+    case databaseResponse(result: SharedTypes.DatabaseResult)
+    case exportHistoryLoaded(format: SharedTypes.ExportFormat, result: SharedTypes.DatabaseResult)
+    case syncHistoryLoaded(result: SharedTypes.DatabaseResult)
+    case syncResponse(result: SharedTypes.HttpResult)
+    case shareResponse(result: SharedTypes.ShareResult)
+    case storageResponse(result: SharedTypes.StorageResult)
+    case timerResponse(output: SharedTypes.TimerOutput)
+    case error(error: SharedTypes.AppError)
+    case dismissError(error_id: UInt32)
+    case dismissAllErrors
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        switch self {
+        case .startWorkout:
+            try serializer.serialize_variant_index(value: 0)
+        case .finishWorkout:
+            try serializer.serialize_variant_index(value: 1)
+        case .discardWorkout:
+            try serializer.serialize_variant_index(value: 2)
+        case .updateWorkoutName(let name):
+            try serializer.serialize_variant_index(value: 3)
+            try serializer.serialize_str(value: name)
+        case .updateWorkoutNotes(let notes):
+            try serializer.serialize_variant_index(value: 4)
+            try serializer.serialize_str(value: notes)
+        case .shareWorkoutSummary:
+            try serializer.serialize_variant_index(value: 5)
+        case .copyWorkoutSummary:
+            try serializer.serialize_variant_index(value: 6)
+        case .dismissWorkoutSummary:
+            try serializer.serialize_variant_index(value: 7)
+        case .setPreferredWeightUnit(let unit):
+            try serializer.serialize_variant_index(value: 8)
+            try unit.serialize(serializer: serializer)
+        case .addExercise(let name, let exercise_type, let muscle_group):
+            try serializer.serialize_variant_index(value: 9)
+            try serializer.serialize_str(value: name)
+            try serializer.serialize_str(value: exercise_type)
+            try serializer.serialize_str(value: muscle_group)
+        case .deleteExercise(let exercise_id):
+            try serializer.serialize_variant_index(value: 10)
+            try serializer.serialize_str(value: exercise_id)
+        case .moveExercise(let from_index, let to_index):
+            try serializer.serialize_variant_index(value: 11)
+            try serializer.serialize_u64(value: from_index)
+            try serializer.serialize_u64(value: to_index)
+        case .showAddExerciseView:
+            try serializer.serialize_variant_index(value: 12)
+        case .dismissAddExerciseView:
+            try serializer.serialize_variant_index(value: 13)
+        case .addSet(let exercise_id):
+            try serializer.serialize_variant_index(value: 14)
+            try serializer.serialize_str(value: exercise_id)
+        case .deleteSet(let exercise_id, let set_index):
+            try serializer.serialize_variant_index(value: 15)
+            try serializer.serialize_str(value: exercise_id)
+            try serializer.serialize_u64(value: set_index)
+        case .updateSetActual(let set_id, let actual):
+            try serializer.serialize_variant_index(value: 16)
+            try serializer.serialize_str(value: set_id)
+            try actual.serialize(serializer: serializer)
+        case .toggleSetCompleted(let set_id):
+            try serializer.serialize_variant_index(value: 17)
+            try serializer.serialize_str(value: set_id)
+        case .timerTick:
+            try serializer.serialize_variant_index(value: 18)
+        case .startTimer:
+            try serializer.serialize_variant_index(value: 19)
+        case .stopTimer:
+            try serializer.serialize_variant_index(value: 20)
+        case .toggleTimer:
+            try serializer.serialize_variant_index(value: 21)
+        case .showStopwatch:
+            try serializer.serialize_variant_index(value: 22)
+        case .dismissStopwatch:
+            try serializer.serialize_variant_index(value: 23)
+        case .showRestTimer(let duration_seconds):
+            try serializer.serialize_variant_index(value: 24)
+            try serializer.serialize_i32(value: duration_seconds)
+        case .dismissRestTimer:
+            try serializer.serialize_variant_index(value: 25)
+        case .loadHistory:
+            try serializer.serialize_variant_index(value: 26)
+        case .loadMoreHistory:
+            try serializer.serialize_variant_index(value: 27)
+        case .setHistoryFilter(let filter):
+            try serializer.serialize_variant_index(value: 28)
+            try filter.serialize(serializer: serializer)
+        case .clearHistoryFilter:
+            try serializer.serialize_variant_index(value: 29)
+        case .viewHistoryItem(let workout_id):
+            try serializer.serialize_variant_index(value: 30)
+            try serializer.serialize_str(value: workout_id)
+        case .viewExerciseHistory(let exercise_id):
+            try serializer.serialize_variant_index(value: 31)
+            try serializer.serialize_str(value: exercise_id)
+        case .dismissCorruptWorkouts:
+            try serializer.serialize_variant_index(value: 32)
+        case .navigateBack:
+            try serializer.serialize_variant_index(value: 33)
+        case .changeTab(let tab):
+            try serializer.serialize_variant_index(value: 34)
+            try tab.serialize(serializer: serializer)
+        case .deleteHistoryWorkout(let workout_id):
+            try serializer.serialize_variant_index(value: 35)
+            try serializer.serialize_str(value: workout_id)
+        case .confirmDeleteHistoryWorkout:
+            try serializer.serialize_variant_index(value: 36)
+        case .cancelDeleteHistoryWorkout:
+            try serializer.serialize_variant_index(value: 37)
+        case .editHistoryWorkout(let workout_id):
+            try serializer.serialize_variant_index(value: 38)
+            try serializer.serialize_str(value: workout_id)
+        case .saveHistoryWorkoutEdits:
+            try serializer.serialize_variant_index(value: 39)
+        case .cancelHistoryWorkoutEdits:
+            try serializer.serialize_variant_index(value: 40)
+        case .importWorkout(let json_data):
+            try serializer.serialize_variant_index(value: 41)
+            try serializer.serialize_str(value: json_data)
+        case .previewCsvImport(let csv_data, let options):
+            try serializer.serialize_variant_index(value: 42)
+            try serializer.serialize_str(value: csv_data)
+            try options.serialize(serializer: serializer)
+        case .confirmCsvImport:
+            try serializer.serialize_variant_index(value: 43)
+        case .cancelCsvImport:
+            try serializer.serialize_variant_index(value: 44)
+        case .importWatchFile(let data):
+            try serializer.serialize_variant_index(value: 45)
+            try serialize_vector_u8(value: data, serializer: serializer)
+        case .dismissImportSummary:
+            try serializer.serialize_variant_index(value: 46)
+        case .exportHistory(let format):
+            try serializer.serialize_variant_index(value: 47)
+            try format.serialize(serializer: serializer)
+        case .showImportView:
+            try serializer.serialize_variant_index(value: 48)
+        case .dismissImportView:
+            try serializer.serialize_variant_index(value: 49)
+        case .loadWorkoutTemplate:
+            try serializer.serialize_variant_index(value: 50)
+        case .calculatePlates(let target_weight, let bar_weight, let use_percentage):
+            try serializer.serialize_variant_index(value: 51)
+            try serializer.serialize_f64(value: target_weight)
+            try serializer.serialize_f64(value: bar_weight)
+            try serialize_option_f64(value: use_percentage, serializer: serializer)
+        case .clearPlateCalculation:
+            try serializer.serialize_variant_index(value: 52)
+        case .showPlateCalculator:
+            try serializer.serialize_variant_index(value: 53)
+        case .dismissPlateCalculator:
+            try serializer.serialize_variant_index(value: 54)
+        case .initialize:
+            try serializer.serialize_variant_index(value: 55)
+        case .apiSessionChanged(let base_url, let token):
+            try serializer.serialize_variant_index(value: 56)
+            try serialize_option_str(value: base_url, serializer: serializer)
+            try serialize_option_str(value: token, serializer: serializer)
+        case .syncNow:
+            try serializer.serialize_variant_index(value: 57)
+        case .databaseResponse(let result):
+            try serializer.serialize_variant_index(value: 58)
+            try result.serialize(serializer: serializer)
+        case .exportHistoryLoaded(let format, let result):
+            try serializer.serialize_variant_index(value: 59)
+            try format.serialize(serializer: serializer)
+            try result.serialize(serializer: serializer)
+        case .syncHistoryLoaded(let result):
+            try serializer.serialize_variant_index(value: 60)
+            try result.serialize(serializer: serializer)
+        case .syncResponse(let result):
+            try serializer.serialize_variant_index(value: 61)
+            try result.serialize(serializer: serializer)
+        case .shareResponse(let result):
+            try serializer.serialize_variant_index(value: 62)
+            try result.serialize(serializer: serializer)
+        case .storageResponse(let result):
+            try serializer.serialize_variant_index(value: 63)
+            try result.serialize(serializer: serializer)
+        case .timerResponse(let output):
+            try serializer.serialize_variant_index(value: 64)
+            try output.serialize(serializer: serializer)
+        case .error(let error):
+            try serializer.serialize_variant_index(value: 65)
+            try error.serialize(serializer: serializer)
+        case .dismissError(let error_id):
+            try serializer.serialize_variant_index(value: 66)
+            try serializer.serialize_u32(value: error_id)
+        case .dismissAllErrors:
+            try serializer.serialize_variant_index(value: 67)
+        }
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> Event {
+        let index = try deserializer.deserialize_variant_index()
+        try deserializer.increase_container_depth()
+        switch index {
+        case 0:
+            try deserializer.decrease_container_depth()
+            return .startWorkout
+        case 1:
+            try deserializer.decrease_container_depth()
+            return .finishWorkout
+        case 2:
+            try deserializer.decrease_container_depth()
+            return .discardWorkout
+        case 3:
+            let name = try deserializer.deserialize_str()
+            try deserializer.decrease_container_depth()
+            return .updateWorkoutName(name: name)
+        case 4:
+            let notes = try deserializer.deserialize_str()
+            try deserializer.decrease_container_depth()
+            return .updateWorkoutNotes(notes: notes)
+        case 5:
+            try deserializer.decrease_container_depth()
+            return .shareWorkoutSummary
+        case 6:
+            try deserializer.decrease_container_depth()
+            return .copyWorkoutSummary
+        case 7:
+            try deserializer.decrease_container_depth()
+            return .dismissWorkoutSummary
+        case 8:
+            let unit = try SharedTypes.WeightUnit.deserialize(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .setPreferredWeightUnit(unit: unit)
+        case 9:
+            let name = try deserializer.deserialize_str()
+            let exercise_type = try deserializer.deserialize_str()
+            let muscle_group = try deserializer.deserialize_str()
+            try deserializer.decrease_container_depth()
+            return .addExercise(name: name, exercise_type: exercise_type, muscle_group: muscle_group)
+        case 10:
+            let exercise_id = try deserializer.deserialize_str()
+            try deserializer.decrease_container_depth()
+            return .deleteExercise(exercise_id: exercise_id)
+        case 11:
+            let from_index = try deserializer.deserialize_u64()
+            let to_index = try deserializer.deserialize_u64()
+            try deserializer.decrease_container_depth()
+            return .moveExercise(from_index: from_index, to_index: to_index)
+        case 12:
+            try deserializer.decrease_container_depth()
+            return .showAddExerciseView
+        case 13:
+            try deserializer.decrease_container_depth()
+            return .dismissAddExerciseView
+        case 14:
+            let exercise_id = try deserializer.deserialize_str()
+            try deserializer.decrease_container_depth()
+            return .addSet(exercise_id: exercise_id)
+        case 15:
+            let exercise_id = try deserializer.deserialize_str()
+            let set_index = try deserializer.deserialize_u64()
+            try deserializer.decrease_container_depth()
+            return .deleteSet(exercise_id: exercise_id, set_index: set_index)
+        case 16:
+            let set_id = try deserializer.deserialize_str()
+            let actual = try SharedTypes.SetActual.deserialize(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .updateSetActual(set_id: set_id, actual: actual)
+        case 17:
+            let set_id = try deserializer.deserialize_str()
+            try deserializer.decrease_container_depth()
+            return .toggleSetCompleted(set_id: set_id)
+        case 18:
+            try deserializer.decrease_container_depth()
+            return .timerTick
+        case 19:
+            try deserializer.decrease_container_depth()
+            return .startTimer
+        case 20:
+            try deserializer.decrease_container_depth()
+            return .stopTimer
+        case 21:
+            try deserializer.decrease_container_depth()
+            return .toggleTimer
+        case 22:
+            try deserializer.decrease_container_depth()
+            return .showStopwatch
+        case 23:
+            try deserializer.decrease_container_depth()
+            return .dismissStopwatch
+        case 24:
+            let duration_seconds = try deserializer.deserialize_i32()
+            try deserializer.decrease_container_depth()
+            return .showRestTimer(duration_seconds: duration_seconds)
+        case 25:
+            try deserializer.decrease_container_depth()
+            return .dismissRestTimer
+        case 26:
+            try deserializer.decrease_container_depth()
+            return .loadHistory
+        case 27:
+            try deserializer.decrease_container_depth()
+            return .loadMoreHistory
+        case 28:
+            let filter = try SharedTypes.HistoryFilter.deserialize(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .setHistoryFilter(filter: filter)
+        case 29:
+            try deserializer.decrease_container_depth()
+            return .clearHistoryFilter
+        case 30:
+            let workout_id = try deserializer.deserialize_str()
+            try deserializer.decrease_container_depth()
+            return .viewHistoryItem(workout_id: workout_id)
+        case 31:
+            let exercise_id = try deserializer.deserialize_str()
+            try deserializer.decrease_container_depth()
+            return .viewExerciseHistory(exercise_id: exercise_id)
+        case 32:
+            try deserializer.decrease_container_depth()
+            return .dismissCorruptWorkouts
+        case 33:
+            try deserializer.decrease_container_depth()
+            return .navigateBack
+        case 34:
+            let tab = try SharedTypes.Tab.deserialize(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .changeTab(tab: tab)
+        case 35:
+            let workout_id = try deserializer.deserialize_str()
+            try deserializer.decrease_container_depth()
+            return .deleteHistoryWorkout(workout_id: workout_id)
+        case 36:
+            try deserializer.decrease_container_depth()
+            return .confirmDeleteHistoryWorkout
+        case 37:
+            try deserializer.decrease_container_depth()
+            return .cancelDeleteHistoryWorkout
+        case 38:
+            let workout_id = try deserializer.deserialize_str()
+            try deserializer.decrease_container_depth()
+            return .editHistoryWorkout(workout_id: workout_id)
+        case 39:
+            try deserializer.decrease_container_depth()
+            return .saveHistoryWorkoutEdits
+        case 40:
+            try deserializer.decrease_container_depth()
+            return .cancelHistoryWorkoutEdits
+        case 41:
+            let json_data = try deserializer.deserialize_str()
+            try deserializer.decrease_container_depth()
+            return .importWorkout(json_data: json_data)
+        case 42:
+            let csv_data = try deserializer.deserialize_str()
+            let options = try SharedTypes.CsvImportOptions.deserialize(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .previewCsvImport(csv_data: csv_data, options: options)
+        case 43:
+            try deserializer.decrease_container_depth()
+            return .confirmCsvImport
+        case 44:
+            try deserializer.decrease_container_depth()
+            return .cancelCsvImport
+        case 45:
+            let data = try deserialize_vector_u8(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .importWatchFile(data: data)
+        case 46:
+            try deserializer.decrease_container_depth()
+            return .dismissImportSummary
+        case 47:
+            let format = try SharedTypes.ExportFormat.deserialize(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .exportHistory(format: format)
+        case 48:
+            try deserializer.decrease_container_depth()
+            return .showImportView
+        case 49:
+            try deserializer.decrease_container_depth()
+            return .dismissImportView
+        case 50:
+            try deserializer.decrease_container_depth()
+            return .loadWorkoutTemplate
+        case 51:
+            let target_weight = try deserializer.deserialize_f64()
+            let bar_weight = try deserializer.deserialize_f64()
+            let use_percentage = try deserialize_option_f64(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .calculatePlates(target_weight: target_weight, bar_weight: bar_weight, use_percentage: use_percentage)
+        case 52:
+            try deserializer.decrease_container_depth()
+            return .clearPlateCalculation
+        case 53:
+            try deserializer.decrease_container_depth()
+            return .showPlateCalculator
+        case 54:
+            try deserializer.decrease_container_depth()
+            return .dismissPlateCalculator
+        case 55:
+            try deserializer.decrease_container_depth()
+            return .initialize
+        case 56:
+            let base_url = try deserialize_option_str(deserializer: deserializer)
+            let token = try deserialize_option_str(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .apiSessionChanged(base_url: base_url, token: token)
+        case 57:
+            try deserializer.decrease_container_depth()
+            return .syncNow
+        case 58:
+            let result = try SharedTypes.DatabaseResult.deserialize(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .databaseResponse(result: result)
+        case 59:
+            let format = try SharedTypes.ExportFormat.deserialize(deserializer: deserializer)
+            let result = try SharedTypes.DatabaseResult.deserialize(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .exportHistoryLoaded(format: format, result: result)
+        case 60:
+            let result = try SharedTypes.DatabaseResult.deserialize(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .syncHistoryLoaded(result: result)
+        case 61:
+            let result = try SharedTypes.HttpResult.deserialize(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .syncResponse(result: result)
+        case 62:
+            let result = try SharedTypes.ShareResult.deserialize(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .shareResponse(result: result)
+        case 63:
+            let result = try SharedTypes.StorageResult.deserialize(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .storageResponse(result: result)
+        case 64:
+            let output = try SharedTypes.TimerOutput.deserialize(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .timerResponse(output: output)
+        case 65:
+            let error = try SharedTypes.AppError.deserialize(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .error(error: error)
+        case 66:
+            let error_id = try deserializer.deserialize_u32()
+            try deserializer.decrease_container_depth()
+            return .dismissError(error_id: error_id)
+        case 67:
+            try deserializer.decrease_container_depth()
+            return .dismissAllErrors
+        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for Event: \(index)")
         }
     }
 
@@ -515,22 +1532,1646 @@ indirect public enum Event: Hashable {
     }
 }
 
-public struct ExerciseViewModel: Hashable {
+public struct Exercise: Hashable {
+    @Indirect public var id: String
+    @Indirect public var superset_id: Int32?
+    @Indirect public var workout_id: String
+    @Indirect public var name: String
+    @Indirect public var pinned_notes: [String]
+    @Indirect public var notes: [String]
+    @Indirect public var duration: Int32?
+    @Indirect public var type: SharedTypes.ExerciseType
+    @Indirect public var weight_unit: SharedTypes.WeightUnit?
+    @Indirect public var default_warm_up_time: Int32?
+    @Indirect public var default_rest_time: Int32?
+    @Indirect public var sets: [SharedTypes.ExerciseSet]
+    @Indirect public var body_part: SharedTypes.BodyPart?
+
+    public init(id: String, superset_id: Int32?, workout_id: String, name: String, pinned_notes: [String], notes: [String], duration: Int32?, type: SharedTypes.ExerciseType, weight_unit: SharedTypes.WeightUnit?, default_warm_up_time: Int32?, default_rest_time: Int32?, sets: [SharedTypes.ExerciseSet], body_part: SharedTypes.BodyPart?) {
+        self.id = id
+        self.superset_id = superset_id
+        self.workout_id = workout_id
+        self.name = name
+        self.pinned_notes = pinned_notes
+        self.notes = notes
+        self.duration = duration
+        self.type = type
+        self.weight_unit = weight_unit
+        self.default_warm_up_time = default_warm_up_time
+        self.default_rest_time = default_rest_time
+        self.sets = sets
+        self.body_part = body_part
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_str(value: self.id)
+        try serialize_option_i32(value: self.superset_id, serializer: serializer)
+        try serializer.serialize_str(value: self.workout_id)
+        try serializer.serialize_str(value: self.name)
+        try serialize_vector_str(value: self.pinned_notes, serializer: serializer)
+        try serialize_vector_str(value: self.notes, serializer: serializer)
+        try serialize_option_i32(value: self.duration, serializer: serializer)
+        try self.type.serialize(serializer: serializer)
+        try serialize_option_WeightUnit(value: self.weight_unit, serializer: serializer)
+        try serialize_option_i32(value: self.default_warm_up_time, serializer: serializer)
+        try serialize_option_i32(value: self.default_rest_time, serializer: serializer)
+        try serialize_vector_ExerciseSet(value: self.sets, serializer: serializer)
+        try serialize_option_BodyPart(value: self.body_part, serializer: serializer)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> Exercise {
+        try deserializer.increase_container_depth()
+        let id = try deserializer.deserialize_str()
+        let superset_id = try deserialize_option_i32(deserializer: deserializer)
+        let workout_id = try deserializer.deserialize_str()
+        let name = try deserializer.deserialize_str()
+        let pinned_notes = try deserialize_vector_str(deserializer: deserializer)
+        let notes = try deserialize_vector_str(deserializer: deserializer)
+        let duration = try deserialize_option_i32(deserializer: deserializer)
+        let type = try SharedTypes.ExerciseType.deserialize(deserializer: deserializer)
+        let weight_unit = try deserialize_option_WeightUnit(deserializer: deserializer)
+        let default_warm_up_time = try deserialize_option_i32(deserializer: deserializer)
+        let default_rest_time = try deserialize_option_i32(deserializer: deserializer)
+        let sets = try deserialize_vector_ExerciseSet(deserializer: deserializer)
+        let body_part = try deserialize_option_BodyPart(deserializer: deserializer)
+        try deserializer.decrease_container_depth()
+        return Exercise.init(id: id, superset_id: superset_id, workout_id: workout_id, name: name, pinned_notes: pinned_notes, notes: notes, duration: duration, type: type, weight_unit: weight_unit, default_warm_up_time: default_warm_up_time, default_rest_time: default_rest_time, sets: sets, body_part: body_part)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> Exercise {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct ExerciseDetailViewModel: Hashable {
+    @Indirect public var name: String
+    @Indirect public var sets: [SharedTypes.SetDetailViewModel]
+
+    public init(name: String, sets: [SharedTypes.SetDetailViewModel]) {
+        self.name = name
+        self.sets = sets
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_str(value: self.name)
+        try serialize_vector_SetDetailViewModel(value: self.sets, serializer: serializer)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> ExerciseDetailViewModel {
+        try deserializer.increase_container_depth()
+        let name = try deserializer.deserialize_str()
+        let sets = try deserialize_vector_SetDetailViewModel(deserializer: deserializer)
+        try deserializer.decrease_container_depth()
+        return ExerciseDetailViewModel.init(name: name, sets: sets)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> ExerciseDetailViewModel {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct ExerciseHistoryViewModel: Hashable {
+    @Indirect public var exercise_name: String
+    @Indirect public var sessions: [SharedTypes.ExerciseSessionViewModel]
+    @Indirect public var best_estimated_one_rep_max: Double?
+    @Indirect public var estimated_one_rep_max_series: [SharedTypes.ChartPointViewModel]
+    @Indirect public var volume_series: [SharedTypes.ChartPointViewModel]
+    @Indirect public var max_weight_series: [SharedTypes.ChartPointViewModel]
+
+    public init(exercise_name: String, sessions: [SharedTypes.ExerciseSessionViewModel], best_estimated_one_rep_max: Double?, estimated_one_rep_max_series: [SharedTypes.ChartPointViewModel], volume_series: [SharedTypes.ChartPointViewModel], max_weight_series: [SharedTypes.ChartPointViewModel]) {
+        self.exercise_name = exercise_name
+        self.sessions = sessions
+        self.best_estimated_one_rep_max = best_estimated_one_rep_max
+        self.estimated_one_rep_max_series = estimated_one_rep_max_series
+        self.volume_series = volume_series
+        self.max_weight_series = max_weight_series
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_str(value: self.exercise_name)
+        try serialize_vector_ExerciseSessionViewModel(value: self.sessions, serializer: serializer)
+        try serialize_option_f64(value: self.best_estimated_one_rep_max, serializer: serializer)
+        try serialize_vector_ChartPointViewModel(value: self.estimated_one_rep_max_series, serializer: serializer)
+        try serialize_vector_ChartPointViewModel(value: self.volume_series, serializer: serializer)
+        try serialize_vector_ChartPointViewModel(value: self.max_weight_series, serializer: serializer)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> ExerciseHistoryViewModel {
+        try deserializer.increase_container_depth()
+        let exercise_name = try deserializer.deserialize_str()
+        let sessions = try deserialize_vector_ExerciseSessionViewModel(deserializer: deserializer)
+        let best_estimated_one_rep_max = try deserialize_option_f64(deserializer: deserializer)
+        let estimated_one_rep_max_series = try deserialize_vector_ChartPointViewModel(deserializer: deserializer)
+        let volume_series = try deserialize_vector_ChartPointViewModel(deserializer: deserializer)
+        let max_weight_series = try deserialize_vector_ChartPointViewModel(deserializer: deserializer)
+        try deserializer.decrease_container_depth()
+        return ExerciseHistoryViewModel.init(exercise_name: exercise_name, sessions: sessions, best_estimated_one_rep_max: best_estimated_one_rep_max, estimated_one_rep_max_series: estimated_one_rep_max_series, volume_series: volume_series, max_weight_series: max_weight_series)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> ExerciseHistoryViewModel {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct ExerciseSessionViewModel: Hashable {
+    @Indirect public var workout_id: String
+    @Indirect public var workout_name: String
+    @Indirect public var date: String
+    @Indirect public var best_set: String?
+    @Indirect public var estimated_one_rep_max: Double?
+    @Indirect public var total_volume: Int32
+    @Indirect public var sets: [SharedTypes.SetDetailViewModel]
+
+    public init(workout_id: String, workout_name: String, date: String, best_set: String?, estimated_one_rep_max: Double?, total_volume: Int32, sets: [SharedTypes.SetDetailViewModel]) {
+        self.workout_id = workout_id
+        self.workout_name = workout_name
+        self.date = date
+        self.best_set = best_set
+        self.estimated_one_rep_max = estimated_one_rep_max
+        self.total_volume = total_volume
+        self.sets = sets
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_str(value: self.workout_id)
+        try serializer.serialize_str(value: self.workout_name)
+        try serializer.serialize_str(value: self.date)
+        try serialize_option_str(value: self.best_set, serializer: serializer)
+        try serialize_option_f64(value: self.estimated_one_rep_max, serializer: serializer)
+        try serializer.serialize_i32(value: self.total_volume)
+        try serialize_vector_SetDetailViewModel(value: self.sets, serializer: serializer)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> ExerciseSessionViewModel {
+        try deserializer.increase_container_depth()
+        let workout_id = try deserializer.deserialize_str()
+        let workout_name = try deserializer.deserialize_str()
+        let date = try deserializer.deserialize_str()
+        let best_set = try deserialize_option_str(deserializer: deserializer)
+        let estimated_one_rep_max = try deserialize_option_f64(deserializer: deserializer)
+        let total_volume = try deserializer.deserialize_i32()
+        let sets = try deserialize_vector_SetDetailViewModel(deserializer: deserializer)
+        try deserializer.decrease_container_depth()
+        return ExerciseSessionViewModel.init(workout_id: workout_id, workout_name: workout_name, date: date, best_set: best_set, estimated_one_rep_max: estimated_one_rep_max, total_volume: total_volume, sets: sets)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> ExerciseSessionViewModel {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct ExerciseSet: Hashable {
+    @Indirect public var id: String
+    @Indirect public var type: SharedTypes.SetType
+    @Indirect public var weight_unit: SharedTypes.WeightUnit?
+    @Indirect public var suggest: SharedTypes.SetSuggest
+    @Indirect public var actual: SharedTypes.SetActual
+    @Indirect public var is_completed: Bool
+    @Indirect public var exercise_id: String
+    @Indirect public var workout_id: String
+    @Indirect public var set_index: Int32
+
+    public init(id: String, type: SharedTypes.SetType, weight_unit: SharedTypes.WeightUnit?, suggest: SharedTypes.SetSuggest, actual: SharedTypes.SetActual, is_completed: Bool, exercise_id: String, workout_id: String, set_index: Int32) {
+        self.id = id
+        self.type = type
+        self.weight_unit = weight_unit
+        self.suggest = suggest
+        self.actual = actual
+        self.is_completed = is_completed
+        self.exercise_id = exercise_id
+        self.workout_id = workout_id
+        self.set_index = set_index
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_str(value: self.id)
+        try self.type.serialize(serializer: serializer)
+        try serialize_option_WeightUnit(value: self.weight_unit, serializer: serializer)
+        try self.suggest.serialize(serializer: serializer)
+        try self.actual.serialize(serializer: serializer)
+        try serializer.serialize_bool(value: self.is_completed)
+        try serializer.serialize_str(value: self.exercise_id)
+        try serializer.serialize_str(value: self.workout_id)
+        try serializer.serialize_i32(value: self.set_index)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> ExerciseSet {
+        try deserializer.increase_container_depth()
+        let id = try deserializer.deserialize_str()
+        let type = try SharedTypes.SetType.deserialize(deserializer: deserializer)
+        let weight_unit = try deserialize_option_WeightUnit(deserializer: deserializer)
+        let suggest = try SharedTypes.SetSuggest.deserialize(deserializer: deserializer)
+        let actual = try SharedTypes.SetActual.deserialize(deserializer: deserializer)
+        let is_completed = try deserializer.deserialize_bool()
+        let exercise_id = try deserializer.deserialize_str()
+        let workout_id = try deserializer.deserialize_str()
+        let set_index = try deserializer.deserialize_i32()
+        try deserializer.decrease_container_depth()
+        return ExerciseSet.init(id: id, type: type, weight_unit: weight_unit, suggest: suggest, actual: actual, is_completed: is_completed, exercise_id: exercise_id, workout_id: workout_id, set_index: set_index)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> ExerciseSet {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+indirect public enum ExerciseType: Hashable {
+    case dumbbell
+    case kettlebell
+    case barbell
+    case hexbar
+    case bodyweight
+    case machine
+    case unknown
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        switch self {
+        case .dumbbell:
+            try serializer.serialize_variant_index(value: 0)
+        case .kettlebell:
+            try serializer.serialize_variant_index(value: 1)
+        case .barbell:
+            try serializer.serialize_variant_index(value: 2)
+        case .hexbar:
+            try serializer.serialize_variant_index(value: 3)
+        case .bodyweight:
+            try serializer.serialize_variant_index(value: 4)
+        case .machine:
+            try serializer.serialize_variant_index(value: 5)
+        case .unknown:
+            try serializer.serialize_variant_index(value: 6)
+        }
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> ExerciseType {
+        let index = try deserializer.deserialize_variant_index()
+        try deserializer.increase_container_depth()
+        switch index {
+        case 0:
+            try deserializer.decrease_container_depth()
+            return .dumbbell
+        case 1:
+            try deserializer.decrease_container_depth()
+            return .kettlebell
+        case 2:
+            try deserializer.decrease_container_depth()
+            return .barbell
+        case 3:
+            try deserializer.decrease_container_depth()
+            return .hexbar
+        case 4:
+            try deserializer.decrease_container_depth()
+            return .bodyweight
+        case 5:
+            try deserializer.decrease_container_depth()
+            return .machine
+        case 6:
+            try deserializer.decrease_container_depth()
+            return .unknown
+        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for ExerciseType: \(index)")
+        }
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> ExerciseType {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct ExerciseViewModel: Hashable {
+    @Indirect public var id: String
+    @Indirect public var name: String
+    @Indirect public var sets: [SharedTypes.SetViewModel]
+    @Indirect public var has_history: Bool
+
+    public init(id: String, name: String, sets: [SharedTypes.SetViewModel], has_history: Bool) {
+        self.id = id
+        self.name = name
+        self.sets = sets
+        self.has_history = has_history
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_str(value: self.id)
+        try serializer.serialize_str(value: self.name)
+        try serialize_vector_SetViewModel(value: self.sets, serializer: serializer)
+        try serializer.serialize_bool(value: self.has_history)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> ExerciseViewModel {
+        try deserializer.increase_container_depth()
+        let id = try deserializer.deserialize_str()
+        let name = try deserializer.deserialize_str()
+        let sets = try deserialize_vector_SetViewModel(deserializer: deserializer)
+        let has_history = try deserializer.deserialize_bool()
+        try deserializer.decrease_container_depth()
+        return ExerciseViewModel.init(id: id, name: name, sets: sets, has_history: has_history)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> ExerciseViewModel {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+indirect public enum ExportFormat: Hashable {
+    case jsonArchive
+    case csv
+    case markdown
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        switch self {
+        case .jsonArchive:
+            try serializer.serialize_variant_index(value: 0)
+        case .csv:
+            try serializer.serialize_variant_index(value: 1)
+        case .markdown:
+            try serializer.serialize_variant_index(value: 2)
+        }
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> ExportFormat {
+        let index = try deserializer.deserialize_variant_index()
+        try deserializer.increase_container_depth()
+        switch index {
+        case 0:
+            try deserializer.decrease_container_depth()
+            return .jsonArchive
+        case 1:
+            try deserializer.decrease_container_depth()
+            return .csv
+        case 2:
+            try deserializer.decrease_container_depth()
+            return .markdown
+        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for ExportFormat: \(index)")
+        }
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> ExportFormat {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct HistoryCursor: Hashable {
+    @Indirect public var start_timestamp: SharedTypes.Timestamp
+    @Indirect public var workout_id: String
+
+    public init(start_timestamp: SharedTypes.Timestamp, workout_id: String) {
+        self.start_timestamp = start_timestamp
+        self.workout_id = workout_id
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try self.start_timestamp.serialize(serializer: serializer)
+        try serializer.serialize_str(value: self.workout_id)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> HistoryCursor {
+        try deserializer.increase_container_depth()
+        let start_timestamp = try SharedTypes.Timestamp.deserialize(deserializer: deserializer)
+        let workout_id = try deserializer.deserialize_str()
+        try deserializer.decrease_container_depth()
+        return HistoryCursor.init(start_timestamp: start_timestamp, workout_id: workout_id)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> HistoryCursor {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct HistoryDetailViewModel: Hashable {
+    @Indirect public var id: String
+    @Indirect public var workout_name: String
+    @Indirect public var formatted_date: String
+    @Indirect public var duration: String?
+    @Indirect public var exercises: [SharedTypes.ExerciseDetailViewModel]
+    @Indirect public var notes: String?
+    @Indirect public var total_volume: Int32
+    @Indirect public var total_sets: UInt64
+
+    public init(id: String, workout_name: String, formatted_date: String, duration: String?, exercises: [SharedTypes.ExerciseDetailViewModel], notes: String?, total_volume: Int32, total_sets: UInt64) {
+        self.id = id
+        self.workout_name = workout_name
+        self.formatted_date = formatted_date
+        self.duration = duration
+        self.exercises = exercises
+        self.notes = notes
+        self.total_volume = total_volume
+        self.total_sets = total_sets
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_str(value: self.id)
+        try serializer.serialize_str(value: self.workout_name)
+        try serializer.serialize_str(value: self.formatted_date)
+        try serialize_option_str(value: self.duration, serializer: serializer)
+        try serialize_vector_ExerciseDetailViewModel(value: self.exercises, serializer: serializer)
+        try serialize_option_str(value: self.notes, serializer: serializer)
+        try serializer.serialize_i32(value: self.total_volume)
+        try serializer.serialize_u64(value: self.total_sets)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> HistoryDetailViewModel {
+        try deserializer.increase_container_depth()
+        let id = try deserializer.deserialize_str()
+        let workout_name = try deserializer.deserialize_str()
+        let formatted_date = try deserializer.deserialize_str()
+        let duration = try deserialize_option_str(deserializer: deserializer)
+        let exercises = try deserialize_vector_ExerciseDetailViewModel(deserializer: deserializer)
+        let notes = try deserialize_option_str(deserializer: deserializer)
+        let total_volume = try deserializer.deserialize_i32()
+        let total_sets = try deserializer.deserialize_u64()
+        try deserializer.decrease_container_depth()
+        return HistoryDetailViewModel.init(id: id, workout_name: workout_name, formatted_date: formatted_date, duration: duration, exercises: exercises, notes: notes, total_volume: total_volume, total_sets: total_sets)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> HistoryDetailViewModel {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct HistoryEditViewModel: Hashable {
+    @Indirect public var id: String
+    @Indirect public var workout_name: String
+    @Indirect public var notes: String?
+    @Indirect public var exercises: [SharedTypes.ExerciseViewModel]
+    @Indirect public var total_volume: Int32
+    @Indirect public var total_sets: UInt64
+
+    public init(id: String, workout_name: String, notes: String?, exercises: [SharedTypes.ExerciseViewModel], total_volume: Int32, total_sets: UInt64) {
+        self.id = id
+        self.workout_name = workout_name
+        self.notes = notes
+        self.exercises = exercises
+        self.total_volume = total_volume
+        self.total_sets = total_sets
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_str(value: self.id)
+        try serializer.serialize_str(value: self.workout_name)
+        try serialize_option_str(value: self.notes, serializer: serializer)
+        try serialize_vector_ExerciseViewModel(value: self.exercises, serializer: serializer)
+        try serializer.serialize_i32(value: self.total_volume)
+        try serializer.serialize_u64(value: self.total_sets)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> HistoryEditViewModel {
+        try deserializer.increase_container_depth()
+        let id = try deserializer.deserialize_str()
+        let workout_name = try deserializer.deserialize_str()
+        let notes = try deserialize_option_str(deserializer: deserializer)
+        let exercises = try deserialize_vector_ExerciseViewModel(deserializer: deserializer)
+        let total_volume = try deserializer.deserialize_i32()
+        let total_sets = try deserializer.deserialize_u64()
+        try deserializer.decrease_container_depth()
+        return HistoryEditViewModel.init(id: id, workout_name: workout_name, notes: notes, exercises: exercises, total_volume: total_volume, total_sets: total_sets)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> HistoryEditViewModel {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct HistoryFilter: Hashable {
+    @Indirect public var from_timestamp: SharedTypes.Timestamp?
+    @Indirect public var to_timestamp: SharedTypes.Timestamp?
+    @Indirect public var exercise_name: String?
+    @Indirect public var name_search: String?
+
+    public init(from_timestamp: SharedTypes.Timestamp?, to_timestamp: SharedTypes.Timestamp?, exercise_name: String?, name_search: String?) {
+        self.from_timestamp = from_timestamp
+        self.to_timestamp = to_timestamp
+        self.exercise_name = exercise_name
+        self.name_search = name_search
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serialize_option_Timestamp(value: self.from_timestamp, serializer: serializer)
+        try serialize_option_Timestamp(value: self.to_timestamp, serializer: serializer)
+        try serialize_option_str(value: self.exercise_name, serializer: serializer)
+        try serialize_option_str(value: self.name_search, serializer: serializer)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> HistoryFilter {
+        try deserializer.increase_container_depth()
+        let from_timestamp = try deserialize_option_Timestamp(deserializer: deserializer)
+        let to_timestamp = try deserialize_option_Timestamp(deserializer: deserializer)
+        let exercise_name = try deserialize_option_str(deserializer: deserializer)
+        let name_search = try deserialize_option_str(deserializer: deserializer)
+        try deserializer.decrease_container_depth()
+        return HistoryFilter.init(from_timestamp: from_timestamp, to_timestamp: to_timestamp, exercise_name: exercise_name, name_search: name_search)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> HistoryFilter {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct HistoryItemViewModel: Hashable {
+    @Indirect public var id: String
+    @Indirect public var name: String
+    @Indirect public var date: String
+    @Indirect public var exercise_count: UInt64
+    @Indirect public var set_count: UInt64
+    @Indirect public var total_volume: Int32
+
+    public init(id: String, name: String, date: String, exercise_count: UInt64, set_count: UInt64, total_volume: Int32) {
+        self.id = id
+        self.name = name
+        self.date = date
+        self.exercise_count = exercise_count
+        self.set_count = set_count
+        self.total_volume = total_volume
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_str(value: self.id)
+        try serializer.serialize_str(value: self.name)
+        try serializer.serialize_str(value: self.date)
+        try serializer.serialize_u64(value: self.exercise_count)
+        try serializer.serialize_u64(value: self.set_count)
+        try serializer.serialize_i32(value: self.total_volume)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> HistoryItemViewModel {
+        try deserializer.increase_container_depth()
+        let id = try deserializer.deserialize_str()
+        let name = try deserializer.deserialize_str()
+        let date = try deserializer.deserialize_str()
+        let exercise_count = try deserializer.deserialize_u64()
+        let set_count = try deserializer.deserialize_u64()
+        let total_volume = try deserializer.deserialize_i32()
+        try deserializer.decrease_container_depth()
+        return HistoryItemViewModel.init(id: id, name: name, date: date, exercise_count: exercise_count, set_count: set_count, total_volume: total_volume)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> HistoryItemViewModel {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct HistoryQuery: Hashable {
+    @Indirect public var cursor: SharedTypes.HistoryCursor?
+    @Indirect public var limit: UInt32
+    @Indirect public var filter: SharedTypes.HistoryFilter
+
+    public init(cursor: SharedTypes.HistoryCursor?, limit: UInt32, filter: SharedTypes.HistoryFilter) {
+        self.cursor = cursor
+        self.limit = limit
+        self.filter = filter
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serialize_option_HistoryCursor(value: self.cursor, serializer: serializer)
+        try serializer.serialize_u32(value: self.limit)
+        try self.filter.serialize(serializer: serializer)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> HistoryQuery {
+        try deserializer.increase_container_depth()
+        let cursor = try deserialize_option_HistoryCursor(deserializer: deserializer)
+        let limit = try deserializer.deserialize_u32()
+        let filter = try SharedTypes.HistoryFilter.deserialize(deserializer: deserializer)
+        try deserializer.decrease_container_depth()
+        return HistoryQuery.init(cursor: cursor, limit: limit, filter: filter)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> HistoryQuery {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct HistoryViewModel: Hashable {
+    @Indirect public var workouts: [SharedTypes.HistoryItemViewModel]
+    @Indirect public var is_loading: Bool
+    @Indirect public var delete_confirmation: SharedTypes.DeleteConfirmationViewModel?
+    @Indirect public var load_problems: SharedTypes.LoadProblemsViewModel?
+    @Indirect public var has_more: Bool
+    @Indirect public var active_filter: SharedTypes.HistoryFilter
+    @Indirect public var is_filtered: Bool
+
+    public init(workouts: [SharedTypes.HistoryItemViewModel], is_loading: Bool, delete_confirmation: SharedTypes.DeleteConfirmationViewModel?, load_problems: SharedTypes.LoadProblemsViewModel?, has_more: Bool, active_filter: SharedTypes.HistoryFilter, is_filtered: Bool) {
+        self.workouts = workouts
+        self.is_loading = is_loading
+        self.delete_confirmation = delete_confirmation
+        self.load_problems = load_problems
+        self.has_more = has_more
+        self.active_filter = active_filter
+        self.is_filtered = is_filtered
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serialize_vector_HistoryItemViewModel(value: self.workouts, serializer: serializer)
+        try serializer.serialize_bool(value: self.is_loading)
+        try serialize_option_DeleteConfirmationViewModel(value: self.delete_confirmation, serializer: serializer)
+        try serialize_option_LoadProblemsViewModel(value: self.load_problems, serializer: serializer)
+        try serializer.serialize_bool(value: self.has_more)
+        try self.active_filter.serialize(serializer: serializer)
+        try serializer.serialize_bool(value: self.is_filtered)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> HistoryViewModel {
+        try deserializer.increase_container_depth()
+        let workouts = try deserialize_vector_HistoryItemViewModel(deserializer: deserializer)
+        let is_loading = try deserializer.deserialize_bool()
+        let delete_confirmation = try deserialize_option_DeleteConfirmationViewModel(deserializer: deserializer)
+        let load_problems = try deserialize_option_LoadProblemsViewModel(deserializer: deserializer)
+        let has_more = try deserializer.deserialize_bool()
+        let active_filter = try SharedTypes.HistoryFilter.deserialize(deserializer: deserializer)
+        let is_filtered = try deserializer.deserialize_bool()
+        try deserializer.decrease_container_depth()
+        return HistoryViewModel.init(workouts: workouts, is_loading: is_loading, delete_confirmation: delete_confirmation, load_problems: load_problems, has_more: has_more, active_filter: active_filter, is_filtered: is_filtered)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> HistoryViewModel {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct HttpHeader: Hashable {
+    @Indirect public var name: String
+    @Indirect public var value: String
+
+    public init(name: String, value: String) {
+        self.name = name
+        self.value = value
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_str(value: self.name)
+        try serializer.serialize_str(value: self.value)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> HttpHeader {
+        try deserializer.increase_container_depth()
+        let name = try deserializer.deserialize_str()
+        let value = try deserializer.deserialize_str()
+        try deserializer.decrease_container_depth()
+        return HttpHeader.init(name: name, value: value)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> HttpHeader {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+indirect public enum HttpMethod: Hashable {
+    case get
+    case post
+    case put
+    case patch
+    case delete
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        switch self {
+        case .get:
+            try serializer.serialize_variant_index(value: 0)
+        case .post:
+            try serializer.serialize_variant_index(value: 1)
+        case .put:
+            try serializer.serialize_variant_index(value: 2)
+        case .patch:
+            try serializer.serialize_variant_index(value: 3)
+        case .delete:
+            try serializer.serialize_variant_index(value: 4)
+        }
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> HttpMethod {
+        let index = try deserializer.deserialize_variant_index()
+        try deserializer.increase_container_depth()
+        switch index {
+        case 0:
+            try deserializer.decrease_container_depth()
+            return .get
+        case 1:
+            try deserializer.decrease_container_depth()
+            return .post
+        case 2:
+            try deserializer.decrease_container_depth()
+            return .put
+        case 3:
+            try deserializer.decrease_container_depth()
+            return .patch
+        case 4:
+            try deserializer.decrease_container_depth()
+            return .delete
+        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for HttpMethod: \(index)")
+        }
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> HttpMethod {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+indirect public enum HttpOperation: Hashable {
+    case send(SharedTypes.HttpRequest)
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        switch self {
+        case .send(let x):
+            try serializer.serialize_variant_index(value: 0)
+            try x.serialize(serializer: serializer)
+        }
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> HttpOperation {
+        let index = try deserializer.deserialize_variant_index()
+        try deserializer.increase_container_depth()
+        switch index {
+        case 0:
+            let x = try SharedTypes.HttpRequest.deserialize(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .send(x)
+        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for HttpOperation: \(index)")
+        }
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> HttpOperation {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct HttpRequest: Hashable {
+    @Indirect public var method: SharedTypes.HttpMethod
+    @Indirect public var url: String
+    @Indirect public var headers: [SharedTypes.HttpHeader]
+    @Indirect public var body: String?
+    @Indirect public var timeout_ms: UInt32
+    @Indirect public var delay_ms: UInt32
+
+    public init(method: SharedTypes.HttpMethod, url: String, headers: [SharedTypes.HttpHeader], body: String?, timeout_ms: UInt32, delay_ms: UInt32) {
+        self.method = method
+        self.url = url
+        self.headers = headers
+        self.body = body
+        self.timeout_ms = timeout_ms
+        self.delay_ms = delay_ms
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try self.method.serialize(serializer: serializer)
+        try serializer.serialize_str(value: self.url)
+        try serialize_vector_HttpHeader(value: self.headers, serializer: serializer)
+        try serialize_option_str(value: self.body, serializer: serializer)
+        try serializer.serialize_u32(value: self.timeout_ms)
+        try serializer.serialize_u32(value: self.delay_ms)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> HttpRequest {
+        try deserializer.increase_container_depth()
+        let method = try SharedTypes.HttpMethod.deserialize(deserializer: deserializer)
+        let url = try deserializer.deserialize_str()
+        let headers = try deserialize_vector_HttpHeader(deserializer: deserializer)
+        let body = try deserialize_option_str(deserializer: deserializer)
+        let timeout_ms = try deserializer.deserialize_u32()
+        let delay_ms = try deserializer.deserialize_u32()
+        try deserializer.decrease_container_depth()
+        return HttpRequest.init(method: method, url: url, headers: headers, body: body, timeout_ms: timeout_ms, delay_ms: delay_ms)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> HttpRequest {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct HttpResponse: Hashable {
+    @Indirect public var status: UInt16
+    @Indirect public var headers: [SharedTypes.HttpHeader]
+    @Indirect public var body: String
+
+    public init(status: UInt16, headers: [SharedTypes.HttpHeader], body: String) {
+        self.status = status
+        self.headers = headers
+        self.body = body
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_u16(value: self.status)
+        try serialize_vector_HttpHeader(value: self.headers, serializer: serializer)
+        try serializer.serialize_str(value: self.body)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> HttpResponse {
+        try deserializer.increase_container_depth()
+        let status = try deserializer.deserialize_u16()
+        let headers = try deserialize_vector_HttpHeader(deserializer: deserializer)
+        let body = try deserializer.deserialize_str()
+        try deserializer.decrease_container_depth()
+        return HttpResponse.init(status: status, headers: headers, body: body)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> HttpResponse {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+indirect public enum HttpResult: Hashable {
+    case response(SharedTypes.HttpResponse)
+    case timedOut
+    case failed(message: String)
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        switch self {
+        case .response(let x):
+            try serializer.serialize_variant_index(value: 0)
+            try x.serialize(serializer: serializer)
+        case .timedOut:
+            try serializer.serialize_variant_index(value: 1)
+        case .failed(let message):
+            try serializer.serialize_variant_index(value: 2)
+            try serializer.serialize_str(value: message)
+        }
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> HttpResult {
+        let index = try deserializer.deserialize_variant_index()
+        try deserializer.increase_container_depth()
+        switch index {
+        case 0:
+            let x = try SharedTypes.HttpResponse.deserialize(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .response(x)
+        case 1:
+            try deserializer.decrease_container_depth()
+            return .timedOut
+        case 2:
+            let message = try deserializer.deserialize_str()
+            try deserializer.decrease_container_depth()
+            return .failed(message: message)
+        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for HttpResult: \(index)")
+        }
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> HttpResult {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct ImportEntry: Hashable {
+    @Indirect public var workout_id: String
+    @Indirect public var name: String
+
+    public init(workout_id: String, name: String) {
+        self.workout_id = workout_id
+        self.name = name
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_str(value: self.workout_id)
+        try serializer.serialize_str(value: self.name)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> ImportEntry {
+        try deserializer.increase_container_depth()
+        let workout_id = try deserializer.deserialize_str()
+        let name = try deserializer.deserialize_str()
+        try deserializer.decrease_container_depth()
+        return ImportEntry.init(workout_id: workout_id, name: name)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> ImportEntry {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct ImportSummary: Hashable {
+    @Indirect public var inserted: [SharedTypes.ImportEntry]
+    @Indirect public var skipped: [SharedTypes.ImportEntry]
+    @Indirect public var conflicting: [SharedTypes.ImportEntry]
+
+    public init(inserted: [SharedTypes.ImportEntry], skipped: [SharedTypes.ImportEntry], conflicting: [SharedTypes.ImportEntry]) {
+        self.inserted = inserted
+        self.skipped = skipped
+        self.conflicting = conflicting
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serialize_vector_ImportEntry(value: self.inserted, serializer: serializer)
+        try serialize_vector_ImportEntry(value: self.skipped, serializer: serializer)
+        try serialize_vector_ImportEntry(value: self.conflicting, serializer: serializer)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> ImportSummary {
+        try deserializer.increase_container_depth()
+        let inserted = try deserialize_vector_ImportEntry(deserializer: deserializer)
+        let skipped = try deserialize_vector_ImportEntry(deserializer: deserializer)
+        let conflicting = try deserialize_vector_ImportEntry(deserializer: deserializer)
+        try deserializer.decrease_container_depth()
+        return ImportSummary.init(inserted: inserted, skipped: skipped, conflicting: conflicting)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> ImportSummary {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct ImportSummaryViewModel: Hashable {
+    @Indirect public var message: String
+    @Indirect public var inserted_count: UInt64
+    @Indirect public var skipped_count: UInt64
+    @Indirect public var conflicting_count: UInt64
+    @Indirect public var conflicts: [String]
+
+    public init(message: String, inserted_count: UInt64, skipped_count: UInt64, conflicting_count: UInt64, conflicts: [String]) {
+        self.message = message
+        self.inserted_count = inserted_count
+        self.skipped_count = skipped_count
+        self.conflicting_count = conflicting_count
+        self.conflicts = conflicts
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_str(value: self.message)
+        try serializer.serialize_u64(value: self.inserted_count)
+        try serializer.serialize_u64(value: self.skipped_count)
+        try serializer.serialize_u64(value: self.conflicting_count)
+        try serialize_vector_str(value: self.conflicts, serializer: serializer)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> ImportSummaryViewModel {
+        try deserializer.increase_container_depth()
+        let message = try deserializer.deserialize_str()
+        let inserted_count = try deserializer.deserialize_u64()
+        let skipped_count = try deserializer.deserialize_u64()
+        let conflicting_count = try deserializer.deserialize_u64()
+        let conflicts = try deserialize_vector_str(deserializer: deserializer)
+        try deserializer.decrease_container_depth()
+        return ImportSummaryViewModel.init(message: message, inserted_count: inserted_count, skipped_count: skipped_count, conflicting_count: conflicting_count, conflicts: conflicts)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> ImportSummaryViewModel {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct LoadProblemsViewModel: Hashable {
+    @Indirect public var message: String
+    @Indirect public var workouts: [SharedTypes.CorruptWorkoutViewModel]
+    @Indirect public var export_payload: String
+
+    public init(message: String, workouts: [SharedTypes.CorruptWorkoutViewModel], export_payload: String) {
+        self.message = message
+        self.workouts = workouts
+        self.export_payload = export_payload
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_str(value: self.message)
+        try serialize_vector_CorruptWorkoutViewModel(value: self.workouts, serializer: serializer)
+        try serializer.serialize_str(value: self.export_payload)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> LoadProblemsViewModel {
+        try deserializer.increase_container_depth()
+        let message = try deserializer.deserialize_str()
+        let workouts = try deserialize_vector_CorruptWorkoutViewModel(deserializer: deserializer)
+        let export_payload = try deserializer.deserialize_str()
+        try deserializer.decrease_container_depth()
+        return LoadProblemsViewModel.init(message: message, workouts: workouts, export_payload: export_payload)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> LoadProblemsViewModel {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct RenderOperation: Hashable {
+
+    public init() {
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> RenderOperation {
+        try deserializer.increase_container_depth()
+        try deserializer.decrease_container_depth()
+        return RenderOperation.init()
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> RenderOperation {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct Request: Hashable {
+    @Indirect public var id: UInt32
+    @Indirect public var effect: SharedTypes.Effect
+
+    public init(id: UInt32, effect: SharedTypes.Effect) {
+        self.id = id
+        self.effect = effect
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_u32(value: self.id)
+        try self.effect.serialize(serializer: serializer)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> Request {
+        try deserializer.increase_container_depth()
+        let id = try deserializer.deserialize_u32()
+        let effect = try SharedTypes.Effect.deserialize(deserializer: deserializer)
+        try deserializer.decrease_container_depth()
+        return Request.init(id: id, effect: effect)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> Request {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct SetActual: Hashable {
+    @Indirect public var weight: Double?
+    @Indirect public var reps: Int32?
+    @Indirect public var duration: Int32?
+    @Indirect public var rpe: Double?
+    @Indirect public var actual_rest_time: Int32?
+
+    public init(weight: Double?, reps: Int32?, duration: Int32?, rpe: Double?, actual_rest_time: Int32?) {
+        self.weight = weight
+        self.reps = reps
+        self.duration = duration
+        self.rpe = rpe
+        self.actual_rest_time = actual_rest_time
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serialize_option_f64(value: self.weight, serializer: serializer)
+        try serialize_option_i32(value: self.reps, serializer: serializer)
+        try serialize_option_i32(value: self.duration, serializer: serializer)
+        try serialize_option_f64(value: self.rpe, serializer: serializer)
+        try serialize_option_i32(value: self.actual_rest_time, serializer: serializer)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> SetActual {
+        try deserializer.increase_container_depth()
+        let weight = try deserialize_option_f64(deserializer: deserializer)
+        let reps = try deserialize_option_i32(deserializer: deserializer)
+        let duration = try deserialize_option_i32(deserializer: deserializer)
+        let rpe = try deserialize_option_f64(deserializer: deserializer)
+        let actual_rest_time = try deserialize_option_i32(deserializer: deserializer)
+        try deserializer.decrease_container_depth()
+        return SetActual.init(weight: weight, reps: reps, duration: duration, rpe: rpe, actual_rest_time: actual_rest_time)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> SetActual {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct SetDetailViewModel: Hashable {
+    @Indirect public var set_number: Int32
+    @Indirect public var display_text: String
+
+    public init(set_number: Int32, display_text: String) {
+        self.set_number = set_number
+        self.display_text = display_text
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_i32(value: self.set_number)
+        try serializer.serialize_str(value: self.display_text)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> SetDetailViewModel {
+        try deserializer.increase_container_depth()
+        let set_number = try deserializer.deserialize_i32()
+        let display_text = try deserializer.deserialize_str()
+        try deserializer.decrease_container_depth()
+        return SetDetailViewModel.init(set_number: set_number, display_text: display_text)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> SetDetailViewModel {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct SetSuggest: Hashable {
+    @Indirect public var weight: Double?
+    @Indirect public var reps: Int32?
+    @Indirect public var rep_range: Int32?
+    @Indirect public var duration: Int32?
+    @Indirect public var rpe: Double?
+    @Indirect public var rest_time: Int32?
+
+    public init(weight: Double?, reps: Int32?, rep_range: Int32?, duration: Int32?, rpe: Double?, rest_time: Int32?) {
+        self.weight = weight
+        self.reps = reps
+        self.rep_range = rep_range
+        self.duration = duration
+        self.rpe = rpe
+        self.rest_time = rest_time
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serialize_option_f64(value: self.weight, serializer: serializer)
+        try serialize_option_i32(value: self.reps, serializer: serializer)
+        try serialize_option_i32(value: self.rep_range, serializer: serializer)
+        try serialize_option_i32(value: self.duration, serializer: serializer)
+        try serialize_option_f64(value: self.rpe, serializer: serializer)
+        try serialize_option_i32(value: self.rest_time, serializer: serializer)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> SetSuggest {
+        try deserializer.increase_container_depth()
+        let weight = try deserialize_option_f64(deserializer: deserializer)
+        let reps = try deserialize_option_i32(deserializer: deserializer)
+        let rep_range = try deserialize_option_i32(deserializer: deserializer)
+        let duration = try deserialize_option_i32(deserializer: deserializer)
+        let rpe = try deserialize_option_f64(deserializer: deserializer)
+        let rest_time = try deserialize_option_i32(deserializer: deserializer)
+        try deserializer.decrease_container_depth()
+        return SetSuggest.init(weight: weight, reps: reps, rep_range: rep_range, duration: duration, rpe: rpe, rest_time: rest_time)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> SetSuggest {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+indirect public enum SetType: Hashable {
+    case warmUp
+    case working
+    case dropSet
+    case amrap
+    case failure
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        switch self {
+        case .warmUp:
+            try serializer.serialize_variant_index(value: 0)
+        case .working:
+            try serializer.serialize_variant_index(value: 1)
+        case .dropSet:
+            try serializer.serialize_variant_index(value: 2)
+        case .amrap:
+            try serializer.serialize_variant_index(value: 3)
+        case .failure:
+            try serializer.serialize_variant_index(value: 4)
+        }
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> SetType {
+        let index = try deserializer.deserialize_variant_index()
+        try deserializer.increase_container_depth()
+        switch index {
+        case 0:
+            try deserializer.decrease_container_depth()
+            return .warmUp
+        case 1:
+            try deserializer.decrease_container_depth()
+            return .working
+        case 2:
+            try deserializer.decrease_container_depth()
+            return .dropSet
+        case 3:
+            try deserializer.decrease_container_depth()
+            return .amrap
+        case 4:
+            try deserializer.decrease_container_depth()
+            return .failure
+        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for SetType: \(index)")
+        }
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> SetType {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+public struct SetViewModel: Hashable {
     @Indirect public var id: String
-    @Indirect public var name: String
-    @Indirect public var sets: [SharedTypes.SetViewModel]
+    @Indirect public var set_number: Int32
+    @Indirect public var previous_display: String
+    @Indirect public var weight: String
+    @Indirect public var reps: String
+    @Indirect public var rpe: String
+    @Indirect public var is_completed: Bool
 
-    public init(id: String, name: String, sets: [SharedTypes.SetViewModel]) {
+    public init(id: String, set_number: Int32, previous_display: String, weight: String, reps: String, rpe: String, is_completed: Bool) {
         self.id = id
-        self.name = name
-        self.sets = sets
+        self.set_number = set_number
+        self.previous_display = previous_display
+        self.weight = weight
+        self.reps = reps
+        self.rpe = rpe
+        self.is_completed = is_completed
     }
 
     public func serialize<S: Serializer>(serializer: S) throws {
         try serializer.increase_container_depth()
         try serializer.serialize_str(value: self.id)
-        try serializer.serialize_str(value: self.name)
-        try serialize_vector_SetViewModel(value: self.sets, serializer: serializer)
+        try serializer.serialize_i32(value: self.set_number)
+        try serializer.serialize_str(value: self.previous_display)
+        try serializer.serialize_str(value: self.weight)
+        try serializer.serialize_str(value: self.reps)
+        try serializer.serialize_str(value: self.rpe)
+        try serializer.serialize_bool(value: self.is_completed)
         try serializer.decrease_container_depth()
     }
 
@@ -540,16 +3181,20 @@ public struct ExerciseViewModel: Hashable {
         return serializer.get_bytes()
     }
 
-    public static func deserialize<D: Deserializer>(deserializer: D) throws -> ExerciseViewModel {
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> SetViewModel {
         try deserializer.increase_container_depth()
         let id = try deserializer.deserialize_str()
-        let name = try deserializer.deserialize_str()
-        let sets = try deserialize_vector_SetViewModel(deserializer: deserializer)
+        let set_number = try deserializer.deserialize_i32()
+        let previous_display = try deserializer.deserialize_str()
+        let weight = try deserializer.deserialize_str()
+        let reps = try deserializer.deserialize_str()
+        let rpe = try deserializer.deserialize_str()
+        let is_completed = try deserializer.deserialize_bool()
         try deserializer.decrease_container_depth()
-        return ExerciseViewModel.init(id: id, name: name, sets: sets)
+        return SetViewModel.init(id: id, set_number: set_number, previous_display: previous_display, weight: weight, reps: reps, rpe: rpe, is_completed: is_completed)
     }
 
-    public static func bincodeDeserialize(input: [UInt8]) throws -> ExerciseViewModel {
+    public static func bincodeDeserialize(input: [UInt8]) throws -> SetViewModel {
         let deserializer = BincodeDeserializer.init(input: input);
         let obj = try deserialize(deserializer: deserializer)
         if deserializer.get_buffer_offset() < input.count {
@@ -559,31 +3204,26 @@ public struct ExerciseViewModel: Hashable {
     }
 }
 
-public struct HistoryItemViewModel: Hashable {
-    @Indirect public var id: String
-    @Indirect public var name: String
-    @Indirect public var date: String
-    @Indirect public var exercise_count: UInt64
-    @Indirect public var set_count: UInt64
-    @Indirect public var total_volume: Int32
-
-    public init(id: String, name: String, date: String, exercise_count: UInt64, set_count: UInt64, total_volume: Int32) {
-        self.id = id
-        self.name = name
-        self.date = date
-        self.exercise_count = exercise_count
-        self.set_count = set_count
-        self.total_volume = total_volume
-    }
+indirect public enum ShareOperation: Hashable {
+    case shareFile(file_name: String, mime_type: String, contents: String)
+    case shareText(text: String)
+    case copyText(text: String)
 
     public func serialize<S: Serializer>(serializer: S) throws {
         try serializer.increase_container_depth()
-        try serializer.serialize_str(value: self.id)
-        try serializer.serialize_str(value: self.name)
-        try serializer.serialize_str(value: self.date)
-        try serializer.serialize_u64(value: self.exercise_count)
-        try serializer.serialize_u64(value: self.set_count)
-        try serializer.serialize_i32(value: self.total_volume)
+        switch self {
+        case .shareFile(let file_name, let mime_type, let contents):
+            try serializer.serialize_variant_index(value: 0)
+            try serializer.serialize_str(value: file_name)
+            try serializer.serialize_str(value: mime_type)
+            try serializer.serialize_str(value: contents)
+        case .shareText(let text):
+            try serializer.serialize_variant_index(value: 1)
+            try serializer.serialize_str(value: text)
+        case .copyText(let text):
+            try serializer.serialize_variant_index(value: 2)
+            try serializer.serialize_str(value: text)
+        }
         try serializer.decrease_container_depth()
     }
 
@@ -593,19 +3233,29 @@ public struct HistoryItemViewModel: Hashable {
         return serializer.get_bytes()
     }
 
-    public static func deserialize<D: Deserializer>(deserializer: D) throws -> HistoryItemViewModel {
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> ShareOperation {
+        let index = try deserializer.deserialize_variant_index()
         try deserializer.increase_container_depth()
-        let id = try deserializer.deserialize_str()
-        let name = try deserializer.deserialize_str()
-        let date = try deserializer.deserialize_str()
-        let exercise_count = try deserializer.deserialize_u64()
-        let set_count = try deserializer.deserialize_u64()
-        let total_volume = try deserializer.deserialize_i32()
-        try deserializer.decrease_container_depth()
-        return HistoryItemViewModel.init(id: id, name: name, date: date, exercise_count: exercise_count, set_count: set_count, total_volume: total_volume)
+        switch index {
+        case 0:
+            let file_name = try deserializer.deserialize_str()
+            let mime_type = try deserializer.deserialize_str()
+            let contents = try deserializer.deserialize_str()
+            try deserializer.decrease_container_depth()
+            return .shareFile(file_name: file_name, mime_type: mime_type, contents: contents)
+        case 1:
+            let text = try deserializer.deserialize_str()
+            try deserializer.decrease_container_depth()
+            return .shareText(text: text)
+        case 2:
+            let text = try deserializer.deserialize_str()
+            try deserializer.decrease_container_depth()
+            return .copyText(text: text)
+        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for ShareOperation: \(index)")
+        }
     }
 
-    public static func bincodeDeserialize(input: [UInt8]) throws -> HistoryItemViewModel {
+    public static func bincodeDeserialize(input: [UInt8]) throws -> ShareOperation {
         let deserializer = BincodeDeserializer.init(input: input);
         let obj = try deserialize(deserializer: deserializer)
         if deserializer.get_buffer_offset() < input.count {
@@ -615,19 +3265,22 @@ public struct HistoryItemViewModel: Hashable {
     }
 }
 
-public struct HistoryViewModel: Hashable {
-    @Indirect public var workouts: [SharedTypes.HistoryItemViewModel]
-    @Indirect public var is_loading: Bool
-
-    public init(workouts: [SharedTypes.HistoryItemViewModel], is_loading: Bool) {
-        self.workouts = workouts
-        self.is_loading = is_loading
-    }
+indirect public enum ShareResult: Hashable {
+    case shared
+    case cancelled
+    case failed(message: String)
 
     public func serialize<S: Serializer>(serializer: S) throws {
         try serializer.increase_container_depth()
-        try serialize_vector_HistoryItemViewModel(value: self.workouts, serializer: serializer)
-        try serializer.serialize_bool(value: self.is_loading)
+        switch self {
+        case .shared:
+            try serializer.serialize_variant_index(value: 0)
+        case .cancelled:
+            try serializer.serialize_variant_index(value: 1)
+        case .failed(let message):
+            try serializer.serialize_variant_index(value: 2)
+            try serializer.serialize_str(value: message)
+        }
         try serializer.decrease_container_depth()
     }
 
@@ -637,15 +3290,25 @@ public struct HistoryViewModel: Hashable {
         return serializer.get_bytes()
     }
 
-    public static func deserialize<D: Deserializer>(deserializer: D) throws -> HistoryViewModel {
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> ShareResult {
+        let index = try deserializer.deserialize_variant_index()
         try deserializer.increase_container_depth()
-        let workouts = try deserialize_vector_HistoryItemViewModel(deserializer: deserializer)
-        let is_loading = try deserializer.deserialize_bool()
-        try deserializer.decrease_container_depth()
-        return HistoryViewModel.init(workouts: workouts, is_loading: is_loading)
+        switch index {
+        case 0:
+            try deserializer.decrease_container_depth()
+            return .shared
+        case 1:
+            try deserializer.decrease_container_depth()
+            return .cancelled
+        case 2:
+            let message = try deserializer.deserialize_str()
+            try deserializer.decrease_container_depth()
+            return .failed(message: message)
+        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for ShareResult: \(index)")
+        }
     }
 
-    public static func bincodeDeserialize(input: [UInt8]) throws -> HistoryViewModel {
+    public static func bincodeDeserialize(input: [UInt8]) throws -> ShareResult {
         let deserializer = BincodeDeserializer.init(input: input);
         let obj = try deserialize(deserializer: deserializer)
         if deserializer.get_buffer_offset() < input.count {
@@ -655,13 +3318,100 @@ public struct HistoryViewModel: Hashable {
     }
 }
 
-public struct RenderOperation: Hashable {
+indirect public enum StorageOperation: Hashable {
+    case saveCurrentWorkout(SharedTypes.Workout)
+    case loadCurrentWorkout
+    case deleteCurrentWorkout
+    case saveSyncState(SharedTypes.SyncState)
+    case loadSyncState
 
-    public init() {
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        switch self {
+        case .saveCurrentWorkout(let x):
+            try serializer.serialize_variant_index(value: 0)
+            try x.serialize(serializer: serializer)
+        case .loadCurrentWorkout:
+            try serializer.serialize_variant_index(value: 1)
+        case .deleteCurrentWorkout:
+            try serializer.serialize_variant_index(value: 2)
+        case .saveSyncState(let x):
+            try serializer.serialize_variant_index(value: 3)
+            try x.serialize(serializer: serializer)
+        case .loadSyncState:
+            try serializer.serialize_variant_index(value: 4)
+        }
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> StorageOperation {
+        let index = try deserializer.deserialize_variant_index()
+        try deserializer.increase_container_depth()
+        switch index {
+        case 0:
+            let x = try SharedTypes.Workout.deserialize(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .saveCurrentWorkout(x)
+        case 1:
+            try deserializer.decrease_container_depth()
+            return .loadCurrentWorkout
+        case 2:
+            try deserializer.decrease_container_depth()
+            return .deleteCurrentWorkout
+        case 3:
+            let x = try SharedTypes.SyncState.deserialize(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .saveSyncState(x)
+        case 4:
+            try deserializer.decrease_container_depth()
+            return .loadSyncState
+        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for StorageOperation: \(index)")
+        }
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> StorageOperation {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
     }
+}
+
+indirect public enum StorageResult: Hashable {
+    case currentWorkoutSaved
+    case currentWorkoutLoaded(workout: SharedTypes.Workout?)
+    case currentWorkoutDeleted
+    case syncStateSaved
+    case syncStateLoaded(state: SharedTypes.SyncState?)
+    case error(message: String)
 
     public func serialize<S: Serializer>(serializer: S) throws {
         try serializer.increase_container_depth()
+        switch self {
+        case .currentWorkoutSaved:
+            try serializer.serialize_variant_index(value: 0)
+        case .currentWorkoutLoaded(let workout):
+            try serializer.serialize_variant_index(value: 1)
+            try serialize_option_Workout(value: workout, serializer: serializer)
+        case .currentWorkoutDeleted:
+            try serializer.serialize_variant_index(value: 2)
+        case .syncStateSaved:
+            try serializer.serialize_variant_index(value: 3)
+        case .syncStateLoaded(let state):
+            try serializer.serialize_variant_index(value: 4)
+            try serialize_option_SyncState(value: state, serializer: serializer)
+        case .error(let message):
+            try serializer.serialize_variant_index(value: 5)
+            try serializer.serialize_str(value: message)
+        }
         try serializer.decrease_container_depth()
     }
 
@@ -671,13 +3421,36 @@ public struct RenderOperation: Hashable {
         return serializer.get_bytes()
     }
 
-    public static func deserialize<D: Deserializer>(deserializer: D) throws -> RenderOperation {
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> StorageResult {
+        let index = try deserializer.deserialize_variant_index()
         try deserializer.increase_container_depth()
-        try deserializer.decrease_container_depth()
-        return RenderOperation.init()
+        switch index {
+        case 0:
+            try deserializer.decrease_container_depth()
+            return .currentWorkoutSaved
+        case 1:
+            let workout = try deserialize_option_Workout(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .currentWorkoutLoaded(workout: workout)
+        case 2:
+            try deserializer.decrease_container_depth()
+            return .currentWorkoutDeleted
+        case 3:
+            try deserializer.decrease_container_depth()
+            return .syncStateSaved
+        case 4:
+            let state = try deserialize_option_SyncState(deserializer: deserializer)
+            try deserializer.decrease_container_depth()
+            return .syncStateLoaded(state: state)
+        case 5:
+            let message = try deserializer.deserialize_str()
+            try deserializer.decrease_container_depth()
+            return .error(message: message)
+        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for StorageResult: \(index)")
+        }
     }
 
-    public static func bincodeDeserialize(input: [UInt8]) throws -> RenderOperation {
+    public static func bincodeDeserialize(input: [UInt8]) throws -> StorageResult {
         let deserializer = BincodeDeserializer.init(input: input);
         let obj = try deserialize(deserializer: deserializer)
         if deserializer.get_buffer_offset() < input.count {
@@ -687,19 +3460,28 @@ public struct RenderOperation: Hashable {
     }
 }
 
-public struct Request: Hashable {
-    @Indirect public var id: UInt32
-    @Indirect public var effect: SharedTypes.Effect
+public struct SyncState: Hashable {
+    @Indirect public var replica_id: String
+    @Indirect public var counter: UInt64
+    @Indirect public var cursor: Int64
+    @Indirect public var seeded: Bool
+    @Indirect public var pending: [SharedTypes.Change]
 
-    public init(id: UInt32, effect: SharedTypes.Effect) {
-        self.id = id
-        self.effect = effect
+    public init(replica_id: String, counter: UInt64, cursor: Int64, seeded: Bool, pending: [SharedTypes.Change]) {
+        self.replica_id = replica_id
+        self.counter = counter
+        self.cursor = cursor
+        self.seeded = seeded
+        self.pending = pending
     }
 
     public func serialize<S: Serializer>(serializer: S) throws {
         try serializer.increase_container_depth()
-        try serializer.serialize_u32(value: self.id)
-        try self.effect.serialize(serializer: serializer)
+        try serializer.serialize_str(value: self.replica_id)
+        try serializer.serialize_u64(value: self.counter)
+        try serializer.serialize_i64(value: self.cursor)
+        try serializer.serialize_bool(value: self.seeded)
+        try serialize_vector_Change(value: self.pending, serializer: serializer)
         try serializer.decrease_container_depth()
     }
 
@@ -709,15 +3491,18 @@ public struct Request: Hashable {
         return serializer.get_bytes()
     }
 
-    public static func deserialize<D: Deserializer>(deserializer: D) throws -> Request {
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> SyncState {
         try deserializer.increase_container_depth()
-        let id = try deserializer.deserialize_u32()
-        let effect = try SharedTypes.Effect.deserialize(deserializer: deserializer)
+        let replica_id = try deserializer.deserialize_str()
+        let counter = try deserializer.deserialize_u64()
+        let cursor = try deserializer.deserialize_i64()
+        let seeded = try deserializer.deserialize_bool()
+        let pending = try deserialize_vector_Change(deserializer: deserializer)
         try deserializer.decrease_container_depth()
-        return Request.init(id: id, effect: effect)
+        return SyncState.init(replica_id: replica_id, counter: counter, cursor: cursor, seeded: seeded, pending: pending)
     }
 
-    public static func bincodeDeserialize(input: [UInt8]) throws -> Request {
+    public static func bincodeDeserialize(input: [UInt8]) throws -> SyncState {
         let deserializer = BincodeDeserializer.init(input: input);
         let obj = try deserialize(deserializer: deserializer)
         if deserializer.get_buffer_offset() < input.count {
@@ -727,49 +3512,41 @@ public struct Request: Hashable {
     }
 }
 
-public struct SetActual: Hashable {
-    @Indirect public var weight: Double?
-    @Indirect public var reps: Int32?
-    @Indirect public var duration: Int32?
-    @Indirect public var rpe: Double?
-    @Indirect public var actual_rest_time: Int32?
+public struct SyncViewModel: Hashable {
+    @Indirect public var is_syncing: Bool
+    @Indirect public var pending_changes: UInt64
+    @Indirect public var last_synced_at: SharedTypes.Timestamp?
 
-    public init(weight: Double?, reps: Int32?, duration: Int32?, rpe: Double?, actual_rest_time: Int32?) {
-        self.weight = weight
-        self.reps = reps
-        self.duration = duration
-        self.rpe = rpe
-        self.actual_rest_time = actual_rest_time
+    public init(is_syncing: Bool, pending_changes: UInt64, last_synced_at: SharedTypes.Timestamp?) {
+        self.is_syncing = is_syncing
+        self.pending_changes = pending_changes
+        self.last_synced_at = last_synced_at
     }
 
     public func serialize<S: Serializer>(serializer: S) throws {
         try serializer.increase_container_depth()
-        try serialize_option_f64(value: self.weight, serializer: serializer)
-        try serialize_option_i32(value: self.reps, serializer: serializer)
-        try serialize_option_i32(value: self.duration, serializer: serializer)
-        try serialize_option_f64(value: self.rpe, serializer: serializer)
-        try serialize_option_i32(value: self.actual_rest_time, serializer: serializer)
+        try serializer.serialize_bool(value: self.is_syncing)
+        try serializer.serialize_u64(value: self.pending_changes)
+        try serialize_option_Timestamp(value: self.last_synced_at, serializer: serializer)
         try serializer.decrease_container_depth()
     }
 
     public func bincodeSerialize() throws -> [UInt8] {
         let serializer = BincodeSerializer.init();
         try self.serialize(serializer: serializer)
-        return serializer.get_bytes()
-    }
-
-    public static func deserialize<D: Deserializer>(deserializer: D) throws -> SetActual {
-        try deserializer.increase_container_depth()
-        let weight = try deserialize_option_f64(deserializer: deserializer)
-        let reps = try deserialize_option_i32(deserializer: deserializer)
-        let duration = try deserialize_option_i32(deserializer: deserializer)
-        let rpe = try deserialize_option_f64(deserializer: deserializer)
-        let actual_rest_time = try deserialize_option_i32(deserializer: deserializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> SyncViewModel {
+        try deserializer.increase_container_depth()
+        let is_syncing = try deserializer.deserialize_bool()
+        let pending_changes = try deserializer.deserialize_u64()
+        let last_synced_at = try deserialize_option_Timestamp(deserializer: deserializer)
         try deserializer.decrease_container_depth()
-        return SetActual.init(weight: weight, reps: reps, duration: duration, rpe: rpe, actual_rest_time: actual_rest_time)
+        return SyncViewModel.init(is_syncing: is_syncing, pending_changes: pending_changes, last_synced_at: last_synced_at)
     }
 
-    public static func bincodeDeserialize(input: [UInt8]) throws -> SetActual {
+    public static func bincodeDeserialize(input: [UInt8]) throws -> SyncViewModel {
         let deserializer = BincodeDeserializer.init(input: input);
         let obj = try deserialize(deserializer: deserializer)
         if deserializer.get_buffer_offset() < input.count {
@@ -779,34 +3556,21 @@ public struct SetActual: Hashable {
     }
 }
 
-public struct SetViewModel: Hashable {
-    @Indirect public var id: String
-    @Indirect public var set_number: Int32
-    @Indirect public var previous_display: String
-    @Indirect public var weight: String
-    @Indirect public var reps: String
-    @Indirect public var rpe: String
-    @Indirect public var is_completed: Bool
-
-    public init(id: String, set_number: Int32, previous_display: String, weight: String, reps: String, rpe: String, is_completed: Bool) {
-        self.id = id
-        self.set_number = set_number
-        self.previous_display = previous_display
-        self.weight = weight
-        self.reps = reps
-        self.rpe = rpe
-        self.is_completed = is_completed
-    }
+indirect public enum Tab: Hashable {
+    case workout
+    case history
+    case debug
 
     public func serialize<S: Serializer>(serializer: S) throws {
         try serializer.increase_container_depth()
-        try serializer.serialize_str(value: self.id)
-        try serializer.serialize_i32(value: self.set_number)
-        try serializer.serialize_str(value: self.previous_display)
-        try serializer.serialize_str(value: self.weight)
-        try serializer.serialize_str(value: self.reps)
-        try serializer.serialize_str(value: self.rpe)
-        try serializer.serialize_bool(value: self.is_completed)
+        switch self {
+        case .workout:
+            try serializer.serialize_variant_index(value: 0)
+        case .history:
+            try serializer.serialize_variant_index(value: 1)
+        case .debug:
+            try serializer.serialize_variant_index(value: 2)
+        }
         try serializer.decrease_container_depth()
     }
 
@@ -816,20 +3580,24 @@ public struct SetViewModel: Hashable {
         return serializer.get_bytes()
     }
 
-    public static func deserialize<D: Deserializer>(deserializer: D) throws -> SetViewModel {
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> Tab {
+        let index = try deserializer.deserialize_variant_index()
         try deserializer.increase_container_depth()
-        let id = try deserializer.deserialize_str()
-        let set_number = try deserializer.deserialize_i32()
-        let previous_display = try deserializer.deserialize_str()
-        let weight = try deserializer.deserialize_str()
-        let reps = try deserializer.deserialize_str()
-        let rpe = try deserializer.deserialize_str()
-        let is_completed = try deserializer.deserialize_bool()
-        try deserializer.decrease_container_depth()
-        return SetViewModel.init(id: id, set_number: set_number, previous_display: previous_display, weight: weight, reps: reps, rpe: rpe, is_completed: is_completed)
+        switch index {
+        case 0:
+            try deserializer.decrease_container_depth()
+            return .workout
+        case 1:
+            try deserializer.decrease_container_depth()
+            return .history
+        case 2:
+            try deserializer.decrease_container_depth()
+            return .debug
+        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for Tab: \(index)")
+        }
     }
 
-    public static func bincodeDeserialize(input: [UInt8]) throws -> SetViewModel {
+    public static func bincodeDeserialize(input: [UInt8]) throws -> Tab {
         let deserializer = BincodeDeserializer.init(input: input);
         let obj = try deserialize(deserializer: deserializer)
         if deserializer.get_buffer_offset() < input.count {
@@ -839,21 +3607,17 @@ public struct SetViewModel: Hashable {
     }
 }
 
-indirect public enum StorageOperation: Hashable {
-    case saveCurrentWorkout(String)
-    case loadCurrentWorkout
-    case deleteCurrentWorkout
+indirect public enum TimerOperation: Hashable {
+    case start
+    case stop
 
     public func serialize<S: Serializer>(serializer: S) throws {
         try serializer.increase_container_depth()
         switch self {
-        case .saveCurrentWorkout(let x):
+        case .start:
             try serializer.serialize_variant_index(value: 0)
-            try serializer.serialize_str(value: x)
-        case .loadCurrentWorkout:
+        case .stop:
             try serializer.serialize_variant_index(value: 1)
-        case .deleteCurrentWorkout:
-            try serializer.serialize_variant_index(value: 2)
         }
         try serializer.decrease_container_depth()
     }
@@ -864,25 +3628,21 @@ indirect public enum StorageOperation: Hashable {
         return serializer.get_bytes()
     }
 
-    public static func deserialize<D: Deserializer>(deserializer: D) throws -> StorageOperation {
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> TimerOperation {
         let index = try deserializer.deserialize_variant_index()
         try deserializer.increase_container_depth()
         switch index {
         case 0:
-            let x = try deserializer.deserialize_str()
             try deserializer.decrease_container_depth()
-            return .saveCurrentWorkout(x)
+            return .start
         case 1:
             try deserializer.decrease_container_depth()
-            return .loadCurrentWorkout
-        case 2:
-            try deserializer.decrease_container_depth()
-            return .deleteCurrentWorkout
-        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for StorageOperation: \(index)")
+            return .stop
+        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for TimerOperation: \(index)")
         }
     }
 
-    public static func bincodeDeserialize(input: [UInt8]) throws -> StorageOperation {
+    public static func bincodeDeserialize(input: [UInt8]) throws -> TimerOperation {
         let deserializer = BincodeDeserializer.init(input: input);
         let obj = try deserialize(deserializer: deserializer)
         if deserializer.get_buffer_offset() < input.count {
@@ -892,21 +3652,20 @@ indirect public enum StorageOperation: Hashable {
     }
 }
 
-indirect public enum StorageResult: Hashable {
-    case currentWorkoutSaved
-    case currentWorkoutLoaded(workout_json: String?)
-    case currentWorkoutDeleted
+indirect public enum TimerOutput: Hashable {
+    case tick
+    case started
+    case stopped
     case error(message: String)
 
     public func serialize<S: Serializer>(serializer: S) throws {
         try serializer.increase_container_depth()
         switch self {
-        case .currentWorkoutSaved:
+        case .tick:
             try serializer.serialize_variant_index(value: 0)
-        case .currentWorkoutLoaded(let workout_json):
+        case .started:
             try serializer.serialize_variant_index(value: 1)
-            try serialize_option_str(value: workout_json, serializer: serializer)
-        case .currentWorkoutDeleted:
+        case .stopped:
             try serializer.serialize_variant_index(value: 2)
         case .error(let message):
             try serializer.serialize_variant_index(value: 3)
@@ -921,29 +3680,28 @@ indirect public enum StorageResult: Hashable {
         return serializer.get_bytes()
     }
 
-    public static func deserialize<D: Deserializer>(deserializer: D) throws -> StorageResult {
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> TimerOutput {
         let index = try deserializer.deserialize_variant_index()
         try deserializer.increase_container_depth()
         switch index {
         case 0:
             try deserializer.decrease_container_depth()
-            return .currentWorkoutSaved
+            return .tick
         case 1:
-            let workout_json = try deserialize_option_str(deserializer: deserializer)
             try deserializer.decrease_container_depth()
-            return .currentWorkoutLoaded(workout_json: workout_json)
+            return .started
         case 2:
             try deserializer.decrease_container_depth()
-            return .currentWorkoutDeleted
+            return .stopped
         case 3:
             let message = try deserializer.deserialize_str()
             try deserializer.decrease_container_depth()
             return .error(message: message)
-        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for StorageResult: \(index)")
+        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for TimerOutput: \(index)")
         }
     }
 
-    public static func bincodeDeserialize(input: [UInt8]) throws -> StorageResult {
+    public static func bincodeDeserialize(input: [UInt8]) throws -> TimerOutput {
         let deserializer = BincodeDeserializer.init(input: input);
         let obj = try deserialize(deserializer: deserializer)
         if deserializer.get_buffer_offset() < input.count {
@@ -953,21 +3711,16 @@ indirect public enum StorageResult: Hashable {
     }
 }
 
-indirect public enum Tab: Hashable {
-    case workout
-    case history
-    case debug
+public struct Timestamp: Hashable {
+    @Indirect public var value: Int64
+
+    public init(value: Int64) {
+        self.value = value
+    }
 
     public func serialize<S: Serializer>(serializer: S) throws {
         try serializer.increase_container_depth()
-        switch self {
-        case .workout:
-            try serializer.serialize_variant_index(value: 0)
-        case .history:
-            try serializer.serialize_variant_index(value: 1)
-        case .debug:
-            try serializer.serialize_variant_index(value: 2)
-        }
+        try serializer.serialize_i64(value: self.value)
         try serializer.decrease_container_depth()
     }
 
@@ -977,24 +3730,98 @@ indirect public enum Tab: Hashable {
         return serializer.get_bytes()
     }
 
-    public static func deserialize<D: Deserializer>(deserializer: D) throws -> Tab {
-        let index = try deserializer.deserialize_variant_index()
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> Timestamp {
         try deserializer.increase_container_depth()
-        switch index {
-        case 0:
-            try deserializer.decrease_container_depth()
-            return .workout
-        case 1:
-            try deserializer.decrease_container_depth()
-            return .history
-        case 2:
-            try deserializer.decrease_container_depth()
-            return .debug
-        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for Tab: \(index)")
+        let value = try deserializer.deserialize_i64()
+        try deserializer.decrease_container_depth()
+        return Timestamp.init(value: value)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> Timestamp {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
         }
+        return obj
     }
+}
 
-    public static func bincodeDeserialize(input: [UInt8]) throws -> Tab {
+public struct ViewModel: Hashable {
+    @Indirect public var selected_tab: SharedTypes.Tab
+    @Indirect public var workout_view: SharedTypes.WorkoutViewModel
+    @Indirect public var history_view: SharedTypes.HistoryViewModel
+    @Indirect public var history_detail_view: SharedTypes.HistoryDetailViewModel?
+    @Indirect public var history_edit_view: SharedTypes.HistoryEditViewModel?
+    @Indirect public var exercise_history_view: SharedTypes.ExerciseHistoryViewModel?
+    @Indirect public var csv_import_preview: SharedTypes.CsvImportPreviewViewModel?
+    @Indirect public var import_summary: SharedTypes.ImportSummaryViewModel?
+    @Indirect public var workout_summary: SharedTypes.WorkoutSummaryViewModel?
+    @Indirect public var preferred_weight_unit: SharedTypes.WeightUnit
+    @Indirect public var errors: SharedTypes.ErrorQueueViewModel
+    @Indirect public var sync: SharedTypes.SyncViewModel
+    @Indirect public var is_loading: Bool
+
+    public init(selected_tab: SharedTypes.Tab, workout_view: SharedTypes.WorkoutViewModel, history_view: SharedTypes.HistoryViewModel, history_detail_view: SharedTypes.HistoryDetailViewModel?, history_edit_view: SharedTypes.HistoryEditViewModel?, exercise_history_view: SharedTypes.ExerciseHistoryViewModel?, csv_import_preview: SharedTypes.CsvImportPreviewViewModel?, import_summary: SharedTypes.ImportSummaryViewModel?, workout_summary: SharedTypes.WorkoutSummaryViewModel?, preferred_weight_unit: SharedTypes.WeightUnit, errors: SharedTypes.ErrorQueueViewModel, sync: SharedTypes.SyncViewModel, is_loading: Bool) {
+        self.selected_tab = selected_tab
+        self.workout_view = workout_view
+        self.history_view = history_view
+        self.history_detail_view = history_detail_view
+        self.history_edit_view = history_edit_view
+        self.exercise_history_view = exercise_history_view
+        self.csv_import_preview = csv_import_preview
+        self.import_summary = import_summary
+        self.workout_summary = workout_summary
+        self.preferred_weight_unit = preferred_weight_unit
+        self.errors = errors
+        self.sync = sync
+        self.is_loading = is_loading
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try self.selected_tab.serialize(serializer: serializer)
+        try self.workout_view.serialize(serializer: serializer)
+        try self.history_view.serialize(serializer: serializer)
+        try serialize_option_HistoryDetailViewModel(value: self.history_detail_view, serializer: serializer)
+        try serialize_option_HistoryEditViewModel(value: self.history_edit_view, serializer: serializer)
+        try serialize_option_ExerciseHistoryViewModel(value: self.exercise_history_view, serializer: serializer)
+        try serialize_option_CsvImportPreviewViewModel(value: self.csv_import_preview, serializer: serializer)
+        try serialize_option_ImportSummaryViewModel(value: self.import_summary, serializer: serializer)
+        try serialize_option_WorkoutSummaryViewModel(value: self.workout_summary, serializer: serializer)
+        try self.preferred_weight_unit.serialize(serializer: serializer)
+        try self.errors.serialize(serializer: serializer)
+        try self.sync.serialize(serializer: serializer)
+        try serializer.serialize_bool(value: self.is_loading)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> ViewModel {
+        try deserializer.increase_container_depth()
+        let selected_tab = try SharedTypes.Tab.deserialize(deserializer: deserializer)
+        let workout_view = try SharedTypes.WorkoutViewModel.deserialize(deserializer: deserializer)
+        let history_view = try SharedTypes.HistoryViewModel.deserialize(deserializer: deserializer)
+        let history_detail_view = try deserialize_option_HistoryDetailViewModel(deserializer: deserializer)
+        let history_edit_view = try deserialize_option_HistoryEditViewModel(deserializer: deserializer)
+        let exercise_history_view = try deserialize_option_ExerciseHistoryViewModel(deserializer: deserializer)
+        let csv_import_preview = try deserialize_option_CsvImportPreviewViewModel(deserializer: deserializer)
+        let import_summary = try deserialize_option_ImportSummaryViewModel(deserializer: deserializer)
+        let workout_summary = try deserialize_option_WorkoutSummaryViewModel(deserializer: deserializer)
+        let preferred_weight_unit = try SharedTypes.WeightUnit.deserialize(deserializer: deserializer)
+        let errors = try SharedTypes.ErrorQueueViewModel.deserialize(deserializer: deserializer)
+        let sync = try SharedTypes.SyncViewModel.deserialize(deserializer: deserializer)
+        let is_loading = try deserializer.deserialize_bool()
+        try deserializer.decrease_container_depth()
+        return ViewModel.init(selected_tab: selected_tab, workout_view: workout_view, history_view: history_view, history_detail_view: history_detail_view, history_edit_view: history_edit_view, exercise_history_view: exercise_history_view, csv_import_preview: csv_import_preview, import_summary: import_summary, workout_summary: workout_summary, preferred_weight_unit: preferred_weight_unit, errors: errors, sync: sync, is_loading: is_loading)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> ViewModel {
         let deserializer = BincodeDeserializer.init(input: input);
         let obj = try deserialize(deserializer: deserializer)
         if deserializer.get_buffer_offset() < input.count {
@@ -1004,17 +3831,20 @@ indirect public enum Tab: Hashable {
     }
 }
 
-indirect public enum TimerOperation: Hashable {
-    case start
-    case stop
+indirect public enum WeightUnit: Hashable {
+    case kg
+    case lb
+    case bodyweight
 
     public func serialize<S: Serializer>(serializer: S) throws {
         try serializer.increase_container_depth()
         switch self {
-        case .start:
+        case .kg:
             try serializer.serialize_variant_index(value: 0)
-        case .stop:
+        case .lb:
             try serializer.serialize_variant_index(value: 1)
+        case .bodyweight:
+            try serializer.serialize_variant_index(value: 2)
         }
         try serializer.decrease_container_depth()
     }
@@ -1025,21 +3855,24 @@ indirect public enum TimerOperation: Hashable {
         return serializer.get_bytes()
     }
 
-    public static func deserialize<D: Deserializer>(deserializer: D) throws -> TimerOperation {
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> WeightUnit {
         let index = try deserializer.deserialize_variant_index()
         try deserializer.increase_container_depth()
         switch index {
         case 0:
             try deserializer.decrease_container_depth()
-            return .start
+            return .kg
         case 1:
             try deserializer.decrease_container_depth()
-            return .stop
-        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for TimerOperation: \(index)")
+            return .lb
+        case 2:
+            try deserializer.decrease_container_depth()
+            return .bodyweight
+        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for WeightUnit: \(index)")
         }
     }
 
-    public static func bincodeDeserialize(input: [UInt8]) throws -> TimerOperation {
+    public static func bincodeDeserialize(input: [UInt8]) throws -> WeightUnit {
         let deserializer = BincodeDeserializer.init(input: input);
         let obj = try deserialize(deserializer: deserializer)
         if deserializer.get_buffer_offset() < input.count {
@@ -1049,21 +3882,86 @@ indirect public enum TimerOperation: Hashable {
     }
 }
 
-indirect public enum TimerOutput: Hashable {
-    case tick
-    case started
-    case stopped
+public struct Workout: Hashable {
+    @Indirect public var schema_version: UInt32
+    @Indirect public var id: String
+    @Indirect public var name: String
+    @Indirect public var note: String?
+    @Indirect public var duration: Int32?
+    @Indirect public var start_timestamp: SharedTypes.Timestamp
+    @Indirect public var end_timestamp: SharedTypes.Timestamp?
+    @Indirect public var exercises: [SharedTypes.Exercise]
+
+    public init(schema_version: UInt32, id: String, name: String, note: String?, duration: Int32?, start_timestamp: SharedTypes.Timestamp, end_timestamp: SharedTypes.Timestamp?, exercises: [SharedTypes.Exercise]) {
+        self.schema_version = schema_version
+        self.id = id
+        self.name = name
+        self.note = note
+        self.duration = duration
+        self.start_timestamp = start_timestamp
+        self.end_timestamp = end_timestamp
+        self.exercises = exercises
+    }
 
     public func serialize<S: Serializer>(serializer: S) throws {
         try serializer.increase_container_depth()
-        switch self {
-        case .tick:
-            try serializer.serialize_variant_index(value: 0)
-        case .started:
-            try serializer.serialize_variant_index(value: 1)
-        case .stopped:
-            try serializer.serialize_variant_index(value: 2)
+        try serializer.serialize_u32(value: self.schema_version)
+        try serializer.serialize_str(value: self.id)
+        try serializer.serialize_str(value: self.name)
+        try serialize_option_str(value: self.note, serializer: serializer)
+        try serialize_option_i32(value: self.duration, serializer: serializer)
+        try self.start_timestamp.serialize(serializer: serializer)
+        try serialize_option_Timestamp(value: self.end_timestamp, serializer: serializer)
+        try serialize_vector_Exercise(value: self.exercises, serializer: serializer)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> Workout {
+        try deserializer.increase_container_depth()
+        let schema_version = try deserializer.deserialize_u32()
+        let id = try deserializer.deserialize_str()
+        let name = try deserializer.deserialize_str()
+        let note = try deserialize_option_str(deserializer: deserializer)
+        let duration = try deserialize_option_i32(deserializer: deserializer)
+        let start_timestamp = try SharedTypes.Timestamp.deserialize(deserializer: deserializer)
+        let end_timestamp = try deserialize_option_Timestamp(deserializer: deserializer)
+        let exercises = try deserialize_vector_Exercise(deserializer: deserializer)
+        try deserializer.decrease_container_depth()
+        return Workout.init(schema_version: schema_version, id: id, name: name, note: note, duration: duration, start_timestamp: start_timestamp, end_timestamp: end_timestamp, exercises: exercises)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> Workout {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
         }
+        return obj
+    }
+}
+
+public struct WorkoutLoadFailure: Hashable {
+    @Indirect public var workout_id: String
+    @Indirect public var error: String
+    @Indirect public var raw_payload: String
+
+    public init(workout_id: String, error: String, raw_payload: String) {
+        self.workout_id = workout_id
+        self.error = error
+        self.raw_payload = raw_payload
+    }
+
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_str(value: self.workout_id)
+        try serializer.serialize_str(value: self.error)
+        try serializer.serialize_str(value: self.raw_payload)
         try serializer.decrease_container_depth()
     }
 
@@ -1073,24 +3971,16 @@ indirect public enum TimerOutput: Hashable {
         return serializer.get_bytes()
     }
 
-    public static func deserialize<D: Deserializer>(deserializer: D) throws -> TimerOutput {
-        let index = try deserializer.deserialize_variant_index()
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> WorkoutLoadFailure {
         try deserializer.increase_container_depth()
-        switch index {
-        case 0:
-            try deserializer.decrease_container_depth()
-            return .tick
-        case 1:
-            try deserializer.decrease_container_depth()
-            return .started
-        case 2:
-            try deserializer.decrease_container_depth()
-            return .stopped
-        default: throw DeserializationError.invalidInput(issue: "Unknown variant index for TimerOutput: \(index)")
-        }
+        let workout_id = try deserializer.deserialize_str()
+        let error = try deserializer.deserialize_str()
+        let raw_payload = try deserializer.deserialize_str()
+        try deserializer.decrease_container_depth()
+        return WorkoutLoadFailure.init(workout_id: workout_id, error: error, raw_payload: raw_payload)
     }
 
-    public static func bincodeDeserialize(input: [UInt8]) throws -> TimerOutput {
+    public static func bincodeDeserialize(input: [UInt8]) throws -> WorkoutLoadFailure {
         let deserializer = BincodeDeserializer.init(input: input);
         let obj = try deserialize(deserializer: deserializer)
         if deserializer.get_buffer_offset() < input.count {
@@ -1100,28 +3990,22 @@ indirect public enum TimerOutput: Hashable {
     }
 }
 
-public struct ViewModel: Hashable {
-    @Indirect public var selected_tab: SharedTypes.Tab
-    @Indirect public var workout_view: SharedTypes.WorkoutViewModel
-    @Indirect public var history_view: SharedTypes.HistoryViewModel
-    @Indirect public var error_message: String?
-    @Indirect public var is_loading: Bool
+public struct WorkoutSummaryViewModel: Hashable {
+    @Indirect public var workout_name: String
+    @Indirect public var text: String
+    @Indirect public var personal_records: [String]
 
-    public init(selected_tab: SharedTypes.Tab, workout_view: SharedTypes.WorkoutViewModel, history_view: SharedTypes.HistoryViewModel, error_message: String?, is_loading: Bool) {
-        self.selected_tab = selected_tab
-        self.workout_view = workout_view
-        self.history_view = history_view
-        self.error_message = error_message
-        self.is_loading = is_loading
+    public init(workout_name: String, text: String, personal_records: [String]) {
+        self.workout_name = workout_name
+        self.text = text
+        self.personal_records = personal_records
     }
 
     public func serialize<S: Serializer>(serializer: S) throws {
         try serializer.increase_container_depth()
-        try self.selected_tab.serialize(serializer: serializer)
-        try self.workout_view.serialize(serializer: serializer)
-        try self.history_view.serialize(serializer: serializer)
-        try serialize_option_str(value: self.error_message, serializer: serializer)
-        try serializer.serialize_bool(value: self.is_loading)
+        try serializer.serialize_str(value: self.workout_name)
+        try serializer.serialize_str(value: self.text)
+        try serialize_vector_str(value: self.personal_records, serializer: serializer)
         try serializer.decrease_container_depth()
     }
 
@@ -1131,18 +4015,16 @@ public struct ViewModel: Hashable {
         return serializer.get_bytes()
     }
 
-    public static func deserialize<D: Deserializer>(deserializer: D) throws -> ViewModel {
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> WorkoutSummaryViewModel {
         try deserializer.increase_container_depth()
-        let selected_tab = try SharedTypes.Tab.deserialize(deserializer: deserializer)
-        let workout_view = try SharedTypes.WorkoutViewModel.deserialize(deserializer: deserializer)
-        let history_view = try SharedTypes.HistoryViewModel.deserialize(deserializer: deserializer)
-        let error_message = try deserialize_option_str(deserializer: deserializer)
-        let is_loading = try deserializer.deserialize_bool()
+        let workout_name = try deserializer.deserialize_str()
+        let text = try deserializer.deserialize_str()
+        let personal_records = try deserialize_vector_str(deserializer: deserializer)
         try deserializer.decrease_container_depth()
-        return ViewModel.init(selected_tab: selected_tab, workout_view: workout_view, history_view: history_view, error_message: error_message, is_loading: is_loading)
+        return WorkoutSummaryViewModel.init(workout_name: workout_name, text: text, personal_records: personal_records)
     }
 
-    public static func bincodeDeserialize(input: [UInt8]) throws -> ViewModel {
+    public static func bincodeDeserialize(input: [UInt8]) throws -> WorkoutSummaryViewModel {
         let deserializer = BincodeDeserializer.init(input: input);
         let obj = try deserialize(deserializer: deserializer)
         if deserializer.get_buffer_offset() < input.count {
@@ -1179,52 +4061,322 @@ public struct WorkoutViewModel: Hashable {
         self.showing_rest_timer = showing_rest_timer
     }
 
-    public func serialize<S: Serializer>(serializer: S) throws {
-        try serializer.increase_container_depth()
-        try serializer.serialize_bool(value: self.has_active_workout)
-        try serializer.serialize_str(value: self.workout_name)
-        try serializer.serialize_str(value: self.formatted_duration)
-        try serializer.serialize_i32(value: self.total_volume)
-        try serializer.serialize_u64(value: self.total_sets)
-        try serialize_vector_ExerciseViewModel(value: self.exercises, serializer: serializer)
-        try serializer.serialize_bool(value: self.timer_running)
-        try serializer.serialize_bool(value: self.showing_add_exercise)
-        try serializer.serialize_bool(value: self.showing_import)
-        try serializer.serialize_bool(value: self.showing_stopwatch)
-        try serialize_option_i32(value: self.showing_rest_timer, serializer: serializer)
-        try serializer.decrease_container_depth()
+    public func serialize<S: Serializer>(serializer: S) throws {
+        try serializer.increase_container_depth()
+        try serializer.serialize_bool(value: self.has_active_workout)
+        try serializer.serialize_str(value: self.workout_name)
+        try serializer.serialize_str(value: self.formatted_duration)
+        try serializer.serialize_i32(value: self.total_volume)
+        try serializer.serialize_u64(value: self.total_sets)
+        try serialize_vector_ExerciseViewModel(value: self.exercises, serializer: serializer)
+        try serializer.serialize_bool(value: self.timer_running)
+        try serializer.serialize_bool(value: self.showing_add_exercise)
+        try serializer.serialize_bool(value: self.showing_import)
+        try serializer.serialize_bool(value: self.showing_stopwatch)
+        try serialize_option_i32(value: self.showing_rest_timer, serializer: serializer)
+        try serializer.decrease_container_depth()
+    }
+
+    public func bincodeSerialize() throws -> [UInt8] {
+        let serializer = BincodeSerializer.init();
+        try self.serialize(serializer: serializer)
+        return serializer.get_bytes()
+    }
+
+    public static func deserialize<D: Deserializer>(deserializer: D) throws -> WorkoutViewModel {
+        try deserializer.increase_container_depth()
+        let has_active_workout = try deserializer.deserialize_bool()
+        let workout_name = try deserializer.deserialize_str()
+        let formatted_duration = try deserializer.deserialize_str()
+        let total_volume = try deserializer.deserialize_i32()
+        let total_sets = try deserializer.deserialize_u64()
+        let exercises = try deserialize_vector_ExerciseViewModel(deserializer: deserializer)
+        let timer_running = try deserializer.deserialize_bool()
+        let showing_add_exercise = try deserializer.deserialize_bool()
+        let showing_import = try deserializer.deserialize_bool()
+        let showing_stopwatch = try deserializer.deserialize_bool()
+        let showing_rest_timer = try deserialize_option_i32(deserializer: deserializer)
+        try deserializer.decrease_container_depth()
+        return WorkoutViewModel.init(has_active_workout: has_active_workout, workout_name: workout_name, formatted_duration: formatted_duration, total_volume: total_volume, total_sets: total_sets, exercises: exercises, timer_running: timer_running, showing_add_exercise: showing_add_exercise, showing_import: showing_import, showing_stopwatch: showing_stopwatch, showing_rest_timer: showing_rest_timer)
+    }
+
+    public static func bincodeDeserialize(input: [UInt8]) throws -> WorkoutViewModel {
+        let deserializer = BincodeDeserializer.init(input: input);
+        let obj = try deserialize(deserializer: deserializer)
+        if deserializer.get_buffer_offset() < input.count {
+            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
+        }
+        return obj
+    }
+}
+
+func serialize_option_BodyPart<S: Serializer>(value: SharedTypes.BodyPart?, serializer: S) throws {
+    if let value = value {
+        try serializer.serialize_option_tag(value: true)
+        try value.serialize(serializer: serializer)
+    } else {
+        try serializer.serialize_option_tag(value: false)
+    }
+}
+
+func deserialize_option_BodyPart<D: Deserializer>(deserializer: D) throws -> SharedTypes.BodyPart? {
+    let tag = try deserializer.deserialize_option_tag()
+    if tag {
+        return try SharedTypes.BodyPart.deserialize(deserializer: deserializer)
+    } else {
+        return nil
+    }
+}
+
+func serialize_option_CsvImportPreviewViewModel<S: Serializer>(value: SharedTypes.CsvImportPreviewViewModel?, serializer: S) throws {
+    if let value = value {
+        try serializer.serialize_option_tag(value: true)
+        try value.serialize(serializer: serializer)
+    } else {
+        try serializer.serialize_option_tag(value: false)
+    }
+}
+
+func deserialize_option_CsvImportPreviewViewModel<D: Deserializer>(deserializer: D) throws -> SharedTypes.CsvImportPreviewViewModel? {
+    let tag = try deserializer.deserialize_option_tag()
+    if tag {
+        return try SharedTypes.CsvImportPreviewViewModel.deserialize(deserializer: deserializer)
+    } else {
+        return nil
+    }
+}
+
+func serialize_option_DeleteConfirmationViewModel<S: Serializer>(value: SharedTypes.DeleteConfirmationViewModel?, serializer: S) throws {
+    if let value = value {
+        try serializer.serialize_option_tag(value: true)
+        try value.serialize(serializer: serializer)
+    } else {
+        try serializer.serialize_option_tag(value: false)
+    }
+}
+
+func deserialize_option_DeleteConfirmationViewModel<D: Deserializer>(deserializer: D) throws -> SharedTypes.DeleteConfirmationViewModel? {
+    let tag = try deserializer.deserialize_option_tag()
+    if tag {
+        return try SharedTypes.DeleteConfirmationViewModel.deserialize(deserializer: deserializer)
+    } else {
+        return nil
+    }
+}
+
+func serialize_option_ErrorViewModel<S: Serializer>(value: SharedTypes.ErrorViewModel?, serializer: S) throws {
+    if let value = value {
+        try serializer.serialize_option_tag(value: true)
+        try value.serialize(serializer: serializer)
+    } else {
+        try serializer.serialize_option_tag(value: false)
+    }
+}
+
+func deserialize_option_ErrorViewModel<D: Deserializer>(deserializer: D) throws -> SharedTypes.ErrorViewModel? {
+    let tag = try deserializer.deserialize_option_tag()
+    if tag {
+        return try SharedTypes.ErrorViewModel.deserialize(deserializer: deserializer)
+    } else {
+        return nil
+    }
+}
+
+func serialize_option_ExerciseHistoryViewModel<S: Serializer>(value: SharedTypes.ExerciseHistoryViewModel?, serializer: S) throws {
+    if let value = value {
+        try serializer.serialize_option_tag(value: true)
+        try value.serialize(serializer: serializer)
+    } else {
+        try serializer.serialize_option_tag(value: false)
+    }
+}
+
+func deserialize_option_ExerciseHistoryViewModel<D: Deserializer>(deserializer: D) throws -> SharedTypes.ExerciseHistoryViewModel? {
+    let tag = try deserializer.deserialize_option_tag()
+    if tag {
+        return try SharedTypes.ExerciseHistoryViewModel.deserialize(deserializer: deserializer)
+    } else {
+        return nil
+    }
+}
+
+func serialize_option_HistoryCursor<S: Serializer>(value: SharedTypes.HistoryCursor?, serializer: S) throws {
+    if let value = value {
+        try serializer.serialize_option_tag(value: true)
+        try value.serialize(serializer: serializer)
+    } else {
+        try serializer.serialize_option_tag(value: false)
+    }
+}
+
+func deserialize_option_HistoryCursor<D: Deserializer>(deserializer: D) throws -> SharedTypes.HistoryCursor? {
+    let tag = try deserializer.deserialize_option_tag()
+    if tag {
+        return try SharedTypes.HistoryCursor.deserialize(deserializer: deserializer)
+    } else {
+        return nil
+    }
+}
+
+func serialize_option_HistoryDetailViewModel<S: Serializer>(value: SharedTypes.HistoryDetailViewModel?, serializer: S) throws {
+    if let value = value {
+        try serializer.serialize_option_tag(value: true)
+        try value.serialize(serializer: serializer)
+    } else {
+        try serializer.serialize_option_tag(value: false)
+    }
+}
+
+func deserialize_option_HistoryDetailViewModel<D: Deserializer>(deserializer: D) throws -> SharedTypes.HistoryDetailViewModel? {
+    let tag = try deserializer.deserialize_option_tag()
+    if tag {
+        return try SharedTypes.HistoryDetailViewModel.deserialize(deserializer: deserializer)
+    } else {
+        return nil
+    }
+}
+
+func serialize_option_HistoryEditViewModel<S: Serializer>(value: SharedTypes.HistoryEditViewModel?, serializer: S) throws {
+    if let value = value {
+        try serializer.serialize_option_tag(value: true)
+        try value.serialize(serializer: serializer)
+    } else {
+        try serializer.serialize_option_tag(value: false)
+    }
+}
+
+func deserialize_option_HistoryEditViewModel<D: Deserializer>(deserializer: D) throws -> SharedTypes.HistoryEditViewModel? {
+    let tag = try deserializer.deserialize_option_tag()
+    if tag {
+        return try SharedTypes.HistoryEditViewModel.deserialize(deserializer: deserializer)
+    } else {
+        return nil
+    }
+}
+
+func serialize_option_ImportSummaryViewModel<S: Serializer>(value: SharedTypes.ImportSummaryViewModel?, serializer: S) throws {
+    if let value = value {
+        try serializer.serialize_option_tag(value: true)
+        try value.serialize(serializer: serializer)
+    } else {
+        try serializer.serialize_option_tag(value: false)
+    }
+}
+
+func deserialize_option_ImportSummaryViewModel<D: Deserializer>(deserializer: D) throws -> SharedTypes.ImportSummaryViewModel? {
+    let tag = try deserializer.deserialize_option_tag()
+    if tag {
+        return try SharedTypes.ImportSummaryViewModel.deserialize(deserializer: deserializer)
+    } else {
+        return nil
+    }
+}
+
+func serialize_option_LoadProblemsViewModel<S: Serializer>(value: SharedTypes.LoadProblemsViewModel?, serializer: S) throws {
+    if let value = value {
+        try serializer.serialize_option_tag(value: true)
+        try value.serialize(serializer: serializer)
+    } else {
+        try serializer.serialize_option_tag(value: false)
+    }
+}
+
+func deserialize_option_LoadProblemsViewModel<D: Deserializer>(deserializer: D) throws -> SharedTypes.LoadProblemsViewModel? {
+    let tag = try deserializer.deserialize_option_tag()
+    if tag {
+        return try SharedTypes.LoadProblemsViewModel.deserialize(deserializer: deserializer)
+    } else {
+        return nil
+    }
+}
+
+func serialize_option_SyncState<S: Serializer>(value: SharedTypes.SyncState?, serializer: S) throws {
+    if let value = value {
+        try serializer.serialize_option_tag(value: true)
+        try value.serialize(serializer: serializer)
+    } else {
+        try serializer.serialize_option_tag(value: false)
+    }
+}
+
+func deserialize_option_SyncState<D: Deserializer>(deserializer: D) throws -> SharedTypes.SyncState? {
+    let tag = try deserializer.deserialize_option_tag()
+    if tag {
+        return try SharedTypes.SyncState.deserialize(deserializer: deserializer)
+    } else {
+        return nil
+    }
+}
+
+func serialize_option_Timestamp<S: Serializer>(value: SharedTypes.Timestamp?, serializer: S) throws {
+    if let value = value {
+        try serializer.serialize_option_tag(value: true)
+        try value.serialize(serializer: serializer)
+    } else {
+        try serializer.serialize_option_tag(value: false)
+    }
+}
+
+func deserialize_option_Timestamp<D: Deserializer>(deserializer: D) throws -> SharedTypes.Timestamp? {
+    let tag = try deserializer.deserialize_option_tag()
+    if tag {
+        return try SharedTypes.Timestamp.deserialize(deserializer: deserializer)
+    } else {
+        return nil
+    }
+}
+
+func serialize_option_WeightUnit<S: Serializer>(value: SharedTypes.WeightUnit?, serializer: S) throws {
+    if let value = value {
+        try serializer.serialize_option_tag(value: true)
+        try value.serialize(serializer: serializer)
+    } else {
+        try serializer.serialize_option_tag(value: false)
     }
+}
 
-    public func bincodeSerialize() throws -> [UInt8] {
-        let serializer = BincodeSerializer.init();
-        try self.serialize(serializer: serializer)
-        return serializer.get_bytes()
+func deserialize_option_WeightUnit<D: Deserializer>(deserializer: D) throws -> SharedTypes.WeightUnit? {
+    let tag = try deserializer.deserialize_option_tag()
+    if tag {
+        return try SharedTypes.WeightUnit.deserialize(deserializer: deserializer)
+    } else {
+        return nil
     }
+}
 
-    public static func deserialize<D: Deserializer>(deserializer: D) throws -> WorkoutViewModel {
-        try deserializer.increase_container_depth()
-        let has_active_workout = try deserializer.deserialize_bool()
-        let workout_name = try deserializer.deserialize_str()
-        let formatted_duration = try deserializer.deserialize_str()
-        let total_volume = try deserializer.deserialize_i32()
-        let total_sets = try deserializer.deserialize_u64()
-        let exercises = try deserialize_vector_ExerciseViewModel(deserializer: deserializer)
-        let timer_running = try deserializer.deserialize_bool()
-        let showing_add_exercise = try deserializer.deserialize_bool()
-        let showing_import = try deserializer.deserialize_bool()
-        let showing_stopwatch = try deserializer.deserialize_bool()
-        let showing_rest_timer = try deserialize_option_i32(deserializer: deserializer)
-        try deserializer.decrease_container_depth()
-        return WorkoutViewModel.init(has_active_workout: has_active_workout, workout_name: workout_name, formatted_duration: formatted_duration, total_volume: total_volume, total_sets: total_sets, exercises: exercises, timer_running: timer_running, showing_add_exercise: showing_add_exercise, showing_import: showing_import, showing_stopwatch: showing_stopwatch, showing_rest_timer: showing_rest_timer)
+func serialize_option_Workout<S: Serializer>(value: SharedTypes.Workout?, serializer: S) throws {
+    if let value = value {
+        try serializer.serialize_option_tag(value: true)
+        try value.serialize(serializer: serializer)
+    } else {
+        try serializer.serialize_option_tag(value: false)
     }
+}
 
-    public static func bincodeDeserialize(input: [UInt8]) throws -> WorkoutViewModel {
-        let deserializer = BincodeDeserializer.init(input: input);
-        let obj = try deserialize(deserializer: deserializer)
-        if deserializer.get_buffer_offset() < input.count {
-            throw DeserializationError.invalidInput(issue: "Some input bytes were not read")
-        }
-        return obj
+func deserialize_option_Workout<D: Deserializer>(deserializer: D) throws -> SharedTypes.Workout? {
+    let tag = try deserializer.deserialize_option_tag()
+    if tag {
+        return try SharedTypes.Workout.deserialize(deserializer: deserializer)
+    } else {
+        return nil
+    }
+}
+
+func serialize_option_WorkoutSummaryViewModel<S: Serializer>(value: SharedTypes.WorkoutSummaryViewModel?, serializer: S) throws {
+    if let value = value {
+        try serializer.serialize_option_tag(value: true)
+        try value.serialize(serializer: serializer)
+    } else {
+        try serializer.serialize_option_tag(value: false)
+    }
+}
+
+func deserialize_option_WorkoutSummaryViewModel<D: Deserializer>(deserializer: D) throws -> SharedTypes.WorkoutSummaryViewModel? {
+    let tag = try deserializer.deserialize_option_tag()
+    if tag {
+        return try SharedTypes.WorkoutSummaryViewModel.deserialize(deserializer: deserializer)
+    } else {
+        return nil
     }
 }
 
@@ -1282,6 +4434,152 @@ func deserialize_option_str<D: Deserializer>(deserializer: D) throws -> String?
     }
 }
 
+func serialize_option_vector_str<S: Serializer>(value: [String]?, serializer: S) throws {
+    if let value = value {
+        try serializer.serialize_option_tag(value: true)
+        try serialize_vector_str(value: value, serializer: serializer)
+    } else {
+        try serializer.serialize_option_tag(value: false)
+    }
+}
+
+func deserialize_option_vector_str<D: Deserializer>(deserializer: D) throws -> [String]? {
+    let tag = try deserializer.deserialize_option_tag()
+    if tag {
+        return try deserialize_vector_str(deserializer: deserializer)
+    } else {
+        return nil
+    }
+}
+
+func serialize_vector_Change<S: Serializer>(value: [SharedTypes.Change], serializer: S) throws {
+    try serializer.serialize_len(value: value.count)
+    for item in value {
+        try item.serialize(serializer: serializer)
+    }
+}
+
+func deserialize_vector_Change<D: Deserializer>(deserializer: D) throws -> [SharedTypes.Change] {
+    let length = try deserializer.deserialize_len()
+    var obj : [SharedTypes.Change] = []
+    for _ in 0..<length {
+        obj.append(try SharedTypes.Change.deserialize(deserializer: deserializer))
+    }
+    return obj
+}
+
+func serialize_vector_ChartPointViewModel<S: Serializer>(value: [SharedTypes.ChartPointViewModel], serializer: S) throws {
+    try serializer.serialize_len(value: value.count)
+    for item in value {
+        try item.serialize(serializer: serializer)
+    }
+}
+
+func deserialize_vector_ChartPointViewModel<D: Deserializer>(deserializer: D) throws -> [SharedTypes.ChartPointViewModel] {
+    let length = try deserializer.deserialize_len()
+    var obj : [SharedTypes.ChartPointViewModel] = []
+    for _ in 0..<length {
+        obj.append(try SharedTypes.ChartPointViewModel.deserialize(deserializer: deserializer))
+    }
+    return obj
+}
+
+func serialize_vector_CorruptWorkoutViewModel<S: Serializer>(value: [SharedTypes.CorruptWorkoutViewModel], serializer: S) throws {
+    try serializer.serialize_len(value: value.count)
+    for item in value {
+        try item.serialize(serializer: serializer)
+    }
+}
+
+func deserialize_vector_CorruptWorkoutViewModel<D: Deserializer>(deserializer: D) throws -> [SharedTypes.CorruptWorkoutViewModel] {
+    let length = try deserializer.deserialize_len()
+    var obj : [SharedTypes.CorruptWorkoutViewModel] = []
+    for _ in 0..<length {
+        obj.append(try SharedTypes.CorruptWorkoutViewModel.deserialize(deserializer: deserializer))
+    }
+    return obj
+}
+
+func serialize_vector_ErrorViewModel<S: Serializer>(value: [SharedTypes.ErrorViewModel], serializer: S) throws {
+    try serializer.serialize_len(value: value.count)
+    for item in value {
+        try item.serialize(serializer: serializer)
+    }
+}
+
+func deserialize_vector_ErrorViewModel<D: Deserializer>(deserializer: D) throws -> [SharedTypes.ErrorViewModel] {
+    let length = try deserializer.deserialize_len()
+    var obj : [SharedTypes.ErrorViewModel] = []
+    for _ in 0..<length {
+        obj.append(try SharedTypes.ErrorViewModel.deserialize(deserializer: deserializer))
+    }
+    return obj
+}
+
+func serialize_vector_Exercise<S: Serializer>(value: [SharedTypes.Exercise], serializer: S) throws {
+    try serializer.serialize_len(value: value.count)
+    for item in value {
+        try item.serialize(serializer: serializer)
+    }
+}
+
+func deserialize_vector_Exercise<D: Deserializer>(deserializer: D) throws -> [SharedTypes.Exercise] {
+    let length = try deserializer.deserialize_len()
+    var obj : [SharedTypes.Exercise] = []
+    for _ in 0..<length {
+        obj.append(try SharedTypes.Exercise.deserialize(deserializer: deserializer))
+    }
+    return obj
+}
+
+func serialize_vector_ExerciseDetailViewModel<S: Serializer>(value: [SharedTypes.ExerciseDetailViewModel], serializer: S) throws {
+    try serializer.serialize_len(value: value.count)
+    for item in value {
+        try item.serialize(serializer: serializer)
+    }
+}
+
+func deserialize_vector_ExerciseDetailViewModel<D: Deserializer>(deserializer: D) throws -> [SharedTypes.ExerciseDetailViewModel] {
+    let length = try deserializer.deserialize_len()
+    var obj : [SharedTypes.ExerciseDetailViewModel] = []
+    for _ in 0..<length {
+        obj.append(try SharedTypes.ExerciseDetailViewModel.deserialize(deserializer: deserializer))
+    }
+    return obj
+}
+
+func serialize_vector_ExerciseSessionViewModel<S: Serializer>(value: [SharedTypes.ExerciseSessionViewModel], serializer: S) throws {
+    try serializer.serialize_len(value: value.count)
+    for item in value {
+        try item.serialize(serializer: serializer)
+    }
+}
+
+func deserialize_vector_ExerciseSessionViewModel<D: Deserializer>(deserializer: D) throws -> [SharedTypes.ExerciseSessionViewModel] {
+    let length = try deserializer.deserialize_len()
+    var obj : [SharedTypes.ExerciseSessionViewModel] = []
+    for _ in 0..<length {
+        obj.append(try SharedTypes.ExerciseSessionViewModel.deserialize(deserializer: deserializer))
+    }
+    return obj
+}
+
+func serialize_vector_ExerciseSet<S: Serializer>(value: [SharedTypes.ExerciseSet], serializer: S) throws {
+    try serializer.serialize_len(value: value.count)
+    for item in value {
+        try item.serialize(serializer: serializer)
+    }
+}
+
+func deserialize_vector_ExerciseSet<D: Deserializer>(deserializer: D) throws -> [SharedTypes.ExerciseSet] {
+    let length = try deserializer.deserialize_len()
+    var obj : [SharedTypes.ExerciseSet] = []
+    for _ in 0..<length {
+        obj.append(try SharedTypes.ExerciseSet.deserialize(deserializer: deserializer))
+    }
+    return obj
+}
+
 func serialize_vector_ExerciseViewModel<S: Serializer>(value: [SharedTypes.ExerciseViewModel], serializer: S) throws {
     try serializer.serialize_len(value: value.count)
     for item in value {
@@ -1314,6 +4612,54 @@ func deserialize_vector_HistoryItemViewModel<D: Deserializer>(deserializer: D) t
     return obj
 }
 
+func serialize_vector_HttpHeader<S: Serializer>(value: [SharedTypes.HttpHeader], serializer: S) throws {
+    try serializer.serialize_len(value: value.count)
+    for item in value {
+        try item.serialize(serializer: serializer)
+    }
+}
+
+func deserialize_vector_HttpHeader<D: Deserializer>(deserializer: D) throws -> [SharedTypes.HttpHeader] {
+    let length = try deserializer.deserialize_len()
+    var obj : [SharedTypes.HttpHeader] = []
+    for _ in 0..<length {
+        obj.append(try SharedTypes.HttpHeader.deserialize(deserializer: deserializer))
+    }
+    return obj
+}
+
+func serialize_vector_ImportEntry<S: Serializer>(value: [SharedTypes.ImportEntry], serializer: S) throws {
+    try serializer.serialize_len(value: value.count)
+    for item in value {
+        try item.serialize(serializer: serializer)
+    }
+}
+
+func deserialize_vector_ImportEntry<D: Deserializer>(deserializer: D) throws -> [SharedTypes.ImportEntry] {
+    let length = try deserializer.deserialize_len()
+    var obj : [SharedTypes.ImportEntry] = []
+    for _ in 0..<length {
+        obj.append(try SharedTypes.ImportEntry.deserialize(deserializer: deserializer))
+    }
+    return obj
+}
+
+func serialize_vector_SetDetailViewModel<S: Serializer>(value: [SharedTypes.SetDetailViewModel], serializer: S) throws {
+    try serializer.serialize_len(value: value.count)
+    for item in value {
+        try item.serialize(serializer: serializer)
+    }
+}
+
+func deserialize_vector_SetDetailViewModel<D: Deserializer>(deserializer: D) throws -> [SharedTypes.SetDetailViewModel] {
+    let length = try deserializer.deserialize_len()
+    var obj : [SharedTypes.SetDetailViewModel] = []
+    for _ in 0..<length {
+        obj.append(try SharedTypes.SetDetailViewModel.deserialize(deserializer: deserializer))
+    }
+    return obj
+}
+
 func serialize_vector_SetViewModel<S: Serializer>(value: [SharedTypes.SetViewModel], serializer: S) throws {
     try serializer.serialize_len(value: value.count)
     for item in value {
@@ -1330,6 +4676,38 @@ func deserialize_vector_SetViewModel<D: Deserializer>(deserializer: D) throws ->
     return obj
 }
 
+func serialize_vector_Workout<S: Serializer>(value: [SharedTypes.Workout], serializer: S) throws {
+    try serializer.serialize_len(value: value.count)
+    for item in value {
+        try item.serialize(serializer: serializer)
+    }
+}
+
+func deserialize_vector_Workout<D: Deserializer>(deserializer: D) throws -> [SharedTypes.Workout] {
+    let length = try deserializer.deserialize_len()
+    var obj : [SharedTypes.Workout] = []
+    for _ in 0..<length {
+        obj.append(try SharedTypes.Workout.deserialize(deserializer: deserializer))
+    }
+    return obj
+}
+
+func serialize_vector_WorkoutLoadFailure<S: Serializer>(value: [SharedTypes.WorkoutLoadFailure], serializer: S) throws {
+    try serializer.serialize_len(value: value.count)
+    for item in value {
+        try item.serialize(serializer: serializer)
+    }
+}
+
+func deserialize_vector_WorkoutLoadFailure<D: Deserializer>(deserializer: D) throws -> [SharedTypes.WorkoutLoadFailure] {
+    let length = try deserializer.deserialize_len()
+    var obj : [SharedTypes.WorkoutLoadFailure] = []
+    for _ in 0..<length {
+        obj.append(try SharedTypes.WorkoutLoadFailure.deserialize(deserializer: deserializer))
+    }
+    return obj
+}
+
 func serialize_vector_str<S: Serializer>(value: [String], serializer: S) throws {
     try serializer.serialize_len(value: value.count)
     for item in value {
@@ -1346,3 +4724,19 @@ func deserialize_vector_str<D: Deserializer>(deserializer: D) throws -> [String]
     return obj
 }
 
+func serialize_vector_u8<S: Serializer>(value: [UInt8], serializer: S) throws {
+    try serializer.serialize_len(value: value.count)
+    for item in value {
+        try serializer.serialize_u8(value: item)
+    }
+}
+
+func deserialize_vector_u8<D: Deserializer>(deserializer: D) throws -> [UInt8] {
+    let length = try deserializer.deserialize_len()
+    var obj : [UInt8] = []
+    for _ in 0..<length {
+        obj.append(try deserializer.deserialize_u8())
+    }
+    return obj
+}
+